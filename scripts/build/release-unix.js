@@ -42,7 +42,7 @@ function requireCommand(command, help) {
 }
 
 function assertUpdaterSigningEnvironment() {
-    for (const name of ['TAURI_SIGNING_PRIVATE_KEY', 'TAURI_SIGNING_PRIVATE_KEY_PASSWORD']) {
+    for (const name of ['TAURI_SIGNING_PRIVATE_KEY', 'TAURI_SIGNING_PRIVATE_KEY_PASSWORD', 'SOURCCEY_UPDATE_PUBLIC_KEY']) {
         if (!process.env[name]) fail(`${name} is required for release updater artifacts.`);
     }
 }
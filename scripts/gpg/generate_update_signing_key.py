@@ -0,0 +1,20 @@
+import base64
+from cryptography.hazmat.primitives import serialization  # pip install cryptography
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+
+# Generates the Ed25519 keypair used to sign updater manifests and checksums.
+# Build the app with SOURCCEY_UPDATE_PUBLIC_KEY set to the public key, and keep the
+# private key in SOURCCEY_UPDATE_SIGNING_KEY wherever releases are published.
+private_key = Ed25519PrivateKey.generate()
+private_der = private_key.private_bytes(
+    encoding=serialization.Encoding.DER,
+    format=serialization.PrivateFormat.PKCS8,
+    encryption_algorithm=serialization.NoEncryption(),
+)
+public_raw = private_key.public_key().public_bytes(
+    encoding=serialization.Encoding.Raw,
+    format=serialization.PublicFormat.Raw,
+)
+
+print(f"SOURCCEY_UPDATE_PUBLIC_KEY={base64.b64encode(public_raw).decode()}")
+print(f"SOURCCEY_UPDATE_SIGNING_KEY={base64.b64encode(private_der).decode()}")
@@ -0,0 +1,49 @@
+import base64
+import json
+import os
+import sys
+from datetime import datetime, timedelta, timezone
+from cryptography.hazmat.primitives import serialization  # pip install cryptography
+
+# Writes <file>.sig next to each file (e.g. latest.json, lerobot-vulcan_*.zip.sha256).
+# Upload the .sig alongside the file; the app refuses unsigned files when built
+# with SOURCCEY_UPDATE_PUBLIC_KEY.
+#
+# Updater manifests (JSON with a "version") get a fresh "expires_at" first, so
+# the version and expiry are both signed and an old manifest cannot be replayed.
+# SOURCCEY_MANIFEST_TTL_DAYS sets how long a manifest stays valid (default 30).
+if len(sys.argv) < 2:
+    print("Usage: SOURCCEY_UPDATE_SIGNING_KEY=<base64 pkcs8> python sign_update_file.py <file> [file...]")
+    sys.exit(1)
+
+encoded_key = os.environ.get("SOURCCEY_UPDATE_SIGNING_KEY", "").strip()
+if not encoded_key:
+    print("SOURCCEY_UPDATE_SIGNING_KEY is not set")
+    sys.exit(1)
+
+private_key = serialization.load_der_private_key(base64.b64decode(encoded_key), password=None)
+
+ttl_days = int(os.environ.get("SOURCCEY_MANIFEST_TTL_DAYS", "30"))
+
+
+def stamp_manifest_expiry(path):
+    if not path.endswith(".json"):
+        return
+    with open(path, "r", encoding="utf-8") as f:
+        manifest = json.load(f)
+    if not isinstance(manifest, dict) or "version" not in manifest:
+        return
+    expires_at = datetime.now(timezone.utc) + timedelta(days=ttl_days)
+    manifest["expires_at"] = expires_at.strftime("%Y-%m-%dT%H:%M:%SZ")
+    with open(path, "w", encoding="utf-8") as f:
+        f.write(json.dumps(manifest, indent=4) + "\n")
+    print(f"Set expires_at {manifest['expires_at']} in {path}")
+
+
+for path in sys.argv[1:]:
+    stamp_manifest_expiry(path)
+    with open(path, "rb") as f:
+        signature = private_key.sign(f.read())
+    with open(f"{path}.sig", "w") as f:
+        f.write(base64.b64encode(signature).decode() + "\n")
+    print(f"Signed {path} -> {path}.sig")
@@ -0,0 +1,264 @@
+#!/usr/bin/env python3
+"""
+Boot Guard for Sourccey Kiosk A/B Updates
+
+Installed next to the session launcher and run in front of the kiosk app:
+
+    sourccey-kiosk-boot-guard -- /usr/bin/<app> --kiosk
+
+While a slot that an update just switched to is still on probation, the guard
+counts its boots, health-checks the running app and, once the slot has failed
+too many boots, points the project root back at the previous slot and
+reinstalls that slot's package. None of this runs inside the new app build, so
+a build that crashes or hangs on startup is still rolled back.
+
+Slot state and update history are the JSON files kept by KioskSlotService in
+src-tauri/src/services/setup/kiosk_slot_service.rs.
+"""
+
+import json
+import os
+import re
+import socket
+import subprocess
+import sys
+import time
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import List, Optional
+
+DEFAULT_PROJECT_ROOT = "/home/sourccey/Desktop/Projects/sourccey-desktop"
+DEFAULT_MAX_BOOT_ATTEMPTS = 3
+SLOT_STATE_FILE = "slot_state.json"
+UPDATE_HISTORY_FILE = "update_history.json"
+PACKAGES_DIR = "packages"
+
+# The app gets this long to start before it is probed, and this long in total
+# to answer; a build that hangs on startup fails the boot when time runs out.
+HEALTH_CHECK_DELAY_SECS = 20
+HEALTH_CHECK_TIMEOUT_SECS = 180
+HEALTH_CHECK_INTERVAL_SECS = 5
+
+# Must match discovery_responder_service.rs
+DISCOVERY_MAGIC = "SOURCCEY_DISCOVER_V1"
+DISCOVERY_PORT = 42111
+
+
+def log(message: str):
+    print(f"[KioskBootGuard] {message}", file=sys.stderr, flush=True)
+
+
+#################################################################
+# Slot layout and state
+#################################################################
+
+def project_root() -> Path:
+    value = os.environ.get("SOURCCEY_PROJECT_ROOT", "").strip()
+    return Path(value or DEFAULT_PROJECT_ROOT)
+
+
+def slots_dir(root: Path) -> Path:
+    value = os.environ.get("SOURCCEY_KIOSK_SLOTS_DIR", "").strip()
+    if value:
+        return Path(value)
+    return root.with_name(f"{root.name}-slots")
+
+
+def max_boot_attempts() -> int:
+    try:
+        value = int(os.environ.get("SOURCCEY_KIOSK_MAX_BOOT_ATTEMPTS", ""))
+    except ValueError:
+        return DEFAULT_MAX_BOOT_ATTEMPTS
+    return value if value > 0 else DEFAULT_MAX_BOOT_ATTEMPTS
+
+
+def read_json(path: Path):
+    with open(path, "r", encoding="utf-8") as handle:
+        return json.load(handle)
+
+
+def write_json_atomically(path: Path, value):
+    tmp_path = path.with_suffix(".json.tmp")
+    with open(tmp_path, "w", encoding="utf-8") as handle:
+        json.dump(value, handle, indent=2)
+    os.replace(tmp_path, path)
+
+
+def switch_active(root: Path, slots: Path, slot: str):
+    """Repoint the project root at a slot the same way KioskSlotService does."""
+    target = slots / slot
+    if not target.exists():
+        raise RuntimeError(f"Kiosk slot {target} does not exist")
+    staging_link = root.with_name(f".{root.name}.switching")
+    if staging_link.is_symlink() or staging_link.exists():
+        staging_link.unlink()
+    os.symlink(target, staging_link)
+    os.replace(staging_link, root)
+
+
+def reinstall_package(slots: Path, slot: str):
+    package = slots / PACKAGES_DIR / f"{slot}.deb"
+    if not package.exists():
+        log(f"No saved package for slot {slot}; skipping reinstall")
+        return
+    result = subprocess.run(
+        ["sudo", "-n", "dpkg", "-i", str(package)],
+        capture_output=True,
+        text=True,
+    )
+    if result.returncode != 0:
+        log(f"Failed to reinstall package for slot {slot}: {result.stderr.strip()}")
+
+
+def parse_timestamp(value: str) -> Optional[datetime]:
+    # chrono writes nanoseconds; fromisoformat only takes microseconds
+    value = re.sub(r"(\.\d{6})\d+", r"\1", value).replace("Z", "+00:00")
+    try:
+        return datetime.fromisoformat(value)
+    except ValueError:
+        return None
+
+
+def finish_record(slots: Path, update_id: Optional[str], outcome: str, error: Optional[str]):
+    if not update_id:
+        return
+    history_path = slots / UPDATE_HISTORY_FILE
+    try:
+        history = read_json(history_path)
+    except (OSError, ValueError):
+        return
+    now = datetime.now(timezone.utc)
+    for record in history:
+        if record.get("id") != update_id:
+            continue
+        record["outcome"] = outcome
+        record["error"] = error
+        record["completedAt"] = now.isoformat()
+        started_at = parse_timestamp(record.get("startedAt", ""))
+        record["durationMs"] = (
+            int((now - started_at).total_seconds() * 1000) if started_at else None
+        )
+    write_json_atomically(history_path, history)
+
+
+#################################################################
+# Health check
+#################################################################
+
+def probe_discovery_responder(timeout: float = 2.0) -> bool:
+    with socket.socket(socket.AF_INET, socket.SOCK_DGRAM) as sock:
+        sock.settimeout(timeout)
+        try:
+            sock.sendto(DISCOVERY_MAGIC.encode(), ("127.0.0.1", DISCOVERY_PORT))
+            payload, _ = sock.recvfrom(1024)
+            return json.loads(payload).get("discovery_magic") == DISCOVERY_MAGIC
+        except (OSError, ValueError):
+            return False
+
+
+def wait_until_healthy(app: subprocess.Popen) -> bool:
+    """True once the app answers discovery; False if it exits or never answers."""
+    started = time.monotonic()
+    while time.monotonic() - started < HEALTH_CHECK_DELAY_SECS:
+        if app.poll() is not None:
+            return False
+        time.sleep(1)
+    while time.monotonic() - started < HEALTH_CHECK_TIMEOUT_SECS:
+        if app.poll() is not None:
+            return False
+        if probe_discovery_responder():
+            return True
+        time.sleep(HEALTH_CHECK_INTERVAL_SECS)
+    return False
+
+
+def stop_app(app: subprocess.Popen):
+    if app.poll() is not None:
+        return
+    app.terminate()
+    try:
+        app.wait(timeout=10)
+    except subprocess.TimeoutExpired:
+        app.kill()
+        app.wait()
+
+
+#################################################################
+# Main
+#################################################################
+
+def exec_app(command: List[str]):
+    os.execvp(command[0], command)
+
+
+def run(command: List[str]) -> int:
+    root = project_root()
+    slots = slots_dir(root)
+    state_path = slots / SLOT_STATE_FILE
+
+    # Kiosks that never ran an A/B update have a plain checkout and nothing to verify
+    if not root.is_symlink() or not state_path.exists():
+        exec_app(command)
+    try:
+        state = read_json(state_path)
+    except (OSError, ValueError) as e:
+        log(f"Could not read slot state: {e}")
+        exec_app(command)
+    if not state.get("pendingVerification"):
+        exec_app(command)
+
+    active = state.get("active")
+    previous = state.get("previous")
+    attempts = int(state.get("bootAttempts", 0)) + 1
+    max_attempts = max_boot_attempts()
+    state["bootAttempts"] = attempts
+
+    if previous and attempts > max_attempts:
+        log(f"Slot {active} failed {max_attempts} boots; rolling back to slot {previous}")
+        try:
+            switch_active(root, slots, previous)
+        except (OSError, RuntimeError) as e:
+            log(f"Rollback failed: {e}")
+            exec_app(command)
+        reinstall_package(slots, previous)
+        state.update(
+            active=previous,
+            previous=active,
+            pendingVerification=False,
+            bootAttempts=0,
+        )
+        write_json_atomically(state_path, state)
+        finish_record(
+            slots,
+            state.get("updateId"),
+            "rolled_back",
+            f"Failed health check on {max_attempts} boots",
+        )
+        exec_app(command)
+
+    write_json_atomically(state_path, state)
+    log(f"Verifying slot {active} (boot {attempts} of {max_attempts})")
+
+    app = subprocess.Popen(command)
+    if not wait_until_healthy(app):
+        log(f"Slot {active} failed its health check on boot {attempts}")
+        stop_app(app)
+        return 1
+
+    state["pendingVerification"] = False
+    state["bootAttempts"] = 0
+    write_json_atomically(state_path, state)
+    finish_record(slots, state.get("updateId"), "succeeded", None)
+    log(f"Slot {active} verified")
+    return app.wait()
+
+
+def main():
+    if "--" not in sys.argv or sys.argv.index("--") == len(sys.argv) - 1:
+        print(f"Usage: {sys.argv[0]} -- <app command...>", file=sys.stderr)
+        sys.exit(2)
+    sys.exit(run(sys.argv[sys.argv.index("--") + 1:]))
+
+
+if __name__ == "__main__":
+    main()
@@ -5,8 +5,11 @@ Session Files Module for Sourccey Kiosk
 Creates desktop session files and launcher scripts for kiosk mode.
 """
 
+from pathlib import Path
 from typing import Callable
 
+BOOT_GUARD_PATH = "/usr/local/bin/sourccey-kiosk-boot-guard"
+
 class SessionFilesCreator:
     def __init__(self, print_status: Callable, print_success: Callable,
                  print_error: Callable, write_file_as_root: Callable):
@@ -75,6 +78,11 @@ fi
 echo "$(date -Is) app_bin=$APP_BIN mode=${{SOURCCEY_APP_MODE:-unset}} display=${{DISPLAY:-unset}}" >> /tmp/sourccey-kiosk-launch.log
 
 openbox-session &
+
+# The boot guard verifies a freshly updated slot and rolls it back from outside the app
+if [ -x "{BOOT_GUARD_PATH}" ]; then
+    exec "{BOOT_GUARD_PATH}" -- "$APP_BIN" --kiosk
+fi
 exec "$APP_BIN" --kiosk
 """
 
@@ -90,6 +98,23 @@ exec "$APP_BIN" --kiosk
         self.print_success("Launcher script created")
         return True
 
+    def install_boot_guard(self) -> bool:
+        """Install the A/B update boot guard the launcher runs the app through"""
+        self.print_status("Installing kiosk boot guard...")
+
+        guard_source = Path(__file__).parent / "kiosk_boot_guard.py"
+        if not self.write_file_as_root(
+            BOOT_GUARD_PATH,
+            guard_source.read_text(),
+            mode=0o755,
+            executable=True
+        ):
+            self.print_error("Failed to install kiosk boot guard")
+            return False
+
+        self.print_success("Kiosk boot guard installed")
+        return True
+
     def create_all(self, binary_name: str, package_name: str, session_name: str) -> bool:
         """Create all session files"""
         self.print_status("Creating session files...")
@@ -100,6 +125,9 @@ exec "$APP_BIN" --kiosk
         if not self.create_launcher_script(binary_name, package_name, session_name):
             return False
 
+        if not self.install_boot_guard():
+            return False
+
         self.print_success("All session files created")
         return True
 
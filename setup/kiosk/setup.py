@@ -494,7 +494,8 @@ class KioskSetupScript:
             self.print_status("Your application will launch in fullscreen automatically")
             print()
 
-    def run(self, no_clean: bool = False, use_https: bool = True, skip_system: bool = False) -> bool:
+    def run(self, no_clean: bool = False, use_https: bool = True, skip_system: bool = False,
+            no_install: bool = False) -> bool:
         """Run the complete kiosk setup process"""
         self.print_header("SOURCCEY KIOSK SETUP")
 
@@ -619,6 +620,12 @@ class KioskSetupScript:
             self.print_error("Tauri build failed")
             return False
 
+        if no_install:
+            # A/B kiosk updates health-check the slot and install the package themselves
+            self.print_warning(f"Skipping install of {deb_path} (--no-install enabled)")
+            self.print_summary()
+            return len(self.errors) == 0
+
         if not self.install_deb(deb_path):
             self.print_error("Installation failed")
             return False
@@ -645,13 +652,16 @@ def main():
                        help='Skip apt update/upgrade and system dependency installation')
     parser.add_argument('--use-https', action='store_true', default=True,
                        help='Use HTTPS URLs for git operations (default: True)')
+    parser.add_argument('--no-install', action='store_true',
+                       help='Build the .deb without installing it or restarting LightDM')
     args = parser.parse_args()
 
     setup = KioskSetupScript()
     success = setup.run(
         no_clean=args.no_clean,
         use_https=args.use_https,
-        skip_system=args.skip_system
+        skip_system=args.skip_system,
+        no_install=args.no_install
     )
 
     if not success:
@@ -1,3 +1,15 @@
 fn main() {
+    // Builds without debug assertions refuse unsigned update files at runtime,
+    // so they must embed the key those files are checked against.
+    println!("cargo:rerun-if-env-changed=SOURCCEY_UPDATE_PUBLIC_KEY");
+    let has_update_key = std::env::var("SOURCCEY_UPDATE_PUBLIC_KEY")
+        .map(|key| !key.trim().is_empty())
+        .unwrap_or(false);
+    if std::env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_none() && !has_update_key {
+        panic!(
+            "SOURCCEY_UPDATE_PUBLIC_KEY must be set for release builds (base64 Ed25519 public key)"
+        );
+    }
+
     tauri_build::build()
 }
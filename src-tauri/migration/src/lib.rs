@@ -11,6 +11,13 @@ mod m20250727_000001_add_owned_robot_indexes;
 mod m20260219_000001_drop_owned_robot_profile_id;
 mod m20260219_000002_add_ai_model_table;
 mod m20260220_000001_add_ai_model_metadata;
+mod m20261018_000001_add_battery_sample_table;
+mod m20261018_000002_add_log_entry_table;
+mod m20261018_000003_add_ai_model_download_table;
+mod m20261018_000004_add_ai_model_training_metadata;
+mod m20261018_000005_add_ai_model_last_used_at;
+mod m20261018_000006_add_robot_catalogue_metadata;
+mod m20261019_000001_scope_owned_robot_nickname_index;
 
 pub struct Migrator;
 
@@ -29,6 +36,13 @@ impl MigratorTrait for Migrator {
             Box::new(m20260219_000001_drop_owned_robot_profile_id::Migration),
             Box::new(m20260219_000002_add_ai_model_table::Migration),
             Box::new(m20260220_000001_add_ai_model_metadata::Migration),
+            Box::new(m20261018_000001_add_battery_sample_table::Migration),
+            Box::new(m20261018_000002_add_log_entry_table::Migration),
+            Box::new(m20261018_000003_add_ai_model_download_table::Migration),
+            Box::new(m20261018_000004_add_ai_model_training_metadata::Migration),
+            Box::new(m20261018_000005_add_ai_model_last_used_at::Migration),
+            Box::new(m20261018_000006_add_robot_catalogue_metadata::Migration),
+            Box::new(m20261019_000001_scope_owned_robot_nickname_index::Migration),
         ]
     }
 }
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BatterySample::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BatterySample::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BatterySample::Voltage).double().not_null())
+                    .col(ColumnDef::new(BatterySample::CurrentA).double().not_null())
+                    .col(
+                        ColumnDef::new(BatterySample::RemainingCapacityAh)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BatterySample::MaxCapacityAh)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BatterySample::StateOfCharge)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BatterySample::TimeToEmptySecs).big_integer())
+                    .col(
+                        ColumnDef::new(BatterySample::RecordedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // History queries and pruning both filter on recorded_at
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_battery_sample_recorded_at")
+                    .table(BatterySample::Table)
+                    .col(BatterySample::RecordedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BatterySample::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BatterySample {
+    Table,
+    Id,
+    Voltage,
+    CurrentA,
+    RemainingCapacityAh,
+    MaxCapacityAh,
+    StateOfCharge,
+    TimeToEmptySecs,
+    RecordedAt,
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(LogEntry::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(LogEntry::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(LogEntry::LoggedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(LogEntry::Level).string().not_null())
+                    .col(ColumnDef::new(LogEntry::Source).string().not_null())
+                    .col(ColumnDef::new(LogEntry::Nickname).string())
+                    .col(ColumnDef::new(LogEntry::CommandLogId).string())
+                    .col(ColumnDef::new(LogEntry::Pid).integer())
+                    .col(ColumnDef::new(LogEntry::Message).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Searches are time-ordered and pruning filters on logged_at
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_log_entry_logged_at")
+                    .table(LogEntry::Table)
+                    .col(LogEntry::LoggedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_log_entry_source_logged_at")
+                    .table(LogEntry::Table)
+                    .col(LogEntry::Source)
+                    .col(LogEntry::LoggedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // External-content FTS5 index over the message column, kept in sync
+        // with log_entry by triggers so writers only insert into one table.
+        let connection = manager.get_connection();
+        connection
+            .execute_unprepared(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS log_entry_fts USING fts5(
+                    message,
+                    content='log_entry',
+                    content_rowid='id',
+                    tokenize='unicode61'
+                )",
+            )
+            .await?;
+        connection
+            .execute_unprepared(
+                "CREATE TRIGGER IF NOT EXISTS log_entry_fts_insert AFTER INSERT ON log_entry BEGIN
+                    INSERT INTO log_entry_fts(rowid, message) VALUES (new.id, new.message);
+                END",
+            )
+            .await?;
+        connection
+            .execute_unprepared(
+                "CREATE TRIGGER IF NOT EXISTS log_entry_fts_delete AFTER DELETE ON log_entry BEGIN
+                    INSERT INTO log_entry_fts(log_entry_fts, rowid, message)
+                    VALUES ('delete', old.id, old.message);
+                END",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let connection = manager.get_connection();
+        connection
+            .execute_unprepared("DROP TRIGGER IF EXISTS log_entry_fts_delete")
+            .await?;
+        connection
+            .execute_unprepared("DROP TRIGGER IF EXISTS log_entry_fts_insert")
+            .await?;
+        connection
+            .execute_unprepared("DROP TABLE IF EXISTS log_entry_fts")
+            .await?;
+        manager
+            .drop_table(Table::drop().table(LogEntry::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum LogEntry {
+    Table,
+    Id,
+    LoggedAt,
+    Level,
+    Source,
+    Nickname,
+    CommandLogId,
+    Pid,
+    Message,
+}
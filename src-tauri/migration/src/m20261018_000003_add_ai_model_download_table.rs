@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AiModelDownload::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AiModelDownload::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AiModelDownload::RepoId).string().not_null())
+                    .col(ColumnDef::new(AiModelDownload::ModelName).string())
+                    .col(ColumnDef::new(AiModelDownload::Revision).string())
+                    .col(ColumnDef::new(AiModelDownload::AllowPatterns).text())
+                    .col(ColumnDef::new(AiModelDownload::IgnorePatterns).text())
+                    .col(ColumnDef::new(AiModelDownload::Status).string().not_null())
+                    .col(ColumnDef::new(AiModelDownload::ErrorMessage).text())
+                    .col(ColumnDef::new(AiModelDownload::CreatedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(AiModelDownload::UpdatedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(AiModelDownload::CompletedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        // The scheduler picks the oldest queued download; lookups by repo
+        // find the one still in flight.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_model_download_status_created_at")
+                    .table(AiModelDownload::Table)
+                    .col(AiModelDownload::Status)
+                    .col(AiModelDownload::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_model_download_repo_id")
+                    .table(AiModelDownload::Table)
+                    .col(AiModelDownload::RepoId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AiModelDownload::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiModelDownload {
+    Table,
+    Id,
+    RepoId,
+    ModelName,
+    Revision,
+    AllowPatterns,
+    IgnorePatterns,
+    Status,
+    ErrorMessage,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only takes one column per ALTER TABLE.
+        let columns = [
+            ColumnDef::new(AiModel::PolicyType).string().to_owned(),
+            ColumnDef::new(AiModel::InputFeatures).text().to_owned(),
+            ColumnDef::new(AiModel::OutputFeatures).text().to_owned(),
+            ColumnDef::new(AiModel::CameraKeys).text().to_owned(),
+            ColumnDef::new(AiModel::DatasetRepoId).string().to_owned(),
+            ColumnDef::new(AiModel::TrainingSteps)
+                .big_integer()
+                .to_owned(),
+            ColumnDef::new(AiModel::SourceRepoId).string().to_owned(),
+            ColumnDef::new(AiModel::SourceRevision).string().to_owned(),
+            ColumnDef::new(AiModel::Checkpoints).text().to_owned(),
+        ];
+        for mut column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(AiModel::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_model_policy_type")
+                    .table(AiModel::Table)
+                    .col(AiModel::PolicyType)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_model_dataset_repo_id")
+                    .table(AiModel::Table)
+                    .col(AiModel::DatasetRepoId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_ai_model_dataset_repo_id")
+                    .table(AiModel::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_ai_model_policy_type")
+                    .table(AiModel::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            AiModel::PolicyType,
+            AiModel::InputFeatures,
+            AiModel::OutputFeatures,
+            AiModel::CameraKeys,
+            AiModel::DatasetRepoId,
+            AiModel::TrainingSteps,
+            AiModel::SourceRepoId,
+            AiModel::SourceRevision,
+            AiModel::Checkpoints,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(AiModel::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiModel {
+    Table,
+    PolicyType,
+    InputFeatures,
+    OutputFeatures,
+    CameraKeys,
+    DatasetRepoId,
+    TrainingSteps,
+    SourceRepoId,
+    SourceRevision,
+    Checkpoints,
+}
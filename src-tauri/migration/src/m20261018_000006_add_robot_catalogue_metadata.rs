@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only takes one column per ALTER TABLE.
+        let columns = [
+            ColumnDef::new(Robot::CatalogueVersion).integer().to_owned(),
+            ColumnDef::new(Robot::ArmCount).integer().to_owned(),
+            ColumnDef::new(Robot::DefaultPorts).text().to_owned(),
+            ColumnDef::new(Robot::ClientRobotType).string().to_owned(),
+            ColumnDef::new(Robot::TeleopTypes).text().to_owned(),
+            ColumnDef::new(Robot::LerobotCommands).text().to_owned(),
+        ];
+        for mut column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Robot::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Robot::CatalogueVersion,
+            Robot::ArmCount,
+            Robot::DefaultPorts,
+            Robot::ClientRobotType,
+            Robot::TeleopTypes,
+            Robot::LerobotCommands,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Robot::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Robot {
+    Table,
+    CatalogueVersion,
+    ArmCount,
+    DefaultPorts,
+    ClientRobotType,
+    TeleopTypes,
+    LerobotCommands,
+}
@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Deleted robots stay in the table until purged, so they must not hold on to their nickname.
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_owned_robot_nickname_unique;")
+            .await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX idx_owned_robot_nickname_unique ON owned_robot (nickname) WHERE deleted_at IS NULL;",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_owned_robot_nickname_unique;")
+            .await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX idx_owned_robot_nickname_unique ON owned_robot (nickname);",
+        )
+        .await?;
+
+        Ok(())
+    }
+}
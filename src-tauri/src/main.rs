@@ -6,16 +6,23 @@
 mod database;
 use database::connection::DatabaseManager;
 use serde::Serialize;
+use services::setup::environment_doctor_service::{
+    DoctorRepairAction, DoctorReport, EnvironmentDoctorService,
+};
+use services::setup::kiosk_slot_service::KioskUpdateRecord;
 use services::setup::kiosk_update_service::{KioskUpdateService, KioskUpdateStatus};
 use services::setup::local_setup_service::{
     DesktopExtrasStatus, LerobotUpdateStatus, LocalSetupService, SetupStatus,
 };
+use services::setup::offline_bundle_service::{OfflineBundleService, OfflineBundleSummary};
+use services::setup::update_signature_service::UpdateSignatureService;
 use tauri::Manager;
 
 // Import modules from the services folder
 mod services;
 use services::directory::directory_service::DirectoryService;
-use services::log::log_service::LogService;
+use services::log::log_broker_service::LogBrokerService;
+use services::log::log_service::{LogEntry, LogFilter, LogLevel, LogService};
 
 // Import modules from the utils folder
 mod utils;
@@ -27,20 +34,49 @@ use modules::log::controllers::command_log_controller::{
     add_command_log, delete_all_command_logs, delete_command_log, get_command_log,
     get_command_logs_paginated, update_command_log,
 };
+use modules::log::controllers::log_index_controller::search_logs;
+use modules::log::controllers::log_stream_controller::{
+    pause_log_subscription, resume_log_subscription, subscribe_logs, unsubscribe_logs,
+};
+use modules::log::controllers::robot_usage_controller::{
+    get_fleet_usage_stats, get_robot_usage_stats,
+};
+use modules::log::services::diagnostic_bundle_service::{
+    DiagnosticBundleService, DiagnosticBundleSummary,
+};
+use modules::log::services::log_index_service::LogIndexService;
 use modules::robot::controllers::discovery_controller::discover_lan_robots;
 use modules::robot::controllers::owned_robot_controller::{
-    add_owned_robot, delete_owned_robot, get_owned_robot_by_id, get_owned_robot_by_nickname,
-    get_owned_robots, update_owned_robot_nickname,
+    add_owned_robot, delete_owned_robot, get_deleted_owned_robots, get_owned_robot_by_id,
+    get_owned_robot_by_nickname, get_owned_robots, purge_owned_robot, restore_owned_robot,
+    update_owned_robot_nickname,
 };
 use modules::robot::controllers::robot_controller::{
-    get_all_robots, get_robot_by_id, upsert_robot_template,
+    get_all_robots, get_robot_by_id, get_robot_capabilities, seed_robot_catalogue,
+    upsert_robot_template,
 };
+use modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 
 // Import Robotics Control Modules
 use modules::ai_model::controllers::ai_model_controller::{
-    add_ai_model, delete_ai_model, download_ai_model_from_huggingface, get_ai_model,
-    get_ai_model_cache_path, get_ai_models_paginated, sync_ai_models_from_cache, update_ai_model,
+    add_ai_model, check_ai_model_compatibility, delete_ai_model,
+    download_ai_model_from_huggingface, get_ai_model, get_ai_model_cache_path,
+    get_ai_models_paginated, sync_ai_models_from_cache, update_ai_model,
+};
+use modules::ai_model::controllers::ai_model_download_controller::{
+    cancel_ai_model_download, get_ai_model_downloads, init_ai_model_download_queue,
+    pause_ai_model_download, queue_ai_model_download, resume_ai_model_download,
+};
+use modules::ai_model::controllers::ai_model_storage_controller::{
+    delete_dataset, get_storage_cleanup_policy, get_storage_report, prune_ai_model_checkpoints,
+    run_storage_cleanup, save_storage_cleanup_policy,
 };
+use modules::ai_model::controllers::hugging_face_controller::{
+    clear_hugging_face_token, get_hugging_face_token_status, save_hugging_face_token,
+    upload_to_hugging_face,
+};
+use modules::ai_model::services::ai_model_download_queue_service::AiModelDownloadQueueService;
+use modules::ai_model::services::ai_model_storage_service::AiModelStorageService;
 use modules::control::controllers::configuration::calibration_controller::{
     auto_calibrate, desktop_auto_calibrate_teleoperator, desktop_get_teleop_calibration_status,
     get_calibration_modified_at, read_calibration, remote_auto_calibrate, write_calibration,
@@ -73,7 +109,9 @@ use modules::control::controllers::remote_control::remote_rollout_controller::{
 use modules::control::controllers::remote_control::remote_teleop_controller::{
     init_remote_teleop, set_remote_teleop_keys, start_remote_teleop, stop_remote_teleop,
 };
-use modules::control::services::kiosk_control::discovery_responder_service::KioskDiscoveryResponderService;
+use modules::control::services::kiosk_control::discovery_responder_service::{
+    KioskDiscoveryResponderService, DISCOVERY_PORT,
+};
 use modules::control::services::kiosk_control::pairing_service::{
     KioskPairingService, KioskPairingState,
 };
@@ -88,12 +126,30 @@ use modules::settings::controllers::desktop_environment::desktop_environment_con
 use modules::settings::controllers::kiosk_environment::kiosk_environment_controller::{
     get_kiosk_environment_settings, save_kiosk_environment_settings,
 };
+use modules::settings::controllers::network_watchdog::network_watchdog_controller::{
+    get_network_watchdog_status, init_network_watchdog,
+};
+use modules::settings::controllers::provisioning_portal::provisioning_portal_controller::{
+    get_provisioning_portal_status, init_provisioning_portal,
+};
 use modules::settings::controllers::wifi::wifi_controller::{
-    connect_to_wifi, disconnect_from_wifi, get_current_wifi_connection, scan_wifi_networks,
-    set_wifi,
+    connect_to_wifi, disconnect_from_wifi, forget_saved_wifi_network, get_current_wifi_connection,
+    init_wifi_signal_stream, list_saved_wifi_networks, scan_wifi_networks,
+    set_saved_wifi_network_priority, set_wifi, start_wifi_signal_stream, stop_wifi_signal_stream,
+};
+use modules::settings::services::desktop_environment::desktop_environment_service::{
+    DesktopEnvironmentService, DesktopEnvironmentSettings,
+};
+use modules::settings::services::kiosk_environment::kiosk_environment_service::KioskEnvironmentSettings;
+use modules::settings::services::network_watchdog::network_watchdog_service::NetworkWatchdogService;
+use modules::status::controllers::battery::battery_controller::{
+    get_battery_data, get_battery_history, get_battery_monitor_status, init_battery_monitor,
 };
-use modules::settings::services::desktop_environment::desktop_environment_service::DesktopEnvironmentService;
-use modules::status::controllers::battery::battery_controller::get_battery_data;
+use modules::status::controllers::telemetry::telemetry_controller::{
+    get_latest_telemetry, get_telemetry_history, init_telemetry,
+};
+use modules::status::services::battery::battery_monitor_service::BatteryMonitorService;
+use modules::status::services::telemetry::telemetry_service::{TelemetryService, TelemetryState};
 
 use tauri_plugin_process::init;
 
@@ -208,14 +264,6 @@ fn read_force_flag_from_manifest(value: &serde_json::Value, target_version: &str
         .unwrap_or(false)
 }
 
-fn resolve_updater_manifest_url() -> String {
-    DesktopEnvironmentService::current_updater_manifest_url().unwrap_or_else(|_| {
-        std::env::var("SOURCCEY_UPDATER_URL").unwrap_or_else(|_| {
-            "https://sourccey.nyc3.cdn.digitaloceanspaces.com/updater/latest.json".to_string()
-        })
-    })
-}
-
 fn read_manifest_target_version(value: &serde_json::Value) -> Option<String> {
     value
         .get("version")
@@ -232,29 +280,15 @@ fn read_manifest_release_notes(value: &serde_json::Value) -> Option<String> {
         .filter(|v| !v.is_empty())
 }
 
-async fn read_updater_manifest() -> Result<serde_json::Value, String> {
-    let url = resolve_updater_manifest_url();
-    let response = reqwest::get(&url)
-        .await
-        .map_err(|e| format!("Failed to fetch updater manifest from {}: {}", url, e))?;
-    if !response.status().is_success() {
-        return Err(format!(
-            "Updater manifest request to {} returned HTTP {}",
-            url,
-            response.status()
-        ));
-    }
-
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse updater manifest from {}: {}", url, e))
+async fn read_updater_manifest(current_version: &str) -> Result<serde_json::Value, String> {
+    let url = DesktopEnvironmentService::resolve_updater_manifest_url();
+    UpdateSignatureService::fetch_verified_manifest(&url, current_version).await
 }
 
 #[tauri::command]
 async fn desktop_update_check(app: tauri::AppHandle) -> Result<DesktopUpdateStatus, String> {
     let current_version = app.package_info().version.to_string();
-    let (manifest, manifest_error) = match read_updater_manifest().await {
+    let (manifest, manifest_error) = match read_updater_manifest(&current_version).await {
         Ok(value) => (Some(value), None),
         Err(error) => {
             eprintln!("{}", error);
@@ -376,8 +410,11 @@ fn get_lerobot_vulcan_dir() -> Result<String, String> {
 #[tauri::command]
 fn write_frontend_log(app: tauri::AppHandle, level: String, message: String) -> Result<(), String> {
     let log_path = frontend_log_path(&app)?;
-    let prefix = format!("frontend/{}", level);
-    LogService::write_log_line(log_path.to_string_lossy().as_ref(), Some(&prefix), &message);
+    let level = LogLevel::parse(&level).unwrap_or(LogLevel::Info);
+    LogService::write_log_entry(
+        log_path.to_string_lossy().as_ref(),
+        &LogEntry::new(level, "frontend", &message),
+    );
     Ok(())
 }
 
@@ -388,7 +425,8 @@ fn get_frontend_log_tail(
 ) -> Result<Vec<String>, String> {
     let log_path = frontend_log_path(&app)?;
     let limit = max_lines.unwrap_or(200).clamp(1, 1000);
-    LogService::read_log_tail(log_path.to_string_lossy().as_ref(), limit)
+    let entries = LogService::read_log_tail(log_path.to_string_lossy().as_ref(), limit)?;
+    Ok(entries.iter().map(LogEntry::display_line).collect())
 }
 
 #[tauri::command]
@@ -397,16 +435,55 @@ fn get_log_tail_all(
     max_lines: Option<usize>,
     max_lines_per_file: Option<usize>,
 ) -> Result<Vec<String>, String> {
+    let entries = get_log_entries(app, max_lines, max_lines_per_file, None)?;
+    Ok(entries.iter().map(LogEntry::display_line).collect())
+}
+
+#[tauri::command]
+fn get_log_entries(
+    app: tauri::AppHandle,
+    max_lines: Option<usize>,
+    max_lines_per_file: Option<usize>,
+    filter: Option<LogFilter>,
+) -> Result<Vec<LogEntry>, String> {
     let log_dir = resolve_log_dir(&app)?;
     let limit = max_lines.unwrap_or(400).clamp(1, 2000);
     let per_file = max_lines_per_file.unwrap_or(200).clamp(1, 1000);
-    LogService::read_log_tail_all(&log_dir, limit, per_file)
+    LogService::read_log_tail_all(&log_dir, limit, per_file, &filter.unwrap_or_default())
+}
+
+#[tauri::command]
+async fn clear_log_dir(app: tauri::AppHandle) -> Result<usize, String> {
+    let log_dir = resolve_log_dir(&app)?;
+    let removed = LogService::clear_log_dir(&log_dir)?;
+    LogIndexService::clear_index(&app).await?;
+    Ok(removed)
 }
 
 #[tauri::command]
-fn clear_log_dir(app: tauri::AppHandle) -> Result<usize, String> {
+async fn export_diagnostic_bundle(
+    app: tauri::AppHandle,
+    output_dir: Option<String>,
+    command_log_limit: Option<usize>,
+) -> Result<DiagnosticBundleSummary, String> {
     let log_dir = resolve_log_dir(&app)?;
-    LogService::clear_log_dir(&log_dir)
+    let output_dir = match output_dir {
+        Some(dir) => std::path::PathBuf::from(dir),
+        None => log_dir
+            .parent()
+            .map(|dir| dir.join("diagnostics"))
+            .unwrap_or_else(|| log_dir.join("diagnostics")),
+    };
+    let system_info = serde_json::to_value(get_system_info(app.state::<TelemetryState>()))
+        .map_err(|e| format!("Failed to serialize system info: {}", e))?;
+    DiagnosticBundleService::export(
+        &app,
+        log_dir,
+        system_info,
+        output_dir,
+        command_log_limit.unwrap_or(200),
+    )
+    .await
 }
 
 #[tauri::command]
@@ -452,6 +529,53 @@ async fn setup_desktop_extras_run(app: tauri::AppHandle) -> Result<(), String> {
         .map_err(|e| format!("Setup task failed: {}", e))?
 }
 
+#[tauri::command]
+async fn setup_offline_bundle_install(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<OfflineBundleSummary, String> {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        OfflineBundleService::install_bundle(&app_handle, std::path::Path::new(&path))
+    })
+    .await
+    .map_err(|e| format!("Setup task failed: {}", e))?
+}
+
+#[tauri::command]
+async fn setup_offline_bundle_create(
+    app: tauri::AppHandle,
+    output_dir: String,
+) -> Result<OfflineBundleSummary, String> {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        OfflineBundleService::create_bundle(&app_handle, std::path::Path::new(&output_dir))
+    })
+    .await
+    .map_err(|e| format!("Setup task failed: {}", e))?
+}
+
+#[tauri::command]
+async fn setup_doctor_check(app: tauri::AppHandle) -> Result<DoctorReport, String> {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || EnvironmentDoctorService::diagnose(&app_handle))
+        .await
+        .map_err(|e| format!("Setup task failed: {}", e))?
+}
+
+#[tauri::command]
+async fn setup_doctor_repair(
+    app: tauri::AppHandle,
+    action: DoctorRepairAction,
+) -> Result<DoctorReport, String> {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        EnvironmentDoctorService::repair(&app_handle, action)
+    })
+    .await
+    .map_err(|e| format!("Setup task failed: {}", e))?
+}
+
 #[tauri::command]
 async fn check_lerobot_update(app: tauri::AppHandle) -> Result<LerobotUpdateStatus, String> {
     let app_handle = app.clone();
@@ -462,6 +586,16 @@ async fn check_lerobot_update(app: tauri::AppHandle) -> Result<LerobotUpdateStat
     .map_err(|e| format!("Setup task failed: {}", e))?
 }
 
+#[tauri::command]
+async fn pin_lerobot_release(app: tauri::AppHandle) -> Result<DesktopEnvironmentSettings, String> {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        LocalSetupService::pin_current_lerobot_release(&app_handle)
+    })
+    .await
+    .map_err(|e| format!("Setup task failed: {}", e))?
+}
+
 #[tauri::command]
 async fn kiosk_setup_repair(app: tauri::AppHandle) -> Result<(), String> {
     let app_handle = app.clone();
@@ -486,11 +620,34 @@ async fn kiosk_update_check(app: tauri::AppHandle) -> Result<KioskUpdateStatus,
         .map_err(|e| format!("Kiosk update check failed: {}", e))?
 }
 
+#[tauri::command]
+async fn kiosk_update_history() -> Result<Vec<KioskUpdateRecord>, String> {
+    tauri::async_runtime::spawn_blocking(KioskUpdateService::get_update_history)
+        .await
+        .map_err(|e| format!("Kiosk update history failed: {}", e))?
+}
+
+#[tauri::command]
+async fn kiosk_pin_release() -> Result<KioskEnvironmentSettings, String> {
+    tauri::async_runtime::spawn_blocking(KioskUpdateService::pin_current_release)
+        .await
+        .map_err(|e| format!("Kiosk release pin failed: {}", e))?
+}
+
 fn main() {
     // Default desktop; --kiosk enables kiosk mode
     let kiosk = is_kiosk_from_args();
+    let smoke_test_port = KioskUpdateService::smoke_test_port();
     let kiosk_host_state = init_kiosk_host();
     let kiosk_host_state_for_setup = kiosk_host_state.clone();
+    let network_watchdog_state = init_network_watchdog();
+    let network_watchdog_state_for_setup = network_watchdog_state.clone();
+    let telemetry_state = init_telemetry();
+    let telemetry_state_for_setup = telemetry_state.clone();
+    let battery_monitor_state = init_battery_monitor();
+    let battery_monitor_state_for_setup = battery_monitor_state.clone();
+    let ai_model_download_state = init_ai_model_download_queue();
+    let ai_model_download_state_for_setup = ai_model_download_state.clone();
     println!("kiosk_detected={}", kiosk);
 
     tauri::Builder::default()
@@ -521,6 +678,17 @@ fn main() {
                     Err(e) => eprintln!("Failed to initialize database: {}", e),
                 }
             });
+            RobotCatalogueService::seed(app_handle);
+            LogIndexService::start(app_handle.clone());
+            LogBrokerService::start(app_handle.clone());
+            // A staged build under test leaves downloads and the model cache alone
+            if smoke_test_port.is_none() {
+                AiModelDownloadQueueService::start(
+                    app_handle.clone(),
+                    ai_model_download_state_for_setup.clone(),
+                );
+                AiModelStorageService::start(app_handle.clone());
+            }
 
             // Apply window policy based on kiosk flag
             if let Some(win) = app.get_webview_window("main") {
@@ -533,6 +701,9 @@ fn main() {
                     let _ = win.eval(
                         "if (window.location.pathname !== '/kiosk/' && window.location.pathname !== '/kiosk') { window.location.replace('/kiosk/'); }",
                     );
+                    if smoke_test_port.is_some() {
+                        let _ = win.hide();
+                    }
                 } else {
                     let _ = win.set_fullscreen(false);
                     let _ = win.set_decorations(true);
@@ -550,22 +721,47 @@ fn main() {
             // Start process monitor in kiosk mode (after app is initialized)
             if kiosk {
                 KioskPairingService::register_kiosk_runtime(app.handle().clone());
-                if let Err(error) =
-                    KioskDiscoveryResponderService::start(kiosk_host_state_for_setup.clone())
-                {
+                if let Err(error) = KioskDiscoveryResponderService::start(
+                    kiosk_host_state_for_setup.clone(),
+                    smoke_test_port.unwrap_or(DISCOVERY_PORT),
+                ) {
                     eprintln!("Failed to start kiosk discovery responder: {}", error);
                 }
+                // A staged build under test must not touch WiFi or the robot
+                if smoke_test_port.is_none() {
+                    NetworkWatchdogService::start(
+                        app.handle().clone(),
+                        network_watchdog_state_for_setup.clone(),
+                    );
+                    TelemetryService::start(
+                        app.handle().clone(),
+                        telemetry_state_for_setup.clone(),
+                    );
+                    BatteryMonitorService::start(
+                        app.handle().clone(),
+                        battery_monitor_state_for_setup.clone(),
+                    );
+                }
             }
 
             Ok(())
         })
         // Only block close in kiosk mode
-        .on_window_event(move |_, event| {
+        .on_window_event(move |window, event| {
             if kiosk {
                 if let tauri::WindowEvent::CloseRequested { api, .. } = event {
                     api.prevent_close();
                 }
             }
+            if let tauri::WindowEvent::Destroyed = event {
+                LogBrokerService::release_owner(window.label());
+            }
+        })
+        // A reloaded page cannot release what the previous page subscribed to
+        .on_page_load(|webview, payload| {
+            if payload.event() == tauri::webview::PageLoadEvent::Started {
+                LogBrokerService::release_owner(webview.label());
+            }
         })
         // Initialize all services
         .manage(init_remote_teleop())
@@ -575,6 +771,12 @@ fn main() {
         .manage(kiosk_host_state)
         .manage(init_kiosk_manual_drive())
         .manage(init_kiosk_pairing())
+        .manage(init_wifi_signal_stream())
+        .manage(network_watchdog_state)
+        .manage(init_provisioning_portal())
+        .manage(telemetry_state)
+        .manage(battery_monitor_state)
+        .manage(ai_model_download_state)
         .invoke_handler(tauri::generate_handler![
             //----------------------------------------------------------//
             // Log API
@@ -585,6 +787,9 @@ fn main() {
             delete_command_log,
             delete_all_command_logs,
             get_command_logs_paginated,
+            search_logs,
+            get_robot_usage_stats,
+            get_fleet_usage_stats,
 
             //----------------------------------------------------------//
             // Robot API
@@ -592,6 +797,8 @@ fn main() {
             get_robot_by_id,
             get_all_robots,
             upsert_robot_template,
+            get_robot_capabilities,
+            seed_robot_catalogue,
             discover_lan_robots,
 
             //----------------------------------------------------------//
@@ -603,6 +810,9 @@ fn main() {
             add_owned_robot,
             update_owned_robot_nickname,
             delete_owned_robot,
+            get_deleted_owned_robots,
+            restore_owned_robot,
+            purge_owned_robot,
 
             //----------------------------------------------------------//
             // Control Functionality
@@ -644,17 +854,30 @@ fn main() {
             write_frontend_log,
             get_frontend_log_tail,
             get_log_tail_all,
+            get_log_entries,
             clear_log_dir,
+            export_diagnostic_bundle,
+            subscribe_logs,
+            unsubscribe_logs,
+            pause_log_subscription,
+            resume_log_subscription,
             setup_check,
             setup_run,
             setup_reset,
             setup_desktop_extras_check,
             setup_desktop_extras_run,
+            setup_offline_bundle_install,
+            setup_offline_bundle_create,
+            setup_doctor_check,
+            setup_doctor_repair,
             check_lerobot_update,
+            pin_lerobot_release,
             desktop_update_check,
             kiosk_setup_repair,
             kiosk_setup_update,
             kiosk_update_check,
+            kiosk_update_history,
+            kiosk_pin_release,
 
             // Kiosk Host Functions
             start_kiosk_host,
@@ -679,11 +902,18 @@ fn main() {
             connect_to_wifi,
             get_current_wifi_connection,
             disconnect_from_wifi,
+            list_saved_wifi_networks,
+            forget_saved_wifi_network,
+            set_saved_wifi_network_priority,
+            start_wifi_signal_stream,
+            stop_wifi_signal_stream,
             set_wifi,
             set_access_point,
             is_access_point_active,
             get_access_point_credentials,
             save_access_point_credentials,
+            get_network_watchdog_status,
+            get_provisioning_portal_status,
             desktop_login_via_studio,
             get_desktop_environment_settings,
             save_desktop_environment_settings,
@@ -692,6 +922,12 @@ fn main() {
 
             // Battery API
             get_battery_data,
+            get_battery_monitor_status,
+            get_battery_history,
+
+            // Telemetry API
+            get_latest_telemetry,
+            get_telemetry_history,
 
             // Store and cart functions removed
 
@@ -707,6 +943,25 @@ fn main() {
             sync_ai_models_from_cache,
             download_ai_model_from_huggingface,
             get_ai_model_cache_path,
+            check_ai_model_compatibility,
+            // AI Model Download Queue API
+            queue_ai_model_download,
+            get_ai_model_downloads,
+            pause_ai_model_download,
+            resume_ai_model_download,
+            cancel_ai_model_download,
+            // AI Model Storage API
+            get_storage_report,
+            delete_dataset,
+            prune_ai_model_checkpoints,
+            get_storage_cleanup_policy,
+            save_storage_cleanup_policy,
+            run_storage_cleanup,
+            // Hugging Face Hub API
+            get_hugging_face_token_status,
+            save_hugging_face_token,
+            clear_hugging_face_token,
+            upload_to_hugging_face,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1 +1,4 @@
 pub mod ai_model_controller;
+pub mod ai_model_download_controller;
+pub mod ai_model_storage_controller;
+pub mod hugging_face_controller;
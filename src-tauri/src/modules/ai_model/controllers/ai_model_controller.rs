@@ -1,10 +1,18 @@
 use crate::modules::ai_model::models::ai_model::AiModel;
+use crate::modules::ai_model::services::ai_model_compatibility_service::{
+    AiModelCompatibilityService, CompatibilityReport,
+};
+use crate::modules::ai_model::services::ai_model_download_queue_service::{
+    AiModelDownloadQueueService, AiModelDownloadState,
+};
+use crate::modules::ai_model::services::ai_model_download_service::AiModelDownloadRequest;
 use crate::modules::ai_model::services::ai_model_service::{
     AiModelFilters, AiModelService, AiModelSyncResult,
 };
+use crate::modules::ai_model::services::ai_model_storage_service::AiModelStorageService;
 use crate::services::directory::directory_service::DirectoryService;
 use crate::utils::pagination::{PaginatedResponse, PaginationParameters};
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Manager, State};
 
 //-------------------------------------------------------------------------//
 // Get AI Model
@@ -57,15 +65,19 @@ pub async fn update_ai_model(app_handle: AppHandle, model: AiModel) -> Result<Ai
 //-------------------------------------------------------------------------//
 // Delete AI Model
 //-------------------------------------------------------------------------//
+/// With `delete_files`, the model's directory is removed from the cache too;
+/// otherwise only the row is soft-deleted.
 #[tauri::command]
-pub async fn delete_ai_model(app_handle: AppHandle, id: String) -> Result<bool, String> {
+pub async fn delete_ai_model(
+    app_handle: AppHandle,
+    id: String,
+    delete_files: Option<bool>,
+) -> Result<bool, String> {
     let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
-    let ai_model_service = AiModelService::new(db_manager.get_connection().clone());
 
-    ai_model_service
-        .delete_ai_model(id)
-        .await
-        .map_err(|e| e.to_string())?;
+    AiModelStorageService::new(db_manager.get_connection().clone())
+        .delete_ai_model(&id, delete_files.unwrap_or(false))
+        .await?;
 
     Ok(true)
 }
@@ -77,12 +89,13 @@ pub async fn delete_ai_model(app_handle: AppHandle, id: String) -> Result<bool,
 pub async fn get_ai_models_paginated(
     app_handle: AppHandle,
     pagination: PaginationParameters,
+    filters: Option<AiModelFilters>,
 ) -> Result<PaginatedResponse<AiModel>, String> {
     let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
     let ai_model_service = AiModelService::new(db_manager.get_connection().clone());
 
     let models = ai_model_service
-        .get_ai_models_paginated(AiModelFilters {}, pagination)
+        .get_ai_models_paginated(filters.unwrap_or_default(), pagination)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -117,34 +130,45 @@ pub fn get_ai_model_cache_path() -> Result<String, String> {
 //-------------------------------------------------------------------------//
 // Download AI Model from Hugging Face
 //-------------------------------------------------------------------------//
+/// Queue the download behind any others and wait for it to finish.
 #[tauri::command]
 pub async fn download_ai_model_from_huggingface(
     app_handle: AppHandle,
+    state: State<'_, AiModelDownloadState>,
     repo_id: String,
     model_name: Option<String>,
+    revision: Option<String>,
+    allow_patterns: Option<Vec<String>>,
+    ignore_patterns: Option<Vec<String>>,
 ) -> Result<String, String> {
-    let repo_id = repo_id.trim().to_string();
-    if repo_id.is_empty() {
-        return Err("repo_id is required".to_string());
-    }
-
-    let app_handle_for_download = app_handle.clone();
-    let download_result = tauri::async_runtime::spawn_blocking(move || {
-        AiModelService::download_ai_model_from_huggingface(
-            app_handle_for_download,
-            &repo_id,
-            model_name.as_deref(),
-        )
-    })
+    AiModelDownloadQueueService::download_and_wait(
+        &app_handle,
+        &state,
+        AiModelDownloadRequest {
+            repo_id,
+            model_name,
+            revision,
+            allow_patterns: allow_patterns.unwrap_or_default(),
+            ignore_patterns: ignore_patterns.unwrap_or_default(),
+        },
+    )
     .await
-    .map_err(|e| format!("Download task failed: {}", e))??;
+}
 
+//-------------------------------------------------------------------------//
+// Check AI Model Compatibility
+//-------------------------------------------------------------------------//
+#[tauri::command]
+pub async fn check_ai_model_compatibility(
+    app_handle: AppHandle,
+    nickname: String,
+    model_path: String,
+) -> Result<CompatibilityReport, String> {
     let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
-    let ai_model_service = AiModelService::new(db_manager.get_connection().clone());
-    ai_model_service
-        .sync_ai_models_from_cache()
-        .await
-        .map_err(|e| e.to_string())?;
-
-    Ok(download_result)
+    Ok(AiModelCompatibilityService::check_for_robot(
+        db_manager.get_connection(),
+        nickname.trim(),
+        model_path.trim(),
+    )
+    .await)
 }
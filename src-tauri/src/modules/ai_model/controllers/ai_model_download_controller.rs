@@ -0,0 +1,54 @@
+use crate::modules::ai_model::models::ai_model_download::AiModelDownload;
+use crate::modules::ai_model::services::ai_model_download_queue_service::{
+    AiModelDownloadQueueService, AiModelDownloadState,
+};
+use crate::modules::ai_model::services::ai_model_download_service::AiModelDownloadRequest;
+use tauri::{command, AppHandle, State};
+
+pub fn init_ai_model_download_queue() -> AiModelDownloadState {
+    AiModelDownloadQueueService::init_download_queue()
+}
+
+#[command]
+pub async fn queue_ai_model_download(
+    app_handle: AppHandle,
+    state: State<'_, AiModelDownloadState>,
+    request: AiModelDownloadRequest,
+) -> Result<AiModelDownload, String> {
+    AiModelDownloadQueueService::enqueue(&app_handle, &state, request).await
+}
+
+#[command]
+pub async fn get_ai_model_downloads(
+    app_handle: AppHandle,
+    limit: Option<u64>,
+) -> Result<Vec<AiModelDownload>, String> {
+    AiModelDownloadQueueService::get_downloads(&app_handle, limit).await
+}
+
+#[command]
+pub async fn pause_ai_model_download(
+    app_handle: AppHandle,
+    state: State<'_, AiModelDownloadState>,
+    repo_id: String,
+) -> Result<AiModelDownload, String> {
+    AiModelDownloadQueueService::pause(&app_handle, &state, &repo_id).await
+}
+
+#[command]
+pub async fn resume_ai_model_download(
+    app_handle: AppHandle,
+    state: State<'_, AiModelDownloadState>,
+    repo_id: String,
+) -> Result<AiModelDownload, String> {
+    AiModelDownloadQueueService::resume(&app_handle, &state, &repo_id).await
+}
+
+#[command]
+pub async fn cancel_ai_model_download(
+    app_handle: AppHandle,
+    state: State<'_, AiModelDownloadState>,
+    repo_id: String,
+) -> Result<AiModelDownload, String> {
+    AiModelDownloadQueueService::cancel(&app_handle, &state, &repo_id).await
+}
@@ -0,0 +1,59 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::ai_model::services::ai_model_metadata_service::AiModelCheckpoint;
+use crate::modules::ai_model::services::ai_model_storage_service::{
+    AiModelStorageService, StorageCleanupPolicy, StorageCleanupResult, StorageReport,
+};
+use tauri::{command, AppHandle, Manager};
+
+fn storage_service(app_handle: &AppHandle) -> AiModelStorageService {
+    let db_manager = app_handle.state::<DatabaseManager>();
+    AiModelStorageService::new(db_manager.get_connection().clone())
+}
+
+//-------------------------------------------------------------------------//
+// Disk Usage
+//-------------------------------------------------------------------------//
+#[command]
+pub async fn get_storage_report(app_handle: AppHandle) -> Result<StorageReport, String> {
+    storage_service(&app_handle).get_report().await
+}
+
+/// Returns the bytes freed.
+#[command]
+pub fn delete_dataset(repo_id: String) -> Result<u64, String> {
+    AiModelStorageService::delete_dataset(&repo_id)
+}
+
+/// Returns the checkpoints that were removed.
+#[command]
+pub async fn prune_ai_model_checkpoints(
+    app_handle: AppHandle,
+    id: String,
+    keep: usize,
+) -> Result<Vec<AiModelCheckpoint>, String> {
+    storage_service(&app_handle)
+        .prune_checkpoints(&id, keep)
+        .await
+}
+
+//-------------------------------------------------------------------------//
+// Cleanup Policy
+//-------------------------------------------------------------------------//
+#[command]
+pub fn get_storage_cleanup_policy() -> Result<StorageCleanupPolicy, String> {
+    AiModelStorageService::get_policy()
+}
+
+#[command]
+pub fn save_storage_cleanup_policy(
+    policy: StorageCleanupPolicy,
+) -> Result<StorageCleanupPolicy, String> {
+    AiModelStorageService::save_policy(policy)
+}
+
+/// Apply the saved policy now instead of waiting for the next check.
+#[command]
+pub async fn run_storage_cleanup(app_handle: AppHandle) -> Result<StorageCleanupResult, String> {
+    let policy = AiModelStorageService::get_policy()?;
+    storage_service(&app_handle).run_cleanup(&policy).await
+}
@@ -0,0 +1,38 @@
+use crate::modules::ai_model::services::hugging_face_token_service::{
+    HuggingFaceTokenService, HuggingFaceTokenStatus,
+};
+use crate::modules::ai_model::services::hugging_face_upload_service::{
+    HubUploadOutcome, HubUploadRequest, HuggingFaceUploadService,
+};
+use tauri::{command, AppHandle};
+
+//-------------------------------------------------------------------------//
+// Access Token
+//-------------------------------------------------------------------------//
+#[command]
+pub fn get_hugging_face_token_status() -> HuggingFaceTokenStatus {
+    HuggingFaceTokenService::get_status()
+}
+
+#[command]
+pub fn save_hugging_face_token(token: String) -> Result<HuggingFaceTokenStatus, String> {
+    HuggingFaceTokenService::save_token(&token)
+}
+
+#[command]
+pub fn clear_hugging_face_token() -> Result<HuggingFaceTokenStatus, String> {
+    HuggingFaceTokenService::clear_token()
+}
+
+//-------------------------------------------------------------------------//
+// Upload to Hugging Face
+//-------------------------------------------------------------------------//
+/// Push a local model or dataset folder to the Hub, reporting progress on
+/// `hugging-face-upload-progress`.
+#[command]
+pub async fn upload_to_hugging_face(
+    app_handle: AppHandle,
+    request: HubUploadRequest,
+) -> Result<HubUploadOutcome, String> {
+    HuggingFaceUploadService::upload(&app_handle, request).await
+}
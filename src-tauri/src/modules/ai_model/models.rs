@@ -1 +1,2 @@
 pub mod ai_model;
+pub mod ai_model_download;
@@ -20,7 +20,24 @@ pub struct Model {
     pub model_path_relative: Option<String>,
     pub latest_checkpoint: Option<i64>,
 
+    // Training Metadata
+    pub policy_type: Option<String>,
+    /// JSON array of feature keys, e.g. `observation.state`.
+    pub input_features: Option<String>,
+    /// JSON array of feature keys, e.g. `action`.
+    pub output_features: Option<String>,
+    /// JSON array of camera names from the `observation.images.*` features.
+    pub camera_keys: Option<String>,
+    pub dataset_repo_id: Option<String>,
+    pub training_steps: Option<i64>,
+    pub source_repo_id: Option<String>,
+    pub source_revision: Option<String>,
+    /// JSON array of `{ step, path, sizeBytes }`, oldest first.
+    pub checkpoints: Option<String>,
+
     // Timestamps
+    /// Last rollout or inference start; storage cleanup evicts the oldest.
+    pub last_used_at: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -87,6 +104,16 @@ impl ActiveModel {
             model_path: Set(model_path),
             model_path_relative: Set(model_path_relative),
             latest_checkpoint: Set(latest_checkpoint),
+            policy_type: Set(None),
+            input_features: Set(None),
+            output_features: Set(None),
+            camera_keys: Set(None),
+            dataset_repo_id: Set(None),
+            training_steps: Set(None),
+            source_repo_id: Set(None),
+            source_revision: Set(None),
+            checkpoints: Set(None),
+            last_used_at: Set(None),
             created_at: Set(Some(Utc::now())),
             updated_at: Set(Some(Utc::now())),
             deleted_at: Set(None),
@@ -0,0 +1,141 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_model_download")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    // Download Request
+    pub repo_id: String,
+    pub model_name: Option<String>,
+    pub revision: Option<String>,
+    /// JSON array of glob patterns.
+    pub allow_patterns: Option<String>,
+    /// JSON array of glob patterns.
+    pub ignore_patterns: Option<String>,
+
+    // Download State
+    pub status: String,
+    pub error_message: Option<String>,
+
+    // Timestamps
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// Type aliases for better readability
+pub type AiModelDownload = Model;
+pub type ActiveAiModelDownload = ActiveModel;
+pub type AiModelDownloadColumn = Column;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiModelDownloadStatus {
+    Queued,
+    Downloading,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl AiModelDownloadStatus {
+    pub const ACTIVE: [AiModelDownloadStatus; 3] = [
+        AiModelDownloadStatus::Queued,
+        AiModelDownloadStatus::Downloading,
+        AiModelDownloadStatus::Paused,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiModelDownloadStatus::Queued => "queued",
+            AiModelDownloadStatus::Downloading => "downloading",
+            AiModelDownloadStatus::Paused => "paused",
+            AiModelDownloadStatus::Completed => "completed",
+            AiModelDownloadStatus::Failed => "failed",
+            AiModelDownloadStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "queued" => Some(AiModelDownloadStatus::Queued),
+            "downloading" => Some(AiModelDownloadStatus::Downloading),
+            "paused" => Some(AiModelDownloadStatus::Paused),
+            "completed" => Some(AiModelDownloadStatus::Completed),
+            "failed" => Some(AiModelDownloadStatus::Failed),
+            "cancelled" => Some(AiModelDownloadStatus::Cancelled),
+            _ => None,
+        }
+    }
+
+    /// Completed, failed and cancelled downloads stay in the table as history.
+    pub fn is_finished(&self) -> bool {
+        !Self::ACTIVE.contains(self)
+    }
+}
+
+impl Model {
+    pub fn download_status(&self) -> Option<AiModelDownloadStatus> {
+        AiModelDownloadStatus::parse(&self.status)
+    }
+
+    pub fn allow_pattern_list(&self) -> Vec<String> {
+        parse_patterns(self.allow_patterns.as_deref())
+    }
+
+    pub fn ignore_pattern_list(&self) -> Vec<String> {
+        parse_patterns(self.ignore_patterns.as_deref())
+    }
+}
+
+fn parse_patterns(value: Option<&str>) -> Vec<String> {
+    value
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default()
+}
+
+fn encode_patterns(patterns: &[String]) -> Option<String> {
+    if patterns.is_empty() {
+        return None;
+    }
+    serde_json::to_string(patterns).ok()
+}
+
+// Helper methods for ActiveModel
+impl ActiveModel {
+    pub fn new(
+        repo_id: String,
+        model_name: Option<String>,
+        revision: Option<String>,
+        allow_patterns: &[String],
+        ignore_patterns: &[String],
+    ) -> Self {
+        Self {
+            id: Set(Uuid::now_v7().to_string()),
+            repo_id: Set(repo_id),
+            model_name: Set(model_name),
+            revision: Set(revision),
+            allow_patterns: Set(encode_patterns(allow_patterns)),
+            ignore_patterns: Set(encode_patterns(ignore_patterns)),
+            status: Set(AiModelDownloadStatus::Queued.as_str().to_string()),
+            error_message: Set(None),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            completed_at: Set(None),
+        }
+    }
+}
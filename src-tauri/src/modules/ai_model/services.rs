@@ -1 +1,13 @@
+pub mod ai_model_compatibility_service;
+pub mod ai_model_download_queue_service;
+pub mod ai_model_download_service;
+pub mod ai_model_metadata_service;
 pub mod ai_model_service;
+pub mod ai_model_storage_service;
+pub mod hugging_face_hub_service;
+pub mod hugging_face_token_service;
+pub mod hugging_face_upload_service;
+
+#[cfg(test)]
+#[path = "services/tests/mock_hub.rs"]
+pub(crate) mod mock_hub;
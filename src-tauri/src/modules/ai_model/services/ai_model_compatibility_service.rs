@@ -0,0 +1,327 @@
+use crate::modules::ai_model::services::ai_model_metadata_service::{
+    AiModelMetadataService, PolicyFeatures, CAMERA_FEATURE_PREFIX,
+};
+use crate::modules::control::services::configuration::configuration_service::ConfigurationService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::control::types::configuration::configuration_types::Camera;
+use crate::modules::robot::services::owned_robot_service::OwnedRobotService;
+use crate::modules::robot::services::robot_catalogue_service::DEFAULT_ROBOT_TYPE;
+use crate::services::directory::directory_service::DirectoryService;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const ACTION_FEATURE: &str = "action";
+const STATE_FEATURE: &str = "observation.state";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilitySeverity {
+    /// The policy cannot drive this robot.
+    Error,
+    /// Worth a look, but the policy can still run.
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilityIssueKind {
+    ModelUnreadable,
+    UnknownRobotType,
+    ActionDimension,
+    StateDimension,
+    MissingCamera,
+    UnusedCamera,
+    CameraResolution,
+    CamerasUnknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityIssue {
+    pub kind: CompatibilityIssueKind,
+    pub severity: CompatibilitySeverity,
+    pub message: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl CompatibilityIssue {
+    fn new(
+        kind: CompatibilityIssueKind,
+        severity: CompatibilitySeverity,
+        message: String,
+        expected: Option<String>,
+        actual: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            severity,
+            message,
+            expected,
+            actual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityReport {
+    pub model_path: String,
+    pub robot_type: String,
+    /// False when any issue is an error.
+    pub compatible: bool,
+    pub issues: Vec<CompatibilityIssue>,
+}
+
+impl CompatibilityReport {
+    fn new(model_path: &str, robot_type: &str, issues: Vec<CompatibilityIssue>) -> Self {
+        Self {
+            model_path: model_path.to_string(),
+            robot_type: robot_type.to_string(),
+            compatible: !issues
+                .iter()
+                .any(|issue| issue.severity == CompatibilitySeverity::Error),
+            issues,
+        }
+    }
+
+    pub fn messages(&self, severity: CompatibilitySeverity) -> Vec<&str> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| issue.message.as_str())
+            .collect()
+    }
+
+    /// Error text for a refused start.
+    pub fn refusal(&self, activity: &str) -> String {
+        format!(
+            "Model {} is not compatible with this {} robot: {}. Fix the model or robot configuration, or start the {} with the compatibility check skipped.",
+            self.model_path,
+            self.robot_type,
+            self.messages(CompatibilitySeverity::Error).join("; "),
+            activity
+        )
+    }
+}
+
+pub struct AiModelCompatibilityService;
+
+impl AiModelCompatibilityService {
+    //-------------------------------------------------------------------------//
+    // Check Model Against Robot
+    //-------------------------------------------------------------------------//
+    /// Compare the policy at `model_path` with the robot `nickname`: its type
+    /// from the owned robot record and its cameras from `config.json`.
+    pub async fn check_for_robot(
+        db_connection: &DatabaseConnection,
+        nickname: &str,
+        model_path: &str,
+    ) -> CompatibilityReport {
+        let robot_type = OwnedRobotService::new(db_connection.clone())
+            .get_owned_robot_by_nickname(nickname.to_string())
+            .await
+            .ok()
+            .flatten()
+            .and_then(|owned| owned.robot?.robot_type)
+            .map(|robot_type| RobotProfileService::normalize_robot_type(&robot_type))
+            .unwrap_or_else(|| DEFAULT_ROBOT_TYPE.to_string());
+        let cameras = DirectoryService::get_robot_config_path(nickname)
+            .ok()
+            .filter(|path| path.is_file())
+            .and_then(|_| ConfigurationService::read_config(nickname).ok())
+            .map(|config| config.cameras);
+
+        Self::check(model_path, &robot_type, cameras.as_ref())
+    }
+
+    pub fn check(
+        model_path: &str,
+        robot_type: &str,
+        cameras: Option<&HashMap<String, Camera>>,
+    ) -> CompatibilityReport {
+        let features = Self::resolve_model_dir(model_path)
+            .and_then(|dir| AiModelMetadataService::read_policy_features(&dir));
+        let Some(features) = features else {
+            // A Hub repo id or missing path: LeRobot resolves it, we can't look.
+            return CompatibilityReport::new(
+                model_path,
+                robot_type,
+                vec![CompatibilityIssue::new(
+                    CompatibilityIssueKind::ModelUnreadable,
+                    CompatibilitySeverity::Warning,
+                    format!(
+                        "No readable policy config at {}; compatibility was not checked",
+                        model_path
+                    ),
+                    None,
+                    None,
+                )],
+            );
+        };
+        CompatibilityReport::new(
+            model_path,
+            robot_type,
+            Self::compare(&features, robot_type, cameras),
+        )
+    }
+
+    /// The mismatches between a policy's features and a robot.
+    pub fn compare(
+        features: &PolicyFeatures,
+        robot_type: &str,
+        cameras: Option<&HashMap<String, Camera>>,
+    ) -> Vec<CompatibilityIssue> {
+        let mut issues = Vec::new();
+
+        match RobotProfileService::for_type_name(robot_type) {
+            Some(profile) => {
+                for (feature, shape, kind) in [
+                    (
+                        "action",
+                        features.outputs.get(ACTION_FEATURE),
+                        CompatibilityIssueKind::ActionDimension,
+                    ),
+                    (
+                        "state",
+                        features.inputs.get(STATE_FEATURE),
+                        CompatibilityIssueKind::StateDimension,
+                    ),
+                ] {
+                    let Some(dimension) = shape.and_then(|shape| shape.first()) else {
+                        continue;
+                    };
+                    if !Self::accepts_dimension(profile, *dimension as usize) {
+                        issues.push(CompatibilityIssue::new(
+                            kind,
+                            CompatibilitySeverity::Error,
+                            format!(
+                                "Policy {} has {} values but a {} robot uses {}",
+                                feature,
+                                dimension,
+                                robot_type,
+                                Self::expected_dimensions(profile)
+                            ),
+                            Some(Self::expected_dimensions(profile)),
+                            Some(dimension.to_string()),
+                        ));
+                    }
+                }
+            }
+            None => issues.push(CompatibilityIssue::new(
+                CompatibilityIssueKind::UnknownRobotType,
+                CompatibilitySeverity::Warning,
+                format!(
+                    "Robot type {} is not known; action and state sizes were not checked",
+                    robot_type
+                ),
+                None,
+                Some(robot_type.to_string()),
+            )),
+        }
+
+        let model_cameras: HashMap<&str, &Vec<u64>> = features
+            .inputs
+            .iter()
+            .filter_map(|(key, shape)| Some((key.strip_prefix(CAMERA_FEATURE_PREFIX)?, shape)))
+            .collect();
+        let Some(cameras) = cameras else {
+            if !model_cameras.is_empty() {
+                issues.push(CompatibilityIssue::new(
+                    CompatibilityIssueKind::CamerasUnknown,
+                    CompatibilitySeverity::Warning,
+                    "Robot has no camera configuration; camera keys were not checked".to_string(),
+                    None,
+                    None,
+                ));
+            }
+            return issues;
+        };
+
+        let mut model_names: Vec<&str> = model_cameras.keys().copied().collect();
+        model_names.sort();
+        let mut robot_names: Vec<&str> = cameras.keys().map(String::as_str).collect();
+        robot_names.sort();
+
+        for name in &model_names {
+            let Some(camera) = cameras.get(*name) else {
+                issues.push(CompatibilityIssue::new(
+                    CompatibilityIssueKind::MissingCamera,
+                    CompatibilitySeverity::Error,
+                    format!(
+                        "Policy expects camera {} which the robot does not have",
+                        name
+                    ),
+                    Some(name.to_string()),
+                    Some(robot_names.join(", ")),
+                ));
+                continue;
+            };
+            // Image features are [channels, height, width]
+            if let [_, height, width] = model_cameras[name].as_slice() {
+                if (*height, *width) != (camera.height as u64, camera.width as u64) {
+                    issues.push(CompatibilityIssue::new(
+                        CompatibilityIssueKind::CameraResolution,
+                        CompatibilitySeverity::Warning,
+                        format!(
+                            "Camera {} records {}x{} but the policy was trained on {}x{}",
+                            name, camera.width, camera.height, width, height
+                        ),
+                        Some(format!("{}x{}", width, height)),
+                        Some(format!("{}x{}", camera.width, camera.height)),
+                    ));
+                }
+            }
+        }
+        for name in robot_names {
+            if !model_cameras.contains_key(name) {
+                issues.push(CompatibilityIssue::new(
+                    CompatibilityIssueKind::UnusedCamera,
+                    CompatibilitySeverity::Warning,
+                    format!("Camera {} is not used by the policy", name),
+                    None,
+                    Some(name.to_string()),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Policies may drive just the arms or the arms and the base, so both
+    /// action sizes are accepted.
+    fn accepts_dimension(profile: &dyn RobotProfile, dimension: usize) -> bool {
+        dimension == profile.arm_joints() || dimension == profile.arm_joints() + profile.base_axes()
+    }
+
+    fn expected_dimensions(profile: &dyn RobotProfile) -> String {
+        if profile.base_axes() == 0 {
+            return profile.arm_joints().to_string();
+        }
+        format!(
+            "{} or {}",
+            profile.arm_joints(),
+            profile.arm_joints() + profile.base_axes()
+        )
+    }
+
+    /// Absolute paths are used as given; relative ones are resolved against the
+    /// LeRobot checkout the commands run in.
+    fn resolve_model_dir(model_path: &str) -> Option<PathBuf> {
+        let path = Path::new(model_path.trim());
+        if path.is_absolute() {
+            return path.is_dir().then(|| path.to_path_buf());
+        }
+        DirectoryService::get_lerobot_vulcan_dir()
+            .ok()
+            .map(|dir| dir.join(path))
+            .filter(|dir| dir.is_dir())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/ai_model_compatibility_service_tests.rs"]
+mod ai_model_compatibility_service_tests;
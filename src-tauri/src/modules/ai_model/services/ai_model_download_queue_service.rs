@@ -0,0 +1,519 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::ai_model::models::ai_model_download::{AiModelDownload, AiModelDownloadStatus};
+use crate::modules::ai_model::services::ai_model_download_service::{
+    AiModelDownloadRequest, AiModelDownloadService,
+};
+use crate::modules::ai_model::services::ai_model_service::AiModelService;
+use crate::modules::ai_model::services::hugging_face_hub_service::{
+    HuggingFaceHubService, SnapshotOptions,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::oneshot;
+
+pub const AI_MODEL_DOWNLOAD_QUEUE_EVENT: &str = "ai-model-download-queue";
+const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 2;
+const MAX_CONCURRENT_DOWNLOADS_LIMIT: usize = 8;
+// Changes wake the scheduler directly; this only bounds how long a missed
+// wake-up can leave the queue idle.
+const SCHEDULER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DownloadStop {
+    Pause,
+    Cancel,
+}
+
+type DownloadWaiter = oneshot::Sender<Result<String, String>>;
+
+struct ActiveDownload {
+    cancel_flag: Arc<AtomicBool>,
+    stop: Option<DownloadStop>,
+}
+
+/// Which repos are transferring right now and what has been asked of them.
+/// The queue order itself lives in the `ai_model_download` table; this is
+/// kept free of I/O so it can be tested directly.
+pub struct AiModelDownloadQueue {
+    max_concurrent: usize,
+    active: HashMap<String, ActiveDownload>,
+    waiters: HashMap<String, Vec<DownloadWaiter>>,
+    wake_requested: bool,
+}
+
+impl AiModelDownloadQueue {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            active: HashMap::new(),
+            waiters: HashMap::new(),
+            wake_requested: false,
+        }
+    }
+
+    pub fn available_slots(&self) -> usize {
+        self.max_concurrent.saturating_sub(self.active.len())
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Claim a slot for `repo_id`, returning the flag that stops its
+    /// transfer. `None` when every slot is taken or the repo is still running.
+    pub fn begin(&mut self, repo_id: &str) -> Option<Arc<AtomicBool>> {
+        if self.available_slots() == 0 || self.active.contains_key(repo_id) {
+            return None;
+        }
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        self.active.insert(
+            repo_id.to_string(),
+            ActiveDownload {
+                cancel_flag: Arc::clone(&cancel_flag),
+                stop: None,
+            },
+        );
+        Some(cancel_flag)
+    }
+
+    /// Ask a running transfer to stop. A cancel overrides an earlier pause.
+    /// Returns false when `repo_id` is not running.
+    pub fn request_stop(&mut self, repo_id: &str, stop: DownloadStop) -> bool {
+        let Some(active) = self.active.get_mut(repo_id) else {
+            return false;
+        };
+        active.stop = active.stop.max(Some(stop));
+        active.cancel_flag.store(true, Ordering::SeqCst);
+        true
+    }
+
+    /// Release the slot held by `repo_id`, returning the stop that was asked for.
+    pub fn finish(&mut self, repo_id: &str) -> Option<DownloadStop> {
+        self.active.remove(repo_id).and_then(|active| active.stop)
+    }
+
+    pub fn add_waiter(&mut self, repo_id: &str) -> oneshot::Receiver<Result<String, String>> {
+        let (sender, receiver) = oneshot::channel();
+        self.waiters
+            .entry(repo_id.to_string())
+            .or_default()
+            .push(sender);
+        receiver
+    }
+
+    pub fn resolve_waiters(&mut self, repo_id: &str, result: Result<String, String>) {
+        for waiter in self.waiters.remove(repo_id).unwrap_or_default() {
+            let _ = waiter.send(result.clone());
+        }
+    }
+
+    /// Status to record once a transfer returns, or `None` when the pause or
+    /// cancel that stopped it already recorded one. Files that finished
+    /// arriving are kept even if a stop came in at the last moment.
+    pub fn finished_status(
+        succeeded: bool,
+        stop: Option<DownloadStop>,
+    ) -> Option<AiModelDownloadStatus> {
+        match (succeeded, stop) {
+            (true, _) => Some(AiModelDownloadStatus::Completed),
+            (false, Some(_)) => None,
+            (false, None) => Some(AiModelDownloadStatus::Failed),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AiModelDownloadState(Arc<(Mutex<AiModelDownloadQueue>, Condvar)>);
+
+pub struct AiModelDownloadQueueService;
+
+impl AiModelDownloadQueueService {
+    /// At most `SOURCCEY_MAX_CONCURRENT_DOWNLOADS` (default 2) models download
+    /// at once.
+    pub fn init_download_queue() -> AiModelDownloadState {
+        let max_concurrent = std::env::var("SOURCCEY_MAX_CONCURRENT_DOWNLOADS")
+            .ok()
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .filter(|value| (1..=MAX_CONCURRENT_DOWNLOADS_LIMIT).contains(value))
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_DOWNLOADS);
+        AiModelDownloadState(Arc::new((
+            Mutex::new(AiModelDownloadQueue::new(max_concurrent)),
+            Condvar::new(),
+        )))
+    }
+
+    /// Start the scheduler. Downloads left running by the previous session are
+    /// queued again and resume from their staged files.
+    pub fn start(app_handle: AppHandle, state: AiModelDownloadState) {
+        thread::spawn(move || {
+            if let Ok(service) = Self::service(&app_handle) {
+                match tauri::async_runtime::block_on(service.requeue_interrupted()) {
+                    Ok(0) => {}
+                    Ok(count) => println!(
+                        "[AiModelDownload] Resuming {} interrupted download(s)",
+                        count
+                    ),
+                    Err(e) => eprintln!(
+                        "[AiModelDownload] Failed to requeue interrupted downloads: {}",
+                        e
+                    ),
+                }
+            }
+            loop {
+                Self::start_queued(&app_handle, &state);
+                Self::wait_for_wake(&state);
+            }
+        });
+    }
+
+    //-------------------------------------------------------------------------//
+    // Queue Download
+    //-------------------------------------------------------------------------//
+    pub async fn enqueue(
+        app_handle: &AppHandle,
+        state: &AiModelDownloadState,
+        request: AiModelDownloadRequest,
+    ) -> Result<AiModelDownload, String> {
+        let request = AiModelDownloadRequest {
+            repo_id: request.repo_id.trim().to_string(),
+            model_name: request
+                .model_name
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty()),
+            revision: request
+                .revision
+                .map(|revision| revision.trim().to_string())
+                .filter(|revision| !revision.is_empty()),
+            ..request
+        };
+        if request.repo_id.is_empty() {
+            return Err("repo_id is required".to_string());
+        }
+        AiModelService::resolve_download_path(&request.repo_id, request.model_name.as_deref())?;
+
+        let download = Self::service(app_handle)?
+            .enqueue(&request)
+            .await
+            .map_err(|e| format!("Failed to queue download of {}: {}", request.repo_id, e))?;
+        Self::emit(app_handle, &download);
+        Self::wake(state);
+        Ok(download)
+    }
+
+    /// Queue `request` and wait until it completes, fails or is stopped.
+    pub async fn download_and_wait(
+        app_handle: &AppHandle,
+        state: &AiModelDownloadState,
+        request: AiModelDownloadRequest,
+    ) -> Result<String, String> {
+        let repo_id = request.repo_id.trim().to_string();
+        let receiver = Self::lock(state)?.add_waiter(&repo_id);
+        if let Err(e) = Self::enqueue(app_handle, state, request).await {
+            Self::lock(state)?.resolve_waiters(&repo_id, Err(e.clone()));
+            return Err(e);
+        }
+        receiver
+            .await
+            .map_err(|_| format!("Download of {} was abandoned", repo_id))?
+    }
+
+    //-------------------------------------------------------------------------//
+    // Get Downloads
+    //-------------------------------------------------------------------------//
+    pub async fn get_downloads(
+        app_handle: &AppHandle,
+        limit: Option<u64>,
+    ) -> Result<Vec<AiModelDownload>, String> {
+        Self::service(app_handle)?
+            .get_downloads(limit)
+            .await
+            .map_err(|e| format!("Failed to load AI model downloads: {}", e))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Pause / Resume / Cancel Download
+    //-------------------------------------------------------------------------//
+    /// Stop a queued or running download, keeping what has arrived so far.
+    pub async fn pause(
+        app_handle: &AppHandle,
+        state: &AiModelDownloadState,
+        repo_id: &str,
+    ) -> Result<AiModelDownload, String> {
+        let service = Self::service(app_handle)?;
+        let download = Self::active_download(&service, repo_id).await?;
+        if download.download_status() == Some(AiModelDownloadStatus::Paused) {
+            return Ok(download);
+        }
+
+        let download =
+            Self::set_status(&service, &download.id, AiModelDownloadStatus::Paused, None).await?;
+        {
+            let mut queue = Self::lock(state)?;
+            queue.request_stop(repo_id, DownloadStop::Pause);
+            queue.resolve_waiters(repo_id, Err(format!("Download of {} was paused", repo_id)));
+        }
+        Self::emit(app_handle, &download);
+        Ok(download)
+    }
+
+    /// Put a paused download back in the queue; it resumes from its staged files.
+    pub async fn resume(
+        app_handle: &AppHandle,
+        state: &AiModelDownloadState,
+        repo_id: &str,
+    ) -> Result<AiModelDownload, String> {
+        let service = Self::service(app_handle)?;
+        let download = Self::active_download(&service, repo_id).await?;
+        if download.download_status() != Some(AiModelDownloadStatus::Paused) {
+            return Ok(download);
+        }
+
+        let download =
+            Self::set_status(&service, &download.id, AiModelDownloadStatus::Queued, None).await?;
+        Self::emit(app_handle, &download);
+        Self::wake(state);
+        Ok(download)
+    }
+
+    /// Stop a download and delete its partial files.
+    pub async fn cancel(
+        app_handle: &AppHandle,
+        state: &AiModelDownloadState,
+        repo_id: &str,
+    ) -> Result<AiModelDownload, String> {
+        let service = Self::service(app_handle)?;
+        let download = Self::active_download(&service, repo_id).await?;
+        let download = Self::set_status(
+            &service,
+            &download.id,
+            AiModelDownloadStatus::Cancelled,
+            None,
+        )
+        .await?;
+
+        let running = {
+            let mut queue = Self::lock(state)?;
+            queue.resolve_waiters(
+                repo_id,
+                Err(format!("Download of {} was cancelled", repo_id)),
+            );
+            queue.request_stop(repo_id, DownloadStop::Cancel)
+        };
+        // A running transfer cleans up after itself once it has stopped.
+        if !running {
+            Self::remove_partial_files(&download);
+        }
+        Self::emit(app_handle, &download);
+        Self::wake(state);
+        Ok(download)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Scheduler
+    //-------------------------------------------------------------------------//
+    fn start_queued(app_handle: &AppHandle, state: &AiModelDownloadState) {
+        let (slots, active_count) = match Self::lock(state) {
+            Ok(queue) => (queue.available_slots(), queue.active_count()),
+            Err(_) => return,
+        };
+        if slots == 0 {
+            return;
+        }
+        let Ok(service) = Self::service(app_handle) else {
+            return;
+        };
+        // Repos still winding down from a pause hold their slot, so look past them.
+        let queued =
+            match tauri::async_runtime::block_on(service.get_queued((slots + active_count) as u64))
+            {
+                Ok(queued) => queued,
+                Err(e) => {
+                    eprintln!("[AiModelDownload] Failed to read download queue: {}", e);
+                    return;
+                }
+            };
+
+        for download in queued {
+            let Some(cancel_flag) = Self::lock(state)
+                .ok()
+                .and_then(|mut queue| queue.begin(&download.repo_id))
+            else {
+                continue;
+            };
+            let download = match tauri::async_runtime::block_on(service.set_status(
+                &download.id,
+                AiModelDownloadStatus::Downloading,
+                None,
+            )) {
+                Ok(download) => download,
+                Err(e) => {
+                    eprintln!(
+                        "[AiModelDownload] Failed to start download of {}: {}",
+                        download.repo_id, e
+                    );
+                    if let Ok(mut queue) = Self::lock(state) {
+                        queue.finish(&download.repo_id);
+                    }
+                    continue;
+                }
+            };
+            Self::emit(app_handle, &download);
+
+            let app_handle = app_handle.clone();
+            let state = state.clone();
+            thread::spawn(move || Self::run(app_handle, state, download, cancel_flag));
+        }
+    }
+
+    fn run(
+        app_handle: AppHandle,
+        state: AiModelDownloadState,
+        download: AiModelDownload,
+        cancel_flag: Arc<AtomicBool>,
+    ) {
+        let options = SnapshotOptions {
+            revision: download.revision.clone(),
+            allow_patterns: download.allow_pattern_list(),
+            ignore_patterns: download.ignore_pattern_list(),
+            cancel_flag: Some(cancel_flag),
+        };
+        let result = AiModelService::download_ai_model_from_huggingface(
+            app_handle.clone(),
+            &download.repo_id,
+            download.model_name.as_deref(),
+            options,
+        );
+
+        let stop = match Self::lock(&state) {
+            Ok(mut queue) => queue.finish(&download.repo_id),
+            Err(_) => None,
+        };
+        if result.is_err() && stop == Some(DownloadStop::Cancel) {
+            Self::remove_partial_files(&download);
+        }
+
+        if let Some(status) = AiModelDownloadQueue::finished_status(result.is_ok(), stop) {
+            if let Ok(service) = Self::service(&app_handle) {
+                let error_message = result.as_ref().err().cloned();
+                match tauri::async_runtime::block_on(service.set_status(
+                    &download.id,
+                    status,
+                    error_message,
+                )) {
+                    Ok(updated) => Self::emit(&app_handle, &updated),
+                    Err(e) => eprintln!(
+                        "[AiModelDownload] Failed to record download of {}: {}",
+                        download.repo_id, e
+                    ),
+                }
+            }
+        }
+        if result.is_ok() {
+            if let Some(db_manager) = app_handle.try_state::<DatabaseManager>() {
+                let ai_model_service = AiModelService::new(db_manager.get_connection().clone());
+                if let Err(e) =
+                    tauri::async_runtime::block_on(ai_model_service.sync_ai_models_from_cache())
+                {
+                    eprintln!("[AiModelDownload] Failed to sync AI models: {}", e);
+                }
+            }
+        }
+
+        if let Ok(mut queue) = Self::lock(&state) {
+            queue.resolve_waiters(&download.repo_id, result);
+        }
+        Self::wake(&state);
+    }
+
+    fn wake(state: &AiModelDownloadState) {
+        let (lock, wake) = &*state.0;
+        if let Ok(mut queue) = lock.lock() {
+            queue.wake_requested = true;
+            wake.notify_one();
+        }
+    }
+
+    fn wait_for_wake(state: &AiModelDownloadState) {
+        let (lock, wake) = &*state.0;
+        let Ok(mut queue) = lock.lock() else {
+            thread::sleep(SCHEDULER_POLL_INTERVAL);
+            return;
+        };
+        if !queue.wake_requested {
+            queue = match wake.wait_timeout(queue, SCHEDULER_POLL_INTERVAL) {
+                Ok((queue, _)) => queue,
+                Err(_) => return,
+            };
+        }
+        queue.wake_requested = false;
+    }
+
+    //-------------------------------------------------------------------------//
+    // Helpers
+    //-------------------------------------------------------------------------//
+    fn lock(state: &AiModelDownloadState) -> Result<MutexGuard<'_, AiModelDownloadQueue>, String> {
+        let (lock, _) = &*state.0;
+        lock.lock()
+            .map_err(|_| "Failed to lock AI model download queue".to_string())
+    }
+
+    fn service(app_handle: &AppHandle) -> Result<AiModelDownloadService, String> {
+        let db_manager = app_handle
+            .try_state::<DatabaseManager>()
+            .ok_or("Database is not initialized".to_string())?;
+        Ok(AiModelDownloadService::new(
+            db_manager.get_connection().clone(),
+        ))
+    }
+
+    async fn active_download(
+        service: &AiModelDownloadService,
+        repo_id: &str,
+    ) -> Result<AiModelDownload, String> {
+        service
+            .get_active_download(repo_id.trim())
+            .await
+            .map_err(|e| format!("Failed to load download of {}: {}", repo_id, e))?
+            .ok_or_else(|| format!("No queued or running download for {}", repo_id))
+    }
+
+    async fn set_status(
+        service: &AiModelDownloadService,
+        id: &str,
+        status: AiModelDownloadStatus,
+        error_message: Option<String>,
+    ) -> Result<AiModelDownload, String> {
+        service
+            .set_status(id, status, error_message)
+            .await
+            .map_err(|e| format!("Failed to update download status: {}", e))
+    }
+
+    fn remove_partial_files(download: &AiModelDownload) {
+        let result = AiModelService::resolve_download_path(
+            &download.repo_id,
+            download.model_name.as_deref(),
+        )
+        .and_then(|model_path| HuggingFaceHubService::remove_partial_downloads(&model_path));
+        if let Err(e) = result {
+            eprintln!(
+                "[AiModelDownload] Failed to remove partial files of {}: {}",
+                download.repo_id, e
+            );
+        }
+    }
+
+    fn emit(app_handle: &AppHandle, download: &AiModelDownload) {
+        if let Err(e) = app_handle.emit(AI_MODEL_DOWNLOAD_QUEUE_EVENT, download) {
+            eprintln!("[AiModelDownload] Failed to emit download update: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/ai_model_download_queue_service_tests.rs"]
+mod ai_model_download_queue_service_tests;
@@ -0,0 +1,153 @@
+use crate::modules::ai_model::models::ai_model_download::{
+    ActiveAiModelDownload, AiModelDownload, AiModelDownloadColumn, AiModelDownloadStatus,
+    Entity as AiModelDownloadEntity,
+};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_HISTORY_LIMIT: u64 = 100;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelDownloadRequest {
+    pub repo_id: String,
+    pub model_name: Option<String>,
+    pub revision: Option<String>,
+    #[serde(default)]
+    pub allow_patterns: Vec<String>,
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+}
+
+pub struct AiModelDownloadService {
+    connection: DatabaseConnection,
+}
+
+impl AiModelDownloadService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    fn active_statuses() -> Vec<&'static str> {
+        AiModelDownloadStatus::ACTIVE
+            .iter()
+            .map(|status| status.as_str())
+            .collect()
+    }
+
+    //-------------------------------------------------------------------------//
+    // Queue Download
+    //-------------------------------------------------------------------------//
+    /// Add a download to the back of the queue. A repo has at most one
+    /// unfinished download: queueing it again returns that row, moving a
+    /// paused one back into the queue.
+    pub async fn enqueue(
+        &self,
+        request: &AiModelDownloadRequest,
+    ) -> Result<AiModelDownload, DbErr> {
+        if let Some(existing) = self.get_active_download(&request.repo_id).await? {
+            if existing.download_status() == Some(AiModelDownloadStatus::Paused) {
+                return self
+                    .set_status(&existing.id, AiModelDownloadStatus::Queued, None)
+                    .await;
+            }
+            return Ok(existing);
+        }
+
+        ActiveAiModelDownload::new(
+            request.repo_id.clone(),
+            request.model_name.clone(),
+            request.revision.clone(),
+            &request.allow_patterns,
+            &request.ignore_patterns,
+        )
+        .insert(&self.connection)
+        .await
+    }
+
+    //-------------------------------------------------------------------------//
+    // Get Downloads
+    //-------------------------------------------------------------------------//
+    /// Unfinished downloads in queue order, then the most recent finished ones.
+    pub async fn get_downloads(&self, limit: Option<u64>) -> Result<Vec<AiModelDownload>, DbErr> {
+        let mut downloads = AiModelDownloadEntity::find()
+            .filter(AiModelDownloadColumn::Status.is_in(Self::active_statuses()))
+            .order_by_asc(AiModelDownloadColumn::CreatedAt)
+            .all(&self.connection)
+            .await?;
+        let finished = AiModelDownloadEntity::find()
+            .filter(AiModelDownloadColumn::Status.is_not_in(Self::active_statuses()))
+            .order_by_desc(AiModelDownloadColumn::UpdatedAt)
+            .limit(limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+            .all(&self.connection)
+            .await?;
+        downloads.extend(finished);
+        Ok(downloads)
+    }
+
+    pub async fn get_active_download(
+        &self,
+        repo_id: &str,
+    ) -> Result<Option<AiModelDownload>, DbErr> {
+        AiModelDownloadEntity::find()
+            .filter(AiModelDownloadColumn::RepoId.eq(repo_id))
+            .filter(AiModelDownloadColumn::Status.is_in(Self::active_statuses()))
+            .order_by_desc(AiModelDownloadColumn::CreatedAt)
+            .one(&self.connection)
+            .await
+    }
+
+    /// The oldest `limit` queued downloads.
+    pub async fn get_queued(&self, limit: u64) -> Result<Vec<AiModelDownload>, DbErr> {
+        AiModelDownloadEntity::find()
+            .filter(AiModelDownloadColumn::Status.eq(AiModelDownloadStatus::Queued.as_str()))
+            .order_by_asc(AiModelDownloadColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.connection)
+            .await
+    }
+
+    //-------------------------------------------------------------------------//
+    // Update Download Status
+    //-------------------------------------------------------------------------//
+    pub async fn set_status(
+        &self,
+        id: &str,
+        status: AiModelDownloadStatus,
+        error_message: Option<String>,
+    ) -> Result<AiModelDownload, DbErr> {
+        let download = AiModelDownloadEntity::find_by_id(id.to_string())
+            .one(&self.connection)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("AI model download {}", id)))?;
+
+        let now = Utc::now();
+        let mut download: ActiveAiModelDownload = download.into();
+        download.status = Set(status.as_str().to_string());
+        download.error_message = Set(error_message);
+        download.updated_at = Set(Some(now));
+        download.completed_at = Set(status.is_finished().then_some(now));
+        download.update(&self.connection).await
+    }
+
+    /// Downloads that were running when the app last exited go back into the
+    /// queue; their staged files let them resume.
+    pub async fn requeue_interrupted(&self) -> Result<u64, DbErr> {
+        let result = AiModelDownloadEntity::update_many()
+            .col_expr(
+                AiModelDownloadColumn::Status,
+                Expr::value(AiModelDownloadStatus::Queued.as_str()),
+            )
+            .col_expr(AiModelDownloadColumn::UpdatedAt, Expr::value(Utc::now()))
+            .filter(AiModelDownloadColumn::Status.eq(AiModelDownloadStatus::Downloading.as_str()))
+            .exec(&self.connection)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/ai_model_download_service_tests.rs"]
+mod ai_model_download_service_tests;
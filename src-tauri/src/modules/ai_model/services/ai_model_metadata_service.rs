@@ -0,0 +1,299 @@
+use crate::modules::ai_model::models::ai_model::{ActiveAiModel, AiModel};
+use crate::modules::ai_model::services::hugging_face_hub_service::HuggingFaceHubService;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "config.json";
+const TRAIN_CONFIG_FILE: &str = "train_config.json";
+const WEIGHTS_FILE: &str = "model.safetensors";
+const PRETRAINED_MODEL_DIR: &str = "pretrained_model";
+const CHECKPOINTS_DIR: &str = "checkpoints";
+const LAST_CHECKPOINT_DIR: &str = "last";
+pub const CAMERA_FEATURE_PREFIX: &str = "observation.images.";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelCheckpoint {
+    pub step: i64,
+    /// Relative to the model directory.
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+/// Feature key -> shape, e.g. `observation.images.front` -> `[3, 480, 640]`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PolicyFeatures {
+    pub inputs: BTreeMap<String, Vec<u64>>,
+    pub outputs: BTreeMap<String, Vec<u64>>,
+}
+
+/// What a model's LeRobot config files and checkpoint directories say about it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AiModelMetadata {
+    /// e.g. `act`, `diffusion`, `pi0`, `xvla`.
+    pub policy_type: Option<String>,
+    pub input_features: Vec<String>,
+    pub output_features: Vec<String>,
+    pub camera_keys: Vec<String>,
+    pub dataset_repo_id: Option<String>,
+    pub training_steps: Option<i64>,
+    pub source_repo_id: Option<String>,
+    pub source_revision: Option<String>,
+    pub checkpoints: Vec<AiModelCheckpoint>,
+}
+
+impl AiModelMetadata {
+    /// True when `model` already holds this metadata.
+    pub fn is_stored_in(&self, model: &AiModel) -> bool {
+        model.policy_type == self.policy_type
+            && model.input_features == encode_list(&self.input_features)
+            && model.output_features == encode_list(&self.output_features)
+            && model.camera_keys == encode_list(&self.camera_keys)
+            && model.dataset_repo_id == self.dataset_repo_id
+            && model.training_steps == self.training_steps
+            && model.source_repo_id == self.source_repo_id
+            && model.source_revision == self.source_revision
+            && model.checkpoints == encode_list(&self.checkpoints)
+    }
+
+    pub fn apply_to(&self, model: &mut ActiveAiModel) {
+        model.policy_type = Set(self.policy_type.clone());
+        model.input_features = Set(encode_list(&self.input_features));
+        model.output_features = Set(encode_list(&self.output_features));
+        model.camera_keys = Set(encode_list(&self.camera_keys));
+        model.dataset_repo_id = Set(self.dataset_repo_id.clone());
+        model.training_steps = Set(self.training_steps);
+        model.source_repo_id = Set(self.source_repo_id.clone());
+        model.source_revision = Set(self.source_revision.clone());
+        model.checkpoints = Set(encode_list(&self.checkpoints));
+    }
+}
+
+fn encode_list<T: Serialize>(values: &[T]) -> Option<String> {
+    if values.is_empty() {
+        return None;
+    }
+    serde_json::to_string(values).ok()
+}
+
+pub struct AiModelMetadataService;
+
+impl AiModelMetadataService {
+    /// Read `config.json` and `train_config.json` from the model root, its
+    /// `pretrained_model/` or the newest checkpoint that has them. Missing or
+    /// malformed files leave the matching fields empty.
+    pub fn read(model_dir: &Path) -> AiModelMetadata {
+        let checkpoints = Self::list_checkpoints(model_dir);
+        let (config, train_config) = Self::read_configs(model_dir, &checkpoints);
+        let policy = config
+            .as_ref()
+            .or_else(|| train_config.as_ref().and_then(|train| train.get("policy")));
+
+        let policy_type = policy
+            .and_then(|policy| policy.get("type"))
+            .or_else(|| train_config.as_ref()?.get("policy")?.get("type"))
+            .and_then(Value::as_str)
+            .map(str::to_lowercase);
+        let input_features = policy
+            .map(|policy| Self::feature_keys(policy, "input_features"))
+            .unwrap_or_default();
+        let output_features = policy
+            .map(|policy| Self::feature_keys(policy, "output_features"))
+            .unwrap_or_default();
+        let camera_keys = Self::camera_keys(&input_features);
+        let dataset_repo_id = train_config
+            .as_ref()
+            .and_then(|train| train.get("dataset")?.get("repo_id"))
+            .and_then(Self::repo_ids);
+        let training_steps = train_config
+            .as_ref()
+            .and_then(|train| train.get("steps"))
+            .and_then(Value::as_i64);
+
+        let (source_repo_id, source_revision) =
+            match HuggingFaceHubService::read_snapshot_source(model_dir) {
+                Some(source) => (Some(source.repo_id), Some(source.commit)),
+                None => (
+                    train_config
+                        .as_ref()
+                        .and_then(|train| train.get("policy")?.get("repo_id"))
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    None,
+                ),
+            };
+
+        AiModelMetadata {
+            policy_type,
+            input_features,
+            output_features,
+            camera_keys,
+            dataset_repo_id,
+            training_steps,
+            source_repo_id,
+            source_revision,
+            checkpoints,
+        }
+    }
+
+    /// Shapes of the policy's input and output features, or `None` when the
+    /// model has no readable config.
+    pub fn read_policy_features(model_dir: &Path) -> Option<PolicyFeatures> {
+        let checkpoints = Self::list_checkpoints(model_dir);
+        let (config, train_config) = Self::read_configs(model_dir, &checkpoints);
+        let policy = config.or_else(|| train_config?.get("policy").cloned())?;
+        let shapes = |field: &str| -> BTreeMap<String, Vec<u64>> {
+            policy
+                .get(field)
+                .and_then(Value::as_object)
+                .map(|features| {
+                    features
+                        .iter()
+                        .map(|(key, feature)| {
+                            let shape = feature
+                                .get("shape")
+                                .and_then(Value::as_array)
+                                .map(|shape| shape.iter().filter_map(Value::as_u64).collect())
+                                .unwrap_or_default();
+                            (key.clone(), shape)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Some(PolicyFeatures {
+            inputs: shapes("input_features"),
+            outputs: shapes("output_features"),
+        })
+    }
+
+    fn read_configs(
+        model_dir: &Path,
+        checkpoints: &[AiModelCheckpoint],
+    ) -> (Option<Value>, Option<Value>) {
+        let config_dir = Self::find_config_dir(model_dir, checkpoints);
+        let read_json = |file: &str| {
+            config_dir
+                .as_ref()
+                .and_then(|dir| fs::read_to_string(dir.join(file)).ok())
+                .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        };
+        (read_json(CONFIG_FILE), read_json(TRAIN_CONFIG_FILE))
+    }
+
+    /// A directory is a model when it has numbered checkpoints or, as pushed
+    /// to the Hub, a policy config next to its weights.
+    pub fn is_model_dir(path: &Path) -> bool {
+        !Self::list_checkpoints(path).is_empty()
+            || (path.join(CONFIG_FILE).is_file() && path.join(WEIGHTS_FILE).is_file())
+    }
+
+    /// Numbered directories under `checkpoints/`, oldest first, with their
+    /// size on disk. The `last` link is not listed separately.
+    pub fn list_checkpoints(model_dir: &Path) -> Vec<AiModelCheckpoint> {
+        let Ok(entries) = fs::read_dir(model_dir.join(CHECKPOINTS_DIR)) else {
+            return Vec::new();
+        };
+        let mut checkpoints: Vec<AiModelCheckpoint> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let step = name.parse::<i64>().ok()?;
+                Some(AiModelCheckpoint {
+                    step,
+                    path: format!("{}/{}", CHECKPOINTS_DIR, name),
+                    size_bytes: Self::directory_size(&entry.path()),
+                })
+            })
+            .collect();
+        checkpoints.sort_by_key(|checkpoint| checkpoint.step);
+        checkpoints
+    }
+
+    pub(crate) fn find_config_dir(
+        model_dir: &Path,
+        checkpoints: &[AiModelCheckpoint],
+    ) -> Option<PathBuf> {
+        let mut candidates = vec![
+            model_dir.to_path_buf(),
+            model_dir.join(PRETRAINED_MODEL_DIR),
+            model_dir
+                .join(CHECKPOINTS_DIR)
+                .join(LAST_CHECKPOINT_DIR)
+                .join(PRETRAINED_MODEL_DIR),
+        ];
+        candidates.extend(
+            checkpoints
+                .iter()
+                .rev()
+                .map(|checkpoint| model_dir.join(&checkpoint.path).join(PRETRAINED_MODEL_DIR)),
+        );
+        candidates
+            .into_iter()
+            .find(|dir| dir.join(CONFIG_FILE).is_file() || dir.join(TRAIN_CONFIG_FILE).is_file())
+    }
+
+    /// Keys of a policy's `input_features`/`output_features` map, sorted.
+    pub(crate) fn feature_keys(policy: &Value, field: &str) -> Vec<String> {
+        let mut keys: Vec<String> = policy
+            .get(field)
+            .and_then(Value::as_object)
+            .map(|features| features.keys().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        keys
+    }
+
+    /// `observation.images.front` -> `front`.
+    pub(crate) fn camera_keys(input_features: &[String]) -> Vec<String> {
+        input_features
+            .iter()
+            .filter_map(|key| key.strip_prefix(CAMERA_FEATURE_PREFIX))
+            .filter(|camera| !camera.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// A dataset `repo_id` is a string, or a list when training mixed datasets.
+    fn repo_ids(value: &Value) -> Option<String> {
+        match value {
+            Value::String(repo_id) if !repo_id.trim().is_empty() => Some(repo_id.clone()),
+            Value::Array(repo_ids) => {
+                let repo_ids: Vec<&str> = repo_ids.iter().filter_map(Value::as_str).collect();
+                (!repo_ids.is_empty()).then(|| repo_ids.join(","))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn directory_size(path: &Path) -> u64 {
+        let mut total = 0;
+        let mut stack = vec![path.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                // Links such as `last` would otherwise be counted twice.
+                let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    stack.push(entry.path());
+                } else if metadata.is_file() {
+                    total += metadata.len();
+                }
+            }
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/ai_model_metadata_service_tests.rs"]
+mod ai_model_metadata_service_tests;
@@ -1,6 +1,10 @@
 use crate::modules::ai_model::models::ai_model::{
     ActiveModel as AiModelActiveModel, AiModel, AiModelColumn, Entity as AiModelEntity,
 };
+use crate::modules::ai_model::services::ai_model_metadata_service::AiModelMetadataService;
+use crate::modules::ai_model::services::hugging_face_hub_service::{
+    HuggingFaceHubService, SnapshotOptions, SnapshotProgress,
+};
 use crate::services::directory::directory_service::DirectoryService;
 use crate::utils::pagination::{PaginatedResponse, PaginationParameters};
 use crate::utils::windows_process::configure_std_command;
@@ -13,10 +17,73 @@ use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AiModelFilters {}
+/// A download whose byte count hasn't moved for this long is reported as
+/// `stalled`; it keeps running and recovers once data flows again.
+const DOWNLOAD_STALL_THRESHOLD: Duration = Duration::from_secs(30);
+// Progress is re-sent this often while no bytes arrive, so the stall timer
+// in the UI keeps counting.
+const DOWNLOAD_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Latest snapshot progress and when its byte count last changed.
+pub(crate) struct DownloadStallWatch {
+    progress: Option<SnapshotProgress>,
+    last_change: Instant,
+}
+
+impl DownloadStallWatch {
+    pub(crate) fn new(now: Instant) -> Self {
+        Self {
+            progress: None,
+            last_change: now,
+        }
+    }
+
+    pub(crate) fn record(&mut self, progress: &SnapshotProgress, now: Instant) {
+        let advanced = self
+            .progress
+            .as_ref()
+            .is_none_or(|previous| progress.downloaded_bytes != previous.downloaded_bytes);
+        if advanced {
+            self.last_change = now;
+        }
+        self.progress = Some(progress.clone());
+    }
+
+    pub(crate) fn stall_seconds(&self, now: Instant) -> u64 {
+        now.saturating_duration_since(self.last_change).as_secs()
+    }
+
+    pub(crate) fn status(&self, now: Instant) -> &'static str {
+        let unfinished = self
+            .progress
+            .as_ref()
+            .is_some_and(|progress| progress.downloaded_bytes < progress.total_bytes);
+        if unfinished && now.saturating_duration_since(self.last_change) >= DOWNLOAD_STALL_THRESHOLD
+        {
+            "stalled"
+        } else {
+            "downloading"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelFilters {
+    /// Matched against the model name and relative path.
+    pub search: Option<String>,
+    pub policy_type: Option<String>,
+    pub dataset_repo_id: Option<String>,
+    pub source_repo_id: Option<String>,
+    /// Camera name, e.g. `front`.
+    pub camera_key: Option<String>,
+    pub has_checkpoints: Option<bool>,
+}
 
 pub struct AiModelService {
     connection: DatabaseConnection,
@@ -75,6 +142,46 @@ impl AiModelService {
         Ok(())
     }
 
+    //-------------------------------------------------------------------------//
+    // Mark AI Model Used
+    //-------------------------------------------------------------------------//
+    /// Stamp `last_used_at` on the model a rollout or inference was started
+    /// with. `model_path` may be the model directory, its relative path or a
+    /// checkpoint inside it; the most specific match wins. Returns whether a
+    /// model matched.
+    pub async fn mark_ai_model_used(&self, model_path: &str) -> Result<bool, DbErr> {
+        let model_path = model_path.trim().trim_end_matches(['/', '\\']);
+        let models = AiModelEntity::find()
+            .filter(AiModelColumn::DeletedAt.is_null())
+            .all(&self.connection)
+            .await?;
+        let is_within =
+            |root: &str| !root.is_empty() && Path::new(model_path).starts_with(Path::new(root));
+        let matched = models
+            .into_iter()
+            .filter_map(|model| {
+                let specificity = [
+                    Some(model.model_path.as_str()),
+                    model.model_path_relative.as_deref(),
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|root| is_within(root))
+                .map(str::len)
+                .max()?;
+                Some((specificity, model))
+            })
+            .max_by_key(|(specificity, _)| *specificity);
+
+        let Some((_, model)) = matched else {
+            return Ok(false);
+        };
+        let mut active: AiModelActiveModel = model.into();
+        active.last_used_at = Set(Some(Utc::now()));
+        active.update(&self.connection).await?;
+        Ok(true)
+    }
+
     //-------------------------------------------------------------------------//
     // Sync AI Models from Cache Directory
     //-------------------------------------------------------------------------//
@@ -98,7 +205,11 @@ impl AiModelService {
             seen_paths.insert(path.clone());
             let path_buf = PathBuf::from(&path);
             let model_path_relative = get_model_relative_path(&cache_dir, &path_buf);
-            let latest_checkpoint = get_latest_checkpoint(&path_buf);
+            let metadata = AiModelMetadataService::read(&path_buf);
+            let latest_checkpoint = metadata
+                .checkpoints
+                .last()
+                .map(|checkpoint| checkpoint.step);
             if let Some(existing_model) = existing_by_path.get(&path) {
                 let mut active: AiModelActiveModel = existing_model.clone().into();
                 let mut changed = false;
@@ -115,6 +226,10 @@ impl AiModelService {
                     active.latest_checkpoint = Set(latest_checkpoint);
                     changed = true;
                 }
+                if !metadata.is_stored_in(existing_model) {
+                    metadata.apply_to(&mut active);
+                    changed = true;
+                }
                 if existing_model.deleted_at.is_some() {
                     active.deleted_at = Set(None);
                     changed = true;
@@ -127,12 +242,13 @@ impl AiModelService {
                     updated += 1;
                 }
             } else {
-                let model = AiModelActiveModel::new(
+                let mut model = AiModelActiveModel::new(
                     name,
                     path,
                     model_path_relative.clone(),
                     latest_checkpoint,
                 );
+                metadata.apply_to(&mut model);
                 model.insert(&self.connection).await?;
                 added += 1;
             }
@@ -165,14 +281,44 @@ impl AiModelService {
     //-------------------------------------------------------------------------//
     pub async fn get_ai_models_paginated(
         &self,
-        _filters: AiModelFilters,
+        filters: AiModelFilters,
         pagination: PaginationParameters,
     ) -> Result<PaginatedResponse<AiModel>, DbErr> {
         let page = pagination.page.unwrap_or(1);
         let page_size = pagination.page_size.unwrap_or(20);
         let offset = (page - 1) * page_size;
 
-        let query = AiModelEntity::find().filter(AiModelColumn::DeletedAt.is_null());
+        let mut query = AiModelEntity::find().filter(AiModelColumn::DeletedAt.is_null());
+        let non_empty = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        if let Some(search) = non_empty(filters.search) {
+            query = query.filter(
+                Condition::any()
+                    .add(AiModelColumn::Name.contains(&search))
+                    .add(AiModelColumn::ModelPathRelative.contains(&search)),
+            );
+        }
+        if let Some(policy_type) = non_empty(filters.policy_type) {
+            query = query.filter(AiModelColumn::PolicyType.eq(policy_type.to_lowercase()));
+        }
+        if let Some(dataset_repo_id) = non_empty(filters.dataset_repo_id) {
+            query = query.filter(AiModelColumn::DatasetRepoId.eq(dataset_repo_id));
+        }
+        if let Some(source_repo_id) = non_empty(filters.source_repo_id) {
+            query = query.filter(AiModelColumn::SourceRepoId.eq(source_repo_id));
+        }
+        if let Some(camera_key) = non_empty(filters.camera_key) {
+            // camera_keys is a JSON array of strings
+            query = query.filter(AiModelColumn::CameraKeys.contains(format!("\"{}\"", camera_key)));
+        }
+        match filters.has_checkpoints {
+            Some(true) => query = query.filter(AiModelColumn::LatestCheckpoint.is_not_null()),
+            Some(false) => query = query.filter(AiModelColumn::LatestCheckpoint.is_null()),
+            None => {}
+        }
 
         let total = query.clone().count(&self.connection).await? as usize;
         let data = query
@@ -202,11 +348,12 @@ impl AiModelService {
     //-------------------------------------------------------------------------//
     // Download AI Model from Hugging Face
     //-------------------------------------------------------------------------//
-    pub fn download_ai_model_from_huggingface(
-        app_handle: AppHandle,
+    /// Where a download of `repo_id` lands: `<repo>/<model_name>` when a name
+    /// is given, otherwise the repository directory itself.
+    pub(crate) fn resolve_download_path(
         repo_id: &str,
         model_name: Option<&str>,
-    ) -> Result<String, String> {
+    ) -> Result<PathBuf, String> {
         if !is_safe_repo_id(repo_id) {
             return Err("Invalid repo_id".to_string());
         }
@@ -228,182 +375,22 @@ impl AiModelService {
         let use_custom_subdirectory = model_name
             .map(|name| !name.trim().is_empty())
             .unwrap_or(false);
-        let model_path = if use_custom_subdirectory {
-            DirectoryService::get_lerobot_ai_model_path(repo_id, &resolved_name)?
+        if use_custom_subdirectory {
+            DirectoryService::get_lerobot_ai_model_path(repo_id, &resolved_name)
         } else {
-            DirectoryService::get_lerobot_ai_model_repository_path(repo_id)?
-        };
-        std::fs::create_dir_all(&model_path)
-            .map_err(|e| format!("Failed to create model directory: {}", e))?;
-
-        let python_path = DirectoryService::get_python_path()?;
-        let downloader_script = r#"
-import json
-import threading
-import time
-from pathlib import Path
-import sys
-
-from huggingface_hub import HfApi, snapshot_download
-
-repo_id = sys.argv[1]
-local_dir = Path(sys.argv[2]).resolve()
-download_cache_root = local_dir / ".cache" / "huggingface" / "download"
-
-STALL_THRESHOLD_SECONDS = 30
-HEARTBEAT_SECONDS = 1.0
-
-
-def emit(payload):
-    print(json.dumps(payload), flush=True)
-
-
-def list_repo_files():
-    files = []
-    try:
-        api = HfApi()
-        for item in api.list_repo_tree(repo_id=repo_id, repo_type="model", recursive=True, expand=True):
-            path = getattr(item, "path", None)
-            size = getattr(item, "size", None)
-            if path and isinstance(size, int) and size >= 0:
-                files.append((path, size))
-    except Exception as error:
-        emit({"event": "metadata_error", "message": str(error)})
-    return files
-
-
-def get_file_size(path: Path):
-    try:
-        if path.exists():
-            return path.stat().st_size
-    except Exception:
-        pass
-    return 0
-
-
-def get_incomplete_size(relative_path: str):
-    parent = download_cache_root.joinpath(*relative_path.split("/")[:-1])
-    if not parent.exists():
-        return 0
-
-    best = 0
-    try:
-        for candidate in parent.glob("*.incomplete"):
-            size = get_file_size(candidate)
-            if size > best:
-                best = size
-    except Exception:
-        return best
-    return best
-
-
-repo_files = list_repo_files()
-total_bytes = int(sum(size for _, size in repo_files if size > 0))
-emit(
-    {
-        "event": "progress",
-        "status": "starting",
-        "progress": 0,
-        "downloaded_bytes": 0,
-        "total_bytes": total_bytes,
-        "file_count": len(repo_files),
+            DirectoryService::get_lerobot_ai_model_repository_path(repo_id)
+        }
     }
-)
-
-monitor_state = {"done": False}
-
-
-def compute_progress():
-    downloaded_bytes = 0
-    active_file = None
-    active_file_bytes = 0
-    active_file_total_bytes = 0
-
-    for relative_path, expected_size in repo_files:
-        if expected_size <= 0:
-            continue
-
-        final_path = local_dir.joinpath(*relative_path.split("/"))
-        final_size = get_file_size(final_path)
-
-        if final_size >= expected_size:
-            downloaded_bytes += expected_size
-            continue
-
-        partial_size = max(final_size, get_incomplete_size(relative_path))
-        partial_size = min(partial_size, expected_size)
-        downloaded_bytes += partial_size
-
-        if partial_size > 0 and partial_size < expected_size and partial_size > active_file_bytes:
-            active_file = relative_path
-            active_file_bytes = int(partial_size)
-            active_file_total_bytes = int(expected_size)
-
-    if total_bytes > 0:
-        progress = int((downloaded_bytes / total_bytes) * 100)
-    else:
-        progress = 0
-
-    return int(downloaded_bytes), int(progress), active_file, active_file_bytes, active_file_total_bytes
-
-
-def monitor_progress():
-    previous_bytes = 0
-    previous_time = time.monotonic()
-    last_change_time = previous_time
-
-    while True:
-        downloaded_bytes, progress, active_file, active_file_bytes, active_file_total_bytes = compute_progress()
-        now = time.monotonic()
 
-        elapsed = max(now - previous_time, 1e-6)
-        delta = max(downloaded_bytes - previous_bytes, 0)
-        speed_bps = int(delta / elapsed)
-
-        if downloaded_bytes > previous_bytes:
-            last_change_time = now
-
-        stall_seconds = int(now - last_change_time)
-        stalled = stall_seconds >= STALL_THRESHOLD_SECONDS and downloaded_bytes < total_bytes
-
-        emit(
-            {
-                "event": "progress",
-                "status": "stalled" if stalled else "downloading",
-                "progress": progress,
-                "downloaded_bytes": downloaded_bytes,
-                "total_bytes": total_bytes,
-                "speed_bps": speed_bps,
-                "stall_seconds": stall_seconds,
-                "active_file": active_file,
-                "active_file_bytes": active_file_bytes,
-                "active_file_total_bytes": active_file_total_bytes,
-            }
-        )
-
-        previous_bytes = downloaded_bytes
-        previous_time = now
-
-        if monitor_state["done"]:
-            break
-
-        time.sleep(HEARTBEAT_SECONDS)
-
-
-monitor_thread = threading.Thread(target=monitor_progress, daemon=True)
-monitor_thread.start()
-
-try:
-    snapshot_download(
-        repo_id=repo_id,
-        repo_type="model",
-        local_dir=str(local_dir),
-        local_dir_use_symlinks=False,
-    )
-finally:
-    monitor_state["done"] = True
-    monitor_thread.join(timeout=5)
-"#;
+    pub fn download_ai_model_from_huggingface(
+        app_handle: AppHandle,
+        repo_id: &str,
+        model_name: Option<&str>,
+        options: SnapshotOptions,
+    ) -> Result<String, String> {
+        let model_path = Self::resolve_download_path(repo_id, model_name)?;
+        std::fs::create_dir_all(&model_path)
+            .map_err(|e| format!("Failed to create model directory: {}", e))?;
 
         let _ = app_handle.emit(
             "ai-model-download-progress",
@@ -414,100 +401,74 @@ finally:
             }),
         );
 
-        let mut command = Command::new(python_path);
-        command
-            .arg("-u")
-            .arg("-c")
-            .arg(downloader_script)
-            .arg(repo_id)
-            .arg(model_path.to_string_lossy().to_string())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
-        configure_std_command(&mut command);
-
-        let mut child = command
-            .spawn()
-            .map_err(|e| format!("Failed to launch model download process: {}", e))?;
-
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or("Failed to capture download stdout")?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or("Failed to capture download stderr")?;
-
-        let stderr_handle = std::thread::spawn(move || {
-            let mut buffer = String::new();
-            let mut reader = BufReader::new(stderr);
-            let _ = reader.read_to_string(&mut buffer);
-            buffer
+        let watch = Mutex::new(DownloadStallWatch::new(Instant::now()));
+        let finished = AtomicBool::new(false);
+        let emit_progress = |watch: &DownloadStallWatch| {
+            let Some(progress) = watch.progress.as_ref() else {
+                return;
+            };
+            let now = Instant::now();
+            let retry_message = (progress.attempt > 1).then(|| {
+                format!(
+                    "Connection interrupted, retrying (attempt {})",
+                    progress.attempt
+                )
+            });
+            let _ = app_handle.emit(
+                "ai-model-download-progress",
+                json!({
+                    "repoId": repo_id,
+                    "status": watch.status(now),
+                    "progress": progress.percent(),
+                    "downloadedBytes": progress.downloaded_bytes,
+                    "totalBytes": progress.total_bytes,
+                    "speedBps": progress.speed_bps,
+                    "stallSeconds": watch.stall_seconds(now),
+                    "currentFile": progress.current_file,
+                    "currentFileBytes": progress.current_file_bytes,
+                    "currentFileTotalBytes": progress.current_file_total_bytes,
+                    "message": retry_message,
+                    "updatedAtEpochMs": Utc::now().timestamp_millis()
+                }),
+            );
+        };
+        let mut on_progress = |progress: &SnapshotProgress| {
+            if let Ok(mut watch) = watch.lock() {
+                watch.record(progress, Instant::now());
+                emit_progress(&watch);
+            }
+        };
+        // Chunk callbacks stop while the connection hangs, so a heartbeat
+        // reports the stall.
+        let result = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                while !finished.load(Ordering::SeqCst) {
+                    std::thread::sleep(DOWNLOAD_HEARTBEAT_INTERVAL);
+                    if let Ok(watch) = watch.lock() {
+                        if !finished.load(Ordering::SeqCst)
+                            && watch.stall_seconds(Instant::now()) > 0
+                        {
+                            emit_progress(&watch);
+                        }
+                    }
+                }
+            });
+            let result = HuggingFaceHubService::from_env().and_then(|hub| {
+                hub.snapshot_download(repo_id, &model_path, &options, &mut on_progress)
+            });
+            finished.store(true, Ordering::SeqCst);
+            result
         });
 
-        for line in BufReader::new(stdout).lines().flatten() {
-            if let Ok(payload) = serde_json::from_str::<serde_json::Value>(&line) {
-                if payload.get("event").and_then(|v| v.as_str()) == Some("progress") {
-                    let progress = payload.get("progress").and_then(|v| v.as_i64());
-                    let status = payload
-                        .get("status")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("downloading");
-                    let downloaded_bytes = payload.get("downloaded_bytes").and_then(|v| v.as_i64());
-                    let total_bytes = payload.get("total_bytes").and_then(|v| v.as_i64());
-                    let speed_bps = payload.get("speed_bps").and_then(|v| v.as_i64());
-                    let stall_seconds = payload.get("stall_seconds").and_then(|v| v.as_i64());
-                    let active_file = payload.get("active_file").and_then(|v| v.as_str());
-                    let active_file_bytes =
-                        payload.get("active_file_bytes").and_then(|v| v.as_i64());
-                    let active_file_total_bytes = payload
-                        .get("active_file_total_bytes")
-                        .and_then(|v| v.as_i64());
-
-                    let _ = app_handle.emit(
-                        "ai-model-download-progress",
-                        json!({
-                            "repoId": repo_id,
-                            "status": status,
-                            "progress": progress,
-                            "downloadedBytes": downloaded_bytes,
-                            "totalBytes": total_bytes,
-                            "speedBps": speed_bps,
-                            "stallSeconds": stall_seconds,
-                            "currentFile": active_file,
-                            "currentFileBytes": active_file_bytes,
-                            "currentFileTotalBytes": active_file_total_bytes,
-                            "updatedAtEpochMs": Utc::now().timestamp_millis()
-                        }),
-                    );
-                } else if payload.get("event").and_then(|v| v.as_str()) == Some("metadata_error") {
-                    let message = payload
-                        .get("message")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("Failed to read repository metadata for byte progress");
-                    let _ = app_handle.emit(
-                        "ai-model-download-progress",
-                        json!({
-                            "repoId": repo_id,
-                            "status": "downloading",
-                            "message": message
-                        }),
-                    );
-                }
+        if let Err(message) = result {
+            // Pausing or cancelling is reported by the download queue instead.
+            if options
+                .cancel_flag
+                .as_ref()
+                .is_some_and(|flag| flag.load(Ordering::SeqCst))
+            {
+                return Err(message);
             }
-        }
-
-        let status = child
-            .wait()
-            .map_err(|e| format!("Failed to wait for download: {}", e))?;
-        let stderr_output = stderr_handle.join().unwrap_or_default().trim().to_string();
-
-        if !status.success() {
-            let message = if !stderr_output.is_empty() {
-                stderr_output
-            } else {
-                "Unknown model download error".to_string()
-            };
             let _ = app_handle.emit(
                 "ai-model-download-progress",
                 json!({
@@ -532,7 +493,7 @@ finally:
     }
 }
 
-fn get_ai_model_cache_dir() -> PathBuf {
+pub(crate) fn get_ai_model_cache_dir() -> PathBuf {
     if cfg!(target_os = "macos") {
         let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
         return home
@@ -576,7 +537,7 @@ fn list_model_dirs(root: &Path) -> Vec<(String, String)> {
                 continue;
             }
 
-            if get_latest_checkpoint(&path).is_some() {
+            if AiModelMetadataService::is_model_dir(&path) {
                 results.push((name.to_string(), path.to_string_lossy().to_string()));
                 continue;
             }
@@ -602,35 +563,6 @@ fn get_model_relative_path(root: &Path, full_path: &Path) -> Option<String> {
         .map(|name| name.to_string())
 }
 
-fn get_latest_checkpoint(model_dir: &Path) -> Option<i64> {
-    let checkpoints_dir = model_dir.join("checkpoints");
-    if !checkpoints_dir.is_dir() {
-        return None;
-    }
-
-    let mut max_step: Option<i64> = None;
-    if let Ok(entries) = std::fs::read_dir(checkpoints_dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if !path.is_dir() {
-                continue;
-            }
-            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
-                continue;
-            };
-            let Ok(step) = name.parse::<i64>() else {
-                continue;
-            };
-            max_step = Some(match max_step {
-                Some(current) => current.max(step),
-                None => step,
-            });
-        }
-    }
-
-    max_step
-}
-
 fn is_safe_repo_id(value: &str) -> bool {
     if value.trim().is_empty()
         || value.starts_with('/')
@@ -657,3 +589,7 @@ fn is_safe_model_name(value: &str) -> bool {
         .chars()
         .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-' || ch == '.' || ch == ' ')
 }
+
+#[cfg(test)]
+#[path = "tests/ai_model_service_tests.rs"]
+mod ai_model_service_tests;
@@ -0,0 +1,656 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::ai_model::models::ai_model::{
+    ActiveModel as AiModelActiveModel, AiModel, AiModelColumn, Entity as AiModelEntity,
+};
+use crate::modules::ai_model::services::ai_model_metadata_service::{
+    AiModelCheckpoint, AiModelMetadataService,
+};
+use crate::modules::ai_model::services::ai_model_service::{
+    get_ai_model_cache_dir, AiModelService,
+};
+use crate::services::directory::directory_service::DirectoryService;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use sea_orm::*;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const STORAGE_CLEANUP_EVENT: &str = "storage-cleanup";
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+const STARTUP_DELAY: Duration = Duration::from_secs(60);
+const GIB: u64 = 1024 * 1024 * 1024;
+const DATASET_INFO_FILE: [&str; 2] = ["meta", "info.json"];
+const CHECKPOINTS_DIR: &str = "checkpoints";
+const LAST_CHECKPOINT_DIR: &str = "last";
+// Top-level LeRobot cache folders that never hold datasets.
+const RESERVED_CACHE_DIRS: [&str; 5] = [
+    "ai_models",
+    "calibration",
+    "credentials",
+    "pairing",
+    "settings",
+];
+
+/// When and how far the model cache and datasets are trimmed automatically.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageCleanupPolicy {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Cleanup starts when free space drops below this...
+    #[serde(default = "default_min_free_bytes")]
+    pub min_free_bytes: u64,
+    /// ...and stops once this much is free again.
+    #[serde(default = "default_target_free_bytes")]
+    pub target_free_bytes: u64,
+    /// Numbered checkpoints kept per model before whole models are evicted.
+    #[serde(default = "default_keep_checkpoints")]
+    pub keep_checkpoints: Option<usize>,
+    /// Recorded datasets are only evicted when this is set.
+    #[serde(default)]
+    pub include_datasets: bool,
+    /// Models and datasets used this recently are never evicted.
+    #[serde(default = "default_protect_recent_hours")]
+    pub protect_recent_hours: u64,
+}
+
+fn default_min_free_bytes() -> u64 {
+    2 * GIB
+}
+
+fn default_target_free_bytes() -> u64 {
+    4 * GIB
+}
+
+fn default_keep_checkpoints() -> Option<usize> {
+    Some(1)
+}
+
+fn default_protect_recent_hours() -> u64 {
+    24
+}
+
+impl Default for StorageCleanupPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_free_bytes: default_min_free_bytes(),
+            target_free_bytes: default_target_free_bytes(),
+            keep_checkpoints: default_keep_checkpoints(),
+            include_datasets: false,
+            protect_recent_hours: default_protect_recent_hours(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelStorageUsage {
+    pub id: String,
+    pub name: String,
+    pub model_path: String,
+    pub size_bytes: u64,
+    /// Oldest first, each with its own size.
+    pub checkpoints: Vec<AiModelCheckpoint>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetStorageUsage {
+    pub repo_id: String,
+    pub path: String,
+    pub size_bytes: u64,
+    pub last_modified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageReport {
+    pub models_root: String,
+    pub datasets_root: String,
+    pub disk_total_bytes: Option<u64>,
+    pub disk_free_bytes: Option<u64>,
+    pub model_bytes: u64,
+    pub dataset_bytes: u64,
+    /// Largest first.
+    pub models: Vec<ModelStorageUsage>,
+    /// Largest first.
+    pub datasets: Vec<DatasetStorageUsage>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageCleanupResult {
+    /// False when free space was already above the policy's threshold.
+    pub triggered: bool,
+    pub free_bytes_before: u64,
+    pub free_bytes_after: Option<u64>,
+    pub freed_bytes: u64,
+    pub pruned_checkpoints: usize,
+    /// Names of the models whose files were deleted.
+    pub removed_models: Vec<String>,
+    pub removed_datasets: Vec<String>,
+    /// Items that could not be removed; cleanup carries on past them.
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CleanupTarget {
+    /// A model by id.
+    Model(String),
+    /// A dataset by repo id.
+    Dataset(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CleanupCandidate {
+    pub target: CleanupTarget,
+    pub size_bytes: u64,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+pub struct AiModelStorageService {
+    connection: DatabaseConnection,
+}
+
+impl AiModelStorageService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Check free space every ten minutes and apply the saved cleanup policy
+    /// when it is enabled, emitting `STORAGE_CLEANUP_EVENT` after a run.
+    pub fn start(app_handle: AppHandle) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            eprintln!("[Storage] Database is not initialized; storage cleanup is unavailable");
+            return;
+        };
+        let service = Self::new(db_manager.get_connection().clone());
+
+        thread::spawn(move || {
+            thread::sleep(STARTUP_DELAY);
+            loop {
+                match Self::get_policy() {
+                    Ok(policy) if policy.enabled => {
+                        match tauri::async_runtime::block_on(service.run_cleanup(&policy)) {
+                            Ok(result) if result.triggered => {
+                                println!(
+                                    "[Storage] Freed {} bytes: {} checkpoints, {} models, {} datasets",
+                                    result.freed_bytes,
+                                    result.pruned_checkpoints,
+                                    result.removed_models.len(),
+                                    result.removed_datasets.len()
+                                );
+                                let _ = app_handle.emit(STORAGE_CLEANUP_EVENT, &result);
+                            }
+                            Ok(_) => {}
+                            Err(e) => eprintln!("[Storage] Cleanup failed: {}", e),
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("[Storage] Failed to read cleanup policy: {}", e),
+                }
+                thread::sleep(CHECK_INTERVAL);
+            }
+        });
+    }
+
+    //-------------------------------------------------------------------------//
+    // Disk Usage Report
+    //-------------------------------------------------------------------------//
+    pub async fn get_report(&self) -> Result<StorageReport, String> {
+        let models_root = get_ai_model_cache_dir();
+        let datasets_root = DirectoryService::get_lerobot_cache_dir()?;
+        let disk_root = Self::nearest_existing_dir(&datasets_root);
+
+        let mut models = Vec::new();
+        for model in self.active_models().await? {
+            let path = PathBuf::from(&model.model_path);
+            if !path.is_dir() {
+                continue;
+            }
+            models.push(ModelStorageUsage {
+                size_bytes: AiModelMetadataService::directory_size(&path),
+                checkpoints: AiModelMetadataService::list_checkpoints(&path),
+                last_used_at: model.last_used_at,
+                id: model.id,
+                name: model.name,
+                model_path: model.model_path,
+            });
+        }
+        models.sort_by_key(|model| Reverse(model.size_bytes));
+
+        let mut datasets = Self::list_datasets(&datasets_root);
+        datasets.sort_by_key(|dataset| Reverse(dataset.size_bytes));
+
+        Ok(StorageReport {
+            models_root: models_root.to_string_lossy().to_string(),
+            datasets_root: datasets_root.to_string_lossy().to_string(),
+            disk_total_bytes: disk_root.and_then(|dir| fs2::total_space(dir).ok()),
+            disk_free_bytes: disk_root.and_then(|dir| fs2::available_space(dir).ok()),
+            model_bytes: models.iter().map(|model| model.size_bytes).sum(),
+            dataset_bytes: datasets.iter().map(|dataset| dataset.size_bytes).sum(),
+            models,
+            datasets,
+        })
+    }
+
+    /// Every LeRobot dataset (a folder with `meta/info.json`) directly under
+    /// `root` or one namespace below it.
+    pub(crate) fn list_datasets(root: &Path) -> Vec<DatasetStorageUsage> {
+        let mut candidates = Vec::new();
+        for entry in Self::child_dirs(root) {
+            let name = entry
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            if name.starts_with('.') || RESERVED_CACHE_DIRS.contains(&name.as_str()) {
+                continue;
+            }
+            if Self::is_dataset_dir(&entry) {
+                candidates.push((name, entry));
+                continue;
+            }
+            for child in Self::child_dirs(&entry) {
+                if Self::is_dataset_dir(&child) {
+                    let child_name = child.file_name().unwrap_or_default().to_string_lossy();
+                    candidates.push((format!("{}/{}", name, child_name), child));
+                }
+            }
+        }
+
+        candidates
+            .into_iter()
+            .map(|(repo_id, path)| DatasetStorageUsage {
+                size_bytes: AiModelMetadataService::directory_size(&path),
+                last_modified_at: Self::last_modified(&path),
+                path: path.to_string_lossy().to_string(),
+                repo_id,
+            })
+            .collect()
+    }
+
+    //-------------------------------------------------------------------------//
+    // Delete Models and Datasets
+    //-------------------------------------------------------------------------//
+    /// Soft-delete the model row and, when `delete_files` is set, remove its
+    /// directory from the model cache. Returns the bytes freed.
+    pub async fn delete_ai_model(&self, id: &str, delete_files: bool) -> Result<u64, String> {
+        let model = AiModelEntity::find_by_id(id.to_string())
+            .one(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or(format!("AI model {} was not found", id))?;
+
+        let freed = if delete_files {
+            Self::remove_dir_within(&get_ai_model_cache_dir(), Path::new(&model.model_path))?
+        } else {
+            0
+        };
+        AiModelService::new(self.connection.clone())
+            .delete_ai_model(id.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(freed)
+    }
+
+    /// Remove a recorded dataset from the LeRobot cache. Returns the bytes
+    /// freed.
+    pub fn delete_dataset(repo_id: &str) -> Result<u64, String> {
+        let root = DirectoryService::get_lerobot_cache_dir()?;
+        Self::delete_dataset_in(&root, repo_id)
+    }
+
+    pub(crate) fn delete_dataset_in(root: &Path, repo_id: &str) -> Result<u64, String> {
+        let dataset = Self::list_datasets(root)
+            .into_iter()
+            .find(|dataset| dataset.repo_id == repo_id.trim())
+            .ok_or(format!("Dataset {} was not found", repo_id))?;
+        Self::remove_dir_within(root, Path::new(&dataset.path))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Prune Checkpoints
+    //-------------------------------------------------------------------------//
+    /// Delete all but the newest `keep` numbered checkpoints of a model and
+    /// refresh its stored checkpoint list. Returns the removed checkpoints.
+    pub async fn prune_checkpoints(
+        &self,
+        id: &str,
+        keep: usize,
+    ) -> Result<Vec<AiModelCheckpoint>, String> {
+        let model = AiModelEntity::find_by_id(id.to_string())
+            .filter(AiModelColumn::DeletedAt.is_null())
+            .one(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or(format!("AI model {} was not found", id))?;
+        self.prune_model_checkpoints(model, keep).await
+    }
+
+    async fn prune_model_checkpoints(
+        &self,
+        model: AiModel,
+        keep: usize,
+    ) -> Result<Vec<AiModelCheckpoint>, String> {
+        let model_dir = PathBuf::from(&model.model_path);
+        let removed = Self::prune_checkpoints_in(&model_dir, keep)?;
+        if removed.is_empty() {
+            return Ok(removed);
+        }
+
+        let metadata = AiModelMetadataService::read(&model_dir);
+        let mut active: AiModelActiveModel = model.into();
+        active.latest_checkpoint = Set(metadata
+            .checkpoints
+            .last()
+            .map(|checkpoint| checkpoint.step));
+        metadata.apply_to(&mut active);
+        active.updated_at = Set(Some(Utc::now()));
+        active
+            .update(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(removed)
+    }
+
+    /// The checkpoint `checkpoints/last` points at is always kept, even when
+    /// it is not among the newest.
+    pub(crate) fn prune_checkpoints_in(
+        model_dir: &Path,
+        keep: usize,
+    ) -> Result<Vec<AiModelCheckpoint>, String> {
+        if keep == 0 {
+            return Err("At least one checkpoint must be kept".to_string());
+        }
+        let checkpoints = AiModelMetadataService::list_checkpoints(model_dir);
+        let checkpoints_dir = model_dir.join(CHECKPOINTS_DIR);
+        let last_target = fs::read_link(checkpoints_dir.join(LAST_CHECKPOINT_DIR))
+            .ok()
+            .and_then(|target| {
+                target
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            });
+
+        let prune_count = checkpoints.len().saturating_sub(keep);
+        let mut removed = Vec::new();
+        for checkpoint in checkpoints.into_iter().take(prune_count) {
+            let path = model_dir.join(&checkpoint.path);
+            if last_target.as_deref() == path.file_name().and_then(|name| name.to_str()) {
+                continue;
+            }
+            fs::remove_dir_all(&path)
+                .map_err(|e| format!("Failed to remove checkpoint {:?}: {}", path, e))?;
+            removed.push(checkpoint);
+        }
+        Ok(removed)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Cleanup Policy
+    //-------------------------------------------------------------------------//
+    pub fn get_policy() -> Result<StorageCleanupPolicy, String> {
+        Self::get_policy_from_path(&Self::policy_file_path()?)
+    }
+
+    pub fn save_policy(policy: StorageCleanupPolicy) -> Result<StorageCleanupPolicy, String> {
+        Self::save_policy_to_path(&Self::policy_file_path()?, policy)
+    }
+
+    pub(crate) fn get_policy_from_path(path: &Path) -> Result<StorageCleanupPolicy, String> {
+        if !path.exists() {
+            return Ok(StorageCleanupPolicy::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read storage cleanup policy {:?}: {}", path, e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse storage cleanup policy {:?}: {}", path, e))
+    }
+
+    pub(crate) fn save_policy_to_path(
+        path: &Path,
+        policy: StorageCleanupPolicy,
+    ) -> Result<StorageCleanupPolicy, String> {
+        if policy.target_free_bytes < policy.min_free_bytes {
+            return Err("The cleanup target must be at least the free space threshold".to_string());
+        }
+        if policy.keep_checkpoints == Some(0) {
+            return Err("At least one checkpoint must be kept".to_string());
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                format!(
+                    "Failed to create storage cleanup policy directory {:?}: {}",
+                    parent, e
+                )
+            })?;
+        }
+        let serialized = serde_json::to_string_pretty(&policy)
+            .map_err(|e| format!("Failed to encode storage cleanup policy: {}", e))?;
+        fs::write(path, serialized)
+            .map_err(|e| format!("Failed to write storage cleanup policy {:?}: {}", path, e))?;
+        Ok(policy)
+    }
+
+    fn policy_file_path() -> Result<PathBuf, String> {
+        let cache_dir = DirectoryService::get_lerobot_cache_dir()?;
+        Ok(cache_dir.join("settings").join("storage_cleanup.json"))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Run Cleanup
+    //-------------------------------------------------------------------------//
+    /// When free space is below `policy.min_free_bytes`, prune old checkpoints
+    /// first, then delete the least recently used models (and datasets, if
+    /// allowed) until `policy.target_free_bytes` is free.
+    pub async fn run_cleanup(
+        &self,
+        policy: &StorageCleanupPolicy,
+    ) -> Result<StorageCleanupResult, String> {
+        let datasets_root = DirectoryService::get_lerobot_cache_dir()?;
+        let free_bytes = Self::read_free_bytes(&datasets_root)
+            .map_err(|e| format!("Could not read free disk space: {}", e))?;
+        let mut result = StorageCleanupResult {
+            free_bytes_before: free_bytes,
+            ..StorageCleanupResult::default()
+        };
+        if free_bytes >= policy.min_free_bytes {
+            return Ok(result);
+        }
+        result.triggered = true;
+
+        let models = self.active_models().await?;
+        if let Some(keep) = policy.keep_checkpoints {
+            for model in models.iter().cloned() {
+                let name = model.name.clone();
+                match self.prune_model_checkpoints(model, keep).await {
+                    Ok(removed) => {
+                        result.pruned_checkpoints += removed.len();
+                        result.freed_bytes += removed
+                            .iter()
+                            .map(|checkpoint| checkpoint.size_bytes)
+                            .sum::<u64>();
+                    }
+                    Err(e) => result.errors.push(format!("{}: {}", name, e)),
+                }
+            }
+        }
+
+        let mut candidates: Vec<CleanupCandidate> = models
+            .iter()
+            .filter(|model| Path::new(&model.model_path).is_dir())
+            .map(|model| CleanupCandidate {
+                target: CleanupTarget::Model(model.id.clone()),
+                size_bytes: AiModelMetadataService::directory_size(Path::new(&model.model_path)),
+                last_used_at: model.last_used_at.or(model.updated_at).or(model.created_at),
+            })
+            .collect();
+        if policy.include_datasets {
+            candidates.extend(
+                Self::list_datasets(&datasets_root)
+                    .into_iter()
+                    .map(|dataset| CleanupCandidate {
+                        target: CleanupTarget::Dataset(dataset.repo_id),
+                        size_bytes: dataset.size_bytes,
+                        last_used_at: dataset.last_modified_at,
+                    }),
+            );
+        }
+
+        for candidate in Self::plan_eviction(
+            candidates,
+            free_bytes + result.freed_bytes,
+            policy,
+            Utc::now(),
+        ) {
+            match &candidate.target {
+                CleanupTarget::Model(id) => {
+                    let name = models
+                        .iter()
+                        .find(|model| &model.id == id)
+                        .map(|model| model.name.clone())
+                        .unwrap_or_else(|| id.clone());
+                    match self.delete_ai_model(id, true).await {
+                        Ok(freed) => {
+                            result.freed_bytes += freed;
+                            result.removed_models.push(name);
+                        }
+                        Err(e) => result.errors.push(format!("{}: {}", name, e)),
+                    }
+                }
+                CleanupTarget::Dataset(repo_id) => {
+                    match Self::delete_dataset_in(&datasets_root, repo_id) {
+                        Ok(freed) => {
+                            result.freed_bytes += freed;
+                            result.removed_datasets.push(repo_id.clone());
+                        }
+                        Err(e) => result.errors.push(format!("{}: {}", repo_id, e)),
+                    }
+                }
+            }
+        }
+
+        result.free_bytes_after = Self::read_free_bytes(&datasets_root).ok();
+        Ok(result)
+    }
+
+    /// Least recently used first (never-used first of all), skipping anything
+    /// used within `protect_recent_hours`, until `target_free_bytes` would be
+    /// free.
+    pub(crate) fn plan_eviction(
+        mut candidates: Vec<CleanupCandidate>,
+        free_bytes: u64,
+        policy: &StorageCleanupPolicy,
+        now: DateTime<Utc>,
+    ) -> Vec<CleanupCandidate> {
+        if free_bytes >= policy.target_free_bytes {
+            return Vec::new();
+        }
+        let protected_since = now - ChronoDuration::hours(policy.protect_recent_hours as i64);
+        candidates.retain(|candidate| {
+            candidate
+                .last_used_at
+                .is_none_or(|used| used < protected_since)
+        });
+        candidates.sort_by_key(|candidate| candidate.last_used_at);
+
+        let mut freed = 0;
+        candidates
+            .into_iter()
+            .take_while(|candidate| {
+                let needed = free_bytes + freed < policy.target_free_bytes;
+                freed += candidate.size_bytes;
+                needed
+            })
+            .collect()
+    }
+
+    //-------------------------------------------------------------------------//
+    // Helpers
+    //-------------------------------------------------------------------------//
+    async fn active_models(&self) -> Result<Vec<AiModel>, String> {
+        AiModelEntity::find()
+            .filter(AiModelColumn::DeletedAt.is_null())
+            .all(&self.connection)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Delete `path` only if it lies strictly inside `root`, so a bad row can
+    /// never take the whole cache (or more) with it.
+    pub(crate) fn remove_dir_within(root: &Path, path: &Path) -> Result<u64, String> {
+        if !path.exists() {
+            return Ok(0);
+        }
+        let root = root
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve {:?}: {}", root, e))?;
+        let target = path
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve {:?}: {}", path, e))?;
+        if target == root || !target.starts_with(&root) {
+            return Err(format!(
+                "Refusing to delete {:?}: it is outside {:?}",
+                path, root
+            ));
+        }
+        let size = AiModelMetadataService::directory_size(&target);
+        fs::remove_dir_all(&target).map_err(|e| format!("Failed to delete {:?}: {}", target, e))?;
+        Ok(size)
+    }
+
+    /// Free space on the volume holding `path`, via `fs2` so it works on every
+    /// platform. The cache may not have been created yet, so the nearest
+    /// existing ancestor is queried instead.
+    pub(crate) fn read_free_bytes(path: &Path) -> Result<u64, String> {
+        let dir = Self::nearest_existing_dir(path)
+            .ok_or_else(|| format!("No existing directory above {:?}", path))?;
+        fs2::available_space(dir).map_err(|e| e.to_string())
+    }
+
+    fn nearest_existing_dir(path: &Path) -> Option<&Path> {
+        path.ancestors().find(|dir| dir.is_dir())
+    }
+
+    fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                    .map(|entry| entry.path())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_dataset_dir(path: &Path) -> bool {
+        DATASET_INFO_FILE
+            .iter()
+            .fold(path.to_path_buf(), |dir, part| dir.join(part))
+            .is_file()
+    }
+
+    /// Newest modification time of the dataset folder and its direct
+    /// subfolders, which change whenever episodes are recorded.
+    fn last_modified(path: &Path) -> Option<DateTime<Utc>> {
+        Self::child_dirs(path)
+            .into_iter()
+            .chain([path.to_path_buf()])
+            .filter_map(|dir| fs::metadata(dir).and_then(|meta| meta.modified()).ok())
+            .max()
+            .map(DateTime::<Utc>::from)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/ai_model_storage_service_tests.rs"]
+mod ai_model_storage_service_tests;
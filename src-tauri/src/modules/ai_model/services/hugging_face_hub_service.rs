@@ -0,0 +1,597 @@
+use crate::modules::ai_model::services::hugging_face_token_service::HuggingFaceTokenService;
+use crate::services::setup::download_service::{
+    DownloadOptions, DownloadProgress, DownloadService,
+};
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, LINK};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const DEFAULT_HUB_ENDPOINT: &str = "https://huggingface.co";
+const DEFAULT_REVISION: &str = "main";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+// Staging area inside the model directory, matching where huggingface_hub
+// keeps its own download metadata.
+const DOWNLOAD_CACHE_DIR: [&str; 3] = [".cache", "huggingface", "download"];
+const MARKER_EXTENSION: &str = "sourccey.json";
+const SNAPSHOT_SOURCE_FILE: [&str; 3] = [".cache", "huggingface", "source.sourccey.json"];
+
+/// A file in a Hub repository at a pinned commit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HubFile {
+    pub path: String,
+    pub size: u64,
+    /// Git blob id, or the LFS pointer's id for large files.
+    pub oid: String,
+    /// Content hash the Hub publishes for LFS files.
+    pub sha256: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotOptions {
+    /// Branch, tag or commit; defaults to `main`.
+    pub revision: Option<String>,
+    /// Only download paths matching one of these globs (all when empty).
+    pub allow_patterns: Vec<String>,
+    pub ignore_patterns: Vec<String>,
+    /// Stops the snapshot between chunks, leaving staged `.part` files to
+    /// resume from.
+    pub cancel_flag: Option<Arc<AtomicBool>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotProgress {
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    pub file_count: usize,
+    pub speed_bps: u64,
+    pub current_file: Option<String>,
+    pub current_file_bytes: u64,
+    pub current_file_total_bytes: u64,
+    pub attempt: u32,
+}
+
+impl SnapshotProgress {
+    pub fn percent(&self) -> u64 {
+        if self.total_bytes == 0 {
+            return 0;
+        }
+        (self.downloaded_bytes.min(self.total_bytes) * 100) / self.total_bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapshotOutcome {
+    pub commit: String,
+    pub downloaded_files: usize,
+    pub skipped_files: usize,
+    pub bytes: u64,
+}
+
+/// Where a downloaded model came from, kept in its directory so the model
+/// list can show the repo and commit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotSource {
+    pub repo_id: String,
+    /// The branch, tag or commit that was asked for.
+    pub revision: Option<String>,
+    pub commit: String,
+}
+
+#[derive(Deserialize)]
+struct RevisionInfo {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct TreeEntry {
+    #[serde(rename = "type")]
+    kind: String,
+    path: String,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    oid: String,
+    lfs: Option<LfsInfo>,
+}
+
+#[derive(Deserialize)]
+struct LfsInfo {
+    oid: String,
+}
+
+/// Written next to the staged download once a file is in place, so later
+/// runs can skip it without re-hashing.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FileMarker {
+    oid: String,
+    size: u64,
+}
+
+pub struct HuggingFaceHubService {
+    endpoint: String,
+    token: Option<String>,
+    client: Client,
+    download_options: DownloadOptions,
+}
+
+impl HuggingFaceHubService {
+    pub fn new(endpoint: &str, token: Option<String>) -> Result<Self, String> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| format!("Failed to build Hugging Face client: {}", e))?;
+        let token = token
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+        Ok(Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            download_options: DownloadOptions {
+                bearer_token: token.clone(),
+                ..DownloadOptions::default()
+            },
+            token,
+            client,
+        })
+    }
+
+    /// Use `HF_ENDPOINT` and the token saved in the app, falling back to
+    /// `HF_TOKEN` or the file written by `huggingface-cli login`.
+    pub fn from_env() -> Result<Self, String> {
+        Self::new(
+            &Self::endpoint_from_env(),
+            HuggingFaceTokenService::resolve_token(),
+        )
+    }
+
+    pub(crate) fn endpoint_from_env() -> String {
+        std::env::var("HF_ENDPOINT")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_HUB_ENDPOINT.to_string())
+    }
+
+    //-------------------------------------------------------------------------//
+    // Hub API
+    //-------------------------------------------------------------------------//
+    /// Resolve a branch or tag to the commit it currently points at.
+    pub fn resolve_revision(
+        &self,
+        repo_id: &str,
+        revision: Option<&str>,
+    ) -> Result<String, String> {
+        let revision = revision
+            .map(str::trim)
+            .filter(|revision| !revision.is_empty())
+            .unwrap_or(DEFAULT_REVISION);
+        let url = format!(
+            "{}/api/models/{}/revision/{}",
+            self.endpoint,
+            repo_id,
+            Self::encode_path_segment(revision)
+        );
+        let (info, _) = self.get_json::<RevisionInfo>(&url, repo_id)?;
+        Ok(info.sha)
+    }
+
+    /// Every file in the repository at `commit`, following the Hub's paging.
+    pub fn list_files(&self, repo_id: &str, commit: &str) -> Result<Vec<HubFile>, String> {
+        let mut next = Some(format!(
+            "{}/api/models/{}/tree/{}?recursive=true",
+            self.endpoint,
+            repo_id,
+            Self::encode_path_segment(commit)
+        ));
+        let mut files = Vec::new();
+        while let Some(url) = next {
+            let (entries, next_url) = self.get_json::<Vec<TreeEntry>>(&url, repo_id)?;
+            files.extend(
+                entries
+                    .into_iter()
+                    .filter(|entry| entry.kind == "file")
+                    .map(|entry| HubFile {
+                        path: entry.path,
+                        size: entry.size,
+                        oid: entry.oid,
+                        sha256: entry.lfs.map(|lfs| lfs.oid),
+                    }),
+            );
+            next = next_url;
+        }
+        Ok(files)
+    }
+
+    fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        repo_id: &str,
+    ) -> Result<(T, Option<String>), String> {
+        let mut request = self.client.get(url);
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let response = request
+            .send()
+            .map_err(|e| format!("Failed to reach Hugging Face: {}", e))?;
+
+        let status = response.status();
+        if status == StatusCode::UNAUTHORIZED || status == StatusCode::NOT_FOUND {
+            return Err(format!(
+                "Repository {} was not found on Hugging Face, or it is private and needs an access token",
+                repo_id
+            ));
+        }
+        if !status.is_success() {
+            return Err(format!("Hugging Face request failed ({}): {}", status, url));
+        }
+
+        let next = response
+            .headers()
+            .get(LINK)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::next_link);
+        let body = response
+            .json::<T>()
+            .map_err(|e| format!("Invalid response from Hugging Face: {}", e))?;
+        Ok((body, next))
+    }
+
+    /// The `rel="next"` target of an RFC 8288 `Link` header.
+    pub(crate) fn next_link(header: &str) -> Option<String> {
+        header.split(',').find_map(|link| {
+            let (target, params) = link.split_once(';')?;
+            params
+                .split(';')
+                .any(|param| param.trim().replace(' ', "") == "rel=\"next\"")
+                .then(|| {
+                    target
+                        .trim()
+                        .trim_start_matches('<')
+                        .trim_end_matches('>')
+                        .to_string()
+                })
+        })
+    }
+
+    //-------------------------------------------------------------------------//
+    // Snapshot Download
+    //-------------------------------------------------------------------------//
+    /// Download the repository at `options.revision` into `local_dir`. Files
+    /// already present from the same blob are skipped; interrupted files
+    /// resume from their staged `.part`. LFS files are checked against the
+    /// Hub's SHA-256 and other files against their git blob id before they
+    /// are moved into place.
+    pub fn snapshot_download(
+        &self,
+        repo_id: &str,
+        local_dir: &Path,
+        options: &SnapshotOptions,
+        on_progress: &mut dyn FnMut(&SnapshotProgress),
+    ) -> Result<SnapshotOutcome, String> {
+        let commit = self.resolve_revision(repo_id, options.revision.as_deref())?;
+        let files = Self::select_files(
+            self.list_files(repo_id, &commit)?,
+            &options.allow_patterns,
+            &options.ignore_patterns,
+        );
+        let staging_dir = DOWNLOAD_CACHE_DIR
+            .iter()
+            .fold(local_dir.to_path_buf(), |dir, part| dir.join(part));
+
+        let mut progress = SnapshotProgress {
+            downloaded_bytes: 0,
+            total_bytes: files.iter().map(|file| file.size).sum(),
+            file_count: files.len(),
+            speed_bps: 0,
+            current_file: None,
+            current_file_bytes: 0,
+            current_file_total_bytes: 0,
+            attempt: 1,
+        };
+        on_progress(&progress);
+
+        let mut outcome = SnapshotOutcome {
+            commit: commit.clone(),
+            downloaded_files: 0,
+            skipped_files: 0,
+            bytes: 0,
+        };
+        for file in &files {
+            let relative = Self::safe_relative_path(&file.path)?;
+            let dest = local_dir.join(&relative);
+            let staged = staging_dir.join(&relative);
+            let marker_path = staged.with_file_name(format!(
+                "{}.{}",
+                relative
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                MARKER_EXTENSION
+            ));
+            let marker = FileMarker {
+                oid: file.oid.clone(),
+                size: file.size,
+            };
+
+            if Self::is_up_to_date(&dest, &marker_path, &marker) {
+                outcome.skipped_files += 1;
+                progress.downloaded_bytes += file.size;
+                on_progress(&progress);
+                continue;
+            }
+
+            let completed_bytes = progress.downloaded_bytes;
+            progress.current_file = Some(file.path.clone());
+            progress.current_file_total_bytes = file.size;
+            let download_options = DownloadOptions {
+                expected_sha256: file.sha256.clone(),
+                cancel_flag: options.cancel_flag.clone(),
+                ..self.download_options.clone()
+            };
+            let url = self.file_url(repo_id, &commit, &file.path);
+            let mut on_file_progress = |file_progress: &DownloadProgress| {
+                progress.current_file_bytes = file_progress.downloaded_bytes;
+                progress.downloaded_bytes = completed_bytes + file_progress.downloaded_bytes;
+                progress.speed_bps = file_progress.bytes_per_second;
+                progress.attempt = file_progress.attempt;
+                on_progress(&progress);
+            };
+            let downloaded =
+                DownloadService::download(&url, &staged, &download_options, &mut on_file_progress)
+                    .map_err(|e| format!("Failed to download {}: {}", file.path, e))?;
+            if downloaded.bytes != file.size {
+                let _ = fs::remove_file(&staged);
+                return Err(format!(
+                    "Downloaded {} has {} bytes, expected {}",
+                    file.path, downloaded.bytes, file.size
+                ));
+            }
+            if file.sha256.is_none() {
+                let blob_oid = Self::git_blob_oid(&staged)?;
+                if !blob_oid.eq_ignore_ascii_case(&file.oid) {
+                    let _ = fs::remove_file(&staged);
+                    return Err(format!(
+                        "Downloaded {} does not match its git blob id: expected {}, got {}",
+                        file.path, file.oid, blob_oid
+                    ));
+                }
+            }
+
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create {:?}: {}", parent, e))?;
+            }
+            fs::rename(&staged, &dest)
+                .map_err(|e| format!("Failed to move {} into place: {}", file.path, e))?;
+            let marker_json = serde_json::to_string(&marker)
+                .map_err(|e| format!("Failed to serialize download marker: {}", e))?;
+            fs::write(&marker_path, marker_json)
+                .map_err(|e| format!("Failed to write {:?}: {}", marker_path, e))?;
+
+            outcome.downloaded_files += 1;
+            outcome.bytes += downloaded.bytes;
+            progress.downloaded_bytes = completed_bytes + file.size;
+            progress.current_file = None;
+            progress.current_file_bytes = 0;
+            progress.current_file_total_bytes = 0;
+            on_progress(&progress);
+        }
+
+        let source = SnapshotSource {
+            repo_id: repo_id.to_string(),
+            revision: options.revision.clone(),
+            commit,
+        };
+        let source_path = Self::snapshot_source_path(local_dir);
+        if let Some(parent) = source_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {:?}: {}", parent, e))?;
+        }
+        let source_json = serde_json::to_string_pretty(&source)
+            .map_err(|e| format!("Failed to serialize snapshot source: {}", e))?;
+        fs::write(&source_path, source_json)
+            .map_err(|e| format!("Failed to write {:?}: {}", source_path, e))?;
+
+        Ok(outcome)
+    }
+
+    /// The repo and commit last downloaded into `local_dir`, if any.
+    pub fn read_snapshot_source(local_dir: &Path) -> Option<SnapshotSource> {
+        fs::read_to_string(Self::snapshot_source_path(local_dir))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    fn snapshot_source_path(local_dir: &Path) -> PathBuf {
+        SNAPSHOT_SOURCE_FILE
+            .iter()
+            .fold(local_dir.to_path_buf(), |dir, part| dir.join(part))
+    }
+
+    /// Delete the staged `.part` files of an abandoned snapshot. A model
+    /// directory that holds nothing but the staging area is removed as well.
+    /// Returns how many partial files were deleted.
+    pub fn remove_partial_downloads(local_dir: &Path) -> Result<usize, String> {
+        let cache_root = local_dir.join(DOWNLOAD_CACHE_DIR[0]);
+        let staging_dir = DOWNLOAD_CACHE_DIR
+            .iter()
+            .fold(local_dir.to_path_buf(), |dir, part| dir.join(part));
+
+        let mut removed = 0;
+        let mut stack = vec![staging_dir];
+        while let Some(dir) = stack.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                    continue;
+                }
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.ends_with(".part") || name.ends_with(".part.json") {
+                    fs::remove_file(&path)
+                        .map_err(|e| format!("Failed to remove {:?}: {}", path, e))?;
+                    removed += 1;
+                }
+            }
+        }
+
+        let only_staging = fs::read_dir(local_dir)
+            .is_ok_and(|entries| entries.flatten().all(|entry| entry.path() == cache_root));
+        if only_staging {
+            fs::remove_dir_all(local_dir)
+                .map_err(|e| format!("Failed to remove {:?}: {}", local_dir, e))?;
+        }
+        Ok(removed)
+    }
+
+    fn is_up_to_date(dest: &Path, marker_path: &Path, expected: &FileMarker) -> bool {
+        let Ok(metadata) = fs::metadata(dest) else {
+            return false;
+        };
+        metadata.len() == expected.size
+            && fs::read_to_string(marker_path)
+                .ok()
+                .and_then(|json| serde_json::from_str::<FileMarker>(&json).ok())
+                .is_some_and(|marker| &marker == expected)
+    }
+
+    /// Git's object id for the file's contents, which the Hub reports as the
+    /// `oid` of files not stored in LFS.
+    pub(crate) fn git_blob_oid(path: &Path) -> Result<String, String> {
+        let mut file =
+            fs::File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+        let size = file
+            .metadata()
+            .map_err(|e| format!("Failed to read {:?}: {}", path, e))?
+            .len();
+        let mut hasher = Sha1::new();
+        hasher.update(format!("blob {}\0", size).as_bytes());
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let read = file
+                .read(&mut buffer)
+                .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    fn file_url(&self, repo_id: &str, commit: &str, path: &str) -> String {
+        let path = path
+            .split('/')
+            .map(Self::encode_path_segment)
+            .collect::<Vec<_>>()
+            .join("/");
+        format!(
+            "{}/{}/resolve/{}/{}",
+            self.endpoint,
+            repo_id,
+            Self::encode_path_segment(commit),
+            path
+        )
+    }
+
+    /// Repository paths come from the server, so refuse anything that would
+    /// land outside the model directory.
+    pub(crate) fn safe_relative_path(path: &str) -> Result<PathBuf, String> {
+        let relative = PathBuf::from(path);
+        let is_safe = !path.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_safe {
+            return Err(format!("Refusing to download unsafe path {}", path));
+        }
+        Ok(relative)
+    }
+
+    pub(crate) fn encode_path_segment(segment: &str) -> String {
+        segment
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    //-------------------------------------------------------------------------//
+    // File Patterns
+    //-------------------------------------------------------------------------//
+    pub(crate) fn select_files(
+        files: Vec<HubFile>,
+        allow_patterns: &[String],
+        ignore_patterns: &[String],
+    ) -> Vec<HubFile> {
+        files
+            .into_iter()
+            .filter(|file| {
+                (allow_patterns.is_empty()
+                    || allow_patterns
+                        .iter()
+                        .any(|pattern| Self::matches_pattern(pattern, &file.path)))
+                    && !ignore_patterns
+                        .iter()
+                        .any(|pattern| Self::matches_pattern(pattern, &file.path))
+            })
+            .collect()
+    }
+
+    /// fnmatch-style globs as used by `huggingface_hub`: `*` also crosses `/`,
+    /// `?` matches one character, and a trailing `/` means the whole folder.
+    pub(crate) fn matches_pattern(pattern: &str, path: &str) -> bool {
+        let pattern = if pattern.ends_with('/') {
+            format!("{}*", pattern)
+        } else {
+            pattern.to_string()
+        };
+        let pattern: Vec<char> = pattern.chars().collect();
+        let path: Vec<char> = path.chars().collect();
+
+        let (mut p, mut s) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while s < path.len() {
+            match pattern.get(p) {
+                Some('*') => {
+                    backtrack = Some((p, s));
+                    p += 1;
+                }
+                Some(&c) if c == '?' || c == path[s] => {
+                    p += 1;
+                    s += 1;
+                }
+                _ => match backtrack {
+                    Some((star, matched)) => {
+                        p = star + 1;
+                        s = matched + 1;
+                        backtrack = Some((star, matched + 1));
+                    }
+                    None => return false,
+                },
+            }
+        }
+        pattern[p..].iter().all(|&c| c == '*')
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/hugging_face_hub_service_tests.rs"]
+mod hugging_face_hub_service_tests;
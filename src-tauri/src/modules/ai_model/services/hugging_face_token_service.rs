@@ -0,0 +1,181 @@
+use crate::services::directory::directory_service::DirectoryService;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HuggingFaceTokenSource {
+    /// Saved from the app's settings.
+    App,
+    /// `HF_TOKEN` or `HUGGING_FACE_HUB_TOKEN`.
+    Environment,
+    /// The token file written by `huggingface-cli login`.
+    CliLogin,
+}
+
+/// What the UI may know about the token in use; the token itself never
+/// leaves the backend.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HuggingFaceTokenStatus {
+    pub has_token: bool,
+    pub source: Option<HuggingFaceTokenSource>,
+    /// e.g. `hf_…x9Qa`
+    pub masked_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedHuggingFaceToken {
+    token: String,
+}
+
+pub struct HuggingFaceTokenService;
+
+impl HuggingFaceTokenService {
+    //-------------------------------------------------------------------------//
+    // Stored Token
+    //-------------------------------------------------------------------------//
+    pub fn get_status() -> HuggingFaceTokenStatus {
+        match Self::resolve_token_with_source() {
+            Some((token, source)) => HuggingFaceTokenStatus {
+                has_token: true,
+                source: Some(source),
+                masked_token: Some(Self::mask(&token)),
+            },
+            None => HuggingFaceTokenStatus {
+                has_token: false,
+                source: None,
+                masked_token: None,
+            },
+        }
+    }
+
+    pub fn save_token(token: &str) -> Result<HuggingFaceTokenStatus, String> {
+        Self::save_token_to_path(&Self::token_file_path()?, token)?;
+        Ok(Self::get_status())
+    }
+
+    pub fn clear_token() -> Result<HuggingFaceTokenStatus, String> {
+        Self::clear_token_at_path(&Self::token_file_path()?)?;
+        Ok(Self::get_status())
+    }
+
+    /// The token for Hub requests: the one saved in the app, then the
+    /// environment, then `huggingface-cli login`, like the Python client.
+    pub fn resolve_token() -> Option<String> {
+        Self::resolve_token_with_source().map(|(token, _)| token)
+    }
+
+    fn resolve_token_with_source() -> Option<(String, HuggingFaceTokenSource)> {
+        if let Some(token) = Self::token_file_path()
+            .ok()
+            .and_then(|path| Self::read_token_from_path(&path))
+        {
+            return Some((token, HuggingFaceTokenSource::App));
+        }
+        for name in ["HF_TOKEN", "HUGGING_FACE_HUB_TOKEN"] {
+            if let Some(token) = std::env::var(name).ok().and_then(Self::normalize) {
+                return Some((token, HuggingFaceTokenSource::Environment));
+            }
+        }
+        Self::cli_token_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(Self::normalize)
+            .map(|token| (token, HuggingFaceTokenSource::CliLogin))
+    }
+
+    pub(crate) fn read_token_from_path(path: &Path) -> Option<String> {
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str::<PersistedHuggingFaceToken>(&content)
+            .ok()
+            .and_then(|persisted| Self::normalize(persisted.token))
+    }
+
+    pub(crate) fn save_token_to_path(path: &Path, token: &str) -> Result<(), String> {
+        let token = Self::normalize(token.to_string())
+            .ok_or("Hugging Face access token cannot be empty".to_string())?;
+        if token.chars().any(char::is_whitespace) {
+            return Err("Hugging Face access token cannot contain whitespace".to_string());
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                format!(
+                    "Failed to create Hugging Face credentials directory {:?}: {}",
+                    parent, e
+                )
+            })?;
+        }
+        let serialized = serde_json::to_string_pretty(&PersistedHuggingFaceToken { token })
+            .map_err(|e| format!("Failed to encode Hugging Face token: {}", e))?;
+        // Written to a sibling created owner-only and renamed over the old
+        // file, so the token is never readable by others, even briefly.
+        let staged = path.with_extension("json.tmp");
+        let _ = fs::remove_file(&staged);
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let written = options.open(&staged).and_then(|mut file| {
+            file.write_all(serialized.as_bytes())?;
+            file.sync_all()
+        });
+        if let Err(e) = written.and_then(|()| fs::rename(&staged, path)) {
+            let _ = fs::remove_file(&staged);
+            return Err(format!(
+                "Failed to write Hugging Face token {:?}: {}",
+                path, e
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn clear_token_at_path(path: &Path) -> Result<(), String> {
+        if !path.exists() {
+            return Ok(());
+        }
+        fs::remove_file(path)
+            .map_err(|e| format!("Failed to remove Hugging Face token {:?}: {}", path, e))
+    }
+
+    pub(crate) fn mask(token: &str) -> String {
+        let chars: Vec<char> = token.chars().collect();
+        if chars.len() <= 8 {
+            return "…".to_string();
+        }
+        let prefix: String = chars[..3].iter().collect();
+        let suffix: String = chars[chars.len() - 4..].iter().collect();
+        format!("{}…{}", prefix, suffix)
+    }
+
+    fn normalize(token: String) -> Option<String> {
+        let token = token.trim();
+        (!token.is_empty()).then(|| token.to_string())
+    }
+
+    // Kept out of `settings/` so diagnostic bundles never pick it up.
+    fn token_file_path() -> Result<PathBuf, String> {
+        let cache_dir = DirectoryService::get_lerobot_cache_dir()?;
+        Ok(cache_dir.join("credentials").join("hugging_face.json"))
+    }
+
+    fn cli_token_path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var("HF_TOKEN_PATH") {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var("HF_HOME")
+            .map(PathBuf::from)
+            .ok()
+            .or_else(|| dirs::home_dir().map(|home| home.join(".cache").join("huggingface")))
+            .map(|home| home.join("token"))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/hugging_face_token_service_tests.rs"]
+mod hugging_face_token_service_tests;
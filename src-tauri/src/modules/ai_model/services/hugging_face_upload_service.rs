@@ -0,0 +1,909 @@
+use crate::modules::ai_model::services::hugging_face_hub_service::HuggingFaceHubService;
+use crate::modules::ai_model::services::hugging_face_token_service::HuggingFaceTokenService;
+use crate::services::directory::directory_service::DirectoryService;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::Utc;
+use reqwest::blocking::{Body, Client, RequestBuilder, Response};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, ETAG};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+pub const HUGGING_FACE_UPLOAD_EVENT: &str = "hugging-face-upload-progress";
+const DEFAULT_REVISION: &str = "main";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+const LFS_CONTENT_TYPE: &str = "application/vnd.git-lfs+json";
+// Files per preupload or LFS batch request, as in huggingface_hub.
+const BATCH_SIZE: usize = 256;
+// The Hub decides regular vs LFS from the size and the first bytes.
+const SAMPLE_SIZE: usize = 512;
+// Local state that must never be pushed: the Hub download staging area.
+const SKIPPED_ROOT_DIRS: [&str; 2] = [".cache", ".git"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HubRepoType {
+    #[default]
+    Model,
+    Dataset,
+}
+
+impl HubRepoType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Model => "model",
+            Self::Dataset => "dataset",
+        }
+    }
+
+    /// Path segment of the REST API, e.g. `/api/datasets/...`.
+    fn api_segment(self) -> &'static str {
+        match self {
+            Self::Model => "models",
+            Self::Dataset => "datasets",
+        }
+    }
+
+    /// Prefix of the repo's git URL, e.g. `/datasets/org/name.git`.
+    fn git_prefix(self) -> &'static str {
+        match self {
+            Self::Model => "",
+            Self::Dataset => "datasets/",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubUploadRequest {
+    pub repo_id: String,
+    #[serde(default)]
+    pub repo_type: HubRepoType,
+    /// Folder to upload. Datasets default to the LeRobot dataset cache.
+    pub local_path: Option<String>,
+    pub commit_message: Option<String>,
+    #[serde(default)]
+    pub private: bool,
+    pub revision: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HubUploadOptions {
+    pub repo_type: HubRepoType,
+    /// Branch to commit to; defaults to `main`.
+    pub revision: Option<String>,
+    pub commit_message: Option<String>,
+    /// Skip local paths matching one of these globs.
+    pub ignore_patterns: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HubUploadPhase {
+    Preparing,
+    Uploading,
+    Committing,
+    Completed,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubUploadProgress {
+    pub phase: HubUploadPhase,
+    pub uploaded_bytes: u64,
+    pub total_bytes: u64,
+    pub file_count: usize,
+    pub current_file: Option<String>,
+}
+
+impl HubUploadProgress {
+    pub fn percent(&self) -> u64 {
+        if self.total_bytes == 0 {
+            return 0;
+        }
+        (self.uploaded_bytes.min(self.total_bytes) * 100) / self.total_bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubUploadOutcome {
+    pub repo_id: String,
+    pub repo_type: HubRepoType,
+    pub commit_oid: String,
+    pub commit_url: Option<String>,
+    pub regular_files: usize,
+    pub lfs_files: usize,
+    /// LFS objects the Hub already had, so their bytes were not sent.
+    pub reused_lfs_files: usize,
+    /// Files the repo's `.gitignore` excludes.
+    pub ignored_files: usize,
+    pub uploaded_bytes: u64,
+}
+
+/// A local file and how the Hub wants it committed.
+#[derive(Clone, Debug)]
+struct UploadFile {
+    path_in_repo: String,
+    local_path: PathBuf,
+    size: u64,
+    lfs: bool,
+    sha256: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PreuploadResponse {
+    files: Vec<PreuploadFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PreuploadFile {
+    path: String,
+    upload_mode: String,
+    #[serde(default)]
+    should_ignore: bool,
+}
+
+#[derive(Deserialize)]
+struct LfsBatchResponse {
+    objects: Vec<LfsBatchObject>,
+}
+
+#[derive(Deserialize)]
+struct LfsBatchObject {
+    oid: String,
+    #[serde(default)]
+    actions: Option<LfsActions>,
+    #[serde(default)]
+    error: Option<LfsObjectError>,
+}
+
+#[derive(Deserialize)]
+struct LfsActions {
+    upload: Option<LfsAction>,
+    verify: Option<LfsAction>,
+}
+
+#[derive(Deserialize)]
+struct LfsAction {
+    href: String,
+    #[serde(default)]
+    header: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct LfsObjectError {
+    code: u16,
+    message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitResponse {
+    commit_oid: String,
+    commit_url: Option<String>,
+}
+
+pub struct HuggingFaceUploadService {
+    endpoint: String,
+    token: String,
+    client: Client,
+}
+
+impl HuggingFaceUploadService {
+    pub fn new(endpoint: &str, token: Option<String>) -> Result<Self, String> {
+        let token = token
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .ok_or(
+                "Uploading to Hugging Face needs an access token with write access. Add one in settings."
+                    .to_string(),
+            )?;
+        // No overall timeout: a large checkpoint part can take minutes.
+        let client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(None)
+            .build()
+            .map_err(|e| format!("Failed to build Hugging Face client: {}", e))?;
+        Ok(Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            token,
+            client,
+        })
+    }
+
+    /// Use `HF_ENDPOINT` and the token the app resolves for Hub requests.
+    pub fn from_env() -> Result<Self, String> {
+        Self::new(
+            &HuggingFaceHubService::endpoint_from_env(),
+            HuggingFaceTokenService::resolve_token(),
+        )
+    }
+
+    //-------------------------------------------------------------------------//
+    // Upload From the App
+    //-------------------------------------------------------------------------//
+    /// Create the repo if needed and commit the request's folder to it,
+    /// emitting `HUGGING_FACE_UPLOAD_EVENT` as files go up.
+    pub async fn upload(
+        app_handle: &AppHandle,
+        request: HubUploadRequest,
+    ) -> Result<HubUploadOutcome, String> {
+        let repo_id = Self::validate_repo_id(&request.repo_id)?;
+        let local_dir = Self::resolve_local_dir(&request, &repo_id)?;
+        let app = app_handle.clone();
+
+        tauri::async_runtime::spawn_blocking(move || {
+            let emit =
+                |status: &str, progress: Option<&HubUploadProgress>, message: Option<&str>| {
+                    let _ = app.emit(
+                    HUGGING_FACE_UPLOAD_EVENT,
+                    json!({
+                        "repoId": repo_id,
+                        "repoType": request.repo_type,
+                        "status": status,
+                        "progress": progress.map(HubUploadProgress::percent),
+                        "phase": progress.map(|progress| progress.phase),
+                        "uploadedBytes": progress.map(|progress| progress.uploaded_bytes),
+                        "totalBytes": progress.map(|progress| progress.total_bytes),
+                        "currentFile": progress.and_then(|progress| progress.current_file.clone()),
+                        "message": message,
+                        "updatedAtEpochMs": Utc::now().timestamp_millis()
+                    }),
+                );
+                };
+
+            let options = HubUploadOptions {
+                repo_type: request.repo_type,
+                revision: request.revision.clone(),
+                commit_message: request.commit_message.clone(),
+                ignore_patterns: Vec::new(),
+            };
+            let result = Self::from_env().and_then(|hub| {
+                hub.create_repo(&repo_id, request.repo_type, request.private)?;
+                hub.upload_folder(&repo_id, &local_dir, &options, &mut |progress| {
+                    emit("uploading", Some(progress), None)
+                })
+            });
+            match &result {
+                Ok(_) => emit("completed", None, None),
+                Err(message) => emit("error", None, Some(message)),
+            }
+            result
+        })
+        .await
+        .map_err(|e| format!("Hugging Face upload task failed: {}", e))?
+    }
+
+    fn resolve_local_dir(request: &HubUploadRequest, repo_id: &str) -> Result<PathBuf, String> {
+        let path = match request
+            .local_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+        {
+            Some(path) if Path::new(path).is_absolute() => PathBuf::from(path),
+            // Relative model paths are the ones rollout and inference accept.
+            Some(path) => DirectoryService::get_lerobot_vulcan_dir()?.join(path),
+            None if request.repo_type == HubRepoType::Dataset => {
+                DirectoryService::get_lerobot_cache_dir()?.join(repo_id)
+            }
+            None => return Err("Choose the model folder to upload".to_string()),
+        };
+        if !path.is_dir() {
+            return Err(format!("Upload folder {} does not exist", path.display()));
+        }
+        Ok(path)
+    }
+
+    pub(crate) fn validate_repo_id(repo_id: &str) -> Result<String, String> {
+        let repo_id = repo_id.trim();
+        let parts: Vec<&str> = repo_id.split('/').collect();
+        let is_valid = !parts.is_empty()
+            && parts.len() <= 2
+            && parts.iter().all(|part| {
+                !part.is_empty()
+                    && part != &"."
+                    && part != &".."
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            });
+        if !is_valid {
+            return Err(format!(
+                "Invalid Hugging Face repo id '{}'; use <namespace>/<name>",
+                repo_id
+            ));
+        }
+        Ok(repo_id.to_string())
+    }
+
+    //-------------------------------------------------------------------------//
+    // Repository
+    //-------------------------------------------------------------------------//
+    /// Create the repository, leaving an existing one (and its visibility)
+    /// as it is.
+    pub fn create_repo(
+        &self,
+        repo_id: &str,
+        repo_type: HubRepoType,
+        private: bool,
+    ) -> Result<(), String> {
+        let (organization, name) = match repo_id.split_once('/') {
+            Some((organization, name)) => (Some(organization), name),
+            None => (None, repo_id),
+        };
+        let mut body = json!({ "name": name, "private": private });
+        if let Some(organization) = organization {
+            body["organization"] = json!(organization);
+        }
+        if repo_type != HubRepoType::Model {
+            body["type"] = json!(repo_type.as_str());
+        }
+
+        let response = self
+            .authorized(
+                self.client
+                    .post(format!("{}/api/repos/create", self.endpoint)),
+            )
+            .json(&body)
+            .send()
+            .map_err(|e| format!("Failed to reach Hugging Face: {}", e))?;
+        if response.status() == StatusCode::CONFLICT {
+            return Ok(());
+        }
+        Self::check_response(response, repo_id, "repository creation").map(|_| ())
+    }
+
+    //-------------------------------------------------------------------------//
+    // Folder Upload
+    //-------------------------------------------------------------------------//
+    /// Commit every file under `local_dir` to the repo in one commit. Large
+    /// and binary files go through Git LFS, in parts when the Hub asks for a
+    /// multipart transfer; objects the Hub already stores are not re-sent.
+    pub fn upload_folder(
+        &self,
+        repo_id: &str,
+        local_dir: &Path,
+        options: &HubUploadOptions,
+        on_progress: &mut dyn FnMut(&HubUploadProgress),
+    ) -> Result<HubUploadOutcome, String> {
+        let revision = options
+            .revision
+            .as_deref()
+            .map(str::trim)
+            .filter(|revision| !revision.is_empty())
+            .unwrap_or(DEFAULT_REVISION);
+        let mut files = Self::collect_files(local_dir, &options.ignore_patterns)?;
+        if files.is_empty() {
+            return Err(format!("{} has no files to upload", local_dir.display()));
+        }
+
+        let mut progress = HubUploadProgress {
+            phase: HubUploadPhase::Preparing,
+            uploaded_bytes: 0,
+            total_bytes: files.iter().map(|file| file.size).sum(),
+            file_count: files.len(),
+            current_file: None,
+        };
+        on_progress(&progress);
+
+        let ignored = self.preupload(repo_id, options.repo_type, revision, &mut files)?;
+        let file_count = files.len();
+        files.retain(|file| !ignored.contains(&file.path_in_repo));
+        let ignored_files = file_count - files.len();
+        progress.total_bytes = files.iter().map(|file| file.size).sum();
+        progress.file_count = files.len();
+
+        for file in files.iter_mut().filter(|file| file.lfs) {
+            progress.current_file = Some(file.path_in_repo.clone());
+            on_progress(&progress);
+            file.sha256 = Some(Self::sha256_file(&file.local_path)?);
+        }
+
+        progress.phase = HubUploadPhase::Uploading;
+        let lfs_files: Vec<&UploadFile> = files.iter().filter(|file| file.lfs).collect();
+        let mut reused_lfs_files = 0;
+        for batch in lfs_files.chunks(BATCH_SIZE) {
+            reused_lfs_files += self.upload_lfs_batch(
+                repo_id,
+                options.repo_type,
+                revision,
+                batch,
+                &mut progress,
+                on_progress,
+            )?;
+        }
+
+        progress.phase = HubUploadPhase::Committing;
+        progress.current_file = None;
+        on_progress(&progress);
+        let commit_message = options
+            .commit_message
+            .as_deref()
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("Upload {} from Sourccey", options.repo_type.as_str()));
+        let commit = self.commit(
+            repo_id,
+            options.repo_type,
+            revision,
+            &commit_message,
+            &files,
+        )?;
+
+        progress.phase = HubUploadPhase::Completed;
+        progress.uploaded_bytes = progress.total_bytes;
+        on_progress(&progress);
+
+        Ok(HubUploadOutcome {
+            repo_id: repo_id.to_string(),
+            repo_type: options.repo_type,
+            commit_oid: commit.commit_oid,
+            commit_url: commit.commit_url,
+            regular_files: files.iter().filter(|file| !file.lfs).count(),
+            lfs_files: lfs_files.len(),
+            reused_lfs_files,
+            ignored_files,
+            uploaded_bytes: progress.total_bytes,
+        })
+    }
+
+    /// Ask the Hub which files go through LFS. Returns the paths it ignores.
+    fn preupload(
+        &self,
+        repo_id: &str,
+        repo_type: HubRepoType,
+        revision: &str,
+        files: &mut [UploadFile],
+    ) -> Result<Vec<String>, String> {
+        let url = format!(
+            "{}/api/{}/{}/preupload/{}",
+            self.endpoint,
+            repo_type.api_segment(),
+            repo_id,
+            HuggingFaceHubService::encode_path_segment(revision)
+        );
+        let mut ignored = Vec::new();
+        for batch in files.chunks_mut(BATCH_SIZE) {
+            let mut entries = Vec::with_capacity(batch.len());
+            for file in batch.iter() {
+                entries.push(json!({
+                    "path": file.path_in_repo,
+                    "sample": BASE64.encode(Self::read_sample(&file.local_path)?),
+                    "size": file.size,
+                }));
+            }
+            let response: PreuploadResponse = self.post_json(
+                self.client.post(&url).json(&json!({ "files": entries })),
+                repo_id,
+                "upload preparation",
+            )?;
+
+            let modes: HashMap<String, PreuploadFile> = response
+                .files
+                .into_iter()
+                .map(|file| (file.path.clone(), file))
+                .collect();
+            for file in batch.iter_mut() {
+                let mode = modes.get(&file.path_in_repo).ok_or(format!(
+                    "Hugging Face did not say how to upload {}",
+                    file.path_in_repo
+                ))?;
+                file.lfs = mode.upload_mode == "lfs";
+                if mode.should_ignore {
+                    ignored.push(file.path_in_repo.clone());
+                }
+            }
+        }
+        Ok(ignored)
+    }
+
+    /// Send one batch of LFS objects. Returns how many the Hub already had.
+    fn upload_lfs_batch(
+        &self,
+        repo_id: &str,
+        repo_type: HubRepoType,
+        revision: &str,
+        files: &[&UploadFile],
+        progress: &mut HubUploadProgress,
+        on_progress: &mut dyn FnMut(&HubUploadProgress),
+    ) -> Result<usize, String> {
+        let objects: Vec<Value> = files
+            .iter()
+            .map(|file| json!({ "oid": file.sha256, "size": file.size }))
+            .collect();
+        let url = format!(
+            "{}/{}{}.git/info/lfs/objects/batch",
+            self.endpoint,
+            repo_type.git_prefix(),
+            repo_id
+        );
+        let response: LfsBatchResponse = self.post_json(
+            self.client
+                .post(&url)
+                .header(ACCEPT, LFS_CONTENT_TYPE)
+                .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+                .body(
+                    json!({
+                        "operation": "upload",
+                        "transfers": ["basic", "multipart"],
+                        "objects": objects,
+                        "hash_algo": "sha256",
+                        "ref": { "name": revision },
+                    })
+                    .to_string(),
+                ),
+            repo_id,
+            "LFS upload request",
+        )?;
+
+        let by_oid: HashMap<&str, &UploadFile> = files
+            .iter()
+            .filter_map(|file| Some((file.sha256.as_deref()?, *file)))
+            .collect();
+        let mut reused = 0;
+        for object in response.objects {
+            let file = by_oid.get(object.oid.as_str()).ok_or(format!(
+                "Hugging Face answered for unknown LFS object {}",
+                object.oid
+            ))?;
+            if let Some(error) = object.error {
+                return Err(format!(
+                    "Hugging Face refused {} ({}): {}",
+                    file.path_in_repo, error.code, error.message
+                ));
+            }
+            progress.current_file = Some(file.path_in_repo.clone());
+            let Some(upload) = object
+                .actions
+                .as_ref()
+                .and_then(|actions| actions.upload.as_ref())
+            else {
+                reused += 1;
+                progress.uploaded_bytes += file.size;
+                on_progress(progress);
+                continue;
+            };
+
+            let uploaded_before = progress.uploaded_bytes;
+            if upload.header.contains_key("chunk_size") {
+                self.upload_multipart(file, upload, &mut |sent| {
+                    progress.uploaded_bytes = uploaded_before + sent;
+                    on_progress(progress);
+                })?;
+            } else {
+                let body = Body::sized(Self::open(&file.local_path)?, file.size);
+                let response = Self::with_headers(self.client.put(&upload.href), &upload.header)
+                    .body(body)
+                    .send()
+                    .map_err(|e| format!("Failed to upload {}: {}", file.path_in_repo, e))?;
+                Self::check_response(response, repo_id, "LFS upload")?;
+            }
+            progress.uploaded_bytes = uploaded_before + file.size;
+            on_progress(progress);
+
+            if let Some(verify) = object
+                .actions
+                .as_ref()
+                .and_then(|actions| actions.verify.as_ref())
+            {
+                let response = Self::with_headers(
+                    self.authorized(self.client.post(&verify.href)),
+                    &verify.header,
+                )
+                .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+                .body(json!({ "oid": object.oid, "size": file.size }).to_string())
+                .send()
+                .map_err(|e| format!("Failed to verify {}: {}", file.path_in_repo, e))?;
+                Self::check_response(response, repo_id, "LFS verification")?;
+            }
+        }
+        Ok(reused)
+    }
+
+    /// PUT each part to its presigned URL, then hand the ETags back to the
+    /// Hub so it can assemble the object.
+    fn upload_multipart(
+        &self,
+        file: &UploadFile,
+        upload: &LfsAction,
+        on_sent: &mut dyn FnMut(u64),
+    ) -> Result<(), String> {
+        let chunk_size = upload
+            .header
+            .get("chunk_size")
+            .and_then(|value| {
+                value
+                    .as_u64()
+                    .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+            })
+            .filter(|size| *size > 0)
+            .ok_or(format!(
+                "Invalid multipart chunk size for {}",
+                file.path_in_repo
+            ))?;
+        let part_urls: BTreeMap<u32, &str> = upload
+            .header
+            .iter()
+            .filter_map(|(key, value)| Some((key.parse::<u32>().ok()?, value.as_str()?)))
+            .collect();
+        let expected_parts = file.size.div_ceil(chunk_size);
+        if part_urls.len() as u64 != expected_parts {
+            return Err(format!(
+                "Hugging Face sent {} upload URLs for {}, expected {}",
+                part_urls.len(),
+                file.path_in_repo,
+                expected_parts
+            ));
+        }
+
+        let mut parts = Vec::with_capacity(part_urls.len());
+        let mut sent = 0;
+        for (index, url) in part_urls.values().enumerate() {
+            let offset = index as u64 * chunk_size;
+            let length = chunk_size.min(file.size - offset);
+            let mut reader = Self::open(&file.local_path)?;
+            reader
+                .seek(SeekFrom::Start(offset))
+                .map_err(|e| format!("Failed to read {}: {}", file.path_in_repo, e))?;
+            let response = self
+                .client
+                .put(*url)
+                .body(Body::sized(reader.take(length), length))
+                .send()
+                .map_err(|e| {
+                    format!(
+                        "Failed to upload part {} of {}: {}",
+                        index + 1,
+                        file.path_in_repo,
+                        e
+                    )
+                })?;
+            if !response.status().is_success() {
+                return Err(format!(
+                    "Upload of part {} of {} failed ({})",
+                    index + 1,
+                    file.path_in_repo,
+                    response.status()
+                ));
+            }
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|value| value.to_str().ok())
+                .ok_or(format!(
+                    "Part {} of {} came back without an ETag",
+                    index + 1,
+                    file.path_in_repo
+                ))?;
+            parts.push(json!({ "partNumber": index + 1, "etag": etag }));
+            sent += length;
+            on_sent(sent);
+        }
+
+        let response = self
+            .authorized(self.client.post(&upload.href))
+            .header(ACCEPT, LFS_CONTENT_TYPE)
+            .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+            .body(json!({ "oid": file.sha256, "parts": parts }).to_string())
+            .send()
+            .map_err(|e| format!("Failed to complete upload of {}: {}", file.path_in_repo, e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Completing the upload of {} failed ({})",
+                file.path_in_repo,
+                response.status()
+            ));
+        }
+        Ok(())
+    }
+
+    /// One commit with regular files inline and LFS files by pointer.
+    fn commit(
+        &self,
+        repo_id: &str,
+        repo_type: HubRepoType,
+        revision: &str,
+        message: &str,
+        files: &[UploadFile],
+    ) -> Result<CommitResponse, String> {
+        let mut lines = vec![json!({
+            "key": "header",
+            "value": { "summary": message, "description": "" },
+        })];
+        for file in files {
+            if file.lfs {
+                lines.push(json!({
+                    "key": "lfsFile",
+                    "value": {
+                        "path": file.path_in_repo,
+                        "algo": "sha256",
+                        "oid": file.sha256,
+                        "size": file.size,
+                    },
+                }));
+            } else {
+                let content = fs::read(&file.local_path)
+                    .map_err(|e| format!("Failed to read {}: {}", file.path_in_repo, e))?;
+                lines.push(json!({
+                    "key": "file",
+                    "value": {
+                        "content": BASE64.encode(content),
+                        "path": file.path_in_repo,
+                        "encoding": "base64",
+                    },
+                }));
+            }
+        }
+        let body = lines
+            .iter()
+            .map(Value::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let url = format!(
+            "{}/api/{}/{}/commit/{}",
+            self.endpoint,
+            repo_type.api_segment(),
+            repo_id,
+            HuggingFaceHubService::encode_path_segment(revision)
+        );
+        self.post_json(
+            self.client
+                .post(&url)
+                .header(CONTENT_TYPE, "application/x-ndjson")
+                .body(body),
+            repo_id,
+            "commit",
+        )
+    }
+
+    //-------------------------------------------------------------------------//
+    // Helpers
+    //-------------------------------------------------------------------------//
+    /// Every file under `local_dir`, sorted, with `/`-separated repo paths.
+    fn collect_files(
+        local_dir: &Path,
+        ignore_patterns: &[String],
+    ) -> Result<Vec<UploadFile>, String> {
+        if !local_dir.is_dir() {
+            return Err(format!("{} is not a folder", local_dir.display()));
+        }
+        let mut files = Vec::new();
+        let mut stack = vec![local_dir.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            let entries = fs::read_dir(&dir)
+                .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let relative = path
+                    .strip_prefix(local_dir)
+                    .map_err(|e| format!("Failed to resolve {}: {}", path.display(), e))?
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy().to_string())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                if path.is_dir() {
+                    if dir != local_dir || !SKIPPED_ROOT_DIRS.contains(&relative.as_str()) {
+                        stack.push(path);
+                    }
+                    continue;
+                }
+                if ignore_patterns
+                    .iter()
+                    .any(|pattern| HuggingFaceHubService::matches_pattern(pattern, &relative))
+                {
+                    continue;
+                }
+                let size = entry
+                    .metadata()
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+                    .len();
+                files.push(UploadFile {
+                    path_in_repo: relative,
+                    local_path: path,
+                    size,
+                    lfs: false,
+                    sha256: None,
+                });
+            }
+        }
+        files.sort_by(|a, b| a.path_in_repo.cmp(&b.path_in_repo));
+        Ok(files)
+    }
+
+    fn read_sample(path: &Path) -> Result<Vec<u8>, String> {
+        let mut sample = Vec::with_capacity(SAMPLE_SIZE);
+        Self::open(path)?
+            .take(SAMPLE_SIZE as u64)
+            .read_to_end(&mut sample)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Ok(sample)
+    }
+
+    fn sha256_file(path: &Path) -> Result<String, String> {
+        let mut file = Self::open(path)?;
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut file, &mut hasher)
+            .map_err(|e| format!("Failed to hash {}: {}", path.display(), e))?;
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    fn open(path: &Path) -> Result<File, String> {
+        File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+    }
+
+    fn authorized(&self, request: RequestBuilder) -> RequestBuilder {
+        request.header(AUTHORIZATION, format!("Bearer {}", self.token))
+    }
+
+    fn with_headers(
+        mut request: RequestBuilder,
+        headers: &HashMap<String, Value>,
+    ) -> RequestBuilder {
+        for (name, value) in headers {
+            if let Some(value) = value.as_str() {
+                request = request.header(name.as_str(), value);
+            }
+        }
+        request
+    }
+
+    fn post_json<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        repo_id: &str,
+        action: &str,
+    ) -> Result<T, String> {
+        let response = self
+            .authorized(request)
+            .send()
+            .map_err(|e| format!("Failed to reach Hugging Face: {}", e))?;
+        Self::check_response(response, repo_id, action)?
+            .json::<T>()
+            .map_err(|e| format!("Invalid {} response from Hugging Face: {}", action, e))
+    }
+
+    fn check_response(response: Response, repo_id: &str, action: &str) -> Result<Response, String> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+            return Err(format!(
+                "Hugging Face rejected the access token for {}; it needs write access to the repository",
+                repo_id
+            ));
+        }
+        let detail = response
+            .json::<Value>()
+            .ok()
+            .and_then(|body| {
+                body.get("error")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .map(|error| format!(": {}", error))
+            .unwrap_or_default();
+        Err(format!(
+            "Hugging Face {} for {} failed ({}){}",
+            action, repo_id, status, detail
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/hugging_face_upload_service_tests.rs"]
+mod hugging_face_upload_service_tests;
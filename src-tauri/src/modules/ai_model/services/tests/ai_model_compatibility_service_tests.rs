@@ -0,0 +1,151 @@
+use super::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn features(action: u64, state: u64, cameras: &[(&str, [u64; 3])]) -> PolicyFeatures {
+    let mut inputs = BTreeMap::from([("observation.state".to_string(), vec![state])]);
+    for (name, shape) in cameras {
+        inputs.insert(format!("observation.images.{}", name), shape.to_vec());
+    }
+    PolicyFeatures {
+        inputs,
+        outputs: BTreeMap::from([("action".to_string(), vec![action])]),
+    }
+}
+
+fn camera(width: u32, height: u32) -> Camera {
+    Camera {
+        camera_type: "opencv".to_string(),
+        camera_index: 0,
+        fps: 30,
+        width,
+        height,
+        color_mode: "rgb".to_string(),
+    }
+}
+
+fn kinds(issues: &[CompatibilityIssue]) -> Vec<(CompatibilityIssueKind, CompatibilitySeverity)> {
+    issues
+        .iter()
+        .map(|issue| (issue.kind, issue.severity))
+        .collect()
+}
+
+#[test]
+fn matching_policy_has_no_issues() {
+    let cameras = HashMap::from([("front".to_string(), camera(640, 480))]);
+
+    let issues = AiModelCompatibilityService::compare(
+        &features(16, 16, &[("front", [3, 480, 640])]),
+        "sourccey",
+        Some(&cameras),
+    );
+
+    assert!(issues.is_empty(), "{:?}", issues);
+    // Arm-only policies fit a Sourccey too.
+    assert!(
+        AiModelCompatibilityService::compare(&features(12, 12, &[]), "sourccey", None).is_empty()
+    );
+}
+
+#[test]
+fn action_size_and_camera_mismatches_are_reported() {
+    let cameras = HashMap::from([
+        ("front".to_string(), camera(1280, 720)),
+        ("top".to_string(), camera(640, 480)),
+    ]);
+
+    let issues = AiModelCompatibilityService::compare(
+        &features(6, 6, &[("front", [3, 480, 640]), ("wrist", [3, 480, 640])]),
+        "sourccey",
+        Some(&cameras),
+    );
+
+    assert_eq!(
+        kinds(&issues),
+        vec![
+            (
+                CompatibilityIssueKind::ActionDimension,
+                CompatibilitySeverity::Error
+            ),
+            (
+                CompatibilityIssueKind::StateDimension,
+                CompatibilitySeverity::Error
+            ),
+            (
+                CompatibilityIssueKind::CameraResolution,
+                CompatibilitySeverity::Warning
+            ),
+            (
+                CompatibilityIssueKind::MissingCamera,
+                CompatibilitySeverity::Error
+            ),
+            (
+                CompatibilityIssueKind::UnusedCamera,
+                CompatibilitySeverity::Warning
+            ),
+        ]
+    );
+    assert_eq!(issues[0].expected.as_deref(), Some("12 or 16"));
+    assert_eq!(issues[0].actual.as_deref(), Some("6"));
+    assert_eq!(issues[3].expected.as_deref(), Some("wrist"));
+    assert_eq!(issues[4].actual.as_deref(), Some("top"));
+
+    let so100 = AiModelCompatibilityService::compare(&features(6, 6, &[]), "so100", None);
+    assert!(so100.is_empty(), "{:?}", so100);
+}
+
+#[test]
+fn unknown_robot_and_missing_camera_config_only_warn() {
+    let issues = AiModelCompatibilityService::compare(
+        &features(7, 7, &[("front", [3, 480, 640])]),
+        "koch",
+        None,
+    );
+
+    assert_eq!(
+        kinds(&issues),
+        vec![
+            (
+                CompatibilityIssueKind::UnknownRobotType,
+                CompatibilitySeverity::Warning
+            ),
+            (
+                CompatibilityIssueKind::CamerasUnknown,
+                CompatibilitySeverity::Warning
+            ),
+        ]
+    );
+}
+
+#[test]
+fn report_reads_the_model_config_from_disk() {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let model_dir = std::env::temp_dir().join(format!("{}_compat_model", nonce));
+    fs::create_dir_all(&model_dir).unwrap();
+    fs::write(
+        model_dir.join("config.json"),
+        r#"{"type": "act", "input_features": {"observation.state": {"type": "STATE", "shape": [6]}}, "output_features": {"action": {"type": "ACTION", "shape": [6]}}}"#,
+    )
+    .unwrap();
+    let model_path = model_dir.to_string_lossy().to_string();
+
+    let report = AiModelCompatibilityService::check(&model_path, "sourccey", None);
+    assert!(!report.compatible);
+    assert_eq!(report.messages(CompatibilitySeverity::Error).len(), 2);
+    assert!(report.refusal("rollout").contains("not compatible"));
+
+    let missing = AiModelCompatibilityService::check("/nonexistent/model", "sourccey", None);
+    assert!(missing.compatible);
+    assert_eq!(
+        kinds(&missing.issues),
+        vec![(
+            CompatibilityIssueKind::ModelUnreadable,
+            CompatibilitySeverity::Warning
+        )]
+    );
+}
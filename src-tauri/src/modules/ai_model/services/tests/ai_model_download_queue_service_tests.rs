@@ -0,0 +1,66 @@
+use super::*;
+
+#[test]
+fn slots_are_limited_and_held_per_repo() {
+    let mut queue = AiModelDownloadQueue::new(2);
+
+    assert!(queue.begin("org/a").is_some());
+    assert!(queue.begin("org/a").is_none());
+    assert!(queue.begin("org/b").is_some());
+    assert_eq!(queue.available_slots(), 0);
+    assert!(queue.begin("org/c").is_none());
+
+    assert_eq!(queue.finish("org/a"), None);
+    assert_eq!(queue.available_slots(), 1);
+    assert!(queue.begin("org/c").is_some());
+}
+
+#[test]
+fn stop_requests_set_the_cancel_flag_and_cancel_wins() {
+    let mut queue = AiModelDownloadQueue::new(2);
+    let flag = queue.begin("org/a").expect("slot should be free");
+
+    assert!(!queue.request_stop("org/missing", DownloadStop::Pause));
+    assert!(queue.request_stop("org/a", DownloadStop::Pause));
+    assert!(flag.load(Ordering::SeqCst));
+    assert!(queue.request_stop("org/a", DownloadStop::Cancel));
+    assert!(queue.request_stop("org/a", DownloadStop::Pause));
+
+    assert_eq!(queue.finish("org/a"), Some(DownloadStop::Cancel));
+    assert_eq!(queue.finish("org/a"), None);
+}
+
+#[test]
+fn finished_status_leaves_stopped_downloads_alone() {
+    assert_eq!(
+        AiModelDownloadQueue::finished_status(true, None),
+        Some(AiModelDownloadStatus::Completed)
+    );
+    assert_eq!(
+        AiModelDownloadQueue::finished_status(true, Some(DownloadStop::Cancel)),
+        Some(AiModelDownloadStatus::Completed)
+    );
+    assert_eq!(
+        AiModelDownloadQueue::finished_status(false, None),
+        Some(AiModelDownloadStatus::Failed)
+    );
+    assert_eq!(
+        AiModelDownloadQueue::finished_status(false, Some(DownloadStop::Pause)),
+        None
+    );
+}
+
+#[test]
+fn waiters_receive_the_outcome_once() {
+    let mut queue = AiModelDownloadQueue::new(1);
+    let mut first = queue.add_waiter("org/a");
+    let mut second = queue.add_waiter("org/a");
+    let mut other = queue.add_waiter("org/b");
+
+    queue.resolve_waiters("org/a", Ok("done".to_string()));
+    queue.resolve_waiters("org/a", Err("late".to_string()));
+
+    assert_eq!(first.try_recv().unwrap(), Ok("done".to_string()));
+    assert_eq!(second.try_recv().unwrap(), Ok("done".to_string()));
+    assert!(other.try_recv().is_err());
+}
@@ -0,0 +1,126 @@
+use super::*;
+use migration::MigratorTrait;
+
+async fn service() -> AiModelDownloadService {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    AiModelDownloadService::new(connection)
+}
+
+fn request(repo_id: &str) -> AiModelDownloadRequest {
+    AiModelDownloadRequest {
+        repo_id: repo_id.to_string(),
+        ignore_patterns: vec!["*.md".to_string()],
+        ..AiModelDownloadRequest::default()
+    }
+}
+
+#[tokio::test]
+async fn queueing_a_repo_twice_reuses_its_download() {
+    let service = service().await;
+
+    let first = service.enqueue(&request("org/act")).await.unwrap();
+    let again = service.enqueue(&request("org/act")).await.unwrap();
+    assert_eq!(again.id, first.id);
+    assert_eq!(first.ignore_pattern_list(), vec!["*.md".to_string()]);
+    assert!(first.allow_pattern_list().is_empty());
+
+    service
+        .set_status(&first.id, AiModelDownloadStatus::Paused, None)
+        .await
+        .unwrap();
+    let resumed = service.enqueue(&request("org/act")).await.unwrap();
+    assert_eq!(resumed.id, first.id);
+    assert_eq!(
+        resumed.download_status(),
+        Some(AiModelDownloadStatus::Queued)
+    );
+
+    service
+        .set_status(&first.id, AiModelDownloadStatus::Completed, None)
+        .await
+        .unwrap();
+    let fresh = service.enqueue(&request("org/act")).await.unwrap();
+    assert_ne!(fresh.id, first.id);
+}
+
+#[tokio::test]
+async fn queued_downloads_come_out_oldest_first() {
+    let service = service().await;
+    let first = service.enqueue(&request("org/a")).await.unwrap();
+    let second = service.enqueue(&request("org/b")).await.unwrap();
+    let third = service.enqueue(&request("org/c")).await.unwrap();
+    service
+        .set_status(&second.id, AiModelDownloadStatus::Paused, None)
+        .await
+        .unwrap();
+
+    let queued = service.get_queued(5).await.unwrap();
+
+    let ids: Vec<&str> = queued.iter().map(|download| download.id.as_str()).collect();
+    assert_eq!(ids, vec![first.id.as_str(), third.id.as_str()]);
+    assert_eq!(service.get_queued(1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn finished_downloads_are_listed_after_the_queue() {
+    let service = service().await;
+    let done = service.enqueue(&request("org/done")).await.unwrap();
+    let waiting = service.enqueue(&request("org/waiting")).await.unwrap();
+    let failed = service
+        .set_status(
+            &done.id,
+            AiModelDownloadStatus::Failed,
+            Some("network".to_string()),
+        )
+        .await
+        .unwrap();
+
+    let downloads = service.get_downloads(None).await.unwrap();
+
+    assert_eq!(downloads.len(), 2);
+    assert_eq!(downloads[0].id, waiting.id);
+    assert_eq!(downloads[1].id, done.id);
+    assert_eq!(failed.error_message.as_deref(), Some("network"));
+    assert!(failed.completed_at.is_some());
+}
+
+#[tokio::test]
+async fn interrupted_downloads_are_requeued() {
+    let service = service().await;
+    let running = service.enqueue(&request("org/running")).await.unwrap();
+    let paused = service.enqueue(&request("org/paused")).await.unwrap();
+    service
+        .set_status(&running.id, AiModelDownloadStatus::Downloading, None)
+        .await
+        .unwrap();
+    service
+        .set_status(&paused.id, AiModelDownloadStatus::Paused, None)
+        .await
+        .unwrap();
+
+    assert_eq!(service.requeue_interrupted().await.unwrap(), 1);
+
+    let running = service
+        .get_active_download("org/running")
+        .await
+        .unwrap()
+        .expect("download should still be active");
+    assert_eq!(
+        running.download_status(),
+        Some(AiModelDownloadStatus::Queued)
+    );
+    let paused = service
+        .get_active_download("org/paused")
+        .await
+        .unwrap()
+        .expect("download should still be active");
+    assert_eq!(
+        paused.download_status(),
+        Some(AiModelDownloadStatus::Paused)
+    );
+}
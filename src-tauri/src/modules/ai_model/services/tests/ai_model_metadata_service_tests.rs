@@ -0,0 +1,179 @@
+use super::*;
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_ai_model", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn write_file(path: &Path, contents: &[u8]) {
+    fs::create_dir_all(path.parent().unwrap()).expect("parent dir should be created");
+    fs::write(path, contents).expect("file should be written");
+}
+
+fn policy_config() -> Value {
+    json!({
+        "type": "ACT",
+        "input_features": {
+            "observation.state": { "type": "STATE", "shape": [12] },
+            "observation.images.wrist": { "type": "VISUAL", "shape": [3, 480, 640] },
+            "observation.images.front": { "type": "VISUAL", "shape": [3, 480, 640] }
+        },
+        "output_features": {
+            "action": { "type": "ACTION", "shape": [12] }
+        }
+    })
+}
+
+#[test]
+fn training_output_is_read_from_the_newest_checkpoint() {
+    let model_dir = temp_dir("training");
+    for (step, weights) in [("005000", 10usize), ("010000", 25)] {
+        let pretrained = model_dir
+            .join("checkpoints")
+            .join(step)
+            .join("pretrained_model");
+        write_file(&pretrained.join("model.safetensors"), &vec![0u8; weights]);
+    }
+    let newest = model_dir.join("checkpoints/010000/pretrained_model");
+    write_file(
+        &newest.join("config.json"),
+        policy_config().to_string().as_bytes(),
+    );
+    write_file(
+        &newest.join("train_config.json"),
+        json!({
+            "dataset": { "repo_id": "sourccey/pick-cube" },
+            "policy": { "type": "act", "repo_id": "sourccey/act-pick-cube" },
+            "steps": 10000
+        })
+        .to_string()
+        .as_bytes(),
+    );
+
+    let metadata = AiModelMetadataService::read(&model_dir);
+
+    assert_eq!(metadata.policy_type.as_deref(), Some("act"));
+    assert_eq!(
+        metadata.input_features,
+        vec![
+            "observation.images.front",
+            "observation.images.wrist",
+            "observation.state"
+        ]
+    );
+    assert_eq!(metadata.output_features, vec!["action"]);
+    assert_eq!(metadata.camera_keys, vec!["front", "wrist"]);
+    assert_eq!(
+        metadata.dataset_repo_id.as_deref(),
+        Some("sourccey/pick-cube")
+    );
+    assert_eq!(metadata.training_steps, Some(10000));
+    assert_eq!(
+        metadata.source_repo_id.as_deref(),
+        Some("sourccey/act-pick-cube")
+    );
+    assert_eq!(metadata.source_revision, None);
+
+    let steps: Vec<i64> = metadata
+        .checkpoints
+        .iter()
+        .map(|checkpoint| checkpoint.step)
+        .collect();
+    assert_eq!(steps, vec![5000, 10000]);
+    assert_eq!(metadata.checkpoints[0].path, "checkpoints/005000");
+    assert_eq!(metadata.checkpoints[0].size_bytes, 10);
+    assert!(metadata.checkpoints[1].size_bytes > 25);
+}
+
+#[test]
+fn hub_download_uses_root_config_and_snapshot_source() {
+    let model_dir = temp_dir("hub");
+    write_file(
+        &model_dir.join("config.json"),
+        json!({ "type": "pi0", "input_features": {}, "output_features": {} })
+            .to_string()
+            .as_bytes(),
+    );
+    write_file(&model_dir.join("model.safetensors"), b"weights");
+    write_file(
+        &model_dir.join(".cache/huggingface/source.sourccey.json"),
+        br#"{"repoId": "lerobot/pi0", "revision": null, "commit": "abc123"}"#,
+    );
+
+    let metadata = AiModelMetadataService::read(&model_dir);
+
+    assert!(AiModelMetadataService::is_model_dir(&model_dir));
+    assert_eq!(metadata.policy_type.as_deref(), Some("pi0"));
+    assert!(metadata.input_features.is_empty());
+    assert!(metadata.checkpoints.is_empty());
+    assert_eq!(metadata.source_repo_id.as_deref(), Some("lerobot/pi0"));
+    assert_eq!(metadata.source_revision.as_deref(), Some("abc123"));
+}
+
+#[test]
+fn missing_or_malformed_configs_leave_metadata_empty() {
+    let model_dir = temp_dir("malformed");
+    write_file(
+        &model_dir.join("checkpoints/000100/pretrained_model/config.json"),
+        b"{not json",
+    );
+    write_file(&model_dir.join("checkpoints/notes/readme.txt"), b"ignored");
+
+    let metadata = AiModelMetadataService::read(&model_dir);
+
+    assert!(AiModelMetadataService::is_model_dir(&model_dir));
+    assert_eq!(metadata.policy_type, None);
+    assert_eq!(metadata.checkpoints.len(), 1);
+    assert!(!AiModelMetadataService::is_model_dir(&temp_dir("empty")));
+}
+
+#[test]
+fn stored_metadata_round_trips_through_the_model_columns() {
+    let metadata = AiModelMetadata {
+        policy_type: Some("diffusion".to_string()),
+        camera_keys: vec!["front".to_string()],
+        checkpoints: vec![AiModelCheckpoint {
+            step: 100,
+            path: "checkpoints/000100".to_string(),
+            size_bytes: 42,
+        }],
+        ..AiModelMetadata::default()
+    };
+    let mut active = ActiveAiModel::new("m".to_string(), "/m".to_string(), None, Some(100));
+    metadata.apply_to(&mut active);
+    let model = AiModel {
+        id: "1".to_string(),
+        name: "m".to_string(),
+        model_path: "/m".to_string(),
+        model_path_relative: None,
+        latest_checkpoint: Some(100),
+        policy_type: active.policy_type.clone().unwrap(),
+        input_features: active.input_features.clone().unwrap(),
+        output_features: active.output_features.clone().unwrap(),
+        camera_keys: active.camera_keys.clone().unwrap(),
+        dataset_repo_id: active.dataset_repo_id.clone().unwrap(),
+        training_steps: active.training_steps.clone().unwrap(),
+        source_repo_id: active.source_repo_id.clone().unwrap(),
+        source_revision: active.source_revision.clone().unwrap(),
+        checkpoints: active.checkpoints.clone().unwrap(),
+        last_used_at: None,
+        created_at: None,
+        updated_at: None,
+        deleted_at: None,
+    };
+
+    assert!(metadata.is_stored_in(&model));
+    assert_eq!(model.camera_keys.as_deref(), Some(r#"["front"]"#));
+    assert_eq!(
+        model.checkpoints.as_deref(),
+        Some(r#"[{"step":100,"path":"checkpoints/000100","sizeBytes":42}]"#)
+    );
+    assert!(!AiModelMetadata::default().is_stored_in(&model));
+}
@@ -0,0 +1,187 @@
+use super::*;
+use crate::modules::ai_model::services::ai_model_metadata_service::{
+    AiModelCheckpoint, AiModelMetadata,
+};
+use migration::MigratorTrait;
+
+async fn service() -> AiModelService {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    AiModelService::new(connection)
+}
+
+async fn add(service: &AiModelService, name: &str, metadata: AiModelMetadata) {
+    let latest_checkpoint = metadata
+        .checkpoints
+        .last()
+        .map(|checkpoint| checkpoint.step);
+    let mut model = AiModelActiveModel::new(
+        name.to_string(),
+        format!("/models/{}", name),
+        Some(format!("org/{}", name)),
+        latest_checkpoint,
+    );
+    metadata.apply_to(&mut model);
+    service
+        .add_ai_model(model)
+        .await
+        .expect("model should be added");
+}
+
+async fn names(service: &AiModelService, filters: AiModelFilters) -> Vec<String> {
+    let mut names: Vec<String> = service
+        .get_ai_models_paginated(
+            filters,
+            PaginationParameters {
+                page: Some(1),
+                page_size: Some(20),
+            },
+        )
+        .await
+        .expect("query should succeed")
+        .data
+        .into_iter()
+        .map(|model| model.name)
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn paginated_models_can_be_filtered_by_metadata() {
+    let service = service().await;
+    add(
+        &service,
+        "act-cube",
+        AiModelMetadata {
+            policy_type: Some("act".to_string()),
+            camera_keys: vec!["front".to_string(), "wrist".to_string()],
+            dataset_repo_id: Some("sourccey/cube".to_string()),
+            checkpoints: vec![AiModelCheckpoint {
+                step: 1000,
+                path: "checkpoints/001000".to_string(),
+                size_bytes: 1,
+            }],
+            ..AiModelMetadata::default()
+        },
+    )
+    .await;
+    add(
+        &service,
+        "pi0-towel",
+        AiModelMetadata {
+            policy_type: Some("pi0".to_string()),
+            camera_keys: vec!["front_left".to_string()],
+            source_repo_id: Some("lerobot/pi0".to_string()),
+            ..AiModelMetadata::default()
+        },
+    )
+    .await;
+
+    assert_eq!(names(&service, AiModelFilters::default()).await.len(), 2);
+    assert_eq!(
+        names(
+            &service,
+            AiModelFilters {
+                policy_type: Some("ACT".to_string()),
+                ..AiModelFilters::default()
+            }
+        )
+        .await,
+        vec!["act-cube"]
+    );
+    assert_eq!(
+        names(
+            &service,
+            AiModelFilters {
+                camera_key: Some("front".to_string()),
+                ..AiModelFilters::default()
+            }
+        )
+        .await,
+        vec!["act-cube"]
+    );
+    assert_eq!(
+        names(
+            &service,
+            AiModelFilters {
+                has_checkpoints: Some(false),
+                search: Some("towel".to_string()),
+                ..AiModelFilters::default()
+            }
+        )
+        .await,
+        vec!["pi0-towel"]
+    );
+    assert_eq!(
+        names(
+            &service,
+            AiModelFilters {
+                source_repo_id: Some("lerobot/pi0".to_string()),
+                dataset_repo_id: Some("sourccey/cube".to_string()),
+                ..AiModelFilters::default()
+            }
+        )
+        .await,
+        Vec::<String>::new()
+    );
+}
+
+#[tokio::test]
+async fn using_a_checkpoint_marks_the_owning_model() {
+    let service = service().await;
+    add(&service, "act", AiModelMetadata::default()).await;
+    add(&service, "act-v2", AiModelMetadata::default()).await;
+
+    assert!(service
+        .mark_ai_model_used("/models/act/checkpoints/001000/pretrained_model")
+        .await
+        .unwrap());
+    assert!(service.mark_ai_model_used("org/act-v2").await.unwrap());
+    assert!(!service.mark_ai_model_used("/models/other").await.unwrap());
+
+    let models = AiModelEntity::find()
+        .all(&service.connection)
+        .await
+        .unwrap();
+    assert!(models.iter().all(|model| model.last_used_at.is_some()));
+}
+
+fn snapshot_progress(downloaded_bytes: u64) -> SnapshotProgress {
+    SnapshotProgress {
+        downloaded_bytes,
+        total_bytes: 1000,
+        file_count: 1,
+        speed_bps: 0,
+        current_file: Some("model.safetensors".to_string()),
+        current_file_bytes: downloaded_bytes,
+        current_file_total_bytes: 1000,
+        attempt: 1,
+    }
+}
+
+#[test]
+fn download_is_reported_stalled_once_bytes_stop_moving() {
+    let start = Instant::now();
+    let mut watch = DownloadStallWatch::new(start);
+    watch.record(&snapshot_progress(100), start);
+
+    let later = start + Duration::from_secs(10);
+    watch.record(&snapshot_progress(100), later);
+    assert_eq!(watch.stall_seconds(later), 10);
+    assert_eq!(watch.status(later), "downloading");
+
+    let hung = start + DOWNLOAD_STALL_THRESHOLD;
+    assert_eq!(watch.status(hung), "stalled");
+
+    watch.record(&snapshot_progress(200), hung);
+    assert_eq!(watch.stall_seconds(hung), 0);
+    assert_eq!(watch.status(hung), "downloading");
+
+    watch.record(&snapshot_progress(1000), hung);
+    assert_eq!(watch.status(hung + DOWNLOAD_STALL_THRESHOLD), "downloading");
+}
@@ -0,0 +1,244 @@
+use super::*;
+use migration::MigratorTrait;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_storage", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn write_file(path: &Path, bytes: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![0u8; bytes]).unwrap();
+}
+
+/// A training output with numbered checkpoints of 10, 20 and 30 bytes.
+fn model_dir(name: &str) -> PathBuf {
+    let dir = temp_dir(name);
+    for (step, bytes) in [("000100", 10), ("000200", 20), ("000300", 30)] {
+        write_file(
+            &dir.join("checkpoints")
+                .join(step)
+                .join("pretrained_model")
+                .join("model.safetensors"),
+            bytes,
+        );
+    }
+    dir
+}
+
+fn candidate(id: &str, size_bytes: u64, hours_ago: Option<i64>) -> CleanupCandidate {
+    CleanupCandidate {
+        target: CleanupTarget::Model(id.to_string()),
+        size_bytes,
+        last_used_at: hours_ago.map(|hours| now() - ChronoDuration::hours(hours)),
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-10-18T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn ids(candidates: &[CleanupCandidate]) -> Vec<&str> {
+    candidates
+        .iter()
+        .map(|candidate| match &candidate.target {
+            CleanupTarget::Model(id) | CleanupTarget::Dataset(id) => id.as_str(),
+        })
+        .collect()
+}
+
+#[test]
+fn eviction_takes_least_recently_used_until_target_is_free() {
+    let policy = StorageCleanupPolicy {
+        target_free_bytes: 100,
+        protect_recent_hours: 24,
+        ..StorageCleanupPolicy::default()
+    };
+    let candidates = vec![
+        candidate("recent", 500, Some(2)),
+        candidate("week-old", 40, Some(24 * 7)),
+        candidate("never-used", 20, None),
+        candidate("month-old", 30, Some(24 * 30)),
+        candidate("two-days-old", 50, Some(48)),
+    ];
+
+    let plan = AiModelStorageService::plan_eviction(candidates.clone(), 10, &policy, now());
+    assert_eq!(ids(&plan), vec!["never-used", "month-old", "week-old"]);
+
+    // Protected items are never taken, even when space stays short.
+    let plan = AiModelStorageService::plan_eviction(candidates.clone(), 0, &policy, now());
+    assert_eq!(
+        ids(&plan),
+        vec!["never-used", "month-old", "week-old", "two-days-old"]
+    );
+
+    assert!(AiModelStorageService::plan_eviction(candidates, 100, &policy, now()).is_empty());
+}
+
+#[test]
+fn pruning_keeps_the_newest_checkpoints_and_the_last_link() {
+    let dir = model_dir("prune");
+    #[cfg(unix)]
+    std::os::unix::fs::symlink("000100", dir.join("checkpoints").join("last")).unwrap();
+
+    let removed = AiModelStorageService::prune_checkpoints_in(&dir, 1).unwrap();
+
+    let remaining: Vec<i64> = AiModelMetadataService::list_checkpoints(&dir)
+        .into_iter()
+        .map(|checkpoint| checkpoint.step)
+        .collect();
+    if cfg!(unix) {
+        assert_eq!(
+            removed.iter().map(|c| c.step).collect::<Vec<_>>(),
+            vec![200]
+        );
+        assert_eq!(remaining, vec![100, 300]);
+    } else {
+        assert_eq!(remaining, vec![300]);
+    }
+    assert_eq!(removed.iter().map(|c| c.size_bytes).sum::<u64>(), 20);
+
+    let error = AiModelStorageService::prune_checkpoints_in(&dir, 0).unwrap_err();
+    assert!(error.contains("At least one"), "{}", error);
+}
+
+#[test]
+fn datasets_are_found_one_namespace_deep_and_deleted_by_repo_id() {
+    let root = temp_dir("datasets");
+    write_file(&root.join("org/pick-place/meta/info.json"), 4);
+    write_file(
+        &root.join("org/pick-place/data/chunk-000/episode_000000.parquet"),
+        96,
+    );
+    write_file(&root.join("local-run/meta/info.json"), 8);
+    // Robot configs, app settings and models are not datasets.
+    write_file(&root.join("sourccey/config.json"), 2);
+    write_file(&root.join("settings/meta/info.json"), 2);
+    write_file(&root.join("ai_models/org/meta/info.json"), 2);
+
+    let mut datasets = AiModelStorageService::list_datasets(&root);
+    datasets.sort_by(|a, b| a.repo_id.cmp(&b.repo_id));
+    assert_eq!(
+        datasets
+            .iter()
+            .map(|dataset| (dataset.repo_id.as_str(), dataset.size_bytes))
+            .collect::<Vec<_>>(),
+        vec![("local-run", 8), ("org/pick-place", 100)]
+    );
+    assert!(datasets[0].last_modified_at.is_some());
+
+    assert_eq!(
+        AiModelStorageService::delete_dataset_in(&root, "org/pick-place").unwrap(),
+        100
+    );
+    assert!(!root.join("org/pick-place").exists());
+    assert!(AiModelStorageService::delete_dataset_in(&root, "org/pick-place").is_err());
+}
+
+#[test]
+fn deletes_stay_inside_their_root() {
+    let root = temp_dir("root");
+    let outside = temp_dir("outside");
+    write_file(&root.join("model/weights.bin"), 5);
+
+    let error = AiModelStorageService::remove_dir_within(&root, &outside).unwrap_err();
+    assert!(error.contains("outside"), "{}", error);
+    assert!(outside.exists());
+    assert!(AiModelStorageService::remove_dir_within(&root, &root).is_err());
+    assert!(AiModelStorageService::remove_dir_within(&root, &root.join("model/..")).is_err());
+
+    assert_eq!(
+        AiModelStorageService::remove_dir_within(&root, &root.join("model")).unwrap(),
+        5
+    );
+    assert_eq!(
+        AiModelStorageService::remove_dir_within(&root, &root.join("model")).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn free_space_is_read_from_nearest_existing_directory() {
+    let root = temp_dir("free_space");
+    let missing = root.join("lerobot").join("datasets");
+    let free = AiModelStorageService::read_free_bytes(&missing).unwrap();
+    assert_eq!(free, fs2::available_space(&root).unwrap());
+}
+
+#[test]
+fn cleanup_policy_round_trips_and_is_validated() {
+    let path = temp_dir("policy")
+        .join("settings")
+        .join("storage_cleanup.json");
+    assert_eq!(
+        AiModelStorageService::get_policy_from_path(&path).unwrap(),
+        StorageCleanupPolicy::default()
+    );
+
+    let policy = StorageCleanupPolicy {
+        enabled: true,
+        keep_checkpoints: Some(2),
+        include_datasets: true,
+        ..StorageCleanupPolicy::default()
+    };
+    AiModelStorageService::save_policy_to_path(&path, policy.clone()).unwrap();
+    assert_eq!(
+        AiModelStorageService::get_policy_from_path(&path).unwrap(),
+        policy
+    );
+
+    let inverted = StorageCleanupPolicy {
+        min_free_bytes: 10,
+        target_free_bytes: 5,
+        ..StorageCleanupPolicy::default()
+    };
+    assert!(AiModelStorageService::save_policy_to_path(&path, inverted).is_err());
+    let keep_none = StorageCleanupPolicy {
+        keep_checkpoints: Some(0),
+        ..StorageCleanupPolicy::default()
+    };
+    assert!(AiModelStorageService::save_policy_to_path(&path, keep_none).is_err());
+}
+
+#[tokio::test]
+async fn pruning_a_model_refreshes_its_stored_checkpoints() {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    let dir = model_dir("prune_db");
+    let model_path = dir.to_string_lossy().to_string();
+    let mut model = AiModelActiveModel::new("act".to_string(), model_path, None, Some(300));
+    AiModelMetadataService::read(&dir).apply_to(&mut model);
+    let model = model.insert(&connection).await.unwrap();
+    let service = AiModelStorageService::new(connection.clone());
+
+    let removed = service.prune_checkpoints(&model.id, 2).await.unwrap();
+    assert_eq!(
+        removed.iter().map(|c| c.step).collect::<Vec<_>>(),
+        vec![100]
+    );
+
+    let stored = AiModelEntity::find_by_id(model.id.clone())
+        .one(&connection)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.latest_checkpoint, Some(300));
+    assert!(!stored.checkpoints.unwrap().contains("000100"));
+
+    // Keeping the files only soft-deletes the row.
+    assert_eq!(service.delete_ai_model(&model.id, false).await.unwrap(), 0);
+    assert!(dir.exists());
+    assert!(service.prune_checkpoints(&model.id, 1).await.is_err());
+}
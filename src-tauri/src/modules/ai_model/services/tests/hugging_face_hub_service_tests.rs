@@ -0,0 +1,339 @@
+use super::*;
+use crate::modules::ai_model::services::mock_hub::{serve, MockHub, Routes};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const COMMIT: &str = "0123456789abcdef0123456789abcdef01234567";
+
+fn temp_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_hub", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn hub(mock: &MockHub, token: Option<&str>) -> HuggingFaceHubService {
+    let mut hub = HuggingFaceHubService::new(&mock.endpoint, token.map(str::to_string))
+        .expect("client should build");
+    hub.download_options = DownloadOptions {
+        max_attempts: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+        progress_interval: Duration::ZERO,
+        ..hub.download_options.clone()
+    };
+    hub
+}
+
+fn blob_oid(contents: &[u8]) -> String {
+    let mut hasher = sha1::Sha1::new();
+    hasher.update(format!("blob {}\0", contents.len()).as_bytes());
+    hasher.update(contents);
+    format!("{:x}", hasher.finalize())
+}
+
+fn ok(body: &[u8]) -> (u16, Vec<String>, Vec<u8>) {
+    (200, Vec::new(), body.to_vec())
+}
+
+/// A model repo with a small config and an LFS weights file.
+fn model_routes(weights: &[u8], weights_sha256: &str) -> Routes {
+    model_routes_serving_config(weights, weights_sha256, br#"{"type": "act"}"#)
+}
+
+/// Like `model_routes`, but the listed config blob id is for the real config
+/// while the Hub serves `served_config`.
+fn model_routes_serving_config(
+    weights: &[u8],
+    weights_sha256: &str,
+    served_config: &[u8],
+) -> Routes {
+    let config = br#"{"type": "act"}"#;
+    let tree = serde_json::json!([
+        { "type": "directory", "path": "pretrained_model", "oid": "d1", "size": 0 },
+        { "type": "file", "path": "config.json", "oid": blob_oid(config), "size": config.len() },
+        {
+            "type": "file",
+            "path": "pretrained_model/model.safetensors",
+            "oid": "w1",
+            "size": weights.len(),
+            "lfs": { "oid": weights_sha256, "size": weights.len(), "pointerSize": 134 }
+        },
+        { "type": "file", "path": "README.md", "oid": blob_oid(b"hello"), "size": 5 }
+    ]);
+    HashMap::from([
+        (
+            "GET /api/models/org/act-model/revision/main".to_string(),
+            ok(format!(r#"{{"sha": "{}"}}"#, COMMIT).as_bytes()),
+        ),
+        (
+            format!(
+                "GET /api/models/org/act-model/tree/{}?recursive=true",
+                COMMIT
+            ),
+            ok(tree.to_string().as_bytes()),
+        ),
+        (
+            format!("GET /org/act-model/resolve/{}/config.json", COMMIT),
+            ok(served_config),
+        ),
+        (
+            format!(
+                "GET /org/act-model/resolve/{}/pretrained_model/model.safetensors",
+                COMMIT
+            ),
+            ok(weights),
+        ),
+        (
+            format!("GET /org/act-model/resolve/{}/README.md", COMMIT),
+            ok(b"hello"),
+        ),
+    ])
+}
+
+#[test]
+fn snapshot_downloads_selected_files_and_skips_them_next_time() {
+    let weights: Vec<u8> = (0..50_000u32).map(|value| (value % 241) as u8).collect();
+    let weights_sha256 = format!("{:x}", Sha256::digest(&weights));
+    let mock = serve(|_| model_routes(&weights, &weights_sha256));
+    let local_dir = temp_dir("snapshot");
+    let options = SnapshotOptions {
+        revision: None,
+        allow_patterns: Vec::new(),
+        ignore_patterns: vec!["*.md".to_string()],
+        ..SnapshotOptions::default()
+    };
+
+    let mut reports = Vec::new();
+    let outcome = hub(&mock, Some("hf_secret"))
+        .snapshot_download("org/act-model", &local_dir, &options, &mut |progress| {
+            reports.push(progress.clone())
+        })
+        .expect("snapshot should download");
+
+    assert_eq!(outcome.commit, COMMIT);
+    assert_eq!(outcome.downloaded_files, 2);
+    assert_eq!(
+        fs::read(local_dir.join("pretrained_model/model.safetensors")).unwrap(),
+        weights
+    );
+    assert!(local_dir.join("config.json").exists());
+    assert!(!local_dir.join("README.md").exists());
+    let last = reports.last().expect("progress should be reported");
+    assert_eq!(last.file_count, 2);
+    assert_eq!(last.downloaded_bytes, last.total_bytes);
+    assert_eq!(last.percent(), 100);
+    assert_eq!(
+        HuggingFaceHubService::read_snapshot_source(&local_dir),
+        Some(SnapshotSource {
+            repo_id: "org/act-model".to_string(),
+            revision: None,
+            commit: COMMIT.to_string(),
+        })
+    );
+    assert!(mock
+        .requests()
+        .iter()
+        .all(|request| request.authorization.as_deref() == Some("Bearer hf_secret")));
+
+    let again = hub(&mock, None)
+        .snapshot_download("org/act-model", &local_dir, &options, &mut |_| {})
+        .expect("second snapshot should succeed");
+    assert_eq!(again.downloaded_files, 0);
+    assert_eq!(again.skipped_files, 2);
+}
+
+#[test]
+fn lfs_checksum_mismatch_fails_and_leaves_nothing_in_place() {
+    let weights = vec![7u8; 4096];
+    let mock = serve(|_| model_routes(&weights, &"0".repeat(64)));
+    let local_dir = temp_dir("checksum");
+
+    let error = hub(&mock, None)
+        .snapshot_download(
+            "org/act-model",
+            &local_dir,
+            &SnapshotOptions {
+                allow_patterns: vec!["pretrained_model/".to_string()],
+                ..SnapshotOptions::default()
+            },
+            &mut |_| {},
+        )
+        .unwrap_err();
+
+    assert!(error.contains("Checksum verification failed"), "{}", error);
+    assert!(!local_dir
+        .join("pretrained_model/model.safetensors")
+        .exists());
+}
+
+#[test]
+fn corrupted_file_of_the_right_size_fails_its_blob_check() {
+    let weights = vec![7u8; 4096];
+    let weights_sha256 = format!("{:x}", Sha256::digest(&weights));
+    let mock =
+        serve(|_| model_routes_serving_config(&weights, &weights_sha256, br#"{"type": "aCt"}"#));
+    let local_dir = temp_dir("blob_oid");
+
+    let error = hub(&mock, None)
+        .snapshot_download(
+            "org/act-model",
+            &local_dir,
+            &SnapshotOptions {
+                allow_patterns: vec!["config.json".to_string()],
+                ..SnapshotOptions::default()
+            },
+            &mut |_| {},
+        )
+        .unwrap_err();
+
+    assert!(error.contains("git blob id"), "{}", error);
+    assert!(!local_dir.join("config.json").exists());
+}
+
+#[test]
+fn git_blob_oid_matches_git_hash_object() {
+    let dir = temp_dir("blob_hash");
+    let path = dir.join("hello.txt");
+    fs::write(&path, b"hello\n").unwrap();
+
+    // `printf 'hello\n' | git hash-object --stdin`
+    assert_eq!(
+        HuggingFaceHubService::git_blob_oid(&path).unwrap(),
+        "ce013625030ba8dba906f756967f9e9ca394464a"
+    );
+}
+
+#[test]
+fn pinned_revision_and_paged_tree_are_followed() {
+    let mock = serve(|endpoint| {
+        let first_page = format!("/api/models/org/act-model/tree/{}?recursive=true", COMMIT);
+        let second_page = format!("{}&cursor=abc", first_page);
+        HashMap::from([
+            (
+                "GET /api/models/org/act-model/revision/refs%2Fpr%2F3".to_string(),
+                ok(format!(r#"{{"sha": "{}"}}"#, COMMIT).as_bytes()),
+            ),
+            (
+                format!("GET {}", first_page),
+                (
+                    200,
+                    vec![format!("Link: <{}{}>; rel=\"next\"", endpoint, second_page)],
+                    br#"[{"type": "file", "path": "a.json", "oid": "a", "size": 1}]"#.to_vec(),
+                ),
+            ),
+            (
+                format!("GET {}", second_page),
+                ok(br#"[{"type": "file", "path": "b/c.json", "oid": "c", "size": 2}]"#),
+            ),
+        ])
+    });
+    let client = hub(&mock, None);
+
+    let commit = client
+        .resolve_revision("org/act-model", Some("refs/pr/3"))
+        .expect("revision should resolve");
+    let files = client
+        .list_files("org/act-model", &commit)
+        .expect("tree should list");
+
+    assert_eq!(commit, COMMIT);
+    let paths: Vec<&str> = files.iter().map(|file| file.path.as_str()).collect();
+    assert_eq!(paths, vec!["a.json", "b/c.json"]);
+    assert_eq!(files[1].size, 2);
+    assert_eq!(files[1].sha256, None);
+}
+
+#[test]
+fn missing_repository_reports_a_token_hint() {
+    let mock = serve(|_| HashMap::new());
+
+    let error = hub(&mock, None)
+        .resolve_revision("org/private-model", None)
+        .unwrap_err();
+
+    assert!(error.contains("org/private-model"), "{}", error);
+    assert!(error.contains("access token"), "{}", error);
+}
+
+#[test]
+fn partial_downloads_are_removed() {
+    let local_dir = temp_dir("partials");
+    let staging = local_dir.join(".cache/huggingface/download");
+    fs::create_dir_all(staging.join("pretrained_model")).unwrap();
+    fs::write(
+        staging.join("pretrained_model/model.safetensors.part"),
+        b"abc",
+    )
+    .unwrap();
+    fs::write(
+        staging.join("pretrained_model/model.safetensors.part.json"),
+        b"{}",
+    )
+    .unwrap();
+    fs::write(staging.join("config.json.sourccey.json"), b"{}").unwrap();
+    fs::write(local_dir.join("config.json"), b"{}").unwrap();
+
+    let removed = HuggingFaceHubService::remove_partial_downloads(&local_dir)
+        .expect("partials should be removed");
+
+    assert_eq!(removed, 2);
+    assert!(!staging
+        .join("pretrained_model/model.safetensors.part")
+        .exists());
+    assert!(staging.join("config.json.sourccey.json").exists());
+    assert!(local_dir.join("config.json").exists());
+
+    // Nothing finished arriving: the whole model directory goes.
+    fs::remove_file(local_dir.join("config.json")).unwrap();
+    fs::write(staging.join("config.json.part"), b"{").unwrap();
+    assert_eq!(
+        HuggingFaceHubService::remove_partial_downloads(&local_dir).unwrap(),
+        1
+    );
+    assert!(!local_dir.exists());
+}
+
+#[test]
+fn patterns_follow_fnmatch_rules() {
+    assert!(HuggingFaceHubService::matches_pattern(
+        "*.json",
+        "config.json"
+    ));
+    assert!(HuggingFaceHubService::matches_pattern(
+        "*.json",
+        "pretrained_model/config.json"
+    ));
+    assert!(HuggingFaceHubService::matches_pattern(
+        "pretrained_model/",
+        "pretrained_model/model.safetensors"
+    ));
+    assert!(HuggingFaceHubService::matches_pattern(
+        "model-?.bin",
+        "model-1.bin"
+    ));
+    assert!(!HuggingFaceHubService::matches_pattern(
+        "*.json",
+        "model.safetensors"
+    ));
+    assert!(!HuggingFaceHubService::matches_pattern(
+        "model-?.bin",
+        "model-10.bin"
+    ));
+}
+
+#[test]
+fn unsafe_repository_paths_are_rejected() {
+    assert!(HuggingFaceHubService::safe_relative_path("a/b.json").is_ok());
+    for path in ["../escape", "/etc/passwd", "a/../../b", ""] {
+        assert!(
+            HuggingFaceHubService::safe_relative_path(path).is_err(),
+            "{}",
+            path
+        );
+    }
+}
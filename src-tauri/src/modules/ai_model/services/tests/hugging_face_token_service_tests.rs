@@ -0,0 +1,75 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn token_path(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    std::env::temp_dir()
+        .join(format!("{}_{}_hf_token", name, nonce))
+        .join("credentials")
+        .join("hugging_face.json")
+}
+
+#[test]
+fn saved_token_round_trips_and_clears() {
+    let path = token_path("round_trip");
+    assert_eq!(HuggingFaceTokenService::read_token_from_path(&path), None);
+
+    HuggingFaceTokenService::save_token_to_path(&path, "  hf_abcdefghijklmnop \n").unwrap();
+    assert_eq!(
+        HuggingFaceTokenService::read_token_from_path(&path).as_deref(),
+        Some("hf_abcdefghijklmnop")
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    HuggingFaceTokenService::clear_token_at_path(&path).unwrap();
+    assert!(!path.exists());
+    // Clearing twice is not an error.
+    HuggingFaceTokenService::clear_token_at_path(&path).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn replacing_a_world_readable_token_file_leaves_it_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+    let path = token_path("replace");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, r#"{"token": "hf_old"}"#).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+    HuggingFaceTokenService::save_token_to_path(&path, "hf_abcdefghijklmnop").unwrap();
+
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(
+        HuggingFaceTokenService::read_token_from_path(&path).as_deref(),
+        Some("hf_abcdefghijklmnop")
+    );
+}
+
+#[test]
+fn blank_or_malformed_tokens_are_rejected() {
+    let path = token_path("invalid");
+
+    let blank = HuggingFaceTokenService::save_token_to_path(&path, "   ").unwrap_err();
+    assert!(blank.contains("cannot be empty"), "{}", blank);
+    let spaced = HuggingFaceTokenService::save_token_to_path(&path, "hf_abc def").unwrap_err();
+    assert!(spaced.contains("whitespace"), "{}", spaced);
+    assert!(!path.exists());
+}
+
+#[test]
+fn masked_token_shows_only_the_ends() {
+    assert_eq!(
+        HuggingFaceTokenService::mask("hf_abcdefghijklmnopx9Qa"),
+        "hf_…x9Qa"
+    );
+    assert_eq!(HuggingFaceTokenService::mask("short"), "…");
+}
@@ -0,0 +1,312 @@
+use super::*;
+use crate::modules::ai_model::services::mock_hub::{serve, MockHub, RecordedRequest, Routes};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TOKEN: &str = "hf_write_token";
+
+fn ok(body: Value) -> (u16, Vec<String>, Vec<u8>) {
+    (200, Vec::new(), body.to_string().into_bytes())
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_upload", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn hub(mock: &MockHub) -> HuggingFaceUploadService {
+    HuggingFaceUploadService::new(&mock.endpoint, Some(TOKEN.to_string()))
+        .expect("client should build")
+}
+
+/// Parse the ndjson commit body into its lines.
+fn commit_lines(request: &RecordedRequest) -> Vec<Value> {
+    String::from_utf8(request.body.clone())
+        .expect("commit body should be UTF-8")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("commit line should be JSON"))
+        .collect()
+}
+
+#[test]
+fn model_checkpoint_is_uploaded_in_parts_and_committed() {
+    let model_dir = temp_dir("model");
+    let config = br#"{"type": "act"}"#;
+    let weights = b"0123456789";
+    fs::write(model_dir.join("config.json"), config).unwrap();
+    fs::create_dir_all(model_dir.join("pretrained_model")).unwrap();
+    fs::write(
+        model_dir.join("pretrained_model/model.safetensors"),
+        weights,
+    )
+    .unwrap();
+    // Download staging from the Hub client is never pushed back.
+    fs::create_dir_all(model_dir.join(".cache/huggingface")).unwrap();
+    fs::write(
+        model_dir.join(".cache/huggingface/source.sourccey.json"),
+        "{}",
+    )
+    .unwrap();
+    let weights_oid = sha256_hex(weights);
+
+    let oid = weights_oid.clone();
+    let mock = serve(move |endpoint| {
+        HashMap::from([
+            (
+                "POST /api/models/org/act-model/preupload/main".to_string(),
+                ok(json!({ "files": [
+                    { "path": "config.json", "uploadMode": "regular", "shouldIgnore": false },
+                    { "path": "pretrained_model/model.safetensors", "uploadMode": "lfs", "shouldIgnore": false }
+                ]})),
+            ),
+            (
+                "POST /org/act-model.git/info/lfs/objects/batch".to_string(),
+                ok(json!({ "transfer": "multipart", "objects": [{
+                    "oid": oid,
+                    "size": 10,
+                    "actions": {
+                        "upload": {
+                            "href": format!("{}/lfs/complete", endpoint),
+                            "header": {
+                                "chunk_size": "4",
+                                "00001": format!("{}/s3/part1", endpoint),
+                                "00002": format!("{}/s3/part2", endpoint),
+                                "00003": format!("{}/s3/part3", endpoint)
+                            }
+                        },
+                        "verify": { "href": format!("{}/lfs/verify", endpoint) }
+                    }
+                }]})),
+            ),
+            (
+                "PUT /s3/part1".to_string(),
+                (200, vec!["ETag: \"e1\"".to_string()], Vec::new()),
+            ),
+            (
+                "PUT /s3/part2".to_string(),
+                (200, vec!["ETag: \"e2\"".to_string()], Vec::new()),
+            ),
+            (
+                "PUT /s3/part3".to_string(),
+                (200, vec!["ETag: \"e3\"".to_string()], Vec::new()),
+            ),
+            ("POST /lfs/complete".to_string(), ok(json!({}))),
+            ("POST /lfs/verify".to_string(), ok(json!({}))),
+            (
+                "POST /api/models/org/act-model/commit/main".to_string(),
+                ok(json!({
+                    "commitOid": "c0ffee",
+                    "commitUrl": format!("{}/org/act-model/commit/c0ffee", endpoint)
+                })),
+            ),
+        ])
+    });
+
+    let mut phases = Vec::new();
+    let outcome = hub(&mock)
+        .upload_folder(
+            "org/act-model",
+            &model_dir,
+            &HubUploadOptions {
+                commit_message: Some("Add step 20000".to_string()),
+                ..HubUploadOptions::default()
+            },
+            &mut |progress| phases.push((progress.phase, progress.uploaded_bytes)),
+        )
+        .expect("upload should succeed");
+
+    assert_eq!(outcome.commit_oid, "c0ffee");
+    assert_eq!((outcome.regular_files, outcome.lfs_files), (1, 1));
+    assert_eq!(outcome.reused_lfs_files, 0);
+    assert_eq!(
+        outcome.uploaded_bytes,
+        (config.len() + weights.len()) as u64
+    );
+    assert!(phases.contains(&(HubUploadPhase::Uploading, 8)));
+    assert_eq!(
+        phases.last(),
+        Some(&(HubUploadPhase::Completed, outcome.uploaded_bytes))
+    );
+
+    let preupload = mock.request("POST /api/models/org/act-model/preupload/main");
+    let paths: Vec<Value> = preupload.json()["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| file["path"].clone())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            json!("config.json"),
+            json!("pretrained_model/model.safetensors")
+        ]
+    );
+    assert_eq!(
+        preupload.authorization.as_deref(),
+        Some("Bearer hf_write_token")
+    );
+
+    // Presigned part URLs must not receive the token.
+    let parts: Vec<RecordedRequest> = mock
+        .requests()
+        .into_iter()
+        .filter(|request| request.route.starts_with("PUT /s3/"))
+        .collect();
+    assert_eq!(
+        parts
+            .iter()
+            .map(|part| part.body.clone())
+            .collect::<Vec<_>>(),
+        vec![b"0123".to_vec(), b"4567".to_vec(), b"89".to_vec()]
+    );
+    assert!(parts.iter().all(|part| part.authorization.is_none()));
+    assert_eq!(
+        mock.request("POST /lfs/complete").json(),
+        json!({ "oid": weights_oid, "parts": [
+            { "partNumber": 1, "etag": "\"e1\"" },
+            { "partNumber": 2, "etag": "\"e2\"" },
+            { "partNumber": 3, "etag": "\"e3\"" }
+        ]})
+    );
+
+    let lines = commit_lines(&mock.request("POST /api/models/org/act-model/commit/main"));
+    assert_eq!(lines[0]["value"]["summary"], "Add step 20000");
+    assert_eq!(
+        lines[1],
+        json!({ "key": "file", "value": {
+            "content": BASE64.encode(config),
+            "path": "config.json",
+            "encoding": "base64"
+        }})
+    );
+    assert_eq!(
+        lines[2],
+        json!({ "key": "lfsFile", "value": {
+            "path": "pretrained_model/model.safetensors",
+            "algo": "sha256",
+            "oid": weights_oid,
+            "size": 10
+        }})
+    );
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn dataset_upload_skips_objects_the_hub_already_has() {
+    let dataset_dir = temp_dir("dataset");
+    let video = b"frames";
+    fs::create_dir_all(dataset_dir.join("videos")).unwrap();
+    fs::write(dataset_dir.join("videos/episode_000000.mp4"), video).unwrap();
+    fs::write(dataset_dir.join("notes.tmp"), "scratch").unwrap();
+    let video_oid = sha256_hex(video);
+
+    let oid = video_oid.clone();
+    let mock = serve(move |_| {
+        HashMap::from([
+            (
+                "POST /api/datasets/org/pick-place/preupload/main".to_string(),
+                ok(json!({ "files": [
+                    { "path": "notes.tmp", "uploadMode": "regular", "shouldIgnore": true },
+                    { "path": "videos/episode_000000.mp4", "uploadMode": "lfs" }
+                ]})),
+            ),
+            (
+                "POST /datasets/org/pick-place.git/info/lfs/objects/batch".to_string(),
+                ok(json!({ "objects": [{ "oid": oid, "size": 6 }] })),
+            ),
+            (
+                "POST /api/datasets/org/pick-place/commit/main".to_string(),
+                ok(json!({ "commitOid": "abc123" })),
+            ),
+        ])
+    });
+
+    let outcome = hub(&mock)
+        .upload_folder(
+            "org/pick-place",
+            &dataset_dir,
+            &HubUploadOptions {
+                repo_type: HubRepoType::Dataset,
+                ..HubUploadOptions::default()
+            },
+            &mut |_| {},
+        )
+        .expect("upload should succeed");
+
+    assert_eq!(outcome.commit_oid, "abc123");
+    assert_eq!(outcome.commit_url, None);
+    assert_eq!(outcome.ignored_files, 1);
+    assert_eq!(outcome.reused_lfs_files, 1);
+    assert!(!mock
+        .requests()
+        .iter()
+        .any(|request| request.route.starts_with("PUT ")));
+
+    let lines = commit_lines(&mock.request("POST /api/datasets/org/pick-place/commit/main"));
+    assert_eq!(lines[0]["value"]["summary"], "Upload dataset from Sourccey");
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1]["key"], "lfsFile");
+    assert_eq!(lines[1]["value"]["oid"], json!(video_oid));
+}
+
+#[test]
+fn repositories_are_created_once_with_the_requested_visibility() {
+    let mock = serve(|_| {
+        HashMap::from([(
+            "POST /api/repos/create".to_string(),
+            (409, Vec::new(), br#"{"error": "exists"}"#.to_vec()),
+        )])
+    });
+
+    hub(&mock)
+        .create_repo("org/pick-place", HubRepoType::Dataset, true)
+        .expect("an existing repo is fine");
+
+    assert_eq!(
+        mock.request("POST /api/repos/create").json(),
+        json!({ "name": "pick-place", "organization": "org", "type": "dataset", "private": true })
+    );
+}
+
+#[test]
+fn missing_or_read_only_tokens_are_reported() {
+    let error = HuggingFaceUploadService::new("http://127.0.0.1:1", None)
+        .err()
+        .expect("uploads need a token");
+    assert!(error.contains("access token"), "{}", error);
+
+    let mock = serve(|_| {
+        HashMap::from([(
+            "POST /api/repos/create".to_string(),
+            (403, Vec::new(), Vec::new()),
+        )])
+    });
+    let error = hub(&mock)
+        .create_repo("org/act-model", HubRepoType::Model, false)
+        .unwrap_err();
+    assert!(error.contains("write access"), "{}", error);
+}
+
+#[test]
+fn repo_ids_are_validated() {
+    assert_eq!(
+        HuggingFaceUploadService::validate_repo_id(" org/act-model ").unwrap(),
+        "org/act-model"
+    );
+    for invalid in ["", "org/", "a/b/c", "../model", "org/my model"] {
+        assert!(
+            HuggingFaceUploadService::validate_repo_id(invalid).is_err(),
+            "{}",
+            invalid
+        );
+    }
+}
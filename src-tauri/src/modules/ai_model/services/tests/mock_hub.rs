@@ -0,0 +1,124 @@
+//! Minimal Hugging Face Hub on localhost for the Hub client and upload tests.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// `"METHOD /path?query"` to `(status, extra headers, body)`.
+pub(crate) type Routes = HashMap<String, (u16, Vec<String>, Vec<u8>)>;
+
+#[derive(Clone, Debug)]
+pub(crate) struct RecordedRequest {
+    pub route: String,
+    pub authorization: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    pub(crate) fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body should be JSON")
+    }
+}
+
+pub(crate) struct MockHub {
+    pub endpoint: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockHub {
+    pub(crate) fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("requests lock").clone()
+    }
+
+    pub(crate) fn request(&self, route: &str) -> RecordedRequest {
+        self.requests()
+            .into_iter()
+            .find(|request| request.route == route)
+            .unwrap_or_else(|| panic!("no request to {}", route))
+    }
+}
+
+/// Start a mock Hub that records each request with its body. Routes are built
+/// from the endpoint so `Link` headers and LFS hrefs can point back at it;
+/// anything unrouted is a 404.
+pub(crate) fn serve(build_routes: impl FnOnce(&str) -> Routes) -> MockHub {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("mock hub should bind");
+    let endpoint = format!(
+        "http://{}",
+        listener
+            .local_addr()
+            .expect("mock hub should have an address")
+    );
+    let routes = build_routes(&endpoint);
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            answer(stream, &routes, &recorded);
+        }
+    });
+
+    MockHub { endpoint, requests }
+}
+
+fn answer(mut stream: TcpStream, routes: &Routes, recorded: &Mutex<Vec<RecordedRequest>>) {
+    let mut reader = BufReader::new(stream.try_clone().expect("stream should clone"));
+    let mut request_line = String::new();
+    let _ = reader.read_line(&mut request_line);
+    let mut parts = request_line.split_whitespace();
+    let route = format!(
+        "{} {}",
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or_default()
+    );
+    let mut authorization = None;
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("authorization") {
+                authorization = Some(value.trim().to_string());
+            }
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    let _ = reader.read_exact(&mut body);
+    recorded
+        .lock()
+        .expect("requests lock")
+        .push(RecordedRequest {
+            route: route.clone(),
+            authorization,
+            body,
+        });
+
+    let (status, headers, body) =
+        routes
+            .get(&route)
+            .cloned()
+            .unwrap_or((404, Vec::new(), Vec::new()));
+    let mut head = format!(
+        "HTTP/1.1 {} Test\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status,
+        body.len()
+    );
+    for header in headers {
+        head.push_str(&header);
+        head.push_str("\r\n");
+    }
+    head.push_str("\r\n");
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(&body);
+    let _ = stream.flush();
+    let _ = stream.shutdown(std::net::Shutdown::Both);
+}
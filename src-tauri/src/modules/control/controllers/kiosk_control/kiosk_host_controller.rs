@@ -5,6 +5,9 @@ use crate::modules::control::services::kiosk_control::pairing_service::{
     KioskPairingService, KioskPairingState,
 };
 use crate::modules::status::services::battery::battery_service::{BatteryData, BatteryService};
+use crate::modules::status::services::telemetry::telemetry_service::{
+    TelemetryPaths, TelemetryService, TelemetryState,
+};
 use crate::utils::windows_process::configure_std_command;
 use serde::Serialize;
 use serde_json::json;
@@ -77,19 +80,34 @@ pub fn is_kiosk_host_active(state: State<KioskHostProcess>, nickname: String) ->
     KioskHostService::is_kiosk_host_active(&state, nickname)
 }
 
+/// Kiosk system summary. Temperature and battery come from the most recent
+/// telemetry sample when the sampler is running, otherwise they are read directly.
 #[command]
-pub fn get_system_info() -> SystemInfo {
+pub fn get_system_info(telemetry_state: State<'_, TelemetryState>) -> SystemInfo {
+    let latest = TelemetryService::get_latest(&telemetry_state)
+        .ok()
+        .flatten();
     let ip_address = get_ip_address();
-    let temperature = get_temperature();
-    let battery_data = BatteryService::get_battery_data().unwrap_or_else(|_| BatteryData {
-        voltage: -1.0,
-        current_a: -1.0,
-        remaining_capacity_ah: -1.0,
-        max_capacity_ah: -1.0,
-        state_of_charge: -1,
-        max_error: -1,
-        error: Some("Failed to read battery data".to_string()),
-    });
+    let temperature = latest
+        .as_ref()
+        .and_then(|sample| sample.cpu_temperature_c)
+        .or_else(|| TelemetryService::read_cpu_temperature(&TelemetryPaths::default().sys_root))
+        .or_else(TelemetryService::read_vcgencmd_temperature)
+        .map(|celsius| format!("{:.0}°C", celsius))
+        .unwrap_or_else(|| "Unknown".to_string());
+    let battery_data = latest
+        .and_then(|sample| sample.battery)
+        .map(Ok)
+        .unwrap_or_else(BatteryService::get_battery_data)
+        .unwrap_or_else(|_| BatteryData {
+            voltage: -1.0,
+            current_a: -1.0,
+            remaining_capacity_ah: -1.0,
+            max_capacity_ah: -1.0,
+            state_of_charge: -1,
+            max_error: -1,
+            error: Some("Failed to read battery data".to_string()),
+        });
     SystemInfo {
         ip_address,
         temperature,
@@ -197,39 +215,6 @@ fn is_private_ip(ip: &str) -> bool {
     false
 }
 
-fn get_temperature() -> String {
-    // Try to get CPU temperature on Linux
-    #[cfg(target_os = "linux")]
-    {
-        // Try different common temperature sensors
-        let temp_paths = [
-            "/sys/class/thermal/thermal_zone0/temp",
-            "/sys/class/hwmon/hwmon0/temp1_input",
-            "/sys/class/hwmon/hwmon1/temp1_input",
-        ];
-
-        for path in &temp_paths {
-            if let Ok(temp_str) = std::fs::read_to_string(path) {
-                if let Ok(temp_millic) = temp_str.trim().parse::<i32>() {
-                    let temp_celsius = temp_millic / 1000;
-                    return format!("{}°C", temp_celsius);
-                }
-            }
-        }
-
-        // Try vcgencmd for Raspberry Pi
-        if let Ok(output) = Command::new("vcgencmd").arg("measure_temp").output() {
-            if let Ok(temp_str) = String::from_utf8(output.stdout) {
-                if let Some(temp_part) = temp_str.strip_prefix("temp=") {
-                    return temp_part.trim().to_string();
-                }
-            }
-        }
-    }
-
-    "Unknown".to_string()
-}
-
 #[command]
 pub fn get_pi_username() -> Result<String, String> {
     #[cfg(target_os = "linux")]
@@ -17,6 +17,9 @@ pub struct RemoteInferenceConfig {
     pub display_ip: Option<String>,
     pub display_port: Option<i32>,
     pub display_compressed_images: bool,
+    /// Start even when the model does not match the robot.
+    #[serde(default)]
+    pub skip_compatibility_check: bool,
 }
 
 // Initialize the state
@@ -12,6 +12,9 @@ pub struct RemoteRolloutConfig {
     pub model_path: String,
     pub task: String,
     pub duration: f64,
+    /// Start even when the model does not match the robot.
+    #[serde(default)]
+    pub skip_compatibility_check: bool,
 }
 
 pub fn init_remote_rollout() -> RemoteRolloutProcess {
@@ -1,3 +1,4 @@
 pub mod configuration;
 pub mod kiosk_control;
 pub mod remote_control;
+pub mod robot_profiles;
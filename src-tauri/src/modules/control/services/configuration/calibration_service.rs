@@ -1,10 +1,12 @@
 use crate::modules::control::controllers::configuration::calibration_controller::{
     CalibrationConfig, DesktopTeleopCalibrationConfig, DesktopTeleopCalibrationStatus,
 };
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use crate::modules::control::types::configuration::calibration_types::{
     Calibration, MotorCalibration,
 };
 use crate::modules::log::services::command_log_service::CommandLogService;
+use crate::modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 use crate::services::directory::directory_service::DirectoryService;
 use crate::services::log::log_service::LogService;
 use crate::services::process::process_service::ProcessService;
@@ -12,7 +14,7 @@ use crate::utils::windows_process::configure_tokio_command;
 use sea_orm::DatabaseConnection;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use tauri::AppHandle;
 use tokio::process::Command;
@@ -136,6 +138,42 @@ impl CalibrationService {
         Ok(())
     }
 
+    /// Remove every calibration file written for `nickname`, including the
+    /// per-arm files of two-arm robots. Returns how many were removed.
+    pub fn remove_nickname_references(nickname: &str) -> Result<usize, String> {
+        let cache_dir = DirectoryService::get_lerobot_cache_dir()?;
+        Self::remove_calibration_files(&cache_dir.join("calibration"), nickname)
+    }
+
+    fn remove_calibration_files(calibration_root: &Path, nickname: &str) -> Result<usize, String> {
+        let name = Self::validate_path_segment(&Self::normalize_nickname(nickname), "nickname")?;
+        let file_names = [
+            format!("{}.json", name),
+            format!("{}_left.json", name),
+            format!("{}_right.json", name),
+        ];
+
+        let mut removed = 0;
+        for kind in ["robots", "teleoperators"] {
+            let kind_root = calibration_root.join(kind);
+            if !kind_root.exists() {
+                continue;
+            }
+            for entry in fs::read_dir(&kind_root).map_err(|e| e.to_string())? {
+                let entry = entry.map_err(|e| e.to_string())?;
+                for file_name in &file_names {
+                    let path = entry.path().join(file_name);
+                    if path.is_file() {
+                        fs::remove_file(&path)
+                            .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+                        removed += 1;
+                    }
+                }
+            }
+        }
+        Ok(removed)
+    }
+
     fn normalize_nickname(nickname: &str) -> String {
         nickname.trim().trim_start_matches('@').to_string()
     }
@@ -717,17 +755,19 @@ impl CalibrationService {
             &start_message,
         );
 
+        let profile = RobotProfileService::resolve(robot_type)?;
+        let capabilities = RobotCatalogueService::bundled_capabilities(profile.robot_type())?
+            .ok_or(format!(
+                "No capabilities are known for {} robots",
+                robot_type
+            ))?;
         let lerobot_dir = DirectoryService::get_lerobot_vulcan_dir()?;
         let python_path = DirectoryService::get_python_path()?;
 
         let mut command_parts = vec!["python".to_string()];
         command_parts
             .push("src/lerobot/scripts/sourccey/calibration/auto_calibrate.py".to_string());
-        command_parts.push(format!("--robot.type={}", robot_type));
-        command_parts.push(format!("--robot.id={}", nickname));
-        if full_reset {
-            command_parts.push(format!("--full_reset=True"));
-        }
+        command_parts.extend(profile.calibrate_args(&capabilities, nickname, full_reset)?);
 
         let mut cmd = Command::new(python_path);
         for arg in &command_parts[1..] {
@@ -807,74 +847,22 @@ impl CalibrationService {
     // Default Calibration Functions
     //------------------------------------------------------------//
     pub fn create_default_calibration(robot_type: &str, nickname: &str) -> Calibration {
-        if robot_type == "so100_follower" {
-            return Self::create_default_so100_calibration();
-        } else if robot_type == "sourccey_follower" {
-            let arm_side = match nickname {
-                "sourccey_left" => "left",
-                "sourccey_right" => "right",
-                _ => {
-                    return Calibration {
-                        motors: HashMap::new(),
-                    }
-                }
-            };
-            return Self::create_default_sourccey_calibration(&arm_side);
+        match RobotProfileService::for_follower_type(robot_type) {
+            Some(profile) => profile.default_calibration(nickname),
+            None => Calibration {
+                motors: HashMap::new(),
+            },
         }
-        return Calibration {
-            motors: HashMap::new(),
-        };
     }
 
-    pub fn create_default_so100_calibration() -> Calibration {
-        // First try to load the default calibration from the lerobot-vulcan repo
+    /// Load a default calibration shipped with lerobot-vulcan, relative to
+    /// `src/lerobot/robots`, falling back to an empty calibration.
+    pub fn load_default_calibration(relative_path: &[&str]) -> Calibration {
         if let Ok(lerobot_dir) = DirectoryService::get_lerobot_vulcan_dir() {
-            let default_path = lerobot_dir
-                .join("src")
-                .join("lerobot")
-                .join("robots")
-                .join("so100_follower")
-                .join("default_calibration.json");
-
-            if let Ok(default_str) = fs::read_to_string(&default_path) {
-                match serde_json::from_str::<Calibration>(&default_str) {
-                    Ok(calibration) => {
-                        return calibration;
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "Failed to parse default calibration file at {:?}: {}. Falling back to built-in defaults.",
-                            default_path, e
-                        );
-                    }
-                }
-            } else {
-                eprintln!(
-                    "Default calibration file not found or unreadable at {:?}. Falling back to built-in defaults.",
-                    default_path
-                );
-            }
-        } else {
-            eprintln!(
-                "Could not resolve lerobot-vulcan directory. Falling back to built-in defaults."
+            let default_path = relative_path.iter().fold(
+                lerobot_dir.join("src").join("lerobot").join("robots"),
+                |path, segment| path.join(segment),
             );
-        }
-
-        let motors = HashMap::new();
-        Calibration { motors }
-    }
-
-    pub fn create_default_sourccey_calibration(arm_side: &str) -> Calibration {
-        // First try to load the default calibration from the lerobot-vulcan repo
-        if let Ok(lerobot_dir) = DirectoryService::get_lerobot_vulcan_dir() {
-            let default_path = lerobot_dir
-                .join("src")
-                .join("lerobot")
-                .join("robots")
-                .join("sourccey")
-                .join("sourccey")
-                .join("sourccey")
-                .join(format!("{}_arm_default_calibration.json", arm_side));
 
             if let Ok(default_str) = fs::read_to_string(&default_path) {
                 match serde_json::from_str::<Calibration>(&default_str) {
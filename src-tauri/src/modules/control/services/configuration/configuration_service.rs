@@ -262,4 +262,38 @@ impl ConfigurationService {
         }
         fs::rename(old_robot_dir, new_robot_dir).map_err(|e| e.to_string())
     }
+
+    /// Remove a robot's config files, returning how many were removed.
+    pub fn remove_robot_cache_dir(nickname: &str) -> Result<usize, String> {
+        let config_path = DirectoryService::get_robot_config_path(nickname)?;
+        let remote_config_path = DirectoryService::get_remote_config_path(nickname)?;
+        Self::remove_robot_config_files(&[config_path, remote_config_path])
+    }
+
+    fn remove_robot_config_files(paths: &[PathBuf]) -> Result<usize, String> {
+        let mut removed = 0;
+        for path in paths {
+            if path.is_file() {
+                fs::remove_file(path)
+                    .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+                removed += 1;
+            }
+        }
+
+        // The cache root also holds datasets, so only an emptied directory is removed.
+        for robot_dir in paths.iter().filter_map(|path| path.parent()) {
+            let is_empty = fs::read_dir(robot_dir)
+                .map(|mut entries| entries.next().is_none())
+                .unwrap_or(false);
+            if is_empty {
+                fs::remove_dir(robot_dir)
+                    .map_err(|e| format!("Failed to remove {}: {}", robot_dir.display(), e))?;
+            }
+        }
+        Ok(removed)
+    }
 }
+
+#[cfg(test)]
+#[path = "tests/configuration_service_tests.rs"]
+mod configuration_service_tests;
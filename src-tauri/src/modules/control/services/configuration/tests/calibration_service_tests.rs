@@ -85,3 +85,33 @@ fn format_process_output_log_lines_splits_multiline_tracebacks() {
         ]
     );
 }
+
+#[test]
+fn remove_calibration_files_only_touches_the_nickname() {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("{}_calibration", nonce));
+    let files = [
+        "robots/sourccey_follower/kitchen_left.json",
+        "robots/sourccey_follower/kitchen_right.json",
+        "robots/sourccey_follower/garage_left.json",
+        "teleoperators/bi_sourccey_leader/kitchen.json",
+    ];
+    for file in files {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{}").unwrap();
+    }
+
+    assert_eq!(
+        CalibrationService::remove_calibration_files(&root, "@kitchen"),
+        Ok(3)
+    );
+    assert!(root.join(files[2]).exists());
+    assert!(!root.join(files[0]).exists());
+    assert!(CalibrationService::remove_calibration_files(&root, "..").is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
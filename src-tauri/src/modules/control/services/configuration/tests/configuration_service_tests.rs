@@ -0,0 +1,33 @@
+use super::ConfigurationService;
+
+#[test]
+fn removing_config_files_keeps_directories_with_other_contents() {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let cache_dir = std::env::temp_dir().join(format!("{}_robot_cache", nonce));
+    let kitchen = cache_dir.join("kitchen");
+    let garage = cache_dir.join("garage");
+    for dir in [&kitchen, &garage] {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("config.json"), "{}").unwrap();
+        std::fs::write(dir.join("remote_config.json"), "{}").unwrap();
+    }
+    // A dataset that happens to share the robot's name.
+    std::fs::write(garage.join("episodes.parquet"), "").unwrap();
+
+    let paths = |dir: &std::path::Path| [dir.join("config.json"), dir.join("remote_config.json")];
+    assert_eq!(
+        ConfigurationService::remove_robot_config_files(&paths(&kitchen)),
+        Ok(2)
+    );
+    assert!(!kitchen.exists());
+    assert_eq!(
+        ConfigurationService::remove_robot_config_files(&paths(&garage)),
+        Ok(2)
+    );
+    assert!(garage.join("episodes.parquet").exists());
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
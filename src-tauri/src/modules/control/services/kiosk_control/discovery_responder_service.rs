@@ -1,32 +1,36 @@
 use crate::modules::control::services::kiosk_control::kiosk_host_service::{
     KioskHostProcess, KioskHostService,
 };
+use crate::modules::control::services::robot_profiles::robot_profile::RobotDiscoveryIdentity;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use serde::Serialize;
 use std::io::ErrorKind;
-use std::net::UdpSocket;
+use std::net::{Ipv4Addr, UdpSocket};
 use std::thread;
 use std::time::Duration;
 
 const DISCOVERY_MAGIC: &str = "SOURCCEY_DISCOVER_V1";
-const DISCOVERY_PORT: u16 = 42111;
-const SOURCCEY_COMMAND_PORT: u16 = 5555;
-const SOURCCEY_OBSERVATION_PORT: u16 = 5556;
+pub const DISCOVERY_PORT: u16 = 42111;
 const DISCOVERY_READ_TIMEOUT_MS: u64 = 250;
 
 #[derive(Serialize)]
 struct DiscoveryResponsePayload {
     discovery_magic: &'static str,
-    robot_type: &'static str,
+    #[serde(flatten)]
+    identity: RobotDiscoveryIdentity,
     host_running: bool,
-    command_port: u16,
-    observation_port: u16,
 }
 
 pub struct KioskDiscoveryResponderService;
 
 impl KioskDiscoveryResponderService {
-    pub fn start(host_state: KioskHostProcess) -> Result<(), String> {
-        let socket = UdpSocket::bind(("0.0.0.0", DISCOVERY_PORT))
+    /// Answers on `port`, which is `DISCOVERY_PORT` except for the smoke test a
+    /// kiosk update runs against a staged build.
+    pub fn start(host_state: KioskHostProcess, port: u16) -> Result<(), String> {
+        let identity = RobotProfileService::kiosk()
+            .discovery_identity()
+            .ok_or("The kiosk robot profile cannot be discovered over the network".to_string())?;
+        let socket = UdpSocket::bind(("0.0.0.0", port))
             .map_err(|e| format!("Failed to bind kiosk discovery responder socket: {}", e))?;
         socket
             .set_read_timeout(Some(Duration::from_millis(DISCOVERY_READ_TIMEOUT_MS)))
@@ -48,6 +52,7 @@ impl KioskDiscoveryResponderService {
                         }
 
                         let payload = Self::build_discovery_response_payload(
+                            identity,
                             KioskHostService::is_any_kiosk_host_active(&host_state),
                         );
                         let _ = socket.send_to(&payload, address);
@@ -67,13 +72,35 @@ impl KioskDiscoveryResponderService {
         Ok(())
     }
 
-    fn build_discovery_response_payload(host_running: bool) -> Vec<u8> {
+    /// True when a responder on this machine answers on `port` within `timeout`.
+    pub fn probe_local(port: u16, timeout: Duration) -> bool {
+        let Ok(socket) = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)) else {
+            return false;
+        };
+        if socket.set_read_timeout(Some(timeout)).is_err()
+            || socket
+                .send_to(DISCOVERY_MAGIC.as_bytes(), (Ipv4Addr::LOCALHOST, port))
+                .is_err()
+        {
+            return false;
+        }
+        let mut buf = [0_u8; 1024];
+        socket
+            .recv_from(&mut buf)
+            .ok()
+            .and_then(|(size, _)| serde_json::from_slice::<serde_json::Value>(&buf[..size]).ok())
+            .map(|payload| payload["discovery_magic"] == DISCOVERY_MAGIC)
+            .unwrap_or(false)
+    }
+
+    fn build_discovery_response_payload(
+        identity: RobotDiscoveryIdentity,
+        host_running: bool,
+    ) -> Vec<u8> {
         serde_json::to_vec(&DiscoveryResponsePayload {
             discovery_magic: DISCOVERY_MAGIC,
-            robot_type: "sourccey",
+            identity,
             host_running,
-            command_port: SOURCCEY_COMMAND_PORT,
-            observation_port: SOURCCEY_OBSERVATION_PORT,
         })
         .unwrap_or_default()
     }
@@ -81,11 +108,20 @@ impl KioskDiscoveryResponderService {
 
 #[cfg(test)]
 mod tests {
-    use super::KioskDiscoveryResponderService;
+    use super::*;
+
+    fn kiosk_identity() -> RobotDiscoveryIdentity {
+        RobotProfileService::kiosk()
+            .discovery_identity()
+            .expect("the kiosk robot should be discoverable")
+    }
 
     #[test]
     fn discovery_payload_reports_stopped_host_with_ports() {
-        let payload = KioskDiscoveryResponderService::build_discovery_response_payload(false);
+        let payload = KioskDiscoveryResponderService::build_discovery_response_payload(
+            kiosk_identity(),
+            false,
+        );
         let parsed: serde_json::Value =
             serde_json::from_slice(&payload).expect("payload should be valid JSON");
 
@@ -98,10 +134,32 @@ mod tests {
 
     #[test]
     fn discovery_payload_reports_running_host() {
-        let payload = KioskDiscoveryResponderService::build_discovery_response_payload(true);
+        let payload = KioskDiscoveryResponderService::build_discovery_response_payload(
+            kiosk_identity(),
+            true,
+        );
         let parsed: serde_json::Value =
             serde_json::from_slice(&payload).expect("payload should be valid JSON");
 
         assert_eq!(parsed["host_running"], true);
     }
+
+    #[test]
+    fn probe_finds_a_responder_only_on_its_port() {
+        let port = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+            .and_then(|socket| socket.local_addr())
+            .expect("a free port should be found")
+            .port();
+        assert!(!KioskDiscoveryResponderService::probe_local(
+            port,
+            Duration::from_millis(200)
+        ));
+
+        KioskDiscoveryResponderService::start(KioskHostService::init_kiosk_host(), port)
+            .expect("responder should start");
+        assert!(KioskDiscoveryResponderService::probe_local(
+            port,
+            Duration::from_secs(2)
+        ));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::modules::control::services::kiosk_control::pairing_service::KioskPairingService;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use crate::modules::log::services::command_log_service::CommandLogService;
 use crate::services::directory::directory_service::DirectoryService;
 use crate::services::log::log_service::LogService;
@@ -52,6 +53,11 @@ impl KioskHostService {
             }
         }
 
+        let profile = RobotProfileService::kiosk();
+        let host_module = profile.host_module().ok_or(format!(
+            "{} robots have no kiosk host",
+            profile.robot_type()
+        ))?;
         let lerobot_dir = DirectoryService::get_lerobot_vulcan_dir()?;
         let python_path = DirectoryService::get_python_path()?;
         // Launch from the prepared virtualenv directly so host start does not
@@ -59,13 +65,13 @@ impl KioskHostService {
         let mut command_parts = vec![python_path.to_string_lossy().to_string()];
         command_parts.push("-u".to_string());
         command_parts.push("-m".to_string());
-        command_parts.push("lerobot.robots.sourccey.sourccey.sourccey.sourccey_host".to_string());
+        command_parts.push(host_module.to_string());
 
         let command_log_service = CommandLogService::new(db_connection.clone());
         let command_log = command_log_service
             .add_robot_command_log(
                 &format!("Starting kiosk host: {}", command_parts.join(" ")),
-                Some("sourccey".to_string()),
+                Some(profile.robot_type().to_string()),
                 Some(nickname.clone()),
             )
             .await
@@ -265,21 +271,11 @@ impl KioskHostService {
             Ok(format!("Robot stopping for nickname: {}", nickname))
         } else {
             println!(
-                "Checking for external sourccey_host process for nickname: {}",
+                "Checking for external kiosk host process for nickname: {}",
                 nickname
             );
             // Fallback: not tracked in state, but may be running externally on Linux.
-            // Find all matching PIDs (command line contains "sourccey_host")
-            let output = Command::new("pgrep")
-                .args(&["-f", "sourccey_host"])
-                .output()
-                .map_err(|e| format!("Failed to run pgrep: {}", e))?;
-
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let pids: Vec<u32> = stdout
-                .lines()
-                .filter_map(|line| line.trim().parse::<u32>().ok())
-                .collect();
+            let pids = Self::stop_external_kiosk_host_processes()?;
 
             if pids.is_empty() {
                 if !emit_events {
@@ -294,7 +290,7 @@ impl KioskHostService {
                     "kiosk-host-stop-error",
                     serde_json::json!({
                         "nickname": nickname,
-                        "error": format!("No kiosk host process found for nickname: {} (and no external sourccey_host found)", nickname),
+                        "error": format!("No kiosk host process found for nickname: {} (and no external host found)", nickname),
                     }),
                 );
 
@@ -304,22 +300,6 @@ impl KioskHostService {
                 ));
             }
 
-            // Try graceful stop first, then force
-            for pid in &pids {
-                let _ = Command::new("kill")
-                    .args(&["-TERM", &pid.to_string()])
-                    .output();
-            }
-
-            std::thread::sleep(std::time::Duration::from_secs(1));
-
-            // Force kill any that remain
-            for pid in &pids {
-                let _ = Command::new("kill")
-                    .args(&["-KILL", &pid.to_string()])
-                    .output();
-            }
-
             // Emit stop success (we attempted to stop external processes)
             if emit_events {
                 let _ = app_handle.emit(
@@ -328,18 +308,58 @@ impl KioskHostService {
                         "nickname": nickname,
                         "pid": pids.first().copied(), // one pid for convenience
                         "exit_code": None::<i32>,
-                        "message": format!("Stopped external sourccey_host process(es): {:?}", pids),
+                        "message": format!("Stopped external kiosk host process(es): {:?}", pids),
                     }),
                 );
             }
 
             Ok(format!(
-                "Stopped external sourccey_host process(es) for nickname: {}",
+                "Stopped external kiosk host process(es) for nickname: {}",
                 nickname
             ))
         }
     }
 
+    /// Stops host processes this app did not start (e.g. launched from a shell),
+    /// returning the PIDs that were signalled.
+    pub fn stop_external_kiosk_host_processes() -> Result<Vec<u32>, String> {
+        let Some(pattern) = RobotProfileService::kiosk().host_process_pattern() else {
+            return Ok(Vec::new());
+        };
+        // Find all matching PIDs (command line contains the host module)
+        let output = Command::new("pgrep")
+            .args(&["-f", pattern])
+            .output()
+            .map_err(|e| format!("Failed to run pgrep: {}", e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let pids: Vec<u32> = stdout
+            .lines()
+            .filter_map(|line| line.trim().parse::<u32>().ok())
+            .collect();
+        if pids.is_empty() {
+            return Ok(pids);
+        }
+
+        // Try graceful stop first, then force
+        for pid in &pids {
+            let _ = Command::new("kill")
+                .args(&["-TERM", &pid.to_string()])
+                .output();
+        }
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        // Force kill any that remain
+        for pid in &pids {
+            let _ = Command::new("kill")
+                .args(&["-KILL", &pid.to_string()])
+                .output();
+        }
+
+        Ok(pids)
+    }
+
     pub fn is_kiosk_host_active(state: &KioskHostProcess, nickname: String) -> bool {
         // 1) Managed by this app
         if state.0.lock().unwrap().contains_key(&nickname) {
@@ -349,6 +369,14 @@ impl KioskHostService {
         Self::has_external_kiosk_host_process()
     }
 
+    pub fn active_nicknames(state: &KioskHostProcess) -> Vec<String> {
+        state
+            .0
+            .lock()
+            .map(|processes| processes.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
     pub fn is_any_kiosk_host_active(state: &KioskHostProcess) -> bool {
         if !state.0.lock().unwrap().is_empty() {
             return true;
@@ -410,7 +438,10 @@ impl KioskHostService {
 
     fn has_external_kiosk_host_process() -> bool {
         // Externally started (Linux): check for the module name in cmdline.
-        let status = Command::new("pgrep").args(["-f", "sourccey_host"]).status();
+        let Some(pattern) = RobotProfileService::kiosk().host_process_pattern() else {
+            return false;
+        };
+        let status = Command::new("pgrep").args(["-f", pattern]).status();
 
         status.map(|s| s.success()).unwrap_or(false)
     }
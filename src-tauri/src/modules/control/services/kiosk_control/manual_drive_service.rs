@@ -1,3 +1,4 @@
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use crate::services::directory::directory_service::DirectoryService;
 use crate::services::log::log_service::LogService;
 use crate::services::process::process_service::ProcessService;
@@ -13,7 +14,6 @@ use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
 
 pub const MANUAL_DRIVE_UDP_PORT: u16 = 5561;
-const ALLOWED_KEYS: [&str; 10] = ["a", "d", "e", "f", "q", "r", "s", "w", "x", "z"];
 
 struct ManualDriveProcessEntry {
     child: CommandChild,
@@ -249,10 +249,11 @@ impl KioskManualDriveService {
     }
 
     fn sanitize_pressed_keys(keys: Vec<String>) -> Vec<String> {
+        let allowed_keys = RobotProfileService::kiosk().manual_drive_keys();
         let mut sanitized: Vec<String> = keys
             .into_iter()
             .map(|k| k.trim().to_lowercase())
-            .filter(|k| ALLOWED_KEYS.contains(&k.as_str()))
+            .filter(|k| allowed_keys.contains(&k.as_str()))
             .collect();
         sanitized.sort();
         sanitized.dedup();
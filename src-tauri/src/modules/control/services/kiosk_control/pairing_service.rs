@@ -1,3 +1,4 @@
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use crate::modules::settings::services::kiosk_environment::kiosk_environment_service::{
     KioskEnvironmentService, KioskEnvironmentSettings,
 };
@@ -30,6 +31,8 @@ struct PersistedCloudPairingState {
     portal_base_url: Option<String>,
     robot_model_name: Option<String>,
     device_auth_token: Option<String>,
+    #[serde(default)]
+    kiosk_nickname: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -127,14 +130,55 @@ impl KioskPairingService {
     }
 
     pub fn init_kiosk_pairing_state() -> KioskPairingState {
+        let robot_type = RobotProfileService::kiosk().robot_type();
+        let nickname = Self::load_persisted_cloud_pairing_state()
+            .ok()
+            .and_then(|persisted| persisted.kiosk_nickname)
+            .unwrap_or_else(|| robot_type.to_string());
         KioskPairingState {
             inner: Arc::new(Mutex::new(KioskPairingRuntimeState {
-                nickname: "sourccey".to_string(),
-                robot_type: "sourccey".to_string(),
+                nickname,
+                robot_type: robot_type.to_string(),
             })),
         }
     }
 
+    /// Set the nickname offered as the default when this kiosk is claimed.
+    pub fn set_kiosk_nickname(state: &KioskPairingState, nickname: &str) -> Result<String, String> {
+        let normalized = nickname.trim().trim_start_matches('@').trim();
+        if normalized.is_empty() {
+            return Err("Nickname cannot be empty".to_string());
+        }
+        if normalized.chars().count() > 64 {
+            return Err("Nickname cannot be longer than 64 characters".to_string());
+        }
+        if normalized.contains('/')
+            || normalized.contains('\\')
+            || normalized.chars().any(|ch| ch.is_control())
+        {
+            return Err("Nickname contains invalid characters".to_string());
+        }
+
+        let mut persisted = Self::load_persisted_cloud_pairing_state().unwrap_or_default();
+        persisted.kiosk_nickname = Some(normalized.to_string());
+        Self::save_persisted_cloud_pairing_state(&persisted)?;
+
+        let mut runtime = state
+            .inner
+            .lock()
+            .map_err(|_| "Failed to lock pairing state".to_string())?;
+        runtime.nickname = normalized.to_string();
+        Ok(runtime.nickname.clone())
+    }
+
+    pub fn get_kiosk_nickname(state: &KioskPairingState) -> Result<String, String> {
+        state
+            .inner
+            .lock()
+            .map(|runtime| runtime.nickname.clone())
+            .map_err(|_| "Failed to lock pairing state".to_string())
+    }
+
     pub fn get_kiosk_cloud_pairing_info(
         state: KioskPairingState,
     ) -> Result<KioskCloudPairingInfo, String> {
@@ -147,6 +191,54 @@ impl KioskPairingService {
         Self::resolve_kiosk_cloud_pairing_info(state, false)
     }
 
+    /// Pairing status from the last persisted state, without contacting the relay.
+    /// Used where the kiosk is likely offline, such as the provisioning portal.
+    pub fn get_cached_kiosk_cloud_pairing_status(
+        state: &KioskPairingState,
+    ) -> Result<KioskCloudPairingInfo, String> {
+        let environment_settings = Self::cloud_environment_settings();
+        let persisted = Self::load_persisted_cloud_pairing_state().unwrap_or_default();
+        let robot_model_name = state
+            .inner
+            .lock()
+            .map(|runtime| runtime.robot_type.clone())
+            .map_err(|_| "Failed to lock pairing state".to_string())?;
+
+        let expired = persisted
+            .expires_at_ms
+            .is_some_and(|expires_at_ms| expires_at_ms <= Self::now_ms());
+        let status = match persisted.status.as_deref() {
+            Some("pending") if expired => "expired".to_string(),
+            Some(status) => status.to_string(),
+            None => "idle".to_string(),
+        };
+        let pairing_code = if status == "pending" {
+            persisted.pairing_code.clone()
+        } else {
+            None
+        };
+
+        Ok(KioskCloudPairingInfo {
+            environment: environment_settings.environment,
+            portal_base_url: persisted
+                .portal_base_url
+                .clone()
+                .unwrap_or(environment_settings.app_base_url),
+            api_base_url: persisted
+                .api_base_url
+                .clone()
+                .unwrap_or(environment_settings.api_base_url),
+            device_id: persisted.device_id.clone().unwrap_or_default(),
+            robot_model_name,
+            pairing_code,
+            expires_at_ms: persisted.expires_at_ms,
+            status,
+            owned_robot_id: persisted.owned_robot_id.clone(),
+            claimed_at_ms: persisted.claimed_at_ms,
+            error_message: None,
+        })
+    }
+
     fn resolve_kiosk_cloud_pairing_info(
         state: KioskPairingState,
         start_if_missing: bool,
@@ -832,6 +924,7 @@ mod tests {
             portal_base_url: Some("http://192.168.1.220:3000".to_string()),
             robot_model_name: Some("sourccey".to_string()),
             device_auth_token: Some("auth-token".to_string()),
+            kiosk_nickname: None,
         };
 
         let payload = KioskPairingService::build_cloud_device_credentials(&state)
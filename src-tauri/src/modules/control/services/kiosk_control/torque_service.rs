@@ -5,8 +5,9 @@ use crate::modules::control::services::kiosk_control::manual_drive_service::{
     KioskManualDriveProcess, KioskManualDriveService,
 };
 use crate::modules::control::services::remote_control::remote_command_utils::{
-    format_command_for_display, resolve_uv_runtime, write_process_log,
+    format_command_for_display, resolve_uv_runtime, write_process_log_entry,
 };
+use crate::services::log::log_service::{LogContext, LogLevel};
 use sea_orm::DatabaseConnection;
 use std::collections::HashMap;
 use std::process::Command;
@@ -159,7 +160,15 @@ impl KioskTorqueService {
     fn emit_log(app_handle: &AppHandle, nickname: &str, message: &str) {
         let formatted = format!("[{}] {}", nickname, message);
         let _ = app_handle.emit("kiosk-host-log", formatted.clone());
-        write_process_log("kiosk-torque", &formatted);
+        let context = LogContext {
+            source: "kiosk-torque".to_string(),
+            nickname: Some(nickname.to_string()),
+            ..LogContext::default()
+        };
+        write_process_log_entry(
+            "kiosk-torque",
+            &context.entry(LogLevel::infer(message), message),
+        );
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::modules::log::services::command_log_service::CommandLogService;
 use crate::services::directory::directory_service::DirectoryService;
-use crate::services::log::log_service::LogService;
+use crate::services::log::log_broker_service::LogBrokerService;
+use crate::services::log::log_service::{LogEntry, LogLevel, LogService};
 use crate::services::setup::local_setup_service::LocalSetupService;
 use sea_orm::DatabaseConnection;
 use std::collections::HashMap;
@@ -23,6 +24,13 @@ pub fn init_managed_processes() -> ManagedRemoteProcesses {
     Arc::new(Mutex::new(HashMap::new()))
 }
 
+pub fn managed_process_nicknames(processes: &ManagedRemoteProcesses) -> Vec<String> {
+    processes
+        .lock()
+        .map(|processes| processes.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
 pub fn resolve_uv_runtime(app_handle: &AppHandle) -> Result<RemoteCommandRuntime, String> {
     let lerobot_dir = DirectoryService::get_lerobot_vulcan_dir()?;
     if !lerobot_dir.exists() {
@@ -76,7 +84,7 @@ pub async fn create_command_log(
         .await
         .map_err(|e| {
             let message = format!("Failed to add command log: {}", e);
-            write_process_log(log_name, &message);
+            write_process_log(log_name, LogLevel::Error, &message);
             message
         })?;
 
@@ -88,10 +96,23 @@ pub fn process_log_path(log_name: &str) -> Result<PathBuf, String> {
     Ok(base_dir.join("logs").join(format!("{}.log", log_name)))
 }
 
-pub fn write_process_log(log_name: &str, message: &str) {
+pub fn write_process_log(log_name: &str, level: LogLevel, message: &str) {
+    write_process_log_entry(log_name, &LogEntry::new(level, log_name, message));
+}
+
+pub fn write_process_log_entry(log_name: &str, entry: &LogEntry) {
     if let Ok(path) = process_log_path(log_name) {
-        LogService::write_log_line(path.to_string_lossy().as_ref(), Some(log_name), message);
+        LogService::write_log_entry(path.to_string_lossy().as_ref(), entry);
+    }
+}
+
+/// Record a line from a managed process: always written to its log file and
+/// offered to live subscribers, who may miss it under load.
+pub fn record_process_output(log_path: Option<&str>, entry: LogEntry) {
+    if let Some(path) = log_path {
+        LogService::write_log_entry(path, &entry);
     }
+    LogBrokerService::publish(&entry);
 }
 
 pub fn format_command_for_display(command_args: &[String]) -> String {
@@ -1,15 +1,23 @@
+use crate::modules::ai_model::services::ai_model_compatibility_service::{
+    AiModelCompatibilityService, CompatibilityReport, CompatibilitySeverity,
+};
+use crate::modules::ai_model::services::ai_model_service::AiModelService;
 use crate::modules::control::controllers::remote_control::remote_inference_controller::RemoteInferenceConfig;
 use crate::modules::control::services::remote_control::remote_command_utils::{
-    create_command_log, format_command_for_display, init_managed_processes, process_log_path,
-    resolve_uv_runtime, write_process_log, ManagedRemoteProcesses,
+    create_command_log, format_command_for_display, init_managed_processes,
+    managed_process_nicknames, process_log_path, record_process_output, resolve_uv_runtime,
+    write_process_log, ManagedRemoteProcesses,
 };
-use crate::services::log::log_service::LogService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::robot::services::robot_catalogue_service::RobotCapabilities;
+use crate::services::log::log_broker_service::LogBrokerService;
+use crate::services::log::log_service::{LogContext, LogEntry, LogLevel};
 use crate::services::process::process_service::ProcessService;
 use sea_orm::DatabaseConnection;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::AppHandle;
-use tauri::Emitter;
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
@@ -22,6 +30,10 @@ impl RemoteInferenceService {
         RemoteInferenceProcess(init_managed_processes())
     }
 
+    pub fn active_nicknames(state: &RemoteInferenceProcess) -> Vec<String> {
+        managed_process_nicknames(&state.0)
+    }
+
     pub async fn start_inference(
         app_handle: AppHandle,
         db_connection: DatabaseConnection,
@@ -29,6 +41,26 @@ impl RemoteInferenceService {
         config: RemoteInferenceConfig,
     ) -> Result<String, String> {
         Self::validate_config(&config)?;
+        let (profile, capabilities) =
+            RobotProfileService::for_nickname(&db_connection, &config.nickname)
+                .await
+                .inspect_err(|message| Self::log_inference_error(message))?;
+        let command_parts = Self::build_command_args(&config, profile, &capabilities)
+            .inspect_err(|message| Self::log_inference_error(message))?;
+        let report = AiModelCompatibilityService::check_for_robot(
+            &db_connection,
+            &config.nickname,
+            &config.model_path,
+        )
+        .await;
+        Self::enforce_compatibility(&config, &report)?;
+        // Storage cleanup evicts the least recently used models first.
+        if let Err(e) = AiModelService::new(db_connection.clone())
+            .mark_ai_model_used(&config.model_path)
+            .await
+        {
+            Self::log_inference_error(&format!("Failed to record model use: {}", e));
+        }
 
         {
             let processes = state.0.lock().unwrap();
@@ -51,7 +83,6 @@ impl RemoteInferenceService {
         let executable = runtime.executable.clone();
         let working_dir = runtime.working_dir.clone();
         let envs = runtime.envs;
-        let command_parts = Self::build_command_args(&config);
         let command_display = format_command_for_display(&command_parts);
 
         let start_message = format!(
@@ -65,7 +96,7 @@ impl RemoteInferenceService {
         );
         Self::log_inference_info(&start_message);
         Self::log_inference_info(&format!("Command: {}", command_display));
-        Self::emit_inference_info(&app_handle, &config.nickname, &start_message);
+        Self::publish_inference_info(&config.nickname, &start_message);
 
         let cmd = app_handle
             .shell()
@@ -87,16 +118,20 @@ impl RemoteInferenceService {
         let command_log_id = create_command_log(
             db_connection.clone(),
             &command_display,
-            "sourccey-inference",
+            &format!("{}-inference", profile.robot_type()),
             &config.nickname,
             "inference",
         )
         .await?;
 
         let pid = child.pid();
-        let nickname_for_logs = config.nickname.clone();
-        let app_handle_for_logs = app_handle.clone();
         let shutdown_for_logs = shutdown_flag.clone();
+        let log_context = LogContext {
+            source: "inference".to_string(),
+            nickname: Some(config.nickname.clone()),
+            command_log_id: Some(command_log_id.clone()),
+            pid: Some(pid),
+        };
         let inference_log_path = process_log_path("inference")
             .ok()
             .map(|p| p.to_string_lossy().to_string());
@@ -112,46 +147,43 @@ impl RemoteInferenceService {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("inference-log", &formatted);
-                            if let Some(path) = &inference_log_path {
-                                LogService::write_log_line(path, Some("inference"), line);
-                            }
+                            record_process_output(
+                                inference_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Stderr(line_bytes) => {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("inference-log", &formatted);
-                            if let Some(path) = &inference_log_path {
-                                LogService::write_log_line(path, Some("inference"), line);
-                            }
+                            record_process_output(
+                                inference_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Error(err) => {
                         let message = format!("Inference shell error: {}", err);
-                        let _ = app_handle_for_logs.emit(
-                            "inference-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        record_process_output(
+                            inference_log_path.as_deref(),
+                            log_context.entry(LogLevel::Error, &message),
                         );
-                        if let Some(path) = &inference_log_path {
-                            LogService::write_log_line(path, Some("inference"), &message);
-                        }
                     }
                     CommandEvent::Terminated(payload) => {
                         let message = format!(
                             "Inference process terminated (code={:?}, signal={:?})",
                             payload.code, payload.signal
                         );
-                        let _ = app_handle_for_logs.emit(
-                            "inference-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        let level = if payload.code == Some(0) {
+                            LogLevel::Info
+                        } else {
+                            LogLevel::Warn
+                        };
+                        record_process_output(
+                            inference_log_path.as_deref(),
+                            log_context.entry(level, &message),
                         );
-                        if let Some(path) = &inference_log_path {
-                            LogService::write_log_line(path, Some("inference"), &message);
-                        }
                         break;
                     }
                     _ => {}
@@ -223,14 +255,21 @@ impl RemoteInferenceService {
         }
     }
 
-    fn build_command_args(config: &RemoteInferenceConfig) -> Vec<String> {
+    fn build_command_args(
+        config: &RemoteInferenceConfig,
+        profile: &dyn RobotProfile,
+        capabilities: &RobotCapabilities,
+    ) -> Result<Vec<String>, String> {
         let mut command_parts = vec![
             "run".to_string(),
             "--no-sync".to_string(),
             "lerobot-inference".to_string(),
         ];
-        command_parts.push(format!("--id={}", config.nickname.trim()));
-        command_parts.push(format!("--remote_ip={}", config.remote_ip.trim()));
+        command_parts.extend(profile.inference_args(
+            capabilities,
+            &config.nickname,
+            &config.remote_ip,
+        )?);
         command_parts.push(format!("--model_path={}", config.model_path.trim()));
         command_parts.push(format!("--single_task={}", config.single_task.trim()));
         command_parts.push(format!("--fps={}", config.fps));
@@ -252,7 +291,7 @@ impl RemoteInferenceService {
             command_parts.push("--display_compressed_images=true".to_string());
         }
 
-        command_parts
+        Ok(command_parts)
     }
 
     fn validate_config(config: &RemoteInferenceConfig) -> Result<(), String> {
@@ -286,23 +325,53 @@ impl RemoteInferenceService {
         Ok(())
     }
 
+    /// Refuse to start a policy that cannot drive the robot unless the check
+    /// was skipped; warnings are only reported.
+    fn enforce_compatibility(
+        config: &RemoteInferenceConfig,
+        report: &CompatibilityReport,
+    ) -> Result<(), String> {
+        for warning in report.messages(CompatibilitySeverity::Warning) {
+            let message = format!("Model compatibility warning: {}", warning);
+            Self::log_inference_info(&message);
+            Self::publish_inference_info(&config.nickname, &message);
+        }
+        if report.compatible {
+            return Ok(());
+        }
+
+        let refusal = report.refusal("inference");
+        if config.skip_compatibility_check {
+            let message = format!("Compatibility check skipped: {}", refusal);
+            Self::log_inference_info(&message);
+            Self::publish_inference_info(&config.nickname, &message);
+            return Ok(());
+        }
+        Self::log_inference_error(&refusal);
+        Err(refusal)
+    }
+
     fn log_inference_error(message: &str) {
-        write_process_log("inference", message);
+        write_process_log("inference", LogLevel::Error, message);
     }
 
     fn log_inference_info(message: &str) {
-        write_process_log("inference", message);
+        write_process_log("inference", LogLevel::Info, message);
     }
 
-    fn emit_inference_info(app_handle: &AppHandle, nickname: &str, message: &str) {
-        let formatted = format!("[{}] {}", nickname, message);
-        let _ = app_handle.emit("inference-log", &formatted);
+    fn publish_inference_info(nickname: &str, message: &str) {
+        LogBrokerService::publish(&LogEntry {
+            nickname: Some(nickname.to_string()),
+            ..LogEntry::new(LogLevel::Info, "inference", message)
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::control::services::robot_profiles::sourccey_profile::SourcceyProfile;
+    use crate::modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 
     fn valid_config() -> RemoteInferenceConfig {
         RemoteInferenceConfig {
@@ -316,6 +385,7 @@ mod tests {
             display_ip: None,
             display_port: None,
             display_compressed_images: false,
+            skip_compatibility_check: false,
         }
     }
 
@@ -333,7 +403,15 @@ mod tests {
 
     #[test]
     fn builds_uv_remote_inference_command() {
-        let command_parts = RemoteInferenceService::build_command_args(&valid_config());
+        let capabilities = RobotCatalogueService::bundled_capabilities("sourccey")
+            .unwrap()
+            .unwrap();
+        let command_parts = RemoteInferenceService::build_command_args(
+            &valid_config(),
+            &SourcceyProfile,
+            &capabilities,
+        )
+        .unwrap();
         assert_eq!(command_parts[0], "run");
         assert_eq!(command_parts[1], "--no-sync");
         assert_eq!(command_parts[2], "lerobot-inference");
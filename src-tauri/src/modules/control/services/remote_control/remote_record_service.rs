@@ -1,16 +1,19 @@
 use crate::modules::control::controllers::remote_control::remote_record_controller::RemoteRecordConfig;
 use crate::modules::control::services::remote_control::remote_command_utils::{
     create_command_log, format_command_for_display, init_managed_processes, process_log_path,
-    resolve_uv_runtime, write_process_log, ManagedRemoteProcesses,
+    record_process_output, resolve_uv_runtime, write_process_log, ManagedRemoteProcesses,
 };
 use crate::modules::control::services::remote_control::remote_teleop_service::RemoteTeleopService;
-use crate::services::log::log_service::LogService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::robot::services::robot_catalogue_service::{RobotCapabilities, RobotCommand};
+use crate::services::log::log_broker_service::LogBrokerService;
+use crate::services::log::log_service::{LogContext, LogEntry, LogLevel};
 use crate::services::process::process_service::ProcessService;
 use sea_orm::DatabaseConnection;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::AppHandle;
-use tauri::Emitter;
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
@@ -32,6 +35,12 @@ impl RemoteRecordService {
         config: RemoteRecordConfig,
     ) -> Result<String, String> {
         Self::validate_config(&config)?;
+        let (profile, capabilities) =
+            RobotProfileService::for_nickname(&db_connection, &config.nickname)
+                .await
+                .inspect_err(|message| Self::log_record_error(message))?;
+        let command_parts = Self::build_command_args(&config, profile, &capabilities)
+            .inspect_err(|message| Self::log_record_error(message))?;
 
         {
             let processes = state.0.lock().unwrap();
@@ -55,7 +64,6 @@ impl RemoteRecordService {
         let working_dir = runtime.working_dir.clone();
         let envs = runtime.envs;
         RemoteTeleopService::update_keyboard_state(&config.nickname, &[])?;
-        let command_parts = Self::build_command_args(&config);
         let command_display = format_command_for_display(&command_parts);
 
         let start_message = format!(
@@ -69,7 +77,7 @@ impl RemoteRecordService {
         );
         Self::log_record_info(&start_message);
         Self::log_record_info(&format!("Command: {}", command_display));
-        Self::emit_record_info(&app_handle, &config.nickname, &start_message);
+        Self::publish_record_info(&config.nickname, &start_message);
 
         let cmd = app_handle
             .shell()
@@ -91,16 +99,20 @@ impl RemoteRecordService {
         let command_log_id = create_command_log(
             db_connection.clone(),
             &command_display,
-            "sourccey-record",
+            &format!("{}-record", profile.robot_type()),
             &config.nickname,
             "record",
         )
         .await?;
 
         let pid = child.pid();
-        let nickname_for_logs = config.nickname.clone();
-        let app_handle_for_logs = app_handle.clone();
         let shutdown_for_logs = shutdown_flag.clone();
+        let log_context = LogContext {
+            source: "record".to_string(),
+            nickname: Some(config.nickname.clone()),
+            command_log_id: Some(command_log_id.clone()),
+            pid: Some(pid),
+        };
         let record_log_path = process_log_path("record")
             .ok()
             .map(|p| p.to_string_lossy().to_string());
@@ -116,46 +128,43 @@ impl RemoteRecordService {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("record-log", &formatted);
-                            if let Some(path) = &record_log_path {
-                                LogService::write_log_line(path, Some("record"), line);
-                            }
+                            record_process_output(
+                                record_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Stderr(line_bytes) => {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("record-log", &formatted);
-                            if let Some(path) = &record_log_path {
-                                LogService::write_log_line(path, Some("record"), line);
-                            }
+                            record_process_output(
+                                record_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Error(err) => {
                         let message = format!("Record shell error: {}", err);
-                        let _ = app_handle_for_logs.emit(
-                            "record-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        record_process_output(
+                            record_log_path.as_deref(),
+                            log_context.entry(LogLevel::Error, &message),
                         );
-                        if let Some(path) = &record_log_path {
-                            LogService::write_log_line(path, Some("record"), &message);
-                        }
                     }
                     CommandEvent::Terminated(payload) => {
                         let message = format!(
                             "Record process terminated (code={:?}, signal={:?})",
                             payload.code, payload.signal
                         );
-                        let _ = app_handle_for_logs.emit(
-                            "record-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        let level = if payload.code == Some(0) {
+                            LogLevel::Info
+                        } else {
+                            LogLevel::Warn
+                        };
+                        record_process_output(
+                            record_log_path.as_deref(),
+                            log_context.entry(level, &message),
                         );
-                        if let Some(path) = &record_log_path {
-                            LogService::write_log_line(path, Some("record"), &message);
-                        }
                         break;
                     }
                     _ => {}
@@ -228,21 +237,26 @@ impl RemoteRecordService {
         }
     }
 
-    fn build_command_args(config: &RemoteRecordConfig) -> Vec<String> {
+    fn build_command_args(
+        config: &RemoteRecordConfig,
+        profile: &dyn RobotProfile,
+        capabilities: &RobotCapabilities,
+    ) -> Result<Vec<String>, String> {
         let mut args = vec![
             "run".to_string(),
             "--no-sync".to_string(),
             "lerobot-record".to_string(),
-            "--robot.type=sourccey_client".to_string(),
-            "--robot.id=sourccey".to_string(),
-            format!("--robot.remote_ip={}", config.remote_ip.trim()),
-            "--teleop.type=bi_sourccey_leader".to_string(),
-            "--teleop.id=sourccey_leader".to_string(),
-            format!("--teleop.left_arm_port={}", config.left_arm_port.trim()),
-            format!("--teleop.right_arm_port={}", config.right_arm_port.trim()),
+        ];
+        args.extend(profile.robot_args(capabilities, RobotCommand::Record, &config.remote_ip)?);
+        args.extend(profile.teleop_args(
+            capabilities,
+            &config.left_arm_port,
+            &config.right_arm_port,
+        )?);
+        args.extend([
             "--teleop_keyboard.type=keyboard".to_string(),
             format!("--teleop_keyboard.id={}", config.keyboard.trim()),
-        ];
+        ]);
         if cfg!(target_os = "macos") {
             args.push(format!(
                 "--teleop_keyboard.input_state_path={}",
@@ -259,7 +273,7 @@ impl RemoteRecordService {
             "--display_data=true".to_string(),
             "--dataset.push_to_hub=false".to_string(),
         ]);
-        args
+        Ok(args)
     }
 
     fn validate_config(config: &RemoteRecordConfig) -> Result<(), String> {
@@ -293,22 +307,26 @@ impl RemoteRecordService {
     }
 
     fn log_record_error(message: &str) {
-        write_process_log("record", message);
+        write_process_log("record", LogLevel::Error, message);
     }
 
     fn log_record_info(message: &str) {
-        write_process_log("record", message);
+        write_process_log("record", LogLevel::Info, message);
     }
 
-    fn emit_record_info(app_handle: &AppHandle, nickname: &str, message: &str) {
-        let formatted = format!("[{}] {}", nickname, message);
-        let _ = app_handle.emit("record-log", &formatted);
+    fn publish_record_info(nickname: &str, message: &str) {
+        LogBrokerService::publish(&LogEntry {
+            nickname: Some(nickname.to_string()),
+            ..LogEntry::new(LogLevel::Info, "record", message)
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::control::services::robot_profiles::sourccey_profile::SourcceyProfile;
+    use crate::modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 
     fn valid_config() -> RemoteRecordConfig {
         RemoteRecordConfig {
@@ -351,7 +369,15 @@ mod tests {
 
     #[test]
     fn builds_uv_remote_record_command() {
-        let command_parts = RemoteRecordService::build_command_args(&valid_config());
+        let capabilities = RobotCatalogueService::bundled_capabilities("sourccey")
+            .unwrap()
+            .unwrap();
+        let command_parts = RemoteRecordService::build_command_args(
+            &valid_config(),
+            &SourcceyProfile,
+            &capabilities,
+        )
+        .unwrap();
         assert_eq!(command_parts[0], "run");
         assert_eq!(command_parts[1], "--no-sync");
         assert_eq!(command_parts[2], "lerobot-record");
@@ -361,5 +387,24 @@ mod tests {
         assert!(command_parts
             .iter()
             .any(|part| part == "--dataset.push_to_hub=false"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--robot.type=sourccey_client"));
+    }
+
+    #[test]
+    fn robots_without_recording_are_refused() {
+        let mut capabilities = RobotCatalogueService::bundled_capabilities("sourccey")
+            .unwrap()
+            .unwrap();
+        capabilities.lerobot_commands = vec![RobotCommand::Teleop];
+        assert_eq!(
+            RemoteRecordService::build_command_args(
+                &valid_config(),
+                &SourcceyProfile,
+                &capabilities
+            ),
+            Err("This robot does not support recording.".to_string())
+        );
     }
 }
@@ -1,15 +1,23 @@
+use crate::modules::ai_model::services::ai_model_compatibility_service::{
+    AiModelCompatibilityService, CompatibilityReport, CompatibilitySeverity,
+};
+use crate::modules::ai_model::services::ai_model_service::AiModelService;
 use crate::modules::control::controllers::remote_control::remote_rollout_controller::RemoteRolloutConfig;
 use crate::modules::control::services::remote_control::remote_command_utils::{
-    create_command_log, format_command_for_display, init_managed_processes, process_log_path,
-    resolve_uv_runtime, write_process_log, ManagedRemoteProcesses,
+    create_command_log, format_command_for_display, init_managed_processes,
+    managed_process_nicknames, process_log_path, record_process_output, resolve_uv_runtime,
+    write_process_log, ManagedRemoteProcesses,
 };
-use crate::services::log::log_service::LogService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::robot::services::robot_catalogue_service::{RobotCapabilities, RobotCommand};
+use crate::services::log::log_broker_service::LogBrokerService;
+use crate::services::log::log_service::{LogContext, LogEntry, LogLevel};
 use crate::services::process::process_service::ProcessService;
 use sea_orm::DatabaseConnection;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::AppHandle;
-use tauri::Emitter;
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
@@ -24,6 +32,10 @@ impl RemoteRolloutService {
         RemoteRolloutProcess(init_managed_processes())
     }
 
+    pub fn active_nicknames(state: &RemoteRolloutProcess) -> Vec<String> {
+        managed_process_nicknames(&state.0)
+    }
+
     pub async fn start_rollout(
         app_handle: AppHandle,
         db_connection: DatabaseConnection,
@@ -31,6 +43,26 @@ impl RemoteRolloutService {
         config: RemoteRolloutConfig,
     ) -> Result<String, String> {
         Self::validate_config(&config)?;
+        let (profile, capabilities) =
+            RobotProfileService::for_nickname(&db_connection, &config.nickname)
+                .await
+                .inspect_err(|message| Self::log_rollout_error(message))?;
+        let command_parts = Self::build_command_args(&config, profile, &capabilities)
+            .inspect_err(|message| Self::log_rollout_error(message))?;
+        let report = AiModelCompatibilityService::check_for_robot(
+            &db_connection,
+            &config.nickname,
+            &config.model_path,
+        )
+        .await;
+        Self::enforce_compatibility(&config, &report)?;
+        // Storage cleanup evicts the least recently used models first.
+        if let Err(e) = AiModelService::new(db_connection.clone())
+            .mark_ai_model_used(&config.model_path)
+            .await
+        {
+            Self::log_rollout_error(&format!("Failed to record model use: {}", e));
+        }
 
         {
             let processes = state.0.lock().unwrap();
@@ -53,7 +85,6 @@ impl RemoteRolloutService {
         let executable = runtime.executable.clone();
         let working_dir = runtime.working_dir.clone();
         let envs = runtime.envs;
-        let command_parts = Self::build_command_args(&config);
         let command_display = format_command_for_display(&command_parts);
 
         let start_message = format!(
@@ -62,7 +93,7 @@ impl RemoteRolloutService {
         );
         Self::log_rollout_info(&start_message);
         Self::log_rollout_info(&format!("Command: {}", command_display));
-        Self::emit_rollout_info(&app_handle, &config.nickname, &start_message);
+        Self::publish_rollout_info(&config.nickname, &start_message);
 
         let cmd = app_handle
             .shell()
@@ -84,16 +115,20 @@ impl RemoteRolloutService {
         let command_log_id = create_command_log(
             db_connection.clone(),
             &command_display,
-            "sourccey-rollout",
+            &format!("{}-rollout", profile.robot_type()),
             &config.nickname,
             "rollout",
         )
         .await?;
 
         let pid = child.pid();
-        let nickname_for_logs = config.nickname.clone();
-        let app_handle_for_logs = app_handle.clone();
         let shutdown_for_logs = shutdown_flag.clone();
+        let log_context = LogContext {
+            source: "rollout".to_string(),
+            nickname: Some(config.nickname.clone()),
+            command_log_id: Some(command_log_id.clone()),
+            pid: Some(pid),
+        };
         let rollout_log_path = process_log_path("rollout")
             .ok()
             .map(|p| p.to_string_lossy().to_string());
@@ -109,46 +144,43 @@ impl RemoteRolloutService {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("rollout-log", &formatted);
-                            if let Some(path) = &rollout_log_path {
-                                LogService::write_log_line(path, Some("rollout"), line);
-                            }
+                            record_process_output(
+                                rollout_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Stderr(line_bytes) => {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("rollout-log", &formatted);
-                            if let Some(path) = &rollout_log_path {
-                                LogService::write_log_line(path, Some("rollout"), line);
-                            }
+                            record_process_output(
+                                rollout_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Error(err) => {
                         let message = format!("Rollout shell error: {}", err);
-                        let _ = app_handle_for_logs.emit(
-                            "rollout-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        record_process_output(
+                            rollout_log_path.as_deref(),
+                            log_context.entry(LogLevel::Error, &message),
                         );
-                        if let Some(path) = &rollout_log_path {
-                            LogService::write_log_line(path, Some("rollout"), &message);
-                        }
                     }
                     CommandEvent::Terminated(payload) => {
                         let message = format!(
                             "Rollout process terminated (code={:?}, signal={:?})",
                             payload.code, payload.signal
                         );
-                        let _ = app_handle_for_logs.emit(
-                            "rollout-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        let level = if payload.code == Some(0) {
+                            LogLevel::Info
+                        } else {
+                            LogLevel::Warn
+                        };
+                        record_process_output(
+                            rollout_log_path.as_deref(),
+                            log_context.entry(level, &message),
                         );
-                        if let Some(path) = &rollout_log_path {
-                            LogService::write_log_line(path, Some("rollout"), &message);
-                        }
                         break;
                     }
                     _ => {}
@@ -220,21 +252,26 @@ impl RemoteRolloutService {
         }
     }
 
-    fn build_command_args(config: &RemoteRolloutConfig) -> Vec<String> {
-        vec![
+    fn build_command_args(
+        config: &RemoteRolloutConfig,
+        profile: &dyn RobotProfile,
+        capabilities: &RobotCapabilities,
+    ) -> Result<Vec<String>, String> {
+        let mut args = vec![
             "run".to_string(),
             "--no-sync".to_string(),
             "lerobot-rollout".to_string(),
             "--strategy.type=base".to_string(),
             format!("--policy.path={}", config.model_path.trim()),
-            "--robot.type=sourccey_client".to_string(),
-            "--robot.id=sourccey".to_string(),
-            format!("--robot.remote_ip={}", config.remote_ip.trim()),
+        ];
+        args.extend(profile.robot_args(capabilities, RobotCommand::Rollout, &config.remote_ip)?);
+        args.extend([
             format!("--task={}", config.task.trim()),
             "--display_data=true".to_string(),
             format!("--duration={}", config.duration),
             format!("--fps={}", DEFAULT_ROLLOUT_FPS),
-        ]
+        ]);
+        Ok(args)
     }
 
     fn validate_config(config: &RemoteRolloutConfig) -> Result<(), String> {
@@ -256,23 +293,57 @@ impl RemoteRolloutService {
         Ok(())
     }
 
+    /// Refuse to start a policy that cannot drive the robot unless the check
+    /// was skipped; warnings are only reported.
+    fn enforce_compatibility(
+        config: &RemoteRolloutConfig,
+        report: &CompatibilityReport,
+    ) -> Result<(), String> {
+        for warning in report.messages(CompatibilitySeverity::Warning) {
+            let message = format!("Model compatibility warning: {}", warning);
+            Self::log_rollout_info(&message);
+            Self::publish_rollout_info(&config.nickname, &message);
+        }
+        if report.compatible {
+            return Ok(());
+        }
+
+        let refusal = report.refusal("rollout");
+        if config.skip_compatibility_check {
+            let message = format!("Compatibility check skipped: {}", refusal);
+            Self::log_rollout_info(&message);
+            Self::publish_rollout_info(&config.nickname, &message);
+            return Ok(());
+        }
+        Self::log_rollout_error(&refusal);
+        Err(refusal)
+    }
+
     fn log_rollout_error(message: &str) {
-        write_process_log("rollout", message);
+        write_process_log("rollout", LogLevel::Error, message);
     }
 
     fn log_rollout_info(message: &str) {
-        write_process_log("rollout", message);
+        write_process_log("rollout", LogLevel::Info, message);
     }
 
-    fn emit_rollout_info(app_handle: &AppHandle, nickname: &str, message: &str) {
-        let formatted = format!("[{}] {}", nickname, message);
-        let _ = app_handle.emit("rollout-log", &formatted);
+    fn publish_rollout_info(nickname: &str, message: &str) {
+        LogBrokerService::publish(&LogEntry {
+            nickname: Some(nickname.to_string()),
+            ..LogEntry::new(LogLevel::Info, "rollout", message)
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::ai_model::services::ai_model_compatibility_service::{
+        CompatibilityIssue, CompatibilityIssueKind,
+    };
+    use crate::modules::control::services::robot_profiles::so100_profile::So100Profile;
+    use crate::modules::control::services::robot_profiles::sourccey_profile::SourcceyProfile;
+    use crate::modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 
     fn valid_config() -> RemoteRolloutConfig {
         RemoteRolloutConfig {
@@ -281,9 +352,35 @@ mod tests {
             model_path: "outputs/train/test/checkpoints/last/pretrained_model".to_string(),
             task: "Fold the shirt".to_string(),
             duration: 300.0,
+            skip_compatibility_check: false,
         }
     }
 
+    #[test]
+    fn incompatible_models_are_refused_unless_skipped() {
+        let report = CompatibilityReport {
+            model_path: "models/so100-act".to_string(),
+            robot_type: "sourccey".to_string(),
+            compatible: false,
+            issues: vec![CompatibilityIssue {
+                kind: CompatibilityIssueKind::ActionDimension,
+                severity: CompatibilitySeverity::Error,
+                message: "Policy action has 6 values but a sourccey robot uses 12 or 16"
+                    .to_string(),
+                expected: Some("12 or 16".to_string()),
+                actual: Some("6".to_string()),
+            }],
+        };
+
+        let error =
+            RemoteRolloutService::enforce_compatibility(&valid_config(), &report).unwrap_err();
+        assert!(error.contains("action has 6 values"), "{}", error);
+
+        let mut skipped = valid_config();
+        skipped.skip_compatibility_check = true;
+        assert!(RemoteRolloutService::enforce_compatibility(&skipped, &report).is_ok());
+    }
+
     #[test]
     fn validates_remote_rollout_config() {
         assert!(RemoteRolloutService::validate_config(&valid_config()).is_ok());
@@ -298,12 +395,31 @@ mod tests {
 
     #[test]
     fn builds_uv_remote_rollout_command() {
-        let command_parts = RemoteRolloutService::build_command_args(&valid_config());
+        let capabilities = RobotCatalogueService::bundled_capabilities("sourccey")
+            .unwrap()
+            .unwrap();
+        let command_parts = RemoteRolloutService::build_command_args(
+            &valid_config(),
+            &SourcceyProfile,
+            &capabilities,
+        )
+        .unwrap();
         assert_eq!(command_parts[0], "run");
         assert_eq!(command_parts[1], "--no-sync");
         assert_eq!(command_parts[2], "lerobot-rollout");
         assert!(command_parts
             .iter()
             .any(|part| part == "--strategy.type=base"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--robot.type=sourccey_client"));
+
+        let so100 = RobotCatalogueService::bundled_capabilities("so100_follower")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            RemoteRolloutService::build_command_args(&valid_config(), &So100Profile, &so100),
+            Err("This robot does not support rollout.".to_string())
+        );
     }
 }
@@ -1,16 +1,20 @@
 use crate::modules::control::controllers::remote_control::remote_teleop_controller::RemoteTeleopConfig;
 use crate::modules::control::services::remote_control::remote_command_utils::{
-    create_command_log, format_command_for_display, init_managed_processes, process_log_path,
-    resolve_uv_runtime, write_process_log, ManagedRemoteProcesses,
+    create_command_log, format_command_for_display, init_managed_processes,
+    managed_process_nicknames, process_log_path, record_process_output, resolve_uv_runtime,
+    write_process_log, ManagedRemoteProcesses,
 };
-use crate::services::log::log_service::LogService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::robot::services::robot_catalogue_service::{RobotCapabilities, RobotCommand};
+use crate::services::log::log_broker_service::LogBrokerService;
+use crate::services::log::log_service::{LogContext, LogEntry, LogLevel};
 use crate::services::process::process_service::ProcessService;
 use sea_orm::DatabaseConnection;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::{fs, path::PathBuf};
 use tauri::AppHandle;
-use tauri::Emitter;
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
@@ -49,6 +53,10 @@ impl RemoteTeleopService {
         RemoteTeleopProcess(init_managed_processes())
     }
 
+    pub fn active_nicknames(state: &RemoteTeleopProcess) -> Vec<String> {
+        managed_process_nicknames(&state.0)
+    }
+
     pub async fn start_teleop(
         app_handle: AppHandle,
         db_connection: DatabaseConnection,
@@ -56,6 +64,12 @@ impl RemoteTeleopService {
         config: RemoteTeleopConfig,
     ) -> Result<String, String> {
         Self::validate_config(&config)?;
+        let (profile, capabilities) =
+            RobotProfileService::for_nickname(&db_connection, &config.nickname)
+                .await
+                .inspect_err(|message| Self::log_teleop_error(message))?;
+        let command_parts = Self::build_command_args(&config, profile, &capabilities)
+            .inspect_err(|message| Self::log_teleop_error(message))?;
 
         {
             let processes = state.0.lock().unwrap();
@@ -79,7 +93,6 @@ impl RemoteTeleopService {
         let working_dir = runtime.working_dir.clone();
         let envs = runtime.envs;
         Self::update_keyboard_state(&config.nickname, &[])?;
-        let command_parts = Self::build_command_args(&config);
         let command_display = format_command_for_display(&command_parts);
 
         let start_message = format!(
@@ -93,12 +106,8 @@ impl RemoteTeleopService {
         );
         Self::log_teleop_info(&start_message);
         Self::log_teleop_info(&format!("Command: {}", command_display));
-        Self::emit_teleop_info(&app_handle, &config.nickname, &start_message);
-        Self::emit_teleop_info(
-            &app_handle,
-            &config.nickname,
-            &format!("Command: {}", command_display),
-        );
+        Self::publish_teleop_info(&config.nickname, &start_message);
+        Self::publish_teleop_info(&config.nickname, &format!("Command: {}", command_display));
 
         let cmd = app_handle
             .shell()
@@ -120,16 +129,20 @@ impl RemoteTeleopService {
         let command_log_id = create_command_log(
             db_connection.clone(),
             &command_display,
-            "sourccey",
+            profile.robot_type(),
             &config.nickname,
             "teleop",
         )
         .await?;
 
         let pid = child.pid();
-        let nickname_for_logs = config.nickname.clone();
-        let app_handle_for_logs = app_handle.clone();
         let shutdown_for_logs = shutdown_flag.clone();
+        let log_context = LogContext {
+            source: "teleop".to_string(),
+            nickname: Some(config.nickname.clone()),
+            command_log_id: Some(command_log_id.clone()),
+            pid: Some(pid),
+        };
         let teleop_log_path = process_log_path("teleop")
             .ok()
             .map(|p| p.to_string_lossy().to_string());
@@ -145,46 +158,43 @@ impl RemoteTeleopService {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("teleop-log", &formatted);
-                            if let Some(path) = &teleop_log_path {
-                                LogService::write_log_line(path, Some("teleop"), line);
-                            }
+                            record_process_output(
+                                teleop_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Stderr(line_bytes) => {
                         let line = String::from_utf8_lossy(&line_bytes);
                         let line = line.trim_end();
                         if !line.is_empty() {
-                            let formatted = format!("[{}] {}", nickname_for_logs, line);
-                            let _ = app_handle_for_logs.emit("teleop-log", &formatted);
-                            if let Some(path) = &teleop_log_path {
-                                LogService::write_log_line(path, Some("teleop"), line);
-                            }
+                            record_process_output(
+                                teleop_log_path.as_deref(),
+                                log_context.entry(LogLevel::infer(line), line),
+                            );
                         }
                     }
                     CommandEvent::Error(err) => {
                         let message = format!("Teleop shell error: {}", err);
-                        let _ = app_handle_for_logs.emit(
-                            "teleop-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        record_process_output(
+                            teleop_log_path.as_deref(),
+                            log_context.entry(LogLevel::Error, &message),
                         );
-                        if let Some(path) = &teleop_log_path {
-                            LogService::write_log_line(path, Some("teleop"), &message);
-                        }
                     }
                     CommandEvent::Terminated(payload) => {
                         let message = format!(
                             "Teleop process terminated (code={:?}, signal={:?})",
                             payload.code, payload.signal
                         );
-                        let _ = app_handle_for_logs.emit(
-                            "teleop-log",
-                            &format!("[{}] {}", nickname_for_logs, message),
+                        let level = if payload.code == Some(0) {
+                            LogLevel::Info
+                        } else {
+                            LogLevel::Warn
+                        };
+                        record_process_output(
+                            teleop_log_path.as_deref(),
+                            log_context.entry(level, &message),
                         );
-                        if let Some(path) = &teleop_log_path {
-                            LogService::write_log_line(path, Some("teleop"), &message);
-                        }
                         break;
                     }
                     _ => {}
@@ -257,21 +267,26 @@ impl RemoteTeleopService {
         }
     }
 
-    fn build_command_args(config: &RemoteTeleopConfig) -> Vec<String> {
+    fn build_command_args(
+        config: &RemoteTeleopConfig,
+        profile: &dyn RobotProfile,
+        capabilities: &RobotCapabilities,
+    ) -> Result<Vec<String>, String> {
         let mut args = vec![
             "run".to_string(),
             "--no-sync".to_string(),
             "lerobot-teleoperate".to_string(),
-            "--robot.type=sourccey_client".to_string(),
-            "--robot.id=sourccey".to_string(),
-            format!("--robot.remote_ip={}", config.remote_ip.trim()),
-            "--teleop.type=bi_sourccey_leader".to_string(),
-            "--teleop.id=sourccey_leader".to_string(),
-            format!("--teleop.left_arm_port={}", config.left_arm_port.trim()),
-            format!("--teleop.right_arm_port={}", config.right_arm_port.trim()),
+        ];
+        args.extend(profile.robot_args(capabilities, RobotCommand::Teleop, &config.remote_ip)?);
+        args.extend(profile.teleop_args(
+            capabilities,
+            &config.left_arm_port,
+            &config.right_arm_port,
+        )?);
+        args.extend([
             "--teleop_keyboard.type=keyboard".to_string(),
             format!("--teleop_keyboard.id={}", config.keyboard.trim()),
-        ];
+        ]);
         if cfg!(target_os = "macos") {
             args.push(format!(
                 "--teleop_keyboard.input_state_path={}",
@@ -282,7 +297,7 @@ impl RemoteTeleopService {
             format!("--fps={}", config.fps),
             "--display_data=false".to_string(),
         ]);
-        args
+        Ok(args)
     }
 
     fn validate_config(config: &RemoteTeleopConfig) -> Result<(), String> {
@@ -302,22 +317,27 @@ impl RemoteTeleopService {
     }
 
     fn log_teleop_error(message: &str) {
-        write_process_log("teleop", message);
+        write_process_log("teleop", LogLevel::Error, message);
     }
 
     fn log_teleop_info(message: &str) {
-        write_process_log("teleop", message);
+        write_process_log("teleop", LogLevel::Info, message);
     }
 
-    fn emit_teleop_info(app_handle: &AppHandle, nickname: &str, message: &str) {
-        let formatted = format!("[{}] {}", nickname, message);
-        let _ = app_handle.emit("teleop-log", &formatted);
+    fn publish_teleop_info(nickname: &str, message: &str) {
+        LogBrokerService::publish(&LogEntry {
+            nickname: Some(nickname.to_string()),
+            ..LogEntry::new(LogLevel::Info, "teleop", message)
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::control::services::robot_profiles::so100_profile::So100Profile;
+    use crate::modules::control::services::robot_profiles::sourccey_profile::SourcceyProfile;
+    use crate::modules::robot::services::robot_catalogue_service::RobotCatalogueService;
 
     fn valid_config() -> RemoteTeleopConfig {
         RemoteTeleopConfig {
@@ -349,9 +369,20 @@ mod tests {
         );
     }
 
+    fn bundled_capabilities(robot_type: &str) -> RobotCapabilities {
+        RobotCatalogueService::bundled_capabilities(robot_type)
+            .unwrap()
+            .expect("robot type should be in the bundled catalogue")
+    }
+
     #[test]
     fn builds_uv_remote_teleop_command() {
-        let command_parts = RemoteTeleopService::build_command_args(&valid_config());
+        let command_parts = RemoteTeleopService::build_command_args(
+            &valid_config(),
+            &SourcceyProfile,
+            &bundled_capabilities("sourccey"),
+        )
+        .unwrap();
         assert_eq!(command_parts[0], "run");
         assert_eq!(command_parts[1], "--no-sync");
         assert_eq!(command_parts[2], "lerobot-teleoperate");
@@ -364,5 +395,44 @@ mod tests {
         assert!(command_parts
             .iter()
             .any(|part| part == "--display_data=false"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--teleop.type=bi_sourccey_leader"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--teleop.right_arm_port=COM4"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--robot.id=sourccey"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--teleop.id=sourccey_leader"));
+    }
+
+    #[test]
+    fn teleop_arguments_follow_the_robot_capabilities() {
+        let mut single_arm = bundled_capabilities("sourccey");
+        single_arm.arm_count = 1;
+        single_arm.teleop_types = vec!["custom_leader".to_string()];
+        let command_parts =
+            RemoteTeleopService::build_command_args(&valid_config(), &SourcceyProfile, &single_arm)
+                .unwrap();
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--teleop.type=custom_leader"));
+        assert!(command_parts
+            .iter()
+            .any(|part| part == "--teleop.port=COM3"));
+        assert!(!command_parts
+            .iter()
+            .any(|part| part.starts_with("--teleop.right_arm_port")));
+
+        let error = RemoteTeleopService::build_command_args(
+            &valid_config(),
+            &So100Profile,
+            &bundled_capabilities("so100_follower"),
+        )
+        .unwrap_err();
+        assert!(error.contains("over the network"), "{}", error);
     }
 }
@@ -0,0 +1,4 @@
+pub mod robot_profile;
+pub mod robot_profile_service;
+pub mod so100_profile;
+pub mod sourccey_profile;
@@ -0,0 +1,130 @@
+use crate::modules::control::types::configuration::calibration_types::Calibration;
+use crate::modules::robot::services::robot_catalogue_service::{RobotCapabilities, RobotCommand};
+use serde::Serialize;
+
+/// How a robot host answers LAN discovery.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct RobotDiscoveryIdentity {
+    pub robot_type: &'static str,
+    pub command_port: u16,
+    pub observation_port: u16,
+}
+
+/// Everything the control layer needs to know about one kind of robot. The
+/// catalogue decides what a robot may do; the profile decides how it is done.
+/// Supporting a new robot means adding a profile and registering it in
+/// `RobotProfileService`.
+pub trait RobotProfile: Send + Sync {
+    /// The catalogue `robot_type` this profile drives.
+    fn robot_type(&self) -> &'static str;
+
+    /// `--robot.id` the remote client loads its calibration under.
+    fn robot_id(&self) -> &'static str;
+
+    /// `--teleop.id` the leader arms load their calibration under.
+    fn teleop_id(&self) -> &'static str;
+
+    /// Other spellings LeRobot and the catalogue use for this robot, matched
+    /// as prefixes (`sourccey_client`, `so101_follower`, ...).
+    fn type_prefixes(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Joints across every arm, as they appear in LeRobot action and state vectors.
+    fn arm_joints(&self) -> usize;
+
+    /// Base axes appended after the arm joints; zero when there is no base.
+    fn base_axes(&self) -> usize {
+        0
+    }
+
+    /// Robot arguments for teleop, record and rollout against a robot host.
+    fn robot_args(
+        &self,
+        capabilities: &RobotCapabilities,
+        command: RobotCommand,
+        remote_ip: &str,
+    ) -> Result<Vec<String>, String> {
+        Ok(vec![
+            format!("--robot.type={}", capabilities.remote_robot_type(command)?),
+            format!("--robot.id={}", self.robot_id()),
+            format!("--robot.remote_ip={}", remote_ip.trim()),
+        ])
+    }
+
+    /// Leader arm arguments for teleop and record.
+    fn teleop_args(
+        &self,
+        capabilities: &RobotCapabilities,
+        left_arm_port: &str,
+        right_arm_port: &str,
+    ) -> Result<Vec<String>, String> {
+        let mut args = vec![
+            format!("--teleop.type={}", capabilities.teleop_type()?),
+            format!("--teleop.id={}", self.teleop_id()),
+        ];
+        args.extend(capabilities.teleop_port_args(left_arm_port, right_arm_port));
+        Ok(args)
+    }
+
+    /// Robot arguments for `lerobot-inference`, which names the robot by nickname.
+    fn inference_args(
+        &self,
+        capabilities: &RobotCapabilities,
+        nickname: &str,
+        remote_ip: &str,
+    ) -> Result<Vec<String>, String> {
+        capabilities.remote_robot_type(RobotCommand::Inference)?;
+        Ok(vec![
+            format!("--id={}", nickname.trim()),
+            format!("--remote_ip={}", remote_ip.trim()),
+        ])
+    }
+
+    /// Robot arguments for the auto calibration script.
+    fn calibrate_args(
+        &self,
+        capabilities: &RobotCapabilities,
+        nickname: &str,
+        full_reset: bool,
+    ) -> Result<Vec<String>, String> {
+        capabilities.require(RobotCommand::Calibrate)?;
+        let mut args = vec![
+            format!("--robot.type={}", self.robot_type()),
+            format!("--robot.id={}", nickname),
+        ];
+        if full_reset {
+            args.push("--full_reset=True".to_string());
+        }
+        Ok(args)
+    }
+
+    /// Python module the kiosk runs to host this robot; `None` when it has no host.
+    fn host_module(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// What `pgrep -f` looks for to find a host started outside the app.
+    fn host_process_pattern(&self) -> Option<&'static str> {
+        self.host_module()
+            .and_then(|module| module.rsplit('.').next())
+    }
+
+    /// `None` for robots that are not reachable over the network.
+    fn discovery_identity(&self) -> Option<RobotDiscoveryIdentity> {
+        None
+    }
+
+    /// The lerobot follower type calibration files are stored under.
+    fn follower_type(&self) -> String {
+        format!("{}_follower", self.robot_type())
+    }
+
+    /// Calibration to start from when none has been written for `nickname`.
+    fn default_calibration(&self, nickname: &str) -> Calibration;
+
+    /// Keys the kiosk manual drive bridge accepts; empty when there is no base to drive.
+    fn manual_drive_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
@@ -0,0 +1,86 @@
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::services::robot_profiles::so100_profile::So100Profile;
+use crate::modules::control::services::robot_profiles::sourccey_profile::SourcceyProfile;
+use crate::modules::robot::services::robot_catalogue_service::{
+    RobotCapabilities, RobotCatalogueService, DEFAULT_ROBOT_TYPE,
+};
+use sea_orm::DatabaseConnection;
+
+static PROFILES: [&dyn RobotProfile; 2] = [&SourcceyProfile, &So100Profile];
+
+pub struct RobotProfileService;
+
+impl RobotProfileService {
+    pub fn profiles() -> &'static [&'static dyn RobotProfile] {
+        &PROFILES
+    }
+
+    pub fn for_robot_type(robot_type: &str) -> Option<&'static dyn RobotProfile> {
+        let robot_type = robot_type.trim();
+        Self::profiles()
+            .iter()
+            .copied()
+            .find(|profile| profile.robot_type().eq_ignore_ascii_case(robot_type))
+    }
+
+    pub fn resolve(robot_type: &str) -> Result<&'static dyn RobotProfile, String> {
+        Self::for_robot_type(robot_type).ok_or(format!(
+            "No robot profile is registered for {} robots",
+            robot_type
+        ))
+    }
+
+    /// Look a profile up by any name LeRobot or the catalogue gives the robot:
+    /// `sourccey_client` and `Sourccey_Follower` both find the Sourccey.
+    pub fn for_type_name(robot_type: &str) -> Option<&'static dyn RobotProfile> {
+        let robot_type = robot_type.trim().to_lowercase();
+        Self::for_robot_type(&robot_type).or_else(|| {
+            Self::profiles().iter().copied().find(|profile| {
+                profile
+                    .type_prefixes()
+                    .iter()
+                    .any(|prefix| robot_type.starts_with(prefix))
+            })
+        })
+    }
+
+    /// The registered `robot_type` for any spelling of it; unknown types are
+    /// returned trimmed and lowercased.
+    pub fn normalize_robot_type(robot_type: &str) -> String {
+        Self::for_type_name(robot_type)
+            .map(|profile| profile.robot_type().to_string())
+            .unwrap_or_else(|| robot_type.trim().to_lowercase())
+    }
+
+    /// Look a profile up by the follower type its calibration files are stored under.
+    pub fn for_follower_type(follower_type: &str) -> Option<&'static dyn RobotProfile> {
+        Self::profiles()
+            .iter()
+            .copied()
+            .find(|profile| profile.follower_type() == follower_type.trim())
+    }
+
+    /// The kiosk only ever hosts the default robot.
+    pub fn kiosk() -> &'static dyn RobotProfile {
+        Self::for_robot_type(DEFAULT_ROBOT_TYPE).unwrap_or(&SourcceyProfile)
+    }
+
+    /// The profile and catalogue capabilities for an owned robot. Unknown
+    /// nicknames fall back to the default robot, like the catalogue does.
+    pub async fn for_nickname(
+        db_connection: &DatabaseConnection,
+        nickname: &str,
+    ) -> Result<(&'static dyn RobotProfile, RobotCapabilities), String> {
+        let robot = RobotCatalogueService::robot_for_nickname(db_connection, nickname).await?;
+        let capabilities = RobotCatalogueService::resolve_capabilities(robot.as_ref())?;
+        let robot_type = robot
+            .as_ref()
+            .and_then(|robot| robot.robot_type.as_deref())
+            .unwrap_or(DEFAULT_ROBOT_TYPE);
+        Ok((Self::resolve(robot_type)?, capabilities))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/robot_profile_service_tests.rs"]
+mod robot_profile_service_tests;
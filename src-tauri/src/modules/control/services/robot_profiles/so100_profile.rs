@@ -0,0 +1,42 @@
+use crate::modules::control::services::configuration::calibration_service::CalibrationService;
+use crate::modules::control::services::robot_profiles::robot_profile::RobotProfile;
+use crate::modules::control::types::configuration::calibration_types::Calibration;
+
+/// The single-arm SO-100, plugged straight into the desktop. It has no host to
+/// discover and no base to drive.
+pub struct So100Profile;
+
+impl RobotProfile for So100Profile {
+    fn robot_type(&self) -> &'static str {
+        "so100_follower"
+    }
+
+    fn robot_id(&self) -> &'static str {
+        "so100"
+    }
+
+    fn teleop_id(&self) -> &'static str {
+        "so100_leader"
+    }
+
+    // The SO-101 shares the SO-100's joint layout.
+    fn type_prefixes(&self) -> &'static [&'static str] {
+        &["so100", "so101"]
+    }
+
+    fn arm_joints(&self) -> usize {
+        6
+    }
+
+    // The catalogue type already names the follower.
+    fn follower_type(&self) -> String {
+        self.robot_type().to_string()
+    }
+
+    fn default_calibration(&self, _nickname: &str) -> Calibration {
+        CalibrationService::load_default_calibration(&[
+            "so100_follower",
+            "default_calibration.json",
+        ])
+    }
+}
@@ -0,0 +1,75 @@
+use crate::modules::control::services::configuration::calibration_service::CalibrationService;
+use crate::modules::control::services::robot_profiles::robot_profile::{
+    RobotDiscoveryIdentity, RobotProfile,
+};
+use crate::modules::control::types::configuration::calibration_types::Calibration;
+use std::collections::HashMap;
+
+const SOURCCEY_COMMAND_PORT: u16 = 5555;
+const SOURCCEY_OBSERVATION_PORT: u16 = 5556;
+const SOURCCEY_HOST_MODULE: &str = "lerobot.robots.sourccey.sourccey.sourccey.sourccey_host";
+const SOURCCEY_MANUAL_DRIVE_KEYS: [&str; 10] = ["a", "d", "e", "f", "q", "r", "s", "w", "x", "z"];
+
+/// The two-arm Sourccey, driven through its kiosk host over the network.
+pub struct SourcceyProfile;
+
+impl RobotProfile for SourcceyProfile {
+    fn robot_type(&self) -> &'static str {
+        "sourccey"
+    }
+
+    fn robot_id(&self) -> &'static str {
+        "sourccey"
+    }
+
+    fn teleop_id(&self) -> &'static str {
+        "sourccey_leader"
+    }
+
+    fn type_prefixes(&self) -> &'static [&'static str] {
+        &["sourccey"]
+    }
+
+    // Two 6-joint arms on a base driven in x, y, theta and lift
+    fn arm_joints(&self) -> usize {
+        12
+    }
+
+    fn base_axes(&self) -> usize {
+        4
+    }
+
+    fn host_module(&self) -> Option<&'static str> {
+        Some(SOURCCEY_HOST_MODULE)
+    }
+
+    fn discovery_identity(&self) -> Option<RobotDiscoveryIdentity> {
+        Some(RobotDiscoveryIdentity {
+            robot_type: self.robot_type(),
+            command_port: SOURCCEY_COMMAND_PORT,
+            observation_port: SOURCCEY_OBSERVATION_PORT,
+        })
+    }
+
+    fn default_calibration(&self, nickname: &str) -> Calibration {
+        let arm_side = match nickname {
+            "sourccey_left" => "left",
+            "sourccey_right" => "right",
+            _ => {
+                return Calibration {
+                    motors: HashMap::new(),
+                }
+            }
+        };
+        CalibrationService::load_default_calibration(&[
+            "sourccey",
+            "sourccey",
+            "sourccey",
+            &format!("{}_arm_default_calibration.json", arm_side),
+        ])
+    }
+
+    fn manual_drive_keys(&self) -> &'static [&'static str] {
+        &SOURCCEY_MANUAL_DRIVE_KEYS
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+use crate::modules::robot::models::owned_robot::ActiveOwnedRobot;
+use crate::modules::robot::services::robot_catalogue_service::RobotCommand;
+use migration::MigratorTrait;
+use sea_orm::{ActiveModelTrait, Database};
+
+const SO100_ID: &str = "0195f368-73ca-76dd-aa6f-5cfa53fa8394";
+
+async fn connection() -> DatabaseConnection {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    connection
+}
+
+fn bundled_capabilities(profile: &dyn RobotProfile) -> RobotCapabilities {
+    RobotCatalogueService::bundled_capabilities(profile.robot_type())
+        .unwrap()
+        .expect("every profile should have a bundled catalogue entry")
+}
+
+#[test]
+fn profiles_are_registered_by_robot_type() {
+    assert_eq!(
+        RobotProfileService::resolve(" Sourccey ")
+            .unwrap()
+            .robot_type(),
+        "sourccey"
+    );
+    assert_eq!(
+        RobotProfileService::for_follower_type("sourccey_follower")
+            .unwrap()
+            .robot_type(),
+        "sourccey"
+    );
+    assert_eq!(
+        RobotProfileService::for_follower_type("so100_follower")
+            .unwrap()
+            .robot_type(),
+        "so100_follower"
+    );
+    assert_eq!(RobotProfileService::kiosk().robot_type(), "sourccey");
+
+    let error = RobotProfileService::resolve("koch").err().unwrap();
+    assert!(error.contains("koch"), "{}", error);
+}
+
+#[test]
+fn robot_types_are_normalized_through_the_registry() {
+    for (raw, normalized) in [
+        ("sourccey_client", "sourccey"),
+        ("Sourccey_Follower", "sourccey"),
+        ("so101_follower", "so100_follower"),
+        ("so100", "so100_follower"),
+        (" koch ", "koch"),
+    ] {
+        assert_eq!(RobotProfileService::normalize_robot_type(raw), normalized);
+    }
+}
+
+#[test]
+fn only_the_kiosk_robot_has_a_host() {
+    let kiosk = RobotProfileService::kiosk();
+    assert_eq!(
+        kiosk.host_module(),
+        Some("lerobot.robots.sourccey.sourccey.sourccey.sourccey_host")
+    );
+    assert_eq!(kiosk.host_process_pattern(), Some("sourccey_host"));
+    assert_eq!(
+        RobotProfileService::resolve("so100_follower")
+            .unwrap()
+            .host_process_pattern(),
+        None
+    );
+}
+
+#[test]
+fn every_profile_is_in_the_bundled_catalogue() {
+    for profile in RobotProfileService::profiles() {
+        bundled_capabilities(*profile);
+    }
+}
+
+#[test]
+fn profiles_build_their_own_arguments() {
+    let sourccey = RobotProfileService::resolve("sourccey").unwrap();
+    let capabilities = bundled_capabilities(sourccey);
+    assert_eq!(
+        sourccey
+            .robot_args(&capabilities, RobotCommand::Record, " 10.0.0.2 ")
+            .unwrap(),
+        vec![
+            "--robot.type=sourccey_client",
+            "--robot.id=sourccey",
+            "--robot.remote_ip=10.0.0.2",
+        ]
+    );
+    assert_eq!(
+        sourccey
+            .calibrate_args(&capabilities, "kitchen", true)
+            .unwrap(),
+        vec![
+            "--robot.type=sourccey",
+            "--robot.id=kitchen",
+            "--full_reset=True",
+        ]
+    );
+    assert_eq!(sourccey.discovery_identity().unwrap().command_port, 5555);
+    assert!(sourccey.manual_drive_keys().contains(&"w"));
+
+    let so100 = RobotProfileService::resolve("so100_follower").unwrap();
+    let capabilities = bundled_capabilities(so100);
+    assert_eq!(
+        so100.teleop_args(&capabilities, "COM3", "COM4").unwrap(),
+        vec![
+            "--teleop.type=so100_leader",
+            "--teleop.id=so100_leader",
+            "--teleop.port=COM3",
+        ]
+    );
+    assert!(so100
+        .inference_args(&capabilities, "desk", "10.0.0.2")
+        .is_err());
+    assert!(so100.discovery_identity().is_none());
+    assert!(so100.manual_drive_keys().is_empty());
+}
+
+#[tokio::test]
+async fn nicknames_resolve_to_their_robots_profile() {
+    let connection = connection().await;
+    RobotCatalogueService::new(connection.clone())
+        .seed_catalogue(&RobotCatalogueService::bundled_catalogue().unwrap())
+        .await
+        .unwrap();
+    ActiveOwnedRobot::new(SO100_ID.to_string())
+        .with_nickname("desk".to_string())
+        .insert(&connection)
+        .await
+        .unwrap();
+
+    let (profile, capabilities) = RobotProfileService::for_nickname(&connection, "desk")
+        .await
+        .unwrap();
+    assert_eq!(profile.robot_type(), "so100_follower");
+    assert_eq!(capabilities.arm_count, 1);
+
+    let (profile, _) = RobotProfileService::for_nickname(&connection, "unknown")
+        .await
+        .unwrap();
+    assert_eq!(profile.robot_type(), "sourccey");
+}
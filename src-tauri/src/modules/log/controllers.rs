@@ -1 +1,4 @@
 pub mod command_log_controller;
+pub mod log_index_controller;
+pub mod log_stream_controller;
+pub mod robot_usage_controller;
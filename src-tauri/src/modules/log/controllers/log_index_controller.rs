@@ -0,0 +1,16 @@
+use crate::modules::log::services::log_index_service::{LogIndexService, LogSearchFilters};
+use crate::services::log::log_service::LogEntry;
+use crate::utils::pagination::{PaginatedResponse, PaginationParameters};
+use tauri::AppHandle;
+
+//-------------------------------------------------------------------------//
+// Search Logs
+//-------------------------------------------------------------------------//
+#[tauri::command]
+pub async fn search_logs(
+    app_handle: AppHandle,
+    filters: LogSearchFilters,
+    pagination: PaginationParameters,
+) -> Result<PaginatedResponse<LogEntry>, String> {
+    LogIndexService::search_logs(&app_handle, filters, pagination).await
+}
@@ -0,0 +1,33 @@
+use crate::services::log::log_broker_service::{LogBrokerService, LogSubscriptionFilter};
+
+//-------------------------------------------------------------------------//
+// Subscribe Logs
+//-------------------------------------------------------------------------//
+/// Returns a subscription id; matching lines arrive as batched `log-batch`
+/// events tagged with it. The subscription lasts until it is released or
+/// the calling webview reloads or closes.
+#[tauri::command]
+pub fn subscribe_logs(
+    webview: tauri::Webview,
+    filter: Option<LogSubscriptionFilter>,
+) -> Result<String, String> {
+    LogBrokerService::subscribe(webview.label(), filter.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn unsubscribe_logs(subscription_id: String) -> Result<bool, String> {
+    LogBrokerService::unsubscribe(&subscription_id)
+}
+
+//-------------------------------------------------------------------------//
+// Pause / Resume
+//-------------------------------------------------------------------------//
+#[tauri::command]
+pub fn pause_log_subscription(subscription_id: String) -> Result<(), String> {
+    LogBrokerService::set_paused(&subscription_id, true)
+}
+
+#[tauri::command]
+pub fn resume_log_subscription(subscription_id: String) -> Result<(), String> {
+    LogBrokerService::set_paused(&subscription_id, false)
+}
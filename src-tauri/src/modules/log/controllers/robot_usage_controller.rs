@@ -0,0 +1,33 @@
+use crate::modules::log::services::robot_usage_service::{RobotUsageService, RobotUsageStats};
+use tauri::{AppHandle, Manager};
+
+//-------------------------------------------------------------------------//
+// Get Robot Usage Stats
+//-------------------------------------------------------------------------//
+#[tauri::command]
+pub async fn get_robot_usage_stats(
+    app_handle: AppHandle,
+    owned_robot_id: String,
+    days: Option<u32>,
+) -> Result<RobotUsageStats, String> {
+    let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
+    let robot_usage_service = RobotUsageService::new(db_manager.get_connection().clone());
+
+    robot_usage_service
+        .get_robot_usage(owned_robot_id, days)
+        .await
+}
+
+//-------------------------------------------------------------------------//
+// Get Fleet Usage Stats
+//-------------------------------------------------------------------------//
+#[tauri::command]
+pub async fn get_fleet_usage_stats(
+    app_handle: AppHandle,
+    days: Option<u32>,
+) -> Result<Vec<RobotUsageStats>, String> {
+    let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
+    let robot_usage_service = RobotUsageService::new(db_manager.get_connection().clone());
+
+    robot_usage_service.get_fleet_usage(days).await
+}
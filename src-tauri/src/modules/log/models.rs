@@ -1 +1,2 @@
 pub mod command_log;
+pub mod log_entry;
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use crate::services::log::log_service::{LogEntry, LogLevel};
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{NotSet, Set};
+use serde::{Deserialize, Serialize};
+
+/// Indexed copy of a line written through `LogService`; the `.log` files stay
+/// the source of truth and this table only backs search.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "log_entry")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    // Log Data
+    pub logged_at: DateTime<Utc>,
+    pub level: String,
+    pub source: String,
+    pub nickname: Option<String>,
+    pub command_log_id: Option<String>,
+    pub pid: Option<i64>,
+    pub message: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// Type aliases for better readability
+pub type IndexedLogEntry = Model;
+pub type ActiveIndexedLogEntry = ActiveModel;
+pub type IndexedLogEntryColumn = Column;
+
+impl ActiveModel {
+    pub fn from_entry(entry: &LogEntry) -> Self {
+        Self {
+            id: NotSet,
+            logged_at: Set(entry.timestamp),
+            level: Set(entry.level.as_str().to_string()),
+            source: Set(entry.source.clone()),
+            nickname: Set(entry.nickname.clone()),
+            command_log_id: Set(entry.command_log_id.clone()),
+            pid: Set(entry.pid.map(i64::from)),
+            message: Set(entry.message.clone()),
+        }
+    }
+}
+
+impl Model {
+    pub fn into_entry(self) -> LogEntry {
+        LogEntry {
+            timestamp: self.logged_at,
+            level: LogLevel::parse(&self.level).unwrap_or(LogLevel::Info),
+            source: self.source,
+            nickname: self.nickname,
+            command_log_id: self.command_log_id,
+            pid: self.pid.and_then(|pid| u32::try_from(pid).ok()),
+            message: self.message,
+        }
+    }
+}
@@ -1 +1,4 @@
 pub mod command_log_service;
+pub mod diagnostic_bundle_service;
+pub mod log_index_service;
+pub mod robot_usage_service;
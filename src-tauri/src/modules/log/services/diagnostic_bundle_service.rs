@@ -0,0 +1,426 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::log::services::command_log_service::{CommandLogFilters, CommandLogService};
+use crate::modules::settings::services::desktop_environment::desktop_environment_service::DesktopEnvironmentService;
+use crate::modules::settings::services::kiosk_environment::kiosk_environment_service::KioskEnvironmentService;
+use crate::services::directory::directory_service::DirectoryService;
+use crate::services::setup::local_setup_service::LocalSetupService;
+use crate::utils::pagination::PaginationParameters;
+use chrono::{DateTime, Local};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+const REDACTED: &str = "[REDACTED]";
+const MAX_BUNDLED_FILE_BYTES: u64 = 20 * 1024 * 1024;
+const MAX_CALIBRATION_DEPTH: usize = 4;
+// Top-level lerobot cache entries that are collected explicitly or never bundled
+const LEROBOT_RESERVED_DIRS: [&str; 4] = ["calibration", "settings", "pairing", "ai_models"];
+const ROBOT_CONFIG_FILES: [&str; 2] = ["config.json", "remote_config.json"];
+const SECRET_KEY_WORDS: [&str; 7] = [
+    "password",
+    "passwd",
+    "passphrase",
+    "token",
+    "secret",
+    "psk",
+    "apikey",
+];
+
+lazy_static! {
+    // A quoted value runs to its closing unescaped quote so passphrases with
+    // spaces are masked whole; `\"` values inside an encoded JSON string end
+    // at the next `\"`.
+    static ref KEY_VALUE_PATTERN: Regex = Regex::new(
+        r#"(\\?"?)([A-Za-z0-9_.-]+)(\\?"?\s*[:=]\s*)(\\"(?:[^"\\]|\\[^"])*|"(?:[^"\\]|\\.)*|[^"\\\s,;}&]+)"#
+    )
+    .expect("key/value pattern should compile");
+    static ref SECRET_FLAG_PATTERN: Regex = Regex::new(
+        r#"(?i)((?:--password|--psk|wifi-sec\.psk|802-1x\.password|wep-key[0-3])\\?"?(?:\s*,\s*|\s+)\\?"?)([^"\\\s,\]]+)"#
+    )
+    .expect("secret flag pattern should compile");
+    static ref BEARER_PATTERN: Regex = Regex::new(r"(?i)\b(bearer\s+)[A-Za-z0-9._~+/=-]+")
+        .expect("bearer pattern should compile");
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticBundleSummary {
+    pub path: String,
+    pub file_count: usize,
+    pub bytes: u64,
+    /// Sections that could not be collected; the bundle is still written.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BundleFile {
+    pub name: String,
+    pub contents: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct BundleContents {
+    pub files: Vec<BundleFile>,
+    pub warnings: Vec<String>,
+}
+
+impl BundleContents {
+    fn add_json<T: Serialize>(&mut self, name: &str, value: Result<T, String>) {
+        let value = value.and_then(|value| {
+            serde_json::to_value(value).map_err(|e| format!("Failed to serialize: {}", e))
+        });
+        match value {
+            Ok(mut value) => {
+                DiagnosticBundleService::redact_json(&mut value);
+                let contents = serde_json::to_vec_pretty(&value).unwrap_or_default();
+                self.files.push(BundleFile {
+                    name: name.to_string(),
+                    contents,
+                });
+            }
+            Err(e) => self.warnings.push(format!("{}: {}", name, e)),
+        }
+    }
+}
+
+pub struct DiagnosticBundleService;
+
+impl DiagnosticBundleService {
+    //-------------------------------------------------------------------------//
+    // Export
+    //-------------------------------------------------------------------------//
+    /// Collect logs, settings, setup state, recent commands and robot
+    /// configuration into a single zip that can be attached to a support ticket.
+    pub async fn export(
+        app_handle: &AppHandle,
+        log_dir: PathBuf,
+        system_info: Value,
+        output_dir: PathBuf,
+        command_log_limit: usize,
+    ) -> Result<DiagnosticBundleSummary, String> {
+        let mut contents = BundleContents::default();
+        contents.add_json("system/system_info.json", Ok(system_info));
+        contents.add_json(
+            "settings/desktop_environment.json",
+            DesktopEnvironmentService::get_settings(),
+        );
+        contents.add_json(
+            "settings/kiosk_environment.json",
+            KioskEnvironmentService::get_settings(),
+        );
+        contents.add_json(
+            "database/command_logs.json",
+            Self::recent_command_logs(app_handle, command_log_limit).await,
+        );
+
+        let app = app_handle.clone();
+        let app_version = app_handle.package_info().version.to_string();
+        tauri::async_runtime::spawn_blocking(move || {
+            contents.add_json("setup/status.json", LocalSetupService::check_status(&app));
+            contents.add_json(
+                "setup/lerobot_update.json",
+                LocalSetupService::check_lerobot_update(&app),
+            );
+
+            Self::collect_logs(&mut contents, "logs/app", &log_dir);
+            match DirectoryService::get_current_dir() {
+                Ok(dir) if dir.join("logs") != log_dir => {
+                    Self::collect_logs(&mut contents, "logs/process", &dir.join("logs"))
+                }
+                Ok(_) => {}
+                Err(e) => contents.warnings.push(format!("logs/process: {}", e)),
+            }
+            match DirectoryService::get_lerobot_cache_dir() {
+                Ok(dir) => Self::collect_lerobot_files(&mut contents, &dir),
+                Err(e) => contents.warnings.push(format!("lerobot: {}", e)),
+            }
+
+            Self::write_bundle(&output_dir, contents, &app_version, Local::now())
+        })
+        .await
+        .map_err(|e| format!("Diagnostic bundle task failed: {}", e))?
+    }
+
+    async fn recent_command_logs(app_handle: &AppHandle, limit: usize) -> Result<Value, String> {
+        let db_manager = app_handle
+            .try_state::<DatabaseManager>()
+            .ok_or("Database is not initialized".to_string())?;
+        let logs = CommandLogService::new(db_manager.get_connection().clone())
+            .get_command_logs_paginated(
+                CommandLogFilters {
+                    robot_id: None,
+                    owned_robot_id: None,
+                    status: None,
+                    command: None,
+                    started_after: None,
+                    started_before: None,
+                    completed_after: None,
+                    completed_before: None,
+                },
+                PaginationParameters {
+                    page: Some(1),
+                    page_size: Some(limit.max(1)),
+                },
+            )
+            .await
+            .map_err(|e| format!("Failed to load command logs: {}", e))?;
+        serde_json::to_value(logs.data).map_err(|e| format!("Failed to serialize: {}", e))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Collection
+    //-------------------------------------------------------------------------//
+    /// Add every current and rotated `.log` file in `dir` with secrets masked.
+    pub(crate) fn collect_logs(contents: &mut BundleContents, prefix: &str, dir: &Path) {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                contents.warnings.push(format!(
+                    "{}: Failed to read {}: {}",
+                    prefix,
+                    dir.display(),
+                    e
+                ));
+                return;
+            }
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.contains(".log"))
+            })
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = format!("{}/{}", prefix, path.file_name().unwrap().to_string_lossy());
+            match Self::read_limited(&path) {
+                Ok(bytes) => contents.files.push(BundleFile {
+                    name,
+                    contents: Self::redact_text(&String::from_utf8_lossy(&bytes)).into_bytes(),
+                }),
+                Err(e) => contents.warnings.push(format!("{}: {}", name, e)),
+            }
+        }
+    }
+
+    /// Add calibration files, per-robot configs and saved settings from the
+    /// lerobot cache. Downloaded models and recorded datasets are left out.
+    pub(crate) fn collect_lerobot_files(contents: &mut BundleContents, cache_dir: &Path) {
+        if !cache_dir.exists() {
+            return;
+        }
+
+        let mut json_files = Vec::new();
+        Self::find_json_files(
+            &cache_dir.join("calibration"),
+            MAX_CALIBRATION_DEPTH,
+            &mut json_files,
+        );
+        Self::find_json_files(&cache_dir.join("settings"), 1, &mut json_files);
+        Self::find_json_files(&cache_dir.join("pairing"), 2, &mut json_files);
+
+        if let Ok(entries) = fs::read_dir(cache_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !path.is_dir() || LEROBOT_RESERVED_DIRS.contains(&name.as_str()) {
+                    continue;
+                }
+                for config in ROBOT_CONFIG_FILES {
+                    let config_path = path.join(config);
+                    if config_path.is_file() {
+                        json_files.push(config_path);
+                    }
+                }
+            }
+        }
+        json_files.sort();
+
+        for path in json_files {
+            let relative = path.strip_prefix(cache_dir).unwrap_or(&path);
+            let name = format!("lerobot/{}", relative.to_string_lossy().replace('\\', "/"));
+            let value = Self::read_limited(&path).and_then(|bytes| {
+                serde_json::from_slice::<Value>(&bytes)
+                    .map_err(|e| format!("Failed to parse JSON: {}", e))
+            });
+            contents.add_json(&name, value);
+        }
+    }
+
+    fn find_json_files(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if depth > 1 {
+                    Self::find_json_files(&path, depth - 1, found);
+                }
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                found.push(path);
+            }
+        }
+    }
+
+    fn read_limited(path: &Path) -> Result<Vec<u8>, String> {
+        let size = fs::metadata(path)
+            .map_err(|e| format!("Failed to read metadata: {}", e))?
+            .len();
+        if size > MAX_BUNDLED_FILE_BYTES {
+            return Err(format!("Skipped, file is {} bytes", size));
+        }
+        fs::read(path).map_err(|e| format!("Failed to read file: {}", e))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Zip
+    //-------------------------------------------------------------------------//
+    pub(crate) fn write_bundle(
+        output_dir: &Path,
+        contents: BundleContents,
+        app_version: &str,
+        created_at: DateTime<Local>,
+    ) -> Result<DiagnosticBundleSummary, String> {
+        fs::create_dir_all(output_dir)
+            .map_err(|e| format!("Failed to create {}: {}", output_dir.display(), e))?;
+        let path = output_dir.join(format!(
+            "sourccey-diagnostics-{}.zip",
+            created_at.format("%Y%m%d-%H%M%S")
+        ));
+
+        let manifest = json!({
+            "createdAt": created_at.to_rfc3339(),
+            "appVersion": app_version,
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "files": contents.files.iter().map(|file| &file.name).collect::<Vec<_>>(),
+            "warnings": &contents.warnings,
+        });
+        let manifest = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+
+        let file = File::create(&path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options =
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let manifest_file = BundleFile {
+            name: "manifest.json".to_string(),
+            contents: manifest,
+        };
+        for file in std::iter::once(&manifest_file).chain(contents.files.iter()) {
+            zip.start_file(file.name.as_str(), options)
+                .map_err(|e| format!("Failed to add {} to bundle: {}", file.name, e))?;
+            zip.write_all(&file.contents)
+                .map_err(|e| format!("Failed to write {} to bundle: {}", file.name, e))?;
+        }
+        zip.finish()
+            .map_err(|e| format!("Failed to finish diagnostic bundle: {}", e))?;
+
+        let bytes = fs::metadata(&path)
+            .map_err(|e| format!("Failed to read diagnostic bundle: {}", e))?
+            .len();
+        Ok(DiagnosticBundleSummary {
+            path: path.to_string_lossy().to_string(),
+            file_count: contents.files.len(),
+            bytes,
+            warnings: contents.warnings,
+        })
+    }
+
+    //-------------------------------------------------------------------------//
+    // Redaction
+    //-------------------------------------------------------------------------//
+    /// Whether a key such as `device_auth_token`, `wifi-sec.psk` or `apiKey`
+    /// names a secret. Matches whole words so `tokenizer_name` is kept.
+    pub(crate) fn is_secret_key(key: &str) -> bool {
+        let mut words = Vec::new();
+        let mut word = String::new();
+        let mut previous_lower = false;
+        for c in key.chars() {
+            if !c.is_ascii_alphanumeric() {
+                words.push(std::mem::take(&mut word));
+                previous_lower = false;
+                continue;
+            }
+            if c.is_ascii_uppercase() && previous_lower {
+                words.push(std::mem::take(&mut word));
+            }
+            previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            word.push(c.to_ascii_lowercase());
+        }
+        words.push(word);
+
+        words
+            .iter()
+            .any(|word| SECRET_KEY_WORDS.contains(&word.as_str()))
+            || words
+                .windows(2)
+                .any(|pair| pair[0] == "api" && pair[1] == "key")
+    }
+
+    pub(crate) fn redact_json(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if Self::is_secret_key(key) && !value.is_null() && !value.is_object() {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        Self::redact_json(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(Self::redact_json),
+            _ => {}
+        }
+    }
+
+    /// Mask secrets in free-form log text: `key=value` and JSON pairs with a
+    /// secret key, nmcli/CLI password arguments and bearer tokens.
+    pub(crate) fn redact_text(text: &str) -> String {
+        let text = Self::redact_key_values(text);
+        let text = SECRET_FLAG_PATTERN.replace_all(&text, format!("${{1}}{}", REDACTED));
+        BEARER_PATTERN
+            .replace_all(&text, format!("${{1}}{}", REDACTED))
+            .into_owned()
+    }
+
+    fn redact_key_values(text: &str) -> String {
+        KEY_VALUE_PATTERN
+            .replace_all(text, |caps: &Captures| {
+                let value = &caps[4];
+                let quote = if value.starts_with("\\\"") {
+                    "\\\""
+                } else if value.starts_with('"') {
+                    "\""
+                } else {
+                    ""
+                };
+                let masked = if Self::is_secret_key(&caps[2]) {
+                    REDACTED.to_string()
+                } else {
+                    // A quoted value may itself be encoded JSON holding secrets
+                    Self::redact_key_values(&value[quote.len()..])
+                };
+                format!("{}{}{}{}{}", &caps[1], &caps[2], &caps[3], quote, masked)
+            })
+            .into_owned()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/diagnostic_bundle_service_tests.rs"]
+mod diagnostic_bundle_service_tests;
@@ -0,0 +1,335 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::log::models::log_entry::{
+    ActiveIndexedLogEntry, Entity as IndexedLogEntryEntity, IndexedLogEntryColumn,
+};
+use crate::services::directory::directory_service::DirectoryService;
+use crate::services::log::log_service::{LogEntry, LogFilter, LogLevel, LogService};
+use crate::utils::pagination::{PaginatedResponse, PaginationParameters};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const INDEX_QUEUE_CAPACITY: usize = 10_000;
+const INDEX_BATCH_SIZE: usize = 200;
+const INDEX_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const INDEX_RETENTION_DAYS: i64 = 14;
+// Rotated files hold about this many lines at the default 5 MB size
+const BACKFILL_MAX_LINES_PER_FILE: usize = 50_000;
+const BACKFILL_MAX_LINES_TOTAL: usize = 500_000;
+const DEFAULT_PAGE_SIZE: usize = 100;
+const MAX_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogSearchFilters {
+    /// Words that must all appear in the message; the last may be partial.
+    pub text: Option<String>,
+    /// e.g. `teleop`, `record`, `rollout`, `inference`, `calibration`, `frontend`.
+    pub sources: Option<Vec<String>>,
+    pub nickname: Option<String>,
+    /// Minimum level to include.
+    pub level: Option<LogLevel>,
+    pub command_log_id: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    /// Return the earliest matches first, e.g. to find when something started.
+    #[serde(default)]
+    pub oldest_first: bool,
+}
+
+pub struct LogIndexService {
+    connection: DatabaseConnection,
+}
+
+impl LogIndexService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Start feeding every entry written through `LogService` into the search
+    /// index. On first run the existing log files are indexed as well.
+    pub fn start(app_handle: AppHandle) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            eprintln!("[LogIndex] Database is not initialized; log search is unavailable");
+            return;
+        };
+        let service = Self::new(db_manager.get_connection().clone());
+        let (sender, receiver) = mpsc::sync_channel(INDEX_QUEUE_CAPACITY);
+        if !LogService::set_index_sink(sender) {
+            return;
+        }
+        let started_at = Utc::now();
+        let log_dirs = Self::log_dirs(&app_handle);
+
+        thread::spawn(move || {
+            match tauri::async_runtime::block_on(service.backfill(&log_dirs, started_at)) {
+                Ok(0) => {}
+                Ok(count) => println!("[LogIndex] Indexed {} existing log lines", count),
+                Err(e) => eprintln!("[LogIndex] Failed to index existing logs: {}", e),
+            }
+
+            let mut last_prune: Option<Instant> = None;
+            while let Some(batch) = Self::next_batch(&receiver) {
+                if let Err(e) = tauri::async_runtime::block_on(service.add_entries(&batch)) {
+                    eprintln!(
+                        "[LogIndex] Failed to index {} log lines: {}",
+                        batch.len(),
+                        e
+                    );
+                }
+
+                if last_prune
+                    .map(|last| last.elapsed() >= PRUNE_INTERVAL)
+                    .unwrap_or(true)
+                {
+                    let cutoff = Utc::now() - chrono::Duration::days(INDEX_RETENTION_DAYS);
+                    if let Err(e) =
+                        tauri::async_runtime::block_on(service.delete_entries_before(cutoff))
+                    {
+                        eprintln!("[LogIndex] Failed to prune log index: {}", e);
+                    }
+                    last_prune = Some(Instant::now());
+                }
+            }
+        });
+    }
+
+    pub async fn search_logs(
+        app_handle: &AppHandle,
+        filters: LogSearchFilters,
+        pagination: PaginationParameters,
+    ) -> Result<PaginatedResponse<LogEntry>, String> {
+        let db_manager = app_handle
+            .try_state::<DatabaseManager>()
+            .ok_or("Database is not initialized".to_string())?;
+        Self::new(db_manager.get_connection().clone())
+            .search(filters, pagination)
+            .await
+            .map_err(|e| format!("Failed to search logs: {}", e))
+    }
+
+    pub async fn clear_index(app_handle: &AppHandle) -> Result<u64, String> {
+        let db_manager = app_handle
+            .try_state::<DatabaseManager>()
+            .ok_or("Database is not initialized".to_string())?;
+        Self::new(db_manager.get_connection().clone())
+            .delete_all_entries()
+            .await
+            .map_err(|e| format!("Failed to clear log index: {}", e))
+    }
+
+    //-------------------------------------------------------------------------//
+    // Add Log Entries
+    //-------------------------------------------------------------------------//
+    pub async fn add_entries(&self, entries: &[LogEntry]) -> Result<(), DbErr> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        IndexedLogEntryEntity::insert_many(entries.iter().map(ActiveIndexedLogEntry::from_entry))
+            .exec_without_returning(&self.connection)
+            .await?;
+        Ok(())
+    }
+
+    //-------------------------------------------------------------------------//
+    // Search Log Entries
+    //-------------------------------------------------------------------------//
+    pub async fn search(
+        &self,
+        filters: LogSearchFilters,
+        pagination: PaginationParameters,
+    ) -> Result<PaginatedResponse<LogEntry>, DbErr> {
+        let page = pagination.page.unwrap_or(1).max(1);
+        let page_size = pagination
+            .page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let offset = (page - 1) * page_size;
+
+        let mut query = IndexedLogEntryEntity::find();
+
+        // Apply filters
+        if let Some(text) = filters.text.as_deref().and_then(Self::match_expression) {
+            query = query.filter(Expr::cust_with_values(
+                "\"log_entry\".\"id\" IN (SELECT rowid FROM log_entry_fts WHERE log_entry_fts MATCH ?)",
+                [text],
+            ));
+        }
+        if let Some(sources) = filters.sources.filter(|sources| !sources.is_empty()) {
+            query = query.filter(IndexedLogEntryColumn::Source.is_in(sources));
+        }
+        if let Some(nickname) = filters.nickname {
+            query = query.filter(IndexedLogEntryColumn::Nickname.eq(nickname));
+        }
+        if let Some(level) = filters.level {
+            let levels = [
+                LogLevel::Debug,
+                LogLevel::Info,
+                LogLevel::Warn,
+                LogLevel::Error,
+            ]
+            .into_iter()
+            .filter(|candidate| *candidate >= level)
+            .map(|candidate| candidate.as_str());
+            query = query.filter(IndexedLogEntryColumn::Level.is_in(levels));
+        }
+        if let Some(command_log_id) = filters.command_log_id {
+            query = query.filter(IndexedLogEntryColumn::CommandLogId.eq(command_log_id));
+        }
+        if let Some(since) = filters.since {
+            query = query.filter(IndexedLogEntryColumn::LoggedAt.gte(since));
+        }
+        if let Some(until) = filters.until {
+            query = query.filter(IndexedLogEntryColumn::LoggedAt.lte(until));
+        }
+
+        // Id breaks ties between lines logged in the same millisecond
+        query = if filters.oldest_first {
+            query
+                .order_by_asc(IndexedLogEntryColumn::LoggedAt)
+                .order_by_asc(IndexedLogEntryColumn::Id)
+        } else {
+            query
+                .order_by_desc(IndexedLogEntryColumn::LoggedAt)
+                .order_by_desc(IndexedLogEntryColumn::Id)
+        };
+
+        let total = query.clone().count(&self.connection).await? as usize;
+        let entries = query
+            .offset(offset as u64)
+            .limit(page_size as u64)
+            .all(&self.connection)
+            .await?;
+
+        let total_pages = total.div_ceil(page_size);
+        Ok(PaginatedResponse {
+            data: entries
+                .into_iter()
+                .map(|entry| entry.into_entry())
+                .collect(),
+            total,
+            page,
+            page_size,
+            total_pages,
+            has_next: page < total_pages,
+            has_previous: page > 1,
+        })
+    }
+
+    //-------------------------------------------------------------------------//
+    // Prune Log Entries
+    //-------------------------------------------------------------------------//
+    pub async fn delete_entries_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = IndexedLogEntryEntity::delete_many()
+            .filter(IndexedLogEntryColumn::LoggedAt.lt(cutoff))
+            .exec(&self.connection)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn delete_all_entries(&self) -> Result<u64, DbErr> {
+        let result = IndexedLogEntryEntity::delete_many()
+            .exec(&self.connection)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Index lines already on disk when the index is empty, e.g. after the
+    /// upgrade that introduced it. Only lines written before `before` are
+    /// taken; newer ones arrive through the sink.
+    async fn backfill(&self, log_dirs: &[PathBuf], before: DateTime<Utc>) -> Result<usize, DbErr> {
+        if IndexedLogEntryEntity::find()
+            .one(&self.connection)
+            .await?
+            .is_some()
+        {
+            return Ok(0);
+        }
+
+        let cutoff = before - chrono::Duration::days(INDEX_RETENTION_DAYS);
+        let mut count = 0;
+        for log_dir in log_dirs {
+            let entries = match LogService::read_log_tail_all(
+                log_dir,
+                BACKFILL_MAX_LINES_TOTAL,
+                BACKFILL_MAX_LINES_PER_FILE,
+                &LogFilter::default(),
+            ) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("[LogIndex] Failed to read {}: {}", log_dir.display(), e);
+                    continue;
+                }
+            };
+            let entries: Vec<LogEntry> = entries
+                .into_iter()
+                .filter(|entry| entry.timestamp >= cutoff && entry.timestamp < before)
+                .collect();
+            for chunk in entries.chunks(INDEX_BATCH_SIZE) {
+                self.add_entries(chunk).await?;
+            }
+            count += entries.len();
+        }
+        Ok(count)
+    }
+
+    /// Wait for the next entry, then gather whatever else arrives within
+    /// `INDEX_FLUSH_INTERVAL` so inserts happen in batches. `None` once every
+    /// sender is gone.
+    fn next_batch(receiver: &mpsc::Receiver<LogEntry>) -> Option<Vec<LogEntry>> {
+        let mut batch = vec![receiver.recv().ok()?];
+        let deadline = Instant::now() + INDEX_FLUSH_INTERVAL;
+        while batch.len() < INDEX_BATCH_SIZE {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok(entry) => batch.push(entry),
+                Err(_) => break,
+            }
+        }
+        Some(batch)
+    }
+
+    /// Turn free text into an FTS5 query: each word is quoted so punctuation
+    /// and FTS operators are taken literally, and the last one matches as a
+    /// prefix. Returns `None` for blank input.
+    pub(crate) fn match_expression(text: &str) -> Option<String> {
+        let words: Vec<String> = text
+            .split_whitespace()
+            .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+            .collect();
+        let (last, rest) = words.split_last()?;
+        let mut expression = rest.join(" ");
+        if !expression.is_empty() {
+            expression.push(' ');
+        }
+        expression.push_str(last);
+        expression.push('*');
+        Some(expression)
+    }
+
+    /// Desktop and kiosk builds write process logs under the project root and
+    /// app logs under the app data directory; these are often the same.
+    fn log_dirs(app_handle: &AppHandle) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+            dirs.push(app_data_dir.join("logs"));
+        }
+        if let Ok(current_dir) = DirectoryService::get_current_dir() {
+            let process_dir = current_dir.join("logs");
+            if !dirs.contains(&process_dir) {
+                dirs.push(process_dir);
+            }
+        }
+        dirs
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/log_index_service_tests.rs"]
+mod log_index_service_tests;
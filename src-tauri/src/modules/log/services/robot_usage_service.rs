@@ -0,0 +1,330 @@
+use crate::modules::log::models::command_log::{
+    CommandLog, CommandLogColumn, Entity as CommandLogEntity,
+};
+use crate::modules::robot::models::owned_robot::{
+    Entity as OwnedRobotEntity, OwnedRobot, OwnedRobotColumn,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sea_orm::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Days covered by the activity histogram when no window is given.
+pub const DEFAULT_HISTOGRAM_DAYS: u32 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RobotActivity {
+    Teleop,
+    Record,
+    Rollout,
+    Inference,
+    Calibrate,
+    Host,
+    Other,
+}
+
+impl RobotActivity {
+    /// Command logs store the command line that was run, not what it was for.
+    pub fn from_command(command: &str) -> Self {
+        if command.contains("lerobot-teleoperate") {
+            Self::Teleop
+        } else if command.contains("lerobot-record") {
+            Self::Record
+        } else if command.contains("lerobot-rollout") {
+            Self::Rollout
+        } else if command.contains("lerobot-inference") {
+            Self::Inference
+        } else if command.contains("auto_calibrate") {
+            Self::Calibrate
+        } else if command.contains("kiosk host") || command.contains("sourccey_host") {
+            Self::Host
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ActivityStats {
+    pub activity: RobotActivity,
+    pub runs: u64,
+    pub succeeded: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+    pub running: u64,
+    pub active_seconds: f64,
+    // Failed share of finished runs; None until one has finished
+    pub failure_rate: Option<f64>,
+}
+
+impl ActivityStats {
+    fn new(activity: RobotActivity) -> Self {
+        Self {
+            activity,
+            runs: 0,
+            succeeded: 0,
+            failed: 0,
+            cancelled: 0,
+            running: 0,
+            active_seconds: 0.0,
+            failure_rate: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DailyActivity {
+    pub date: NaiveDate,
+    pub runs: u64,
+    pub failed: u64,
+    pub active_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RobotUsageStats {
+    pub owned_robot_id: String,
+    pub nickname: Option<String>,
+    // Start of the window the totals cover; None for all time
+    pub since: Option<DateTime<Utc>>,
+    pub total_runs: u64,
+    pub failed_runs: u64,
+    pub failure_rate: Option<f64>,
+    pub active_hours: f64,
+    pub teleop_hours: f64,
+    pub recording_sessions: u64,
+    // Episodes requested by recording sessions that finished successfully
+    pub recorded_episodes: u64,
+    pub rollout_runs: u64,
+    pub last_active_at: Option<DateTime<Utc>>,
+    // Not limited to the window
+    pub last_calibrated_at: Option<DateTime<Utc>>,
+    pub by_activity: Vec<ActivityStats>,
+    // One entry per day, oldest first, including idle days
+    pub daily: Vec<DailyActivity>,
+}
+
+pub struct RobotUsageService {
+    connection: DatabaseConnection,
+}
+
+impl RobotUsageService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    //-------------------------------------------------------------------------//
+    // Get Usage Stats
+    //-------------------------------------------------------------------------//
+    /// Usage of one owned robot. With `days` the totals cover that many days;
+    /// the histogram always covers `days` or `DEFAULT_HISTOGRAM_DAYS`.
+    pub async fn get_robot_usage(
+        &self,
+        owned_robot_id: String,
+        days: Option<u32>,
+    ) -> Result<RobotUsageStats, String> {
+        Self::validate_days(days)?;
+        let owned_robot = OwnedRobotEntity::find_by_id(owned_robot_id.clone())
+            .one(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Owned robot not found for id {}", owned_robot_id))?;
+        let command_logs = CommandLogEntity::find()
+            .filter(CommandLogColumn::OwnedRobotId.eq(owned_robot_id))
+            .filter(CommandLogColumn::DeletedAt.is_null())
+            .all(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self::summarize(
+            &owned_robot,
+            &command_logs,
+            days,
+            Utc::now(),
+        ))
+    }
+
+    /// Usage of every owned robot, busiest first.
+    pub async fn get_fleet_usage(&self, days: Option<u32>) -> Result<Vec<RobotUsageStats>, String> {
+        Self::validate_days(days)?;
+        let owned_robots = OwnedRobotEntity::find()
+            .filter(OwnedRobotColumn::DeletedAt.is_null())
+            .all(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut command_logs: HashMap<String, Vec<CommandLog>> = HashMap::new();
+        for command_log in CommandLogEntity::find()
+            .filter(CommandLogColumn::OwnedRobotId.is_not_null())
+            .filter(CommandLogColumn::DeletedAt.is_null())
+            .all(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            if let Some(owned_robot_id) = command_log.owned_robot_id.clone() {
+                command_logs
+                    .entry(owned_robot_id)
+                    .or_default()
+                    .push(command_log);
+            }
+        }
+
+        let now = Utc::now();
+        let mut fleet: Vec<RobotUsageStats> = owned_robots
+            .iter()
+            .map(|owned_robot| {
+                let logs = command_logs
+                    .get(&owned_robot.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                Self::summarize(owned_robot, logs, days, now)
+            })
+            .collect();
+        fleet.sort_by(|a, b| b.active_hours.total_cmp(&a.active_hours));
+        Ok(fleet)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Aggregation Functions
+    //-------------------------------------------------------------------------//
+    pub(crate) fn summarize(
+        owned_robot: &OwnedRobot,
+        command_logs: &[CommandLog],
+        days: Option<u32>,
+        now: DateTime<Utc>,
+    ) -> RobotUsageStats {
+        let since = days.map(|days| Self::window_start(days, now));
+        let histogram_start = Self::window_start(days.unwrap_or(DEFAULT_HISTOGRAM_DAYS), now);
+
+        let mut by_activity: BTreeMap<RobotActivity, ActivityStats> = BTreeMap::new();
+        let mut daily: BTreeMap<NaiveDate, DailyActivity> = BTreeMap::new();
+        let mut date = histogram_start.date_naive();
+        while date <= now.date_naive() {
+            daily.insert(
+                date,
+                DailyActivity {
+                    date,
+                    runs: 0,
+                    failed: 0,
+                    active_seconds: 0.0,
+                },
+            );
+            date += Duration::days(1);
+        }
+
+        let mut recorded_episodes = 0;
+        let mut last_active_at: Option<DateTime<Utc>> = None;
+        let mut last_calibrated_at: Option<DateTime<Utc>> = None;
+        for command_log in command_logs {
+            let activity = RobotActivity::from_command(&command_log.command);
+            let seconds = Self::active_seconds(command_log, now);
+
+            if activity == RobotActivity::Calibrate && command_log.is_success() {
+                let calibrated_at = command_log.completed_at.unwrap_or(command_log.started_at);
+                last_calibrated_at = last_calibrated_at.max(Some(calibrated_at));
+            }
+            if let Some(day) = daily.get_mut(&command_log.started_at.date_naive()) {
+                day.runs += 1;
+                day.failed += command_log.is_failed() as u64;
+                day.active_seconds += seconds;
+            }
+            if since.is_some_and(|since| command_log.started_at < since) {
+                continue;
+            }
+
+            let stats = by_activity
+                .entry(activity)
+                .or_insert_with(|| ActivityStats::new(activity));
+            stats.runs += 1;
+            stats.active_seconds += seconds;
+            match command_log.status.as_str() {
+                "success" => stats.succeeded += 1,
+                "failed" => stats.failed += 1,
+                "cancelled" => stats.cancelled += 1,
+                _ => stats.running += 1,
+            }
+            if activity == RobotActivity::Record && command_log.is_success() {
+                recorded_episodes += Self::requested_episodes(&command_log.command);
+            }
+            last_active_at = last_active_at.max(Some(command_log.started_at));
+        }
+
+        let mut by_activity: Vec<ActivityStats> = by_activity.into_values().collect();
+        for stats in &mut by_activity {
+            stats.failure_rate = Self::failure_rate(
+                stats.failed,
+                stats.succeeded + stats.failed + stats.cancelled,
+            );
+        }
+        let activity =
+            |activity: RobotActivity| by_activity.iter().find(|s| s.activity == activity);
+        let total_runs = by_activity.iter().map(|stats| stats.runs).sum();
+        let failed_runs = by_activity.iter().map(|stats| stats.failed).sum();
+        let finished_runs = by_activity
+            .iter()
+            .map(|stats| stats.succeeded + stats.failed + stats.cancelled)
+            .sum();
+
+        RobotUsageStats {
+            owned_robot_id: owned_robot.id.clone(),
+            nickname: owned_robot.nickname.clone(),
+            since,
+            total_runs,
+            failed_runs,
+            failure_rate: Self::failure_rate(failed_runs, finished_runs),
+            active_hours: by_activity.iter().map(|s| s.active_seconds).sum::<f64>() / 3600.0,
+            teleop_hours: activity(RobotActivity::Teleop).map_or(0.0, |s| s.active_seconds)
+                / 3600.0,
+            recording_sessions: activity(RobotActivity::Record).map_or(0, |s| s.runs),
+            recorded_episodes,
+            rollout_runs: activity(RobotActivity::Rollout).map_or(0, |s| s.runs),
+            last_active_at,
+            last_calibrated_at,
+            daily: daily.into_values().collect(),
+            by_activity,
+        }
+    }
+
+    /// Running commands count up to now.
+    fn active_seconds(command_log: &CommandLog, now: DateTime<Utc>) -> f64 {
+        let milliseconds = match (command_log.execution_time_ms, command_log.completed_at) {
+            (Some(milliseconds), _) => milliseconds,
+            (None, Some(completed_at)) => {
+                (completed_at - command_log.started_at).num_milliseconds()
+            }
+            (None, None) if command_log.is_running() => {
+                (now - command_log.started_at).num_milliseconds()
+            }
+            (None, None) => 0,
+        };
+        milliseconds.max(0) as f64 / 1000.0
+    }
+
+    fn requested_episodes(command: &str) -> u64 {
+        command
+            .split_whitespace()
+            .find_map(|part| part.strip_prefix("--dataset.num_episodes="))
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn failure_rate(failed: u64, finished: u64) -> Option<f64> {
+        (finished > 0).then(|| failed as f64 / finished as f64)
+    }
+
+    fn window_start(days: u32, now: DateTime<Utc>) -> DateTime<Utc> {
+        let first_day = now.date_naive() - Duration::days(i64::from(days.max(1)) - 1);
+        first_day.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+    }
+
+    fn validate_days(days: Option<u32>) -> Result<(), String> {
+        match days {
+            Some(0) => Err("Usage window must be at least one day".to_string()),
+            Some(days) if days > 3660 => Err("Usage window cannot exceed ten years".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/robot_usage_service_tests.rs"]
+mod robot_usage_service_tests;
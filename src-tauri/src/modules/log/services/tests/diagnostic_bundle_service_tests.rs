@@ -0,0 +1,201 @@
+use super::*;
+use std::io::Read;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_diagnostics", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn write_file(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).expect("parent dir should be created");
+    fs::write(path, contents).expect("file should be written");
+}
+
+#[test]
+fn secret_keys_are_matched_on_whole_words() {
+    for key in [
+        "device_auth_token",
+        "password",
+        "wifi-sec.psk",
+        "apiKey",
+        "HF_API_KEY",
+        "clientSecret",
+    ] {
+        assert!(DiagnosticBundleService::is_secret_key(key), "{}", key);
+    }
+    for key in ["tokenizer_name", "ssid", "keyboard", "nickname"] {
+        assert!(!DiagnosticBundleService::is_secret_key(key), "{}", key);
+    }
+}
+
+#[test]
+fn nested_json_secrets_are_redacted() {
+    let mut value = json!({
+        "ssid": "sourccey-ap",
+        "password": "hunter22",
+        "pairing": {
+            "device_auth_token": "dat_123",
+            "expires_at": null,
+            "devices": [{ "name": "arm", "accessToken": "abc" }],
+        },
+        "tokenizer_name": "t5",
+    });
+
+    DiagnosticBundleService::redact_json(&mut value);
+
+    assert_eq!(
+        value,
+        json!({
+            "ssid": "sourccey-ap",
+            "password": "[REDACTED]",
+            "pairing": {
+                "device_auth_token": "[REDACTED]",
+                "expires_at": null,
+                "devices": [{ "name": "arm", "accessToken": "[REDACTED]" }],
+            },
+            "tokenizer_name": "t5",
+        })
+    );
+}
+
+#[test]
+fn log_text_secrets_are_redacted() {
+    let text = concat!(
+        "connecting ssid=home password=hunter22 retries=3\n",
+        r#"{"message":"paired {\"device_auth_token\": \"dat_123\"}","source":"setup"}"#,
+        "\n",
+        r#"running nmcli ["con", "modify", "ap", "wifi-sec.psk", "s3cret!"]"#,
+        "\n",
+        "curl -H Authorization: Bearer eyJhbGciOi.abc\n",
+        "start_ap --ssid sourccey --password apPass1\n",
+        "12:30:45 tokenizer_name=t5\n",
+    );
+
+    let redacted = DiagnosticBundleService::redact_text(text);
+
+    for secret in ["hunter22", "dat_123", "s3cret!", "eyJhbGciOi", "apPass1"] {
+        assert!(
+            !redacted.contains(secret),
+            "{} leaked in:\n{}",
+            secret,
+            redacted
+        );
+    }
+    assert!(redacted.contains("ssid=home password=[REDACTED] retries=3"));
+    assert!(redacted.contains(r#"\"device_auth_token\": \"[REDACTED]\""#));
+    assert!(redacted.contains("12:30:45 tokenizer_name=t5"));
+}
+
+#[test]
+fn quoted_secrets_with_spaces_are_fully_redacted() {
+    let text = concat!(
+        r#"{"ssid":"Home Net","password":"correct horse \"battery\" staple","retries":3}"#,
+        "\n",
+        r#"{"message":"saved {\"psk\": \"my wifi pass\"}"}"#,
+        "\n",
+        "connect ssid=\"Home Net\" psk=\"open sesame now\" band=5\n",
+    );
+
+    let redacted = DiagnosticBundleService::redact_text(text);
+
+    for secret in ["horse", "battery", "staple", "wifi", "sesame", "now"] {
+        assert!(
+            !redacted.contains(secret),
+            "{} leaked in:\n{}",
+            secret,
+            redacted
+        );
+    }
+    assert!(redacted.contains(r#""password":"[REDACTED]","retries":3"#));
+    assert!(redacted.contains(r#"\"psk\": \"[REDACTED]\"}"#));
+    assert!(redacted.contains(r#"ssid="Home Net" psk="[REDACTED]" band=5"#));
+}
+
+#[test]
+fn bundle_collects_logs_and_configs_into_zip() {
+    let root = temp_dir("bundle");
+    let log_dir = root.join("logs");
+    let cache_dir = root.join("lerobot");
+    let output_dir = root.join("out");
+    write_file(&log_dir.join("teleop.log"), "started token=abc\n");
+    write_file(&log_dir.join("teleop.log.1"), "older\n");
+    write_file(&log_dir.join("notes.txt"), "ignored\n");
+    write_file(
+        &cache_dir.join("calibration/robots/sourccey/left_arm.json"),
+        r#"{"shoulder": {"homing_offset": 12}}"#,
+    );
+    write_file(
+        &cache_dir.join("settings/access_point_credentials.json"),
+        r#"{"ssid": "sourccey", "password": "apPass1"}"#,
+    );
+    write_file(
+        &cache_dir.join("sourccey-1/config.json"),
+        r#"{"id": "sourccey-1"}"#,
+    );
+    write_file(&cache_dir.join("ai_models/org/model/config.json"), "{}");
+    write_file(&cache_dir.join("broken/config.json"), "{not json");
+
+    let mut contents = BundleContents::default();
+    DiagnosticBundleService::collect_logs(&mut contents, "logs/app", &log_dir);
+    DiagnosticBundleService::collect_lerobot_files(&mut contents, &cache_dir);
+    let created_at = "2026-10-18T09:08:07+00:00"
+        .parse::<DateTime<chrono::FixedOffset>>()
+        .expect("timestamp should parse")
+        .with_timezone(&Local);
+    let summary = DiagnosticBundleService::write_bundle(&output_dir, contents, "1.2.3", created_at)
+        .expect("bundle should be written");
+
+    assert!(summary.path.ends_with(&format!(
+        "sourccey-diagnostics-{}.zip",
+        created_at.format("%Y%m%d-%H%M%S")
+    )));
+    assert_eq!(summary.file_count, 5);
+    assert_eq!(summary.warnings.len(), 1);
+    assert!(summary.warnings[0].starts_with("lerobot/broken/config.json"));
+
+    let mut archive = zip::ZipArchive::new(File::open(&summary.path).expect("bundle should open"))
+        .expect("bundle should be a zip");
+    let mut names: Vec<String> = archive.file_names().map(str::to_string).collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "lerobot/calibration/robots/sourccey/left_arm.json",
+            "lerobot/settings/access_point_credentials.json",
+            "lerobot/sourccey-1/config.json",
+            "logs/app/teleop.log",
+            "logs/app/teleop.log.1",
+            "manifest.json",
+        ]
+    );
+
+    let read = |archive: &mut zip::ZipArchive<File>, name: &str| {
+        let mut text = String::new();
+        archive
+            .by_name(name)
+            .expect("file should be bundled")
+            .read_to_string(&mut text)
+            .expect("file should read");
+        text
+    };
+    assert_eq!(
+        read(&mut archive, "logs/app/teleop.log"),
+        "started token=[REDACTED]\n"
+    );
+    let credentials: Value = serde_json::from_str(&read(
+        &mut archive,
+        "lerobot/settings/access_point_credentials.json",
+    ))
+    .expect("credentials should be JSON");
+    assert_eq!(credentials["password"], "[REDACTED]");
+    let manifest: Value = serde_json::from_str(&read(&mut archive, "manifest.json"))
+        .expect("manifest should be JSON");
+    assert_eq!(manifest["appVersion"], "1.2.3");
+    assert_eq!(manifest["files"].as_array().map(Vec::len), Some(5));
+}
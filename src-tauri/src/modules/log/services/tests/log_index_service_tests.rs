@@ -0,0 +1,193 @@
+use super::*;
+use migration::MigratorTrait;
+
+async fn indexed(entries: &[LogEntry]) -> LogIndexService {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    let service = LogIndexService::new(connection);
+    service
+        .add_entries(entries)
+        .await
+        .expect("entries should be indexed");
+    service
+}
+
+fn entry(minute: u32, level: LogLevel, source: &str, nickname: &str, message: &str) -> LogEntry {
+    LogEntry {
+        timestamp: format!("2026-10-18T09:{:02}:00Z", minute)
+            .parse()
+            .expect("timestamp should parse"),
+        nickname: Some(nickname.to_string()),
+        ..LogEntry::new(level, source, message)
+    }
+}
+
+fn sample_entries() -> Vec<LogEntry> {
+    vec![
+        entry(
+            1,
+            LogLevel::Info,
+            "teleop",
+            "alpha",
+            "Teleop started at 30 fps",
+        ),
+        entry(
+            2,
+            LogLevel::Warn,
+            "teleop",
+            "alpha",
+            "Left arm motor 3 overload detected",
+        ),
+        entry(
+            3,
+            LogLevel::Error,
+            "record",
+            "beta",
+            "Right arm overload: torque disabled",
+        ),
+        entry(
+            4,
+            LogLevel::Warn,
+            "teleop",
+            "alpha",
+            "Left arm overload cleared",
+        ),
+        entry(
+            5,
+            LogLevel::Info,
+            "frontend",
+            "alpha",
+            "Opened calibration page",
+        ),
+    ]
+}
+
+fn page(page: usize, page_size: usize) -> PaginationParameters {
+    PaginationParameters {
+        page: Some(page),
+        page_size: Some(page_size),
+    }
+}
+
+fn messages(response: &PaginatedResponse<LogEntry>) -> Vec<&str> {
+    response
+        .data
+        .iter()
+        .map(|entry| entry.message.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn search_finds_first_overload_of_the_left_arm() {
+    let service = indexed(&sample_entries()).await;
+
+    let response = service
+        .search(
+            LogSearchFilters {
+                text: Some("left arm overl".to_string()),
+                oldest_first: true,
+                ..LogSearchFilters::default()
+            },
+            page(1, 1),
+        )
+        .await
+        .expect("search should succeed");
+
+    assert_eq!(
+        messages(&response),
+        vec!["Left arm motor 3 overload detected"]
+    );
+    assert_eq!(response.total, 2);
+    assert_eq!(response.total_pages, 2);
+    assert!(response.has_next);
+    let first = &response.data[0];
+    assert_eq!(first.level, LogLevel::Warn);
+    assert_eq!(first.nickname.as_deref(), Some("alpha"));
+}
+
+#[tokio::test]
+async fn search_filters_by_source_level_nickname_and_time() {
+    let service = indexed(&sample_entries()).await;
+
+    let errors = service
+        .search(
+            LogSearchFilters {
+                level: Some(LogLevel::Error),
+                ..LogSearchFilters::default()
+            },
+            page(1, 10),
+        )
+        .await
+        .expect("search should succeed");
+    assert_eq!(
+        messages(&errors),
+        vec!["Right arm overload: torque disabled"]
+    );
+
+    let alpha_teleop = service
+        .search(
+            LogSearchFilters {
+                sources: Some(vec!["teleop".to_string()]),
+                nickname: Some("alpha".to_string()),
+                since: Some("2026-10-18T09:02:00Z".parse().expect("since should parse")),
+                until: Some("2026-10-18T09:04:00Z".parse().expect("until should parse")),
+                ..LogSearchFilters::default()
+            },
+            page(1, 10),
+        )
+        .await
+        .expect("search should succeed");
+    assert_eq!(
+        messages(&alpha_teleop),
+        vec![
+            "Left arm overload cleared",
+            "Left arm motor 3 overload detected"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pruning_removes_entries_from_the_text_index() {
+    let service = indexed(&sample_entries()).await;
+
+    let removed = service
+        .delete_entries_before("2026-10-18T09:03:00Z".parse().expect("cutoff should parse"))
+        .await
+        .expect("prune should succeed");
+    let response = service
+        .search(
+            LogSearchFilters {
+                text: Some("overload".to_string()),
+                ..LogSearchFilters::default()
+            },
+            page(1, 10),
+        )
+        .await
+        .expect("search should succeed");
+
+    assert_eq!(removed, 2);
+    assert_eq!(
+        messages(&response),
+        vec![
+            "Left arm overload cleared",
+            "Right arm overload: torque disabled"
+        ]
+    );
+}
+
+#[test]
+fn match_expression_quotes_words_and_prefixes_the_last() {
+    assert_eq!(
+        LogIndexService::match_expression("left  arm overl"),
+        Some("\"left\" \"arm\" \"overl\"*".to_string())
+    );
+    assert_eq!(
+        LogIndexService::match_expression("say \"hi\" OR"),
+        Some("\"say\" \"\"\"hi\"\"\" \"OR\"*".to_string())
+    );
+    assert_eq!(LogIndexService::match_expression("   "), None);
+}
@@ -0,0 +1,290 @@
+use super::*;
+use crate::modules::log::services::command_log_service::CommandLogService;
+use crate::modules::robot::models::owned_robot::ActiveOwnedRobot;
+use crate::modules::robot::services::owned_robot_service::OwnedRobotService;
+use crate::modules::robot::services::robot_service::RobotService;
+use chrono::TimeZone;
+use migration::MigratorTrait;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap()
+}
+
+fn owned_robot() -> OwnedRobot {
+    OwnedRobot {
+        id: "owned-1".to_string(),
+        robot_id: "robot-1".to_string(),
+        nickname: Some("kitchen".to_string()),
+        registration_date: now(),
+        confirmation_date: now(),
+        last_active_date: now(),
+        created_at: None,
+        updated_at: None,
+        deleted_at: None,
+    }
+}
+
+fn command_log(
+    command: &str,
+    status: &str,
+    started_at: DateTime<Utc>,
+    execution_time_ms: Option<i64>,
+) -> CommandLog {
+    CommandLog {
+        id: format!("{}-{}", command, started_at.timestamp()),
+        command: command.to_string(),
+        description: None,
+        status: status.to_string(),
+        exit_code: None,
+        output: None,
+        error_message: None,
+        robot_id: None,
+        owned_robot_id: Some("owned-1".to_string()),
+        execution_time_ms,
+        started_at,
+        completed_at: execution_time_ms.map(|ms| started_at + Duration::milliseconds(ms)),
+        created_at: None,
+        updated_at: None,
+        deleted_at: None,
+    }
+}
+
+#[test]
+fn commands_are_classified_by_what_they_launched() {
+    assert_eq!(
+        RobotActivity::from_command("uv run lerobot-teleoperate --robot.type=sourccey_client"),
+        RobotActivity::Teleop
+    );
+    assert_eq!(
+        RobotActivity::from_command("uv run lerobot-record --dataset.num_episodes=5"),
+        RobotActivity::Record
+    );
+    assert_eq!(
+        RobotActivity::from_command(
+            "python src/lerobot/scripts/sourccey/calibration/auto_calibrate.py"
+        ),
+        RobotActivity::Calibrate
+    );
+    assert_eq!(
+        RobotActivity::from_command("Starting kiosk host: python -u -m lerobot.robots.sourccey.sourccey.sourccey.sourccey_host"),
+        RobotActivity::Host
+    );
+    assert_eq!(
+        RobotActivity::from_command("python detect.py"),
+        RobotActivity::Other
+    );
+}
+
+#[test]
+fn summary_totals_hours_sessions_episodes_and_failures() {
+    let hour = 3_600_000;
+    let logs = vec![
+        command_log(
+            "lerobot-teleoperate",
+            "success",
+            now() - Duration::days(1),
+            Some(2 * hour),
+        ),
+        command_log(
+            "lerobot-teleoperate",
+            "cancelled",
+            now() - Duration::days(1),
+            Some(hour),
+        ),
+        command_log(
+            "lerobot-record --dataset.num_episodes=10",
+            "success",
+            now() - Duration::days(2),
+            Some(hour),
+        ),
+        command_log(
+            "lerobot-record --dataset.num_episodes=7",
+            "failed",
+            now() - Duration::days(2),
+            Some(1000),
+        ),
+        command_log(
+            "lerobot-rollout",
+            "failed",
+            now() - Duration::days(3),
+            Some(1000),
+        ),
+        command_log(
+            "lerobot-rollout",
+            "success",
+            now() - Duration::days(3),
+            Some(1000),
+        ),
+    ];
+
+    let stats = RobotUsageService::summarize(&owned_robot(), &logs, None, now());
+
+    assert_eq!(stats.nickname.as_deref(), Some("kitchen"));
+    assert_eq!(stats.since, None);
+    assert_eq!(stats.total_runs, 6);
+    assert_eq!(stats.failed_runs, 2);
+    assert_eq!(stats.failure_rate, Some(2.0 / 6.0));
+    assert_eq!(stats.teleop_hours, 3.0);
+    assert_eq!(stats.recording_sessions, 2);
+    assert_eq!(stats.recorded_episodes, 10);
+    assert_eq!(stats.rollout_runs, 2);
+    assert_eq!(stats.last_active_at, Some(now() - Duration::days(1)));
+
+    let rollout = stats
+        .by_activity
+        .iter()
+        .find(|s| s.activity == RobotActivity::Rollout)
+        .unwrap();
+    assert_eq!(rollout.failure_rate, Some(0.5));
+    let teleop = stats
+        .by_activity
+        .iter()
+        .find(|s| s.activity == RobotActivity::Teleop)
+        .unwrap();
+    assert_eq!(teleop.failure_rate, Some(0.0));
+}
+
+#[test]
+fn running_commands_count_until_now_and_have_no_failure_rate() {
+    let logs = vec![command_log(
+        "lerobot-teleoperate",
+        "running",
+        now() - Duration::minutes(30),
+        None,
+    )];
+
+    let stats = RobotUsageService::summarize(&owned_robot(), &logs, Some(1), now());
+
+    assert_eq!(stats.teleop_hours, 0.5);
+    assert_eq!(stats.by_activity[0].running, 1);
+    assert_eq!(stats.failure_rate, None);
+}
+
+#[test]
+fn window_limits_totals_but_not_the_last_calibration() {
+    let logs = vec![
+        command_log(
+            "auto_calibrate.py",
+            "success",
+            now() - Duration::days(40),
+            Some(1000),
+        ),
+        command_log(
+            "auto_calibrate.py",
+            "failed",
+            now() - Duration::days(2),
+            Some(1000),
+        ),
+        command_log(
+            "lerobot-rollout",
+            "success",
+            now() - Duration::days(10),
+            Some(1000),
+        ),
+        command_log("lerobot-rollout", "success", now(), Some(1000)),
+    ];
+
+    let stats = RobotUsageService::summarize(&owned_robot(), &logs, Some(7), now());
+
+    assert_eq!(
+        stats.since,
+        Some(Utc.with_ymd_and_hms(2026, 10, 13, 0, 0, 0).unwrap())
+    );
+    assert_eq!(stats.total_runs, 2);
+    assert_eq!(stats.rollout_runs, 1);
+    assert_eq!(
+        stats.last_calibrated_at,
+        Some(now() - Duration::days(40) + Duration::seconds(1))
+    );
+}
+
+#[test]
+fn daily_histogram_is_zero_filled_oldest_first() {
+    let logs = vec![
+        command_log(
+            "lerobot-teleoperate",
+            "success",
+            now() - Duration::days(2),
+            Some(60_000),
+        ),
+        command_log(
+            "lerobot-rollout",
+            "failed",
+            now() - Duration::days(2),
+            Some(1000),
+        ),
+        command_log(
+            "lerobot-rollout",
+            "success",
+            now() - Duration::days(90),
+            Some(1000),
+        ),
+    ];
+
+    let stats = RobotUsageService::summarize(&owned_robot(), &logs, None, now());
+
+    assert_eq!(stats.daily.len(), DEFAULT_HISTOGRAM_DAYS as usize);
+    assert_eq!(
+        stats.daily[0].date,
+        NaiveDate::from_ymd_opt(2026, 9, 20).unwrap()
+    );
+    assert_eq!(stats.daily.last().unwrap().date, now().date_naive());
+    let busy_day = &stats.daily[DEFAULT_HISTOGRAM_DAYS as usize - 3];
+    assert_eq!(busy_day.runs, 2);
+    assert_eq!(busy_day.failed, 1);
+    assert_eq!(busy_day.active_seconds, 61.0);
+    assert_eq!(stats.daily.iter().map(|day| day.runs).sum::<u64>(), 2);
+    // Totals still cover all time.
+    assert_eq!(stats.total_runs, 3);
+}
+
+#[tokio::test]
+async fn fleet_usage_lists_every_robot_and_rejects_an_empty_window() {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    let robot = RobotService::new(connection.clone())
+        .upsert_robot_template(Some("sourccey".to_string()), None)
+        .await
+        .unwrap();
+    let owned_robot_service = OwnedRobotService::new(connection.clone());
+    for nickname in ["kitchen", "garage"] {
+        owned_robot_service
+            .add_owned_robot(
+                ActiveOwnedRobot::new(robot.id.clone()).with_nickname(nickname.to_string()),
+            )
+            .await
+            .unwrap();
+    }
+    CommandLogService::new(connection.clone())
+        .add_robot_command_log("lerobot-teleoperate", None, Some("garage".to_string()))
+        .await
+        .unwrap();
+
+    let service = RobotUsageService::new(connection);
+    let fleet = service.get_fleet_usage(Some(7)).await.unwrap();
+    assert_eq!(fleet.len(), 2);
+    let usage = |nickname: &str| {
+        fleet
+            .iter()
+            .find(|stats| stats.nickname.as_deref() == Some(nickname))
+            .unwrap()
+    };
+    assert_eq!(usage("garage").total_runs, 1);
+    assert_eq!(usage("kitchen").total_runs, 0);
+    assert_eq!(usage("kitchen").daily.len(), 7);
+
+    let garage = service
+        .get_robot_usage(usage("garage").owned_robot_id.clone(), None)
+        .await
+        .unwrap();
+    assert_eq!(garage.by_activity[0].running, 1);
+    assert!(service.get_fleet_usage(Some(0)).await.is_err());
+    assert!(service
+        .get_robot_usage("missing".to_string(), None)
+        .await
+        .is_err());
+}
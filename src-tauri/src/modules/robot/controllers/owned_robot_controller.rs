@@ -3,7 +3,9 @@
 use crate::modules::robot::models::owned_robot::{
     ActiveOwnedRobot, OwnedRobot, OwnedRobotWithRelations,
 };
-use crate::modules::robot::services::owned_robot_service::OwnedRobotService;
+use crate::modules::robot::services::owned_robot_service::{
+    OwnedRobotPurgeResult, OwnedRobotService,
+};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 
@@ -19,6 +21,12 @@ pub struct UpdateOwnedRobotNicknameRequest {
     pub nickname: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeOwnedRobotRequest {
+    pub id: String,
+    pub confirmation: String,
+}
+
 //----------------------------------------------------------//
 // GET Robot Functions
 //----------------------------------------------------------//
@@ -132,3 +140,46 @@ pub async fn delete_owned_robot(app_handle: AppHandle, id: String) -> Result<(),
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn get_deleted_owned_robots(
+    app_handle: AppHandle,
+) -> Result<Vec<OwnedRobotWithRelations>, String> {
+    let db_manager = match app_handle.try_state::<crate::database::connection::DatabaseManager>() {
+        Some(manager) => manager,
+        None => return Err("Database not initialized".to_string()),
+    };
+
+    let owned_robot_service = OwnedRobotService::new(db_manager.get_connection().clone());
+    owned_robot_service
+        .get_deleted_owned_robots()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn restore_owned_robot(app_handle: AppHandle, id: String) -> Result<OwnedRobot, String> {
+    let db_manager = match app_handle.try_state::<crate::database::connection::DatabaseManager>() {
+        Some(manager) => manager,
+        None => return Err("Database not initialized".to_string()),
+    };
+
+    let owned_robot_service = OwnedRobotService::new(db_manager.get_connection().clone());
+    owned_robot_service.restore_owned_robot(id).await
+}
+
+#[tauri::command]
+pub async fn purge_owned_robot(
+    app_handle: AppHandle,
+    request: PurgeOwnedRobotRequest,
+) -> Result<OwnedRobotPurgeResult, String> {
+    let db_manager = match app_handle.try_state::<crate::database::connection::DatabaseManager>() {
+        Some(manager) => manager,
+        None => return Err("Database not initialized".to_string()),
+    };
+
+    let owned_robot_service = OwnedRobotService::new(db_manager.get_connection().clone());
+    owned_robot_service
+        .purge_owned_robot(request.id, request.confirmation)
+        .await
+}
@@ -1,4 +1,7 @@
 use crate::modules::robot::models::robot::Robot;
+use crate::modules::robot::services::robot_catalogue_service::{
+    RobotCapabilities, RobotCatalogueSeedResult, RobotCatalogueService,
+};
 use crate::modules::robot::services::robot_service::RobotService;
 use tauri::{AppHandle, Manager};
 
@@ -38,3 +41,27 @@ pub async fn upsert_robot_template(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn get_robot_capabilities(
+    app_handle: AppHandle,
+    nickname: String,
+) -> Result<RobotCapabilities, String> {
+    let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
+
+    RobotCatalogueService::capabilities_for_nickname(db_manager.get_connection(), &nickname).await
+}
+
+/// Re-apply the bundled catalogue; it is also seeded at startup.
+#[tauri::command]
+pub async fn seed_robot_catalogue(
+    app_handle: AppHandle,
+) -> Result<RobotCatalogueSeedResult, String> {
+    let db_manager = app_handle.state::<crate::database::connection::DatabaseManager>();
+    let catalogue_service = RobotCatalogueService::new(db_manager.get_connection().clone());
+
+    catalogue_service
+        .seed_catalogue(&RobotCatalogueService::bundled_catalogue()?)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -24,6 +24,19 @@ pub struct Model {
     pub robot_type: Option<String>,
     pub github_url: Option<String>,
 
+    // Catalogue Data
+    /// Version of the bundled catalogue entry; `None` for rows created ad hoc.
+    pub catalogue_version: Option<i32>,
+    pub arm_count: Option<i32>,
+    /// JSON object `{ command, observation }` of the host's ZMQ ports.
+    pub default_ports: Option<String>,
+    /// `--robot.type` when the app drives the robot over the network.
+    pub client_robot_type: Option<String>,
+    /// JSON array of `--teleop.type` values, preferred first.
+    pub teleop_types: Option<String>,
+    /// JSON array of the commands the robot supports, e.g. `teleop`, `record`.
+    pub lerobot_commands: Option<String>,
+
     // Timestamps
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
@@ -111,6 +124,12 @@ impl ActiveModel {
             image: Set(None),
             robot_type: Set(None),
             github_url: Set(None),
+            catalogue_version: Set(None),
+            arm_count: Set(None),
+            default_ports: Set(None),
+            client_robot_type: Set(None),
+            teleop_types: Set(None),
+            lerobot_commands: Set(None),
             created_at: Set(Some(Utc::now())),
             updated_at: Set(Some(Utc::now())),
             deleted_at: Set(None),
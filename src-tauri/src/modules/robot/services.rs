@@ -1,3 +1,4 @@
 pub mod discovery_service;
 pub mod owned_robot_service;
+pub mod robot_catalogue_service;
 pub mod robot_service;
@@ -1,3 +1,4 @@
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::ErrorKind;
@@ -7,8 +8,6 @@ use std::time::{Duration, Instant};
 
 const DISCOVERY_MAGIC: &str = "SOURCCEY_DISCOVER_V1";
 const DISCOVERY_PORT: u16 = 42111;
-const SOURCCEY_COMMAND_PORT: u16 = 5555;
-const SOURCCEY_OBSERVATION_PORT: u16 = 5556;
 const DISCOVERY_TIMEOUT_MS: u64 = 2_500;
 const DISCOVERY_READ_TIMEOUT_MS: u64 = 250;
 const DISCOVERY_SEND_INTERVAL_MS: u64 = 200;
@@ -40,7 +39,7 @@ pub struct LanRobotDiscoveryResult {
 }
 
 #[derive(Debug, Deserialize)]
-struct SourcceyDiscoveredRobot {
+struct DiscoveredRobotPayload {
     discovery_magic: String,
     robot_type: String,
     host_running: Option<bool>,
@@ -130,14 +129,11 @@ fn broadcast_discover(local_ip: Ipv4Addr) -> Result<Vec<DiscoveredLanRobotHost>,
 }
 
 fn parse_discovery_response(payload: &str, source_ip: IpAddr) -> Option<DiscoveredLanRobotHost> {
-    let parsed = serde_json::from_str::<SourcceyDiscoveredRobot>(payload).ok()?;
+    let parsed = serde_json::from_str::<DiscoveredRobotPayload>(payload).ok()?;
     if parsed.discovery_magic.trim() != DISCOVERY_MAGIC {
         return None;
     }
-    let robot_type = parsed.robot_type.trim();
-    if !robot_type.eq_ignore_ascii_case("sourccey") {
-        return None;
-    }
+    let identity = RobotProfileService::for_robot_type(&parsed.robot_type)?.discovery_identity()?;
 
     let ip_address = match source_ip {
         IpAddr::V4(ip) => ip.to_string(),
@@ -147,13 +143,13 @@ fn parse_discovery_response(payload: &str, source_ip: IpAddr) -> Option<Discover
     Some(DiscoveredLanRobotHost {
         ip_address,
         host_running: parsed.host_running.unwrap_or(true),
-        command_port: parsed.command_port.unwrap_or(SOURCCEY_COMMAND_PORT),
-        observation_port: parsed.observation_port.unwrap_or(SOURCCEY_OBSERVATION_PORT),
+        command_port: parsed.command_port.unwrap_or(identity.command_port),
+        observation_port: parsed.observation_port.unwrap_or(identity.observation_port),
         source: "udp-discovery".to_string(),
         protocol_version: None,
         robot_name: None,
         nickname: None,
-        robot_type: Some("sourccey".to_string()),
+        robot_type: Some(identity.robot_type.to_string()),
         hostname: None,
         capabilities: None,
     })
@@ -331,6 +327,25 @@ mod tests {
         assert_eq!(parsed.observation_port, 5556);
     }
 
+    #[test]
+    fn only_accepts_robots_with_a_discoverable_profile() {
+        let source_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 42));
+        let parsed = parse_discovery_response(
+            r#"{"discovery_magic":"SOURCCEY_DISCOVER_V1","robot_type":"Sourccey"}"#,
+            source_ip,
+        )
+        .expect("expected discovery response");
+        assert_eq!(parsed.robot_type.as_deref(), Some("sourccey"));
+
+        for robot_type in ["koch", "so100_follower"] {
+            let payload = format!(
+                r#"{{"discovery_magic":"SOURCCEY_DISCOVER_V1","robot_type":"{}"}}"#,
+                robot_type
+            );
+            assert!(parse_discovery_response(&payload, source_ip).is_none());
+        }
+    }
+
     #[test]
     fn rejects_payload_without_robot_type() {
         let payload = r#"{"discovery_magic":"SOURCCEY_DISCOVER_V1"}"#;
@@ -1,13 +1,26 @@
 #![allow(dead_code)]
 
+use crate::database::traits::BaseActiveModel;
 use crate::modules::control::services::configuration::calibration_service::CalibrationService;
 use crate::modules::control::services::configuration::configuration_service::ConfigurationService;
+use crate::modules::log::models::command_log::{CommandLogColumn, Entity as CommandLogEntity};
 use crate::modules::robot::models::owned_robot::{
     ActiveOwnedRobot, Entity as OwnedRobotEntity, OwnedRobot, OwnedRobotColumn,
     OwnedRobotWithRelations,
 };
 use crate::modules::robot::models::robot::Entity as RobotEntity;
+use sea_orm::sea_query::Expr;
 use sea_orm::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OwnedRobotPurgeResult {
+    pub id: String,
+    pub nickname: Option<String>,
+    pub detached_command_logs: u64,
+    pub removed_calibration_files: usize,
+    pub removed_config_files: usize,
+}
 
 pub struct OwnedRobotService {
     connection: DatabaseConnection,
@@ -127,10 +140,142 @@ impl OwnedRobotService {
     //----------------------------------------------------------//
     // DELETE Robot Functions
     //----------------------------------------------------------//
+    /// Move an owned robot to the trash. Its files and command history are
+    /// kept so it can be restored.
     pub async fn delete_owned_robot(&self, id: String) -> Result<(), DbErr> {
-        OwnedRobotEntity::delete_by_id(id)
-            .exec(&self.connection)
-            .await?;
+        let Some(owned_robot) = OwnedRobotEntity::find_by_id(id)
+            .filter(OwnedRobotColumn::DeletedAt.is_null())
+            .one(&self.connection)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let mut active_model: ActiveOwnedRobot = owned_robot.into();
+        active_model.soft_delete();
+        active_model.update(&self.connection).await?;
         Ok(())
     }
+
+    pub async fn get_deleted_owned_robots(&self) -> Result<Vec<OwnedRobotWithRelations>, DbErr> {
+        let results = OwnedRobotEntity::find()
+            .filter(OwnedRobotColumn::DeletedAt.is_not_null())
+            .order_by_desc(OwnedRobotColumn::DeletedAt)
+            .find_with_related(RobotEntity)
+            .all(&self.connection)
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|(owned_robot, robot_vec)| {
+                let robot = robot_vec.first().cloned();
+                OwnedRobotWithRelations { owned_robot, robot }
+            })
+            .collect())
+    }
+
+    pub async fn restore_owned_robot(&self, id: String) -> Result<OwnedRobot, String> {
+        let owned_robot = self.find_deleted_owned_robot(&id).await?;
+
+        if let Some(nickname) = owned_robot.nickname.clone() {
+            if self
+                .get_owned_robot_by_nickname(nickname.clone())
+                .await
+                .map_err(|e| e.to_string())?
+                .is_some()
+            {
+                return Err(format!(
+                    "Another robot is already named '{}'. Rename it before restoring this one.",
+                    nickname
+                ));
+            }
+        }
+
+        let mut active_model: ActiveOwnedRobot = owned_robot.into();
+        active_model.clear_deleted_at();
+        active_model.update_timestamp();
+        active_model
+            .update(&self.connection)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Permanently remove a deleted robot along with its calibration and
+    /// config files. `confirmation` must repeat the robot's display name.
+    pub async fn purge_owned_robot(
+        &self,
+        id: String,
+        confirmation: String,
+    ) -> Result<OwnedRobotPurgeResult, String> {
+        let (owned_robot, detached_command_logs) =
+            self.purge_owned_robot_record(&id, &confirmation).await?;
+        let mut result = OwnedRobotPurgeResult {
+            id: owned_robot.id,
+            nickname: owned_robot.nickname.clone(),
+            detached_command_logs,
+            removed_calibration_files: 0,
+            removed_config_files: 0,
+        };
+
+        // Files are named by nickname, so leave them while another robot uses it.
+        let Some(nickname) = owned_robot.nickname else {
+            return Ok(result);
+        };
+        let shared = OwnedRobotEntity::find()
+            .filter(OwnedRobotColumn::Nickname.eq(nickname.clone()))
+            .count(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+            > 0;
+        if !shared {
+            result.removed_calibration_files =
+                CalibrationService::remove_nickname_references(&nickname)?;
+            result.removed_config_files = ConfigurationService::remove_robot_cache_dir(&nickname)?;
+        }
+        Ok(result)
+    }
+
+    /// Delete the row and detach its command logs, which outlive the robot.
+    async fn purge_owned_robot_record(
+        &self,
+        id: &str,
+        confirmation: &str,
+    ) -> Result<(OwnedRobot, u64), String> {
+        let owned_robot = self.find_deleted_owned_robot(id).await?;
+        let display_name = owned_robot.get_display_name();
+        if confirmation.trim() != display_name {
+            return Err(format!(
+                "Type '{}' to confirm permanently deleting this robot.",
+                display_name
+            ));
+        }
+
+        let txn = self.connection.begin().await.map_err(|e| e.to_string())?;
+        let detached = CommandLogEntity::update_many()
+            .col_expr(CommandLogColumn::OwnedRobotId, Expr::value(None::<String>))
+            .filter(CommandLogColumn::OwnedRobotId.eq(owned_robot.id.clone()))
+            .exec(&txn)
+            .await
+            .map_err(|e| e.to_string())?
+            .rows_affected;
+        OwnedRobotEntity::delete_by_id(owned_robot.id.clone())
+            .exec(&txn)
+            .await
+            .map_err(|e| e.to_string())?;
+        txn.commit().await.map_err(|e| e.to_string())?;
+        Ok((owned_robot, detached))
+    }
+
+    async fn find_deleted_owned_robot(&self, id: &str) -> Result<OwnedRobot, String> {
+        OwnedRobotEntity::find_by_id(id.to_string())
+            .filter(OwnedRobotColumn::DeletedAt.is_not_null())
+            .one(&self.connection)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Deleted robot not found for id {}", id))
+    }
 }
+
+#[cfg(test)]
+#[path = "tests/owned_robot_service_tests.rs"]
+mod owned_robot_service_tests;
@@ -0,0 +1,351 @@
+use crate::database::connection::DatabaseManager;
+use crate::database::traits::BaseActiveModel;
+use crate::modules::robot::models::owned_robot::{Entity as OwnedRobotEntity, OwnedRobotColumn};
+use crate::modules::robot::models::robot::{ActiveRobot, Entity as RobotEntity, Robot};
+use crate::modules::robot::services::owned_robot_service::OwnedRobotService;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::{AppHandle, Manager};
+
+const BUNDLED_CATALOGUE: &str = include_str!("../../../../../data/default_robots.json");
+
+/// Robots without a catalogue entry are driven as Sourccey, as they always were.
+pub const DEFAULT_ROBOT_TYPE: &str = "sourccey";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RobotCommand {
+    Teleop,
+    Record,
+    Rollout,
+    Inference,
+    Calibrate,
+}
+
+impl RobotCommand {
+    fn label(&self) -> &'static str {
+        match self {
+            RobotCommand::Teleop => "teleoperation",
+            RobotCommand::Record => "recording",
+            RobotCommand::Rollout => "rollout",
+            RobotCommand::Inference => "inference",
+            RobotCommand::Calibrate => "calibration",
+        }
+    }
+}
+
+/// ZMQ ports of the robot host; empty for arms plugged straight into USB.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RobotPorts {
+    pub command: Option<u16>,
+    pub observation: Option<u16>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RobotCapabilities {
+    pub arm_count: u8,
+    #[serde(default)]
+    pub default_ports: RobotPorts,
+    /// `--robot.type` for driving the robot over the network; `None` when it
+    /// can only be driven locally.
+    pub client_robot_type: Option<String>,
+    /// `--teleop.type` values, preferred first.
+    #[serde(default)]
+    pub teleop_types: Vec<String>,
+    #[serde(default)]
+    pub lerobot_commands: Vec<RobotCommand>,
+}
+
+impl RobotCapabilities {
+    /// Capabilities stored on a robot row; `None` for rows created before
+    /// the catalogue or ad hoc through `upsert_robot_template`.
+    pub fn from_robot(robot: &Robot) -> Option<Self> {
+        let arm_count = u8::try_from(robot.arm_count?).ok()?;
+        Some(Self {
+            arm_count,
+            default_ports: Self::parse_json(&robot.default_ports).unwrap_or_default(),
+            client_robot_type: robot.client_robot_type.clone(),
+            teleop_types: Self::parse_json(&robot.teleop_types).unwrap_or_default(),
+            lerobot_commands: Self::parse_json(&robot.lerobot_commands).unwrap_or_default(),
+        })
+    }
+
+    pub fn apply_to(&self, robot: &mut ActiveRobot) {
+        robot.arm_count = Set(Some(i32::from(self.arm_count)));
+        robot.default_ports = Set(serde_json::to_string(&self.default_ports).ok());
+        robot.client_robot_type = Set(self.client_robot_type.clone());
+        robot.teleop_types = Set(serde_json::to_string(&self.teleop_types).ok());
+        robot.lerobot_commands = Set(serde_json::to_string(&self.lerobot_commands).ok());
+    }
+
+    pub fn supports(&self, command: RobotCommand) -> bool {
+        self.lerobot_commands.contains(&command)
+    }
+
+    pub fn require(&self, command: RobotCommand) -> Result<(), String> {
+        if !self.supports(command) {
+            return Err(format!("This robot does not support {}.", command.label()));
+        }
+        Ok(())
+    }
+
+    /// The `--robot.type` for running `command` against the robot host.
+    pub fn remote_robot_type(&self, command: RobotCommand) -> Result<&str, String> {
+        self.require(command)?;
+        self.client_robot_type.as_deref().ok_or(format!(
+            "This robot cannot be driven over the network for {}.",
+            command.label()
+        ))
+    }
+
+    pub fn teleop_type(&self) -> Result<&str, String> {
+        self.teleop_types
+            .first()
+            .map(String::as_str)
+            .ok_or("This robot has no supported leader arm.".to_string())
+    }
+
+    /// `--teleop.*_port` arguments: one port per arm, left first.
+    pub fn teleop_port_args(&self, left_arm_port: &str, right_arm_port: &str) -> Vec<String> {
+        if self.arm_count >= 2 {
+            vec![
+                format!("--teleop.left_arm_port={}", left_arm_port.trim()),
+                format!("--teleop.right_arm_port={}", right_arm_port.trim()),
+            ]
+        } else {
+            vec![format!("--teleop.port={}", left_arm_port.trim())]
+        }
+    }
+
+    fn parse_json<T: for<'de> Deserialize<'de>>(value: &Option<String>) -> Option<T> {
+        serde_json::from_str(value.as_deref()?).ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct RobotCatalogueEntry {
+    /// Stable across catalogue releases; also the robot row's id.
+    pub id: String,
+    pub name: String,
+    pub long_name: Option<String>,
+    pub description: Option<String>,
+    pub short_description: Option<String>,
+    pub image: Option<String>,
+    pub robot_type: String,
+    pub github_url: Option<String>,
+    /// Bumped whenever the entry changes; rows are only rewritten for newer versions.
+    pub version: i32,
+    pub capabilities: RobotCapabilities,
+    /// Set to retire an entry while keeping its id reserved.
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl RobotCatalogueEntry {
+    fn apply_to(&self, robot: &mut ActiveRobot) {
+        robot.name = Set(Some(self.name.clone()));
+        robot.long_name = Set(self.long_name.clone());
+        robot.description = Set(self.description.clone());
+        robot.short_description = Set(self.short_description.clone());
+        robot.image = Set(self.image.clone());
+        robot.robot_type = Set(Some(self.robot_type.clone()));
+        robot.github_url = Set(self.github_url.clone());
+        robot.catalogue_version = Set(Some(self.version));
+        self.capabilities.apply_to(robot);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RobotCatalogueSeedResult {
+    pub inserted: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    /// Catalogue rows soft-deleted because their entry was removed or retired.
+    pub removed: usize,
+    /// Ad hoc rows of a catalogued type whose owned robots were moved over.
+    pub adopted: usize,
+}
+
+pub struct RobotCatalogueService {
+    connection: DatabaseConnection,
+}
+
+impl RobotCatalogueService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Seed the bundled catalogue at startup. Failures are logged and leave
+    /// the existing rows as they were.
+    pub fn seed(app_handle: &AppHandle) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            eprintln!("[Robot Catalogue] Database is not initialized; skipping seeding");
+            return;
+        };
+        let service = Self::new(db_manager.get_connection().clone());
+
+        let result = Self::bundled_catalogue().and_then(|entries| {
+            tauri::async_runtime::block_on(service.seed_catalogue(&entries))
+                .map_err(|e| e.to_string())
+        });
+        match result {
+            Ok(result) => println!(
+                "[Robot Catalogue] Seeded: {} inserted, {} updated, {} unchanged, {} removed, {} adopted",
+                result.inserted, result.updated, result.unchanged, result.removed, result.adopted
+            ),
+            Err(e) => eprintln!("[Robot Catalogue] Failed to seed: {}", e),
+        }
+    }
+
+    pub fn bundled_catalogue() -> Result<Vec<RobotCatalogueEntry>, String> {
+        Self::parse_catalogue(BUNDLED_CATALOGUE)
+    }
+
+    pub(crate) fn parse_catalogue(json: &str) -> Result<Vec<RobotCatalogueEntry>, String> {
+        let entries: Vec<RobotCatalogueEntry> = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse robot catalogue: {}", e))?;
+        let mut ids = HashSet::new();
+        for entry in &entries {
+            if !ids.insert(entry.id.as_str()) {
+                return Err(format!("Robot catalogue lists {} twice", entry.id));
+            }
+        }
+        Ok(entries)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Seed Catalogue
+    //-------------------------------------------------------------------------//
+    /// Upsert every live entry by id, rewriting rows only for newer versions,
+    /// and soft-delete catalogue rows whose entry is gone. Owned robots of ad
+    /// hoc rows with a catalogued type are moved onto the catalogue row.
+    pub async fn seed_catalogue(
+        &self,
+        entries: &[RobotCatalogueEntry],
+    ) -> Result<RobotCatalogueSeedResult, DbErr> {
+        let txn = self.connection.begin().await?;
+        let existing = RobotEntity::find().all(&txn).await?;
+        let mut result = RobotCatalogueSeedResult::default();
+
+        let live: Vec<&RobotCatalogueEntry> = entries
+            .iter()
+            .filter(|entry| entry.deleted_at.is_none())
+            .collect();
+        for entry in &live {
+            match existing.iter().find(|robot| robot.id == entry.id) {
+                Some(robot)
+                    if robot.deleted_at.is_none()
+                        && robot
+                            .catalogue_version
+                            .is_some_and(|version| version >= entry.version) =>
+                {
+                    result.unchanged += 1;
+                }
+                Some(robot) => {
+                    let mut active: ActiveRobot = robot.clone().into();
+                    entry.apply_to(&mut active);
+                    active.clear_deleted_at();
+                    active.update_timestamp();
+                    active.update(&txn).await?;
+                    result.updated += 1;
+                }
+                None => {
+                    let mut active = ActiveRobot::new();
+                    active.id = Set(entry.id.clone());
+                    entry.apply_to(&mut active);
+                    active.insert(&txn).await?;
+                    result.inserted += 1;
+                }
+            }
+
+            let ad_hoc = existing.iter().filter(|robot| {
+                robot.id != entry.id
+                    && robot.catalogue_version.is_none()
+                    && robot.deleted_at.is_none()
+                    && robot.robot_type.as_deref().is_some_and(|robot_type| {
+                        robot_type.eq_ignore_ascii_case(&entry.robot_type)
+                    })
+            });
+            for robot in ad_hoc {
+                OwnedRobotEntity::update_many()
+                    .col_expr(OwnedRobotColumn::RobotId, Expr::value(entry.id.clone()))
+                    .filter(OwnedRobotColumn::RobotId.eq(robot.id.clone()))
+                    .exec(&txn)
+                    .await?;
+                let mut active: ActiveRobot = robot.clone().into();
+                active.soft_delete();
+                active.update(&txn).await?;
+                result.adopted += 1;
+            }
+        }
+
+        let live_ids: HashSet<&str> = live.iter().map(|entry| entry.id.as_str()).collect();
+        let removed = existing.iter().filter(|robot| {
+            robot.catalogue_version.is_some()
+                && robot.deleted_at.is_none()
+                && !live_ids.contains(robot.id.as_str())
+        });
+        for robot in removed {
+            let mut active: ActiveRobot = robot.clone().into();
+            active.soft_delete();
+            active.update(&txn).await?;
+            result.removed += 1;
+        }
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Resolve Capabilities
+    //-------------------------------------------------------------------------//
+    /// Capabilities of the robot `nickname`: from its robot row, else the
+    /// bundled entry for its type, else the default robot's.
+    pub async fn capabilities_for_nickname(
+        db_connection: &DatabaseConnection,
+        nickname: &str,
+    ) -> Result<RobotCapabilities, String> {
+        let robot = Self::robot_for_nickname(db_connection, nickname).await?;
+        Self::resolve_capabilities(robot.as_ref())
+    }
+
+    /// The catalogue row behind an owned robot, if the nickname is known.
+    pub async fn robot_for_nickname(
+        db_connection: &DatabaseConnection,
+        nickname: &str,
+    ) -> Result<Option<Robot>, String> {
+        Ok(OwnedRobotService::new(db_connection.clone())
+            .get_owned_robot_by_nickname(nickname.to_string())
+            .await
+            .map_err(|e| e.to_string())?
+            .and_then(|owned| owned.robot))
+    }
+
+    pub(crate) fn resolve_capabilities(robot: Option<&Robot>) -> Result<RobotCapabilities, String> {
+        if let Some(capabilities) = robot.and_then(RobotCapabilities::from_robot) {
+            return Ok(capabilities);
+        }
+        let robot_type = robot
+            .and_then(|robot| robot.robot_type.as_deref())
+            .unwrap_or(DEFAULT_ROBOT_TYPE);
+        Self::bundled_capabilities(robot_type)?.ok_or(format!(
+            "No capabilities are known for {} robots",
+            robot_type
+        ))
+    }
+
+    pub fn bundled_capabilities(robot_type: &str) -> Result<Option<RobotCapabilities>, String> {
+        Ok(Self::bundled_catalogue()?
+            .into_iter()
+            .filter(|entry| entry.deleted_at.is_none())
+            .find(|entry| entry.robot_type.eq_ignore_ascii_case(robot_type.trim()))
+            .map(|entry| entry.capabilities))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/robot_catalogue_service_tests.rs"]
+mod robot_catalogue_service_tests;
@@ -0,0 +1,105 @@
+use super::*;
+use crate::modules::log::services::command_log_service::CommandLogService;
+use crate::modules::robot::services::robot_service::RobotService;
+use migration::MigratorTrait;
+
+async fn connection() -> DatabaseConnection {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    connection
+}
+
+async fn add_robot(connection: &DatabaseConnection, nickname: &str) -> OwnedRobot {
+    let robot = RobotService::new(connection.clone())
+        .upsert_robot_template(Some("sourccey".to_string()), None)
+        .await
+        .unwrap();
+    OwnedRobotService::new(connection.clone())
+        .add_owned_robot(ActiveOwnedRobot::new(robot.id).with_nickname(nickname.to_string()))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn deleting_moves_a_robot_to_the_trash_and_restore_brings_it_back() {
+    let connection = connection().await;
+    let service = OwnedRobotService::new(connection.clone());
+    let owned = add_robot(&connection, "kitchen").await;
+
+    service.delete_owned_robot(owned.id.clone()).await.unwrap();
+    assert!(service.get_owned_robots().await.unwrap().is_empty());
+    assert!(service
+        .get_owned_robot_by_nickname("kitchen".to_string())
+        .await
+        .unwrap()
+        .is_none());
+    let trash = service.get_deleted_owned_robots().await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].owned_robot.id, owned.id);
+
+    let restored = service.restore_owned_robot(owned.id.clone()).await.unwrap();
+    assert!(restored.deleted_at.is_none());
+    assert_eq!(service.get_owned_robots().await.unwrap().len(), 1);
+    assert!(service.restore_owned_robot(owned.id).await.is_err());
+}
+
+#[tokio::test]
+async fn restoring_refuses_a_nickname_that_was_reused() {
+    let connection = connection().await;
+    let service = OwnedRobotService::new(connection.clone());
+    let owned = add_robot(&connection, "kitchen").await;
+    service.delete_owned_robot(owned.id.clone()).await.unwrap();
+    add_robot(&connection, "kitchen").await;
+
+    let error = service.restore_owned_robot(owned.id).await.unwrap_err();
+    assert!(error.contains("kitchen"), "{}", error);
+}
+
+#[tokio::test]
+async fn purging_needs_confirmation_and_detaches_command_logs() {
+    let connection = connection().await;
+    let service = OwnedRobotService::new(connection.clone());
+    let owned = add_robot(&connection, "kitchen").await;
+    let command_log = CommandLogService::new(connection.clone())
+        .add_robot_command_log("lerobot-teleoperate", None, Some("kitchen".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(
+        command_log.owned_robot_id.as_deref(),
+        Some(owned.id.as_str())
+    );
+
+    // Only robots in the trash can be purged.
+    assert!(service
+        .purge_owned_robot_record(&owned.id, "kitchen")
+        .await
+        .is_err());
+    service.delete_owned_robot(owned.id.clone()).await.unwrap();
+    let error = service
+        .purge_owned_robot_record(&owned.id, "garage")
+        .await
+        .unwrap_err();
+    assert!(error.contains("'kitchen'"), "{}", error);
+
+    let (purged, detached) = service
+        .purge_owned_robot_record(&owned.id, " kitchen ")
+        .await
+        .unwrap();
+    assert_eq!(purged.id, owned.id);
+    assert_eq!(detached, 1);
+    assert!(OwnedRobotEntity::find_by_id(owned.id)
+        .one(&connection)
+        .await
+        .unwrap()
+        .is_none());
+    let command_log = CommandLogService::new(connection.clone())
+        .get_command_log(command_log.id)
+        .await
+        .unwrap()
+        .expect("command history outlives the robot");
+    assert_eq!(command_log.owned_robot_id, None);
+}
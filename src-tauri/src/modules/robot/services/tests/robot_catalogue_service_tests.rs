@@ -0,0 +1,214 @@
+use super::*;
+use crate::modules::robot::models::owned_robot::ActiveOwnedRobot;
+use crate::modules::robot::services::robot_service::RobotService;
+use migration::MigratorTrait;
+
+const SOURCCEY_ID: &str = "01965652-97ed-7b82-bdc4-22865064d073";
+const SO100_ID: &str = "0195f368-73ca-76dd-aa6f-5cfa53fa8394";
+
+async fn connection() -> DatabaseConnection {
+    let connection = Database::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should open");
+    migration::Migrator::up(&connection, None)
+        .await
+        .expect("migrations should run");
+    connection
+}
+
+fn catalogue() -> Vec<RobotCatalogueEntry> {
+    RobotCatalogueService::bundled_catalogue().expect("bundled catalogue should parse")
+}
+
+async fn find_robot(connection: &DatabaseConnection, id: &str) -> Robot {
+    RobotEntity::find_by_id(id.to_string())
+        .one(connection)
+        .await
+        .unwrap()
+        .expect("robot row should exist")
+}
+
+#[test]
+fn bundled_catalogue_describes_each_robot() {
+    let entries = catalogue();
+    let sourccey = entries
+        .iter()
+        .find(|entry| entry.id == SOURCCEY_ID)
+        .unwrap();
+    assert_eq!(sourccey.robot_type, "sourccey");
+    assert_eq!(sourccey.capabilities.arm_count, 2);
+    assert_eq!(
+        sourccey
+            .capabilities
+            .remote_robot_type(RobotCommand::Rollout),
+        Ok("sourccey_client")
+    );
+    assert_eq!(
+        sourccey.capabilities.teleop_type(),
+        Ok("bi_sourccey_leader")
+    );
+    assert_eq!(sourccey.capabilities.default_ports.command, Some(5555));
+
+    let so100 = entries.iter().find(|entry| entry.id == SO100_ID).unwrap();
+    assert_eq!(so100.capabilities.arm_count, 1);
+    assert!(so100.capabilities.supports(RobotCommand::Teleop));
+    assert!(so100
+        .capabilities
+        .remote_robot_type(RobotCommand::Teleop)
+        .is_err());
+}
+
+#[test]
+fn duplicate_catalogue_ids_are_rejected() {
+    let entry = r#"{"id":"a","name":"A","robot_type":"a","version":1,
+        "capabilities":{"arm_count":1,"client_robot_type":null}}"#;
+    let error =
+        RobotCatalogueService::parse_catalogue(&format!("[{},{}]", entry, entry)).unwrap_err();
+    assert!(error.contains("twice"), "{}", error);
+}
+
+#[tokio::test]
+async fn seeding_upserts_by_id_and_tracks_versions() {
+    let connection = connection().await;
+    let service = RobotCatalogueService::new(connection.clone());
+    let mut entries = catalogue();
+
+    let result = service.seed_catalogue(&entries).await.unwrap();
+    assert_eq!(result.inserted, 2);
+    let result = service.seed_catalogue(&entries).await.unwrap();
+    assert_eq!(
+        result,
+        RobotCatalogueSeedResult {
+            unchanged: 2,
+            ..RobotCatalogueSeedResult::default()
+        }
+    );
+
+    // Entry changes only land once its version is bumped.
+    let sourccey = entries
+        .iter_mut()
+        .find(|entry| entry.id == SOURCCEY_ID)
+        .unwrap();
+    sourccey.short_description = Some("Now with wheels".to_string());
+    assert_eq!(service.seed_catalogue(&entries).await.unwrap().unchanged, 2);
+    entries
+        .iter_mut()
+        .find(|entry| entry.id == SOURCCEY_ID)
+        .unwrap()
+        .version = 2;
+    assert_eq!(service.seed_catalogue(&entries).await.unwrap().updated, 1);
+    let robot = find_robot(&connection, SOURCCEY_ID).await;
+    assert_eq!(robot.catalogue_version, Some(2));
+    assert_eq!(robot.short_description.as_deref(), Some("Now with wheels"));
+    assert_eq!(
+        RobotCapabilities::from_robot(&robot).unwrap().teleop_types,
+        vec!["bi_sourccey_leader".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn removed_entries_are_soft_deleted_and_restored() {
+    let connection = connection().await;
+    let service = RobotCatalogueService::new(connection.clone());
+    let entries = catalogue();
+    service.seed_catalogue(&entries).await.unwrap();
+
+    let without_so100: Vec<RobotCatalogueEntry> = entries
+        .iter()
+        .filter(|entry| entry.id != SO100_ID)
+        .cloned()
+        .collect();
+    assert_eq!(
+        service
+            .seed_catalogue(&without_so100)
+            .await
+            .unwrap()
+            .removed,
+        1
+    );
+    assert!(find_robot(&connection, SO100_ID).await.deleted_at.is_some());
+    // Ad hoc rows are not the catalogue's to remove.
+    let ad_hoc = RobotService::new(connection.clone())
+        .upsert_robot_template(Some("koch".to_string()), None)
+        .await
+        .unwrap();
+    service.seed_catalogue(&without_so100).await.unwrap();
+    assert!(find_robot(&connection, &ad_hoc.id)
+        .await
+        .deleted_at
+        .is_none());
+
+    assert_eq!(service.seed_catalogue(&entries).await.unwrap().updated, 1);
+    assert!(find_robot(&connection, SO100_ID).await.deleted_at.is_none());
+}
+
+#[tokio::test]
+async fn ad_hoc_rows_of_a_catalogued_type_are_adopted() {
+    let connection = connection().await;
+    let legacy = RobotService::new(connection.clone())
+        .upsert_robot_template(Some("sourccey".to_string()), Some("Sourccey".to_string()))
+        .await
+        .unwrap();
+    let owned = ActiveOwnedRobot::new(legacy.id.clone())
+        .with_nickname("kitchen".to_string())
+        .insert(&connection)
+        .await
+        .unwrap();
+
+    let result = RobotCatalogueService::new(connection.clone())
+        .seed_catalogue(&catalogue())
+        .await
+        .unwrap();
+    assert_eq!(result.adopted, 1);
+
+    assert!(find_robot(&connection, &legacy.id)
+        .await
+        .deleted_at
+        .is_some());
+    let owned = OwnedRobotEntity::find_by_id(owned.id)
+        .one(&connection)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(owned.robot_id, SOURCCEY_ID);
+
+    let capabilities = RobotCatalogueService::capabilities_for_nickname(&connection, "kitchen")
+        .await
+        .unwrap();
+    assert_eq!(
+        capabilities.client_robot_type.as_deref(),
+        Some("sourccey_client")
+    );
+}
+
+#[tokio::test]
+async fn capabilities_fall_back_to_the_bundled_catalogue() {
+    let connection = connection().await;
+    let robot_service = RobotService::new(connection.clone());
+
+    let sourccey = RobotCatalogueService::bundled_capabilities("sourccey")
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        RobotCatalogueService::resolve_capabilities(None),
+        Ok(sourccey)
+    );
+
+    let so100 = robot_service
+        .upsert_robot_template(Some("so100_follower".to_string()), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        RobotCatalogueService::resolve_capabilities(Some(&so100))
+            .unwrap()
+            .teleop_types,
+        vec!["so100_leader".to_string()]
+    );
+
+    let unknown = robot_service
+        .upsert_robot_template(Some("koch".to_string()), None)
+        .await
+        .unwrap();
+    let error = RobotCatalogueService::resolve_capabilities(Some(&unknown)).unwrap_err();
+    assert!(error.contains("koch"), "{}", error);
+}
@@ -2,4 +2,6 @@ pub mod access_point;
 pub mod desktop_auth_controller;
 pub mod desktop_environment;
 pub mod kiosk_environment;
+pub mod network_watchdog;
+pub mod provisioning_portal;
 pub mod wifi;
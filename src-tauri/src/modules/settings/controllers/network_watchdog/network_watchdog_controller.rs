@@ -0,0 +1,15 @@
+use crate::modules::settings::services::network_watchdog::network_watchdog_service::{
+    NetworkWatchdogService, NetworkWatchdogState, NetworkWatchdogStatus,
+};
+use tauri::State;
+
+pub fn init_network_watchdog() -> NetworkWatchdogState {
+    NetworkWatchdogService::init_network_watchdog()
+}
+
+#[tauri::command]
+pub fn get_network_watchdog_status(
+    state: State<'_, NetworkWatchdogState>,
+) -> Result<NetworkWatchdogStatus, String> {
+    NetworkWatchdogService::get_status(&state)
+}
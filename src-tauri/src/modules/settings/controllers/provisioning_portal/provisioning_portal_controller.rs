@@ -0,0 +1,15 @@
+use crate::modules::settings::services::provisioning_portal::provisioning_portal_service::{
+    ProvisioningPortalService, ProvisioningPortalState, ProvisioningPortalStatus,
+};
+use tauri::State;
+
+pub fn init_provisioning_portal() -> ProvisioningPortalState {
+    ProvisioningPortalService::init_provisioning_portal()
+}
+
+#[tauri::command]
+pub fn get_provisioning_portal_status(
+    state: State<'_, ProvisioningPortalState>,
+) -> Result<ProvisioningPortalStatus, String> {
+    ProvisioningPortalService::get_status(&state)
+}
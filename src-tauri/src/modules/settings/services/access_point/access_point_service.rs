@@ -2,9 +2,13 @@ use crate::services::directory::directory_service::DirectoryService;
 use crate::services::directory::remote_directory_service::RemoteDirectoryService;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::net::Ipv4Addr;
 use std::path::PathBuf;
 use std::process::Command;
 
+/// Connection profile name `set_access_point.py` creates for AP mode.
+pub const HOTSPOT_CONNECTION_NAME: &str = "Hotspot";
+
 pub struct AccessPointService;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,6 +112,12 @@ impl AccessPointService {
 
     pub async fn is_access_point_active() -> Result<bool, String> {
         println!("[AccessPoint] Checking if access point is active");
+        Self::hotspot_is_active()
+    }
+
+    /// Blocking check used by the network watchdog. Matches the hotspot profile
+    /// name exactly instead of substring-matching the whole `nmcli` output.
+    pub fn hotspot_is_active() -> Result<bool, String> {
         let output = Command::new("nmcli")
             .arg("-t")
             .arg("-f")
@@ -119,8 +129,40 @@ impl AccessPointService {
             .map_err(|e| format!("Failed to check if access point is active: {}", e))?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let is_active = stdout.contains("Hotspot");
-        Ok(is_active)
+        Ok(Self::active_connections_include_hotspot(&stdout))
+    }
+
+    /// The address `set_access_point.py`'s shared-mode hotspot was given on the
+    /// AP interface; `None` while the hotspot is down or still configuring.
+    pub fn hotspot_address() -> Result<Option<Ipv4Addr>, String> {
+        let output = Command::new("nmcli")
+            .arg("-g")
+            .arg("IP4.ADDRESS")
+            .arg("connection")
+            .arg("show")
+            .arg(HOTSPOT_CONNECTION_NAME)
+            .output()
+            .map_err(|e| format!("Failed to read access point address: {}", e))?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(Self::parse_ipv4_address(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    /// First address in `nmcli -g IP4.ADDRESS` output (`10.42.0.1/24 | ...`).
+    fn parse_ipv4_address(stdout: &str) -> Option<Ipv4Addr> {
+        stdout
+            .split(['|', '\n'])
+            .filter_map(|entry| entry.trim().split('/').next()?.parse().ok())
+            .next()
+    }
+
+    fn active_connections_include_hotspot(stdout: &str) -> bool {
+        stdout
+            .lines()
+            .any(|line| line.trim_end() == HOTSPOT_CONNECTION_NAME)
     }
 
     pub fn get_saved_access_point_credentials() -> Result<Option<AccessPointCredentials>, String> {
@@ -202,3 +244,7 @@ impl AccessPointService {
             .join("access_point_credentials.json"))
     }
 }
+
+#[cfg(test)]
+#[path = "tests/access_point_service_tests.rs"]
+mod access_point_service_tests;
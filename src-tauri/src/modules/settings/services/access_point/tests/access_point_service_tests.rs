@@ -0,0 +1,10 @@
+use super::*;
+
+#[test]
+fn hotspot_address_is_the_first_ipv4_entry() {
+    assert_eq!(
+        AccessPointService::parse_ipv4_address("10.42.0.1/24 | 192.168.4.1/24\n"),
+        Some(Ipv4Addr::new(10, 42, 0, 1))
+    );
+    assert_eq!(AccessPointService::parse_ipv4_address("\n"), None);
+}
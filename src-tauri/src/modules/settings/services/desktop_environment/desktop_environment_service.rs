@@ -1,4 +1,5 @@
 use crate::services::directory::directory_service::DirectoryService;
+use crate::services::setup::release_channel_service::{ReleaseChannel, ReleaseChannelService};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -78,6 +79,8 @@ pub struct DesktopEnvironmentSettings {
     pub studio_web_url: String,
     pub updater_manifest_url: String,
     pub teleop_log_level: String,
+    pub release_channel: String,
+    pub pinned_lerobot_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +91,11 @@ pub struct SaveDesktopEnvironmentSettingsRequest {
     pub custom_studio_web_url: Option<String>,
     pub custom_updater_manifest_url: Option<String>,
     pub teleop_log_level: Option<String>,
+    #[serde(default)]
+    pub release_channel: Option<String>,
+    /// An empty string clears the pin; `None` keeps the saved one.
+    #[serde(default)]
+    pub pinned_lerobot_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +109,10 @@ struct PersistedDesktopEnvironmentSettings {
     custom_updater_manifest_url: String,
     #[serde(default = "default_teleop_log_level")]
     teleop_log_level: String,
+    #[serde(default)]
+    release_channel: String,
+    #[serde(default)]
+    pinned_lerobot_tag: Option<String>,
 }
 
 fn default_teleop_log_level() -> String {
@@ -119,6 +131,8 @@ impl DesktopEnvironmentService {
             DEFAULT_LOCAL_DESKTOP_STUDIO_WEB_URL.to_string(),
             DEFAULT_LOCAL_DESKTOP_UPDATER_MANIFEST_URL.to_string(),
             DEFAULT_TELEOP_LOG_LEVEL.to_string(),
+            ReleaseChannel::Stable,
+            None,
         )
     }
 
@@ -138,6 +152,41 @@ impl DesktopEnvironmentService {
         Ok(Self::get_settings()?.updater_manifest_url)
     }
 
+    /// The manifest the app updater and the LeRobot installer both read: the
+    /// selected environment's, else `SOURCCEY_UPDATER_URL`, else production.
+    pub fn resolve_updater_manifest_url() -> String {
+        Self::current_updater_manifest_url().unwrap_or_else(|_| {
+            std::env::var("SOURCCEY_UPDATER_URL")
+                .unwrap_or_else(|_| PRODUCTION_UPDATER_MANIFEST_URL.to_string())
+        })
+    }
+
+    /// Switch to the pinned channel and freeze LeRobot on `tag`.
+    pub fn pin_lerobot_release(tag: &str) -> Result<DesktopEnvironmentSettings, String> {
+        let path = Self::settings_file_path()?;
+        Self::pin_lerobot_release_at_path(&path, tag)
+    }
+
+    fn pin_lerobot_release_at_path(
+        path: &Path,
+        tag: &str,
+    ) -> Result<DesktopEnvironmentSettings, String> {
+        let existing_settings =
+            Self::get_settings_from_path(path).unwrap_or_else(|_| Self::default_settings());
+        Self::save_settings_to_path(
+            path,
+            SaveDesktopEnvironmentSettingsRequest {
+                environment: existing_settings.environment,
+                custom_graphql_api_url: None,
+                custom_studio_web_url: None,
+                custom_updater_manifest_url: None,
+                teleop_log_level: None,
+                release_channel: Some(ReleaseChannel::Pinned.as_str().to_string()),
+                pinned_lerobot_tag: Some(tag.to_string()),
+            },
+        )
+    }
+
     fn get_settings_from_path(path: &Path) -> Result<DesktopEnvironmentSettings, String> {
         if !path.exists() {
             return Ok(Self::default_settings());
@@ -174,6 +223,8 @@ impl DesktopEnvironmentService {
             custom_studio_web_url,
             custom_updater_manifest_url,
             Self::normalize_teleop_log_level(&persisted.teleop_log_level)?,
+            ReleaseChannel::parse(&persisted.release_channel)?,
+            ReleaseChannelService::normalize_pinned_tag(persisted.pinned_lerobot_tag.as_deref()),
         ))
     }
 
@@ -208,6 +259,21 @@ impl DesktopEnvironmentService {
                 .as_deref()
                 .unwrap_or(existing_settings.teleop_log_level.as_str()),
         )?;
+        let release_channel = ReleaseChannel::parse(
+            request
+                .release_channel
+                .as_deref()
+                .unwrap_or(existing_settings.release_channel.as_str()),
+        )?;
+        let pinned_lerobot_tag = ReleaseChannelService::normalize_pinned_tag(
+            request
+                .pinned_lerobot_tag
+                .as_deref()
+                .or(existing_settings.pinned_lerobot_tag.as_deref()),
+        );
+        if release_channel == ReleaseChannel::Pinned && pinned_lerobot_tag.is_none() {
+            return Err("The pinned release channel needs a LeRobot tag".to_string());
+        }
 
         let payload = PersistedDesktopEnvironmentSettings {
             environment: environment.as_str().to_string(),
@@ -215,6 +281,8 @@ impl DesktopEnvironmentService {
             custom_studio_web_url: custom_studio_web_url.clone(),
             custom_updater_manifest_url: custom_updater_manifest_url.clone(),
             teleop_log_level: teleop_log_level.clone(),
+            release_channel: release_channel.as_str().to_string(),
+            pinned_lerobot_tag: pinned_lerobot_tag.clone(),
         };
 
         if let Some(parent) = path.parent() {
@@ -253,6 +321,8 @@ impl DesktopEnvironmentService {
             custom_studio_web_url,
             custom_updater_manifest_url,
             teleop_log_level,
+            release_channel,
+            pinned_lerobot_tag,
         ))
     }
 
@@ -296,6 +366,8 @@ impl DesktopEnvironmentService {
         custom_studio_web_url: String,
         custom_updater_manifest_url: String,
         teleop_log_level: String,
+        release_channel: ReleaseChannel,
+        pinned_lerobot_tag: Option<String>,
     ) -> DesktopEnvironmentSettings {
         let (graphql_api_url, studio_web_url, updater_manifest_url) = match environment {
             DesktopEnvironment::Production => (
@@ -326,6 +398,8 @@ impl DesktopEnvironmentService {
             studio_web_url,
             updater_manifest_url,
             teleop_log_level,
+            release_channel: release_channel.as_str().to_string(),
+            pinned_lerobot_tag,
         }
     }
 
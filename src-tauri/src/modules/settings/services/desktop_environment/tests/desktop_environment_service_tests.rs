@@ -49,6 +49,8 @@ fn save_and_load_roundtrip_preserves_local_custom_urls() {
         custom_studio_web_url: Some("dev-box.local:3000".to_string()),
         custom_updater_manifest_url: Some("dev-box.local:3000/latest.json".to_string()),
         teleop_log_level: Some("error".to_string()),
+        release_channel: None,
+        pinned_lerobot_tag: None,
     };
 
     let saved = DesktopEnvironmentService::save_settings_to_path(&path, request).unwrap();
@@ -90,6 +92,8 @@ fn non_local_persistence_uses_local_defaults_for_custom_fields() {
             custom_studio_web_url: Some("http://ignored.local:3000".to_string()),
             custom_updater_manifest_url: Some("http://ignored/latest.json".to_string()),
             teleop_log_level: None,
+            release_channel: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -124,6 +128,8 @@ fn updating_only_teleop_log_level_preserves_existing_local_urls() {
             custom_studio_web_url: Some("dev-box.local:3000".to_string()),
             custom_updater_manifest_url: Some("dev-box.local:3000/latest.json".to_string()),
             teleop_log_level: Some("warning".to_string()),
+            release_channel: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -136,6 +142,8 @@ fn updating_only_teleop_log_level_preserves_existing_local_urls() {
             custom_studio_web_url: None,
             custom_updater_manifest_url: None,
             teleop_log_level: Some("error".to_string()),
+            release_channel: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -149,3 +157,35 @@ fn updating_only_teleop_log_level_preserves_existing_local_urls() {
 
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn pinning_lerobot_release_keeps_environment_and_can_be_cleared() {
+    let path = test_file_path("desktop_environment_pin");
+
+    let pinned =
+        DesktopEnvironmentService::pin_lerobot_release_at_path(&path, "vulcan/0.3.0").unwrap();
+    assert_eq!(pinned.environment, "production");
+    assert_eq!(pinned.release_channel, "pinned");
+    assert_eq!(pinned.pinned_lerobot_tag.as_deref(), Some("vulcan/0.3.0"));
+
+    let stable = DesktopEnvironmentService::save_settings_to_path(
+        &path,
+        SaveDesktopEnvironmentSettingsRequest {
+            environment: "production".to_string(),
+            custom_graphql_api_url: None,
+            custom_studio_web_url: None,
+            custom_updater_manifest_url: None,
+            teleop_log_level: None,
+            release_channel: Some("stable".to_string()),
+            pinned_lerobot_tag: Some(String::new()),
+        },
+    )
+    .unwrap();
+    assert_eq!(stable.release_channel, "stable");
+    assert_eq!(stable.pinned_lerobot_tag, None);
+
+    let loaded = DesktopEnvironmentService::get_settings_from_path(&path).unwrap();
+    assert_eq!(loaded.release_channel, "stable");
+
+    let _ = std::fs::remove_file(path);
+}
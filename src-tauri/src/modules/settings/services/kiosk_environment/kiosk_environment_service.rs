@@ -1,4 +1,5 @@
 use crate::services::directory::directory_service::DirectoryService;
+use crate::services::setup::release_channel_service::{ReleaseChannel, ReleaseChannelService};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -65,6 +66,9 @@ pub struct KioskEnvironmentSettings {
     pub custom_api_base_url: String,
     pub app_base_url: String,
     pub api_base_url: String,
+    pub release_channel: String,
+    pub pinned_app_tag: Option<String>,
+    pub pinned_lerobot_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +77,13 @@ pub struct SaveKioskEnvironmentSettingsRequest {
     pub environment: String,
     pub custom_app_base_url: Option<String>,
     pub custom_api_base_url: Option<String>,
+    #[serde(default)]
+    pub release_channel: Option<String>,
+    /// An empty string clears the pin; `None` keeps the saved one.
+    #[serde(default)]
+    pub pinned_app_tag: Option<String>,
+    #[serde(default)]
+    pub pinned_lerobot_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +93,19 @@ struct PersistedKioskEnvironmentSettings {
     custom_app_base_url: String,
     #[serde(default)]
     custom_api_base_url: String,
+    #[serde(default)]
+    release_channel: String,
+    #[serde(default)]
+    pinned_app_tag: Option<String>,
+    #[serde(default)]
+    pinned_lerobot_tag: Option<String>,
+}
+
+/// Release channel plus the per-component pins it applies to.
+struct KioskReleaseSelection {
+    channel: ReleaseChannel,
+    pinned_app_tag: Option<String>,
+    pinned_lerobot_tag: Option<String>,
 }
 
 impl KioskEnvironmentService {
@@ -90,6 +114,11 @@ impl KioskEnvironmentService {
             KioskEnvironment::Local,
             DEFAULT_LOCAL_KIOSK_APP_BASE_URL.to_string(),
             DEFAULT_LOCAL_KIOSK_API_BASE_URL.to_string(),
+            KioskReleaseSelection {
+                channel: ReleaseChannel::Stable,
+                pinned_app_tag: None,
+                pinned_lerobot_tag: None,
+            },
         )
     }
 
@@ -122,10 +151,20 @@ impl KioskEnvironmentService {
             Some(&persisted.custom_app_base_url),
             Some(&persisted.custom_api_base_url),
         )?;
+        let release = KioskReleaseSelection {
+            channel: ReleaseChannel::parse(&persisted.release_channel)?,
+            pinned_app_tag: ReleaseChannelService::normalize_pinned_tag(
+                persisted.pinned_app_tag.as_deref(),
+            ),
+            pinned_lerobot_tag: ReleaseChannelService::normalize_pinned_tag(
+                persisted.pinned_lerobot_tag.as_deref(),
+            ),
+        };
         Ok(Self::resolve_settings(
             environment,
             custom_app_base_url,
             custom_api_base_url,
+            release,
         ))
     }
 
@@ -153,11 +192,15 @@ impl KioskEnvironmentService {
                 .as_deref()
                 .or(Some(existing_settings.custom_api_base_url.as_str())),
         )?;
+        let release = Self::resolve_release_selection(&request, &existing_settings)?;
 
         let payload = PersistedKioskEnvironmentSettings {
             environment: environment.as_str().to_string(),
             custom_app_base_url: custom_app_base_url.clone(),
             custom_api_base_url: custom_api_base_url.clone(),
+            release_channel: release.channel.as_str().to_string(),
+            pinned_app_tag: release.pinned_app_tag.clone(),
+            pinned_lerobot_tag: release.pinned_lerobot_tag.clone(),
         };
 
         if let Some(parent) = path.parent() {
@@ -194,9 +237,40 @@ impl KioskEnvironmentService {
             environment,
             custom_app_base_url,
             custom_api_base_url,
+            release,
         ))
     }
 
+    /// Switch to the pinned channel, freezing the kiosk app and/or LeRobot on
+    /// the given tags. A component without a tag keeps following stable.
+    pub fn pin_release(
+        pinned_app_tag: Option<String>,
+        pinned_lerobot_tag: Option<String>,
+    ) -> Result<KioskEnvironmentSettings, String> {
+        let path = Self::settings_file_path()?;
+        Self::pin_release_at_path(&path, pinned_app_tag, pinned_lerobot_tag)
+    }
+
+    fn pin_release_at_path(
+        path: &Path,
+        pinned_app_tag: Option<String>,
+        pinned_lerobot_tag: Option<String>,
+    ) -> Result<KioskEnvironmentSettings, String> {
+        let existing_settings =
+            Self::get_settings_from_path(path).unwrap_or_else(|_| Self::default_settings());
+        Self::save_settings_to_path(
+            path,
+            SaveKioskEnvironmentSettingsRequest {
+                environment: existing_settings.environment,
+                custom_app_base_url: None,
+                custom_api_base_url: None,
+                release_channel: Some(ReleaseChannel::Pinned.as_str().to_string()),
+                pinned_app_tag: Some(pinned_app_tag.unwrap_or_default()),
+                pinned_lerobot_tag: Some(pinned_lerobot_tag.unwrap_or_default()),
+            },
+        )
+    }
+
     pub fn current_storage_key() -> Result<String, String> {
         let settings = Self::get_settings()?;
         Self::storage_key_for_settings(&settings)
@@ -250,10 +324,47 @@ impl KioskEnvironmentService {
         Ok(normalized)
     }
 
+    fn resolve_release_selection(
+        request: &SaveKioskEnvironmentSettingsRequest,
+        existing_settings: &KioskEnvironmentSettings,
+    ) -> Result<KioskReleaseSelection, String> {
+        let channel = ReleaseChannel::parse(
+            request
+                .release_channel
+                .as_deref()
+                .unwrap_or(existing_settings.release_channel.as_str()),
+        )?;
+        let pinned_app_tag = ReleaseChannelService::normalize_pinned_tag(
+            request
+                .pinned_app_tag
+                .as_deref()
+                .or(existing_settings.pinned_app_tag.as_deref()),
+        );
+        let pinned_lerobot_tag = ReleaseChannelService::normalize_pinned_tag(
+            request
+                .pinned_lerobot_tag
+                .as_deref()
+                .or(existing_settings.pinned_lerobot_tag.as_deref()),
+        );
+        if channel == ReleaseChannel::Pinned
+            && pinned_app_tag.is_none()
+            && pinned_lerobot_tag.is_none()
+        {
+            return Err("The pinned release channel needs an app or LeRobot tag".to_string());
+        }
+
+        Ok(KioskReleaseSelection {
+            channel,
+            pinned_app_tag,
+            pinned_lerobot_tag,
+        })
+    }
+
     fn resolve_settings(
         environment: KioskEnvironment,
         custom_app_base_url: String,
         custom_api_base_url: String,
+        release: KioskReleaseSelection,
     ) -> KioskEnvironmentSettings {
         let (app_base_url, api_base_url) = match environment {
             KioskEnvironment::Production => (
@@ -275,6 +386,9 @@ impl KioskEnvironmentService {
             custom_api_base_url,
             app_base_url,
             api_base_url,
+            release_channel: release.channel.as_str().to_string(),
+            pinned_app_tag: release.pinned_app_tag,
+            pinned_lerobot_tag: release.pinned_lerobot_tag,
         }
     }
 
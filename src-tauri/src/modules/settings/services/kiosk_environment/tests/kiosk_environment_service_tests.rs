@@ -24,6 +24,9 @@ fn local_urls_survive_environment_switches_and_reload() {
             environment: "local".to_string(),
             custom_app_base_url: Some(local_app_url.to_string()),
             custom_api_base_url: Some(local_api_url.to_string()),
+            release_channel: None,
+            pinned_app_tag: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -34,6 +37,9 @@ fn local_urls_survive_environment_switches_and_reload() {
             environment: "production".to_string(),
             custom_app_base_url: None,
             custom_api_base_url: None,
+            release_channel: None,
+            pinned_app_tag: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -52,6 +58,9 @@ fn local_urls_survive_environment_switches_and_reload() {
             environment: "local".to_string(),
             custom_app_base_url: None,
             custom_api_base_url: None,
+            release_channel: None,
+            pinned_app_tag: None,
+            pinned_lerobot_tag: None,
         },
     )
     .unwrap();
@@ -72,6 +81,9 @@ fn storage_key_uses_fixed_names_for_hosted_environments() {
         custom_api_base_url: "http://192.168.1.220:5200".to_string(),
         app_base_url: "https://studio.vulcanrobotics.ai".to_string(),
         api_base_url: "https://api.studio.vulcanrobotics.ai".to_string(),
+        release_channel: "stable".to_string(),
+        pinned_app_tag: None,
+        pinned_lerobot_tag: None,
     };
     let staging = KioskEnvironmentSettings {
         environment: "staging".to_string(),
@@ -81,6 +93,9 @@ fn storage_key_uses_fixed_names_for_hosted_environments() {
         custom_api_base_url: "http://192.168.1.220:5200".to_string(),
         app_base_url: "https://staging.factory.studio.vulcanrobotics.ai".to_string(),
         api_base_url: "https://api.staging.factory.studio.vulcanrobotics.ai".to_string(),
+        release_channel: "stable".to_string(),
+        pinned_app_tag: None,
+        pinned_lerobot_tag: None,
     };
 
     assert_eq!(
@@ -103,6 +118,9 @@ fn storage_key_normalizes_local_custom_host() {
         custom_api_base_url: "HTTP://Dev-Box.local:5200/".to_string(),
         app_base_url: "http://Dev-Box.local:3000".to_string(),
         api_base_url: "http://Dev-Box.local:5200".to_string(),
+        release_channel: "stable".to_string(),
+        pinned_app_tag: None,
+        pinned_lerobot_tag: None,
     };
 
     assert_eq!(
@@ -114,3 +132,71 @@ fn storage_key_normalizes_local_custom_host() {
         "http://dev-box.local:5200"
     );
 }
+
+#[test]
+fn release_channel_pins_persist_and_survive_environment_switches() {
+    let path = test_file_path("kiosk_environment_pin");
+
+    let pinned = KioskEnvironmentService::pin_release_at_path(
+        &path,
+        None,
+        Some(" vulcan/0.3.0 ".to_string()),
+    )
+    .unwrap();
+    assert_eq!(pinned.release_channel, "pinned");
+    assert_eq!(pinned.pinned_app_tag, None);
+    assert_eq!(pinned.pinned_lerobot_tag.as_deref(), Some("vulcan/0.3.0"));
+
+    let switched = KioskEnvironmentService::save_settings_to_path(
+        &path,
+        SaveKioskEnvironmentSettingsRequest {
+            environment: "staging".to_string(),
+            custom_app_base_url: None,
+            custom_api_base_url: None,
+            release_channel: None,
+            pinned_app_tag: None,
+            pinned_lerobot_tag: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(switched.release_channel, "pinned");
+    assert_eq!(switched.pinned_lerobot_tag.as_deref(), Some("vulcan/0.3.0"));
+
+    let beta = KioskEnvironmentService::save_settings_to_path(
+        &path,
+        SaveKioskEnvironmentSettingsRequest {
+            environment: "staging".to_string(),
+            custom_app_base_url: None,
+            custom_api_base_url: None,
+            release_channel: Some("beta".to_string()),
+            pinned_app_tag: None,
+            pinned_lerobot_tag: Some(String::new()),
+        },
+    )
+    .unwrap();
+    assert_eq!(beta.release_channel, "beta");
+    assert_eq!(beta.pinned_lerobot_tag, None);
+
+    let reloaded = KioskEnvironmentService::get_settings_from_path(&path).unwrap();
+    assert_eq!(reloaded.release_channel, "beta");
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn pinned_channel_requires_a_tag() {
+    let path = test_file_path("kiosk_environment_empty_pin");
+    let result = KioskEnvironmentService::save_settings_to_path(
+        &path,
+        SaveKioskEnvironmentSettingsRequest {
+            environment: "production".to_string(),
+            custom_app_base_url: None,
+            custom_api_base_url: None,
+            release_channel: Some("pinned".to_string()),
+            pinned_app_tag: None,
+            pinned_lerobot_tag: None,
+        },
+    );
+    assert!(result.is_err());
+    assert!(!path.exists());
+}
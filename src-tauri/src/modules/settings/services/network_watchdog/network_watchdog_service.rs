@@ -0,0 +1,375 @@
+use crate::modules::settings::services::access_point::access_point_service::{
+    AccessPointService, HOTSPOT_CONNECTION_NAME,
+};
+use crate::modules::settings::services::provisioning_portal::provisioning_portal_service::ProvisioningPortalService;
+use crate::modules::settings::services::wifi::wifi_backend::{SavedWiFiNetwork, WiFiNetwork};
+use crate::modules::settings::services::wifi::wifi_service::WiFiService;
+use serde::Serialize;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+pub const NETWORK_MODE_EVENT: &str = "kiosk:network-mode";
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_LOSS_THRESHOLD_SECS: u64 = 60;
+// Each rejoin attempt briefly drops the AP, so keep attempts infrequent.
+const REJOIN_SCAN_INTERVAL: Duration = Duration::from_secs(180);
+const RADIO_SETTLE_DELAY: Duration = Duration::from_secs(3);
+const SCAN_RESULTS_DELAY: Duration = Duration::from_secs(5);
+const PROC_NET_ROUTE_PATH: &str = "/proc/net/route";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkMode {
+    Client,
+    AccessPoint,
+    Disconnected,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkWatchdogStatus {
+    pub mode: NetworkMode,
+    pub fallback_active: bool,
+    pub client_ssid: Option<String>,
+    pub access_point_ssid: Option<String>,
+    pub access_point_ip: Option<String>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct NetworkWatchdogState(Arc<Mutex<NetworkWatchdogStatus>>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkWatchdogAction {
+    None,
+    StartAccessPoint,
+    ScanForKnownNetworks,
+}
+
+/// Decides when to fall back to AP mode and when to try rejoining a known
+/// network. Kept free of I/O so the timing rules can be tested directly.
+pub struct NetworkWatchdogMonitor {
+    loss_threshold: Duration,
+    rejoin_interval: Duration,
+    lost_since: Option<Instant>,
+    last_rejoin_scan: Option<Instant>,
+    fallback_active: bool,
+}
+
+impl NetworkWatchdogMonitor {
+    pub fn new(loss_threshold: Duration, rejoin_interval: Duration) -> Self {
+        Self {
+            loss_threshold,
+            rejoin_interval,
+            lost_since: None,
+            last_rejoin_scan: None,
+            fallback_active: false,
+        }
+    }
+
+    pub fn fallback_active(&self) -> bool {
+        self.fallback_active
+    }
+
+    pub fn observe(
+        &mut self,
+        upstream_connected: bool,
+        access_point_active: bool,
+        now: Instant,
+    ) -> NetworkWatchdogAction {
+        if upstream_connected {
+            self.lost_since = None;
+            if !access_point_active {
+                self.fallback_active = false;
+            }
+            return NetworkWatchdogAction::None;
+        }
+
+        if access_point_active {
+            self.lost_since = None;
+            // An AP someone enabled by hand is left alone.
+            if !self.fallback_active {
+                return NetworkWatchdogAction::None;
+            }
+            let due = self
+                .last_rejoin_scan
+                .map(|last| now.duration_since(last) >= self.rejoin_interval)
+                .unwrap_or(true);
+            if due {
+                self.last_rejoin_scan = Some(now);
+                return NetworkWatchdogAction::ScanForKnownNetworks;
+            }
+            return NetworkWatchdogAction::None;
+        }
+
+        let lost_since = *self.lost_since.get_or_insert(now);
+        if now.duration_since(lost_since) >= self.loss_threshold {
+            self.lost_since = None;
+            self.fallback_active = true;
+            self.last_rejoin_scan = Some(now);
+            return NetworkWatchdogAction::StartAccessPoint;
+        }
+        NetworkWatchdogAction::None
+    }
+}
+
+pub struct NetworkWatchdogService;
+
+impl NetworkWatchdogService {
+    pub fn init_network_watchdog() -> NetworkWatchdogState {
+        NetworkWatchdogState(Arc::new(Mutex::new(NetworkWatchdogStatus {
+            mode: NetworkMode::Disconnected,
+            fallback_active: false,
+            client_ssid: None,
+            access_point_ssid: None,
+            access_point_ip: None,
+            last_error: None,
+        })))
+    }
+
+    pub fn get_status(state: &NetworkWatchdogState) -> Result<NetworkWatchdogStatus, String> {
+        state
+            .0
+            .lock()
+            .map(|status| status.clone())
+            .map_err(|_| "Failed to lock network watchdog state".to_string())
+    }
+
+    /// Start the kiosk network watchdog. When upstream connectivity has been gone for
+    /// `SOURCCEY_NETWORK_WATCHDOG_LOSS_SECS` (default 60s) the saved access point is
+    /// brought up; while in fallback AP mode, known networks are rescanned and rejoined.
+    pub fn start(app_handle: AppHandle, state: NetworkWatchdogState) {
+        let loss_threshold = Duration::from_secs(
+            std::env::var("SOURCCEY_NETWORK_WATCHDOG_LOSS_SECS")
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .filter(|value| *value > 0)
+                .unwrap_or(DEFAULT_LOSS_THRESHOLD_SECS),
+        );
+
+        thread::spawn(move || {
+            let mut monitor = NetworkWatchdogMonitor::new(loss_threshold, REJOIN_SCAN_INTERVAL);
+            loop {
+                let mut client_ssid = Self::current_client_ssid();
+                let mut upstream_connected = client_ssid.is_some() || Self::has_default_route();
+                let mut access_point_active =
+                    AccessPointService::hotspot_is_active().unwrap_or(false);
+
+                let mut last_error = None;
+                match monitor.observe(upstream_connected, access_point_active, Instant::now()) {
+                    NetworkWatchdogAction::StartAccessPoint => {
+                        match Self::start_fallback_access_point(&state) {
+                            Ok(()) => access_point_active = true,
+                            Err(e) => {
+                                eprintln!("[NetworkWatchdog] {}", e);
+                                last_error = Some(e);
+                            }
+                        }
+                    }
+                    NetworkWatchdogAction::ScanForKnownNetworks => {
+                        if let Err(e) = Self::rejoin_known_network(&state) {
+                            eprintln!("[NetworkWatchdog] {}", e);
+                            last_error = Some(e);
+                        }
+                        // The attempt either rejoined a network or put the AP back up.
+                        client_ssid = Self::current_client_ssid();
+                        upstream_connected = client_ssid.is_some() || Self::has_default_route();
+                        access_point_active =
+                            AccessPointService::hotspot_is_active().unwrap_or(false);
+                    }
+                    NetworkWatchdogAction::None => {}
+                }
+
+                let mode = if access_point_active {
+                    NetworkMode::AccessPoint
+                } else if upstream_connected {
+                    NetworkMode::Client
+                } else {
+                    NetworkMode::Disconnected
+                };
+                Self::update_status(
+                    &app_handle,
+                    &state,
+                    mode,
+                    monitor.fallback_active(),
+                    client_ssid,
+                    last_error,
+                );
+
+                let access_point_ip = Self::get_status(&state)
+                    .ok()
+                    .and_then(|status| status.access_point_ip);
+                ProvisioningPortalService::sync(&app_handle, access_point_active, access_point_ip);
+
+                thread::sleep(POLL_INTERVAL);
+            }
+        });
+    }
+
+    fn update_status(
+        app_handle: &AppHandle,
+        state: &NetworkWatchdogState,
+        mode: NetworkMode,
+        fallback_active: bool,
+        client_ssid: Option<String>,
+        last_error: Option<String>,
+    ) {
+        let Ok(mut status) = state.0.lock() else {
+            return;
+        };
+        let changed = status.mode != mode
+            || status.fallback_active != fallback_active
+            || status.client_ssid != client_ssid
+            || (last_error.is_some() && status.last_error != last_error);
+
+        status.mode = mode;
+        status.fallback_active = fallback_active;
+        status.client_ssid = client_ssid;
+        if last_error.is_some() {
+            status.last_error = last_error;
+        }
+        if mode != NetworkMode::AccessPoint {
+            status.access_point_ip = None;
+        } else if status.access_point_ssid.is_none() {
+            // An AP started by hand still broadcasts the saved SSID.
+            status.access_point_ssid = AccessPointService::get_saved_access_point_credentials()
+                .ok()
+                .flatten()
+                .map(|credentials| credentials.ssid);
+        }
+
+        if changed {
+            let _ = app_handle.emit(NETWORK_MODE_EVENT, status.clone());
+        }
+    }
+
+    fn start_fallback_access_point(state: &NetworkWatchdogState) -> Result<(), String> {
+        let credentials = AccessPointService::get_saved_access_point_credentials()?
+            .ok_or("Connectivity lost but no access point credentials are saved".to_string())?;
+
+        println!(
+            "[NetworkWatchdog] Connectivity lost; starting access point {}",
+            credentials.ssid
+        );
+        let ip_address = tauri::async_runtime::block_on(AccessPointService::set_access_point(
+            credentials.ssid.clone(),
+            credentials.password,
+        ))?;
+
+        let mut status = state
+            .0
+            .lock()
+            .map_err(|_| "Failed to lock network watchdog state".to_string())?;
+        status.access_point_ssid = Some(credentials.ssid);
+        status.access_point_ip = ip_address;
+        status.last_error = None;
+        Ok(())
+    }
+
+    /// A single-radio Pi cannot scan reliably while it is serving the AP, so the
+    /// AP is taken down for the scan and brought back up if no known network is
+    /// in range or joining it fails.
+    fn rejoin_known_network(state: &NetworkWatchdogState) -> Result<(), String> {
+        let backend = WiFiService::backend();
+        let saved = backend.list_saved_networks()?;
+        let has_candidates = saved
+            .iter()
+            .any(|network| network.name != HOTSPOT_CONNECTION_NAME && network.autoconnect);
+        if !has_candidates {
+            return Ok(());
+        }
+
+        backend.disconnect()?;
+        thread::sleep(RADIO_SETTLE_DELAY);
+        // The first scan only requests fresh results from NetworkManager.
+        let _ = backend.scan_networks();
+        thread::sleep(SCAN_RESULTS_DELAY);
+        let rejoined = match backend.scan_networks() {
+            Ok(visible) => match Self::select_known_network(&saved, &visible) {
+                Some(ssid) => {
+                    println!(
+                        "[NetworkWatchdog] Known network {} is reachable; leaving access point mode",
+                        ssid
+                    );
+                    tauri::async_runtime::block_on(WiFiService::set_wifi(ssid.clone()))
+                        .map_err(|e| format!("Failed to rejoin {}: {}", ssid, e))
+                        .and_then(|_| {
+                            // set_wifi succeeds once AP mode is off, even if the join failed.
+                            thread::sleep(SCAN_RESULTS_DELAY);
+                            Self::current_client_ssid()
+                                .map(|_| ())
+                                .ok_or_else(|| format!("Could not rejoin {}", ssid))
+                        })
+                }
+                None => Err("No known network in range".to_string()),
+            },
+            Err(e) => Err(e),
+        };
+
+        match rejoined {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                println!("[NetworkWatchdog] {}; restoring access point", e);
+                Self::start_fallback_access_point(state)
+            }
+        }
+    }
+
+    /// Pick the visible saved network with the highest autoconnect priority,
+    /// breaking ties by signal strength. The hotspot profile is never a candidate.
+    pub fn select_known_network(
+        saved: &[SavedWiFiNetwork],
+        visible: &[WiFiNetwork],
+    ) -> Option<String> {
+        saved
+            .iter()
+            .filter(|network| network.name != HOTSPOT_CONNECTION_NAME && network.autoconnect)
+            .filter_map(|network| {
+                visible
+                    .iter()
+                    .find(|candidate| candidate.ssid == network.ssid)
+                    .map(|candidate| (network.priority, candidate.signal_strength, network))
+            })
+            .max_by_key(|(priority, signal_strength, _)| (*priority, *signal_strength))
+            .map(|(_, _, network)| network.ssid.clone())
+    }
+
+    fn current_client_ssid() -> Option<String> {
+        let current = WiFiService::backend().current_connection().ok().flatten()?;
+        let access_point_ssid = AccessPointService::get_saved_access_point_credentials()
+            .ok()
+            .flatten()
+            .map(|credentials| credentials.ssid);
+        if access_point_ssid.as_deref() == Some(current.ssid.as_str()) {
+            return None;
+        }
+        Some(current.ssid)
+    }
+
+    fn has_default_route() -> bool {
+        fs::read_to_string(PROC_NET_ROUTE_PATH)
+            .map(|content| Self::route_table_has_default_route(&content))
+            .unwrap_or(false)
+    }
+
+    /// `/proc/net/route` lists destinations in hex; a default route has destination
+    /// `00000000` and the RTF_UP (0x1) and RTF_GATEWAY (0x2) flags set.
+    pub fn route_table_has_default_route(content: &str) -> bool {
+        content.lines().skip(1).any(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 || fields[1] != "00000000" {
+                return false;
+            }
+            u32::from_str_radix(fields[3], 16)
+                .map(|flags| flags & 0x3 == 0x3)
+                .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/network_watchdog_service_tests.rs"]
+mod network_watchdog_service_tests;
@@ -0,0 +1,139 @@
+use super::{NetworkWatchdogAction, NetworkWatchdogMonitor, NetworkWatchdogService};
+use crate::modules::settings::services::wifi::wifi_backend::{SavedWiFiNetwork, WiFiNetwork};
+use std::time::{Duration, Instant};
+
+fn monitor() -> NetworkWatchdogMonitor {
+    NetworkWatchdogMonitor::new(Duration::from_secs(30), Duration::from_secs(60))
+}
+
+fn saved(name: &str, ssid: &str, priority: i32) -> SavedWiFiNetwork {
+    SavedWiFiNetwork {
+        id: format!("{}-id", name),
+        name: name.to_string(),
+        ssid: ssid.to_string(),
+        security: "WPA2".to_string(),
+        hidden: false,
+        autoconnect: true,
+        priority,
+    }
+}
+
+fn visible(ssid: &str, signal_strength: i32) -> WiFiNetwork {
+    WiFiNetwork {
+        ssid: ssid.to_string(),
+        signal_strength,
+        security: "WPA2".to_string(),
+    }
+}
+
+#[test]
+fn starts_access_point_only_after_loss_threshold() {
+    let mut monitor = monitor();
+    let start = Instant::now();
+
+    assert_eq!(
+        monitor.observe(false, false, start),
+        NetworkWatchdogAction::None
+    );
+    assert_eq!(
+        monitor.observe(false, false, start + Duration::from_secs(29)),
+        NetworkWatchdogAction::None
+    );
+    assert_eq!(
+        monitor.observe(false, false, start + Duration::from_secs(30)),
+        NetworkWatchdogAction::StartAccessPoint
+    );
+    assert!(monitor.fallback_active());
+}
+
+#[test]
+fn brief_outage_resets_loss_timer() {
+    let mut monitor = monitor();
+    let start = Instant::now();
+
+    monitor.observe(false, false, start);
+    monitor.observe(true, false, start + Duration::from_secs(20));
+    assert_eq!(
+        monitor.observe(false, false, start + Duration::from_secs(40)),
+        NetworkWatchdogAction::None
+    );
+}
+
+#[test]
+fn rescans_for_known_networks_while_in_fallback_access_point() {
+    let mut monitor = monitor();
+    let start = Instant::now();
+
+    monitor.observe(false, false, start);
+    monitor.observe(false, false, start + Duration::from_secs(30));
+
+    assert_eq!(
+        monitor.observe(false, true, start + Duration::from_secs(35)),
+        NetworkWatchdogAction::None
+    );
+    assert_eq!(
+        monitor.observe(false, true, start + Duration::from_secs(90)),
+        NetworkWatchdogAction::ScanForKnownNetworks
+    );
+
+    monitor.observe(true, false, start + Duration::from_secs(100));
+    assert!(!monitor.fallback_active());
+}
+
+#[test]
+fn leaves_manually_enabled_access_point_alone() {
+    let mut monitor = monitor();
+    let start = Instant::now();
+
+    for offset in [0, 60, 120, 600] {
+        assert_eq!(
+            monitor.observe(false, true, start + Duration::from_secs(offset)),
+            NetworkWatchdogAction::None
+        );
+    }
+}
+
+#[test]
+fn selects_highest_priority_visible_saved_network() {
+    let saved_networks = vec![
+        saved("Hotspot", "Sourccey-AP", 100),
+        saved("Office", "Office", 5),
+        saved("Lab", "Lab", 10),
+        saved("Home", "Home", 50),
+    ];
+    let visible_networks = vec![
+        visible("Sourccey-AP", 99),
+        visible("Office", 90),
+        visible("Lab", 20),
+    ];
+
+    assert_eq!(
+        NetworkWatchdogService::select_known_network(&saved_networks, &visible_networks),
+        Some("Lab".to_string())
+    );
+    assert_eq!(
+        NetworkWatchdogService::select_known_network(&saved_networks, &[]),
+        None
+    );
+}
+
+#[test]
+fn detects_default_route_in_proc_net_route() {
+    let header =
+        "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n";
+    let with_default = format!(
+        "{}wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0\nwlan0\t0001A8C0\t00000000\t0001\t0\t0\t600\t00FFFFFF\t0\t0\t0\n",
+        header
+    );
+    let local_only = format!(
+        "{}wlan0\t002AA8C0\t00000000\t0001\t0\t0\t600\t00FFFFFF\t0\t0\t0\n",
+        header
+    );
+
+    assert!(NetworkWatchdogService::route_table_has_default_route(
+        &with_default
+    ));
+    assert!(!NetworkWatchdogService::route_table_has_default_route(
+        &local_only
+    ));
+}
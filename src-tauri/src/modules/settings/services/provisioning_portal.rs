@@ -0,0 +1 @@
+pub mod provisioning_portal_service;
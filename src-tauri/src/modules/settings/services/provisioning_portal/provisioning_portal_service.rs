@@ -0,0 +1,742 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::control::services::kiosk_control::pairing_service::{
+    KioskPairingService, KioskPairingState,
+};
+use crate::modules::robot::services::owned_robot_service::OwnedRobotService;
+use crate::modules::settings::services::access_point::access_point_service::AccessPointService;
+use crate::modules::settings::services::wifi::wifi_backend::WiFiConnectRequest;
+use crate::modules::settings::services::wifi::wifi_service::WiFiService;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const PROVISIONING_PORTAL_PORT: u16 = 8080;
+pub const PROVISIONING_PORTAL_EVENT: &str = "kiosk:provisioning-portal";
+const MAX_REQUEST_BYTES: usize = 16 * 1024;
+const MAX_FAILED_LOGINS: u32 = 5;
+const FREE_LOGIN_ATTEMPTS: u32 = 3;
+const LOGIN_BACKOFF_BASE: Duration = Duration::from_secs(30);
+const LOGIN_BACKOFF_MAX: Duration = Duration::from_secs(60 * 60);
+const SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const CONNECT_DELAY: Duration = Duration::from_secs(2);
+
+const PORTAL_PAGE: &str = r#"<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Sourccey Setup</title>
+<style>
+body { font-family: sans-serif; max-width: 28rem; margin: 1.5rem auto; padding: 0 1rem; }
+input, select, button { width: 100%; padding: .6rem; margin: .3rem 0; box-sizing: border-box; }
+section { display: none; } section.active { display: block; }
+#message { color: #b00; min-height: 1.2rem; }
+</style>
+</head>
+<body>
+<h2>Sourccey Setup</h2>
+<p id="message"></p>
+<section id="login" class="active">
+  <input id="password" type="password" placeholder="Access point password">
+  <input id="pin" inputmode="numeric" placeholder="PIN shown on the robot screen">
+  <button onclick="login()">Continue</button>
+</section>
+<section id="setup">
+  <h3>Robot</h3>
+  <p>Pairing code: <b id="pairing">-</b></p>
+  <input id="nickname" placeholder="Nickname">
+  <button onclick="saveNickname()">Save nickname</button>
+  <h3>WiFi</h3>
+  <button onclick="scan()">Scan networks</button>
+  <select id="ssid"></select>
+  <input id="wifiPassword" type="password" placeholder="WiFi password">
+  <button onclick="connectWifi()">Connect</button>
+</section>
+<script>
+let token = null;
+const msg = (text) => document.getElementById('message').textContent = text || '';
+async function call(method, path, body) {
+  const response = await fetch(path, {
+    method,
+    headers: Object.assign({ 'Content-Type': 'application/json' }, token ? { Authorization: 'Bearer ' + token } : {}),
+    body: body ? JSON.stringify(body) : undefined,
+  });
+  const data = await response.json();
+  if (!response.ok) throw new Error(data.error || response.statusText);
+  return data;
+}
+async function login() {
+  try {
+    const data = await call('POST', '/api/session', {
+      password: document.getElementById('password').value,
+      pin: document.getElementById('pin').value,
+    });
+    token = data.token;
+    document.getElementById('login').classList.remove('active');
+    document.getElementById('setup').classList.add('active');
+    msg('');
+    loadPairing();
+    scan();
+  } catch (e) { msg(e.message); }
+}
+async function loadPairing() {
+  try {
+    const data = await call('GET', '/api/pairing');
+    document.getElementById('pairing').textContent = data.pairingCode || data.status;
+  } catch (e) { msg(e.message); }
+}
+async function scan() {
+  try {
+    const networks = await call('GET', '/api/networks');
+    const select = document.getElementById('ssid');
+    select.innerHTML = '';
+    networks.forEach((n) => {
+      const option = document.createElement('option');
+      option.value = n.ssid;
+      option.dataset.security = n.security;
+      option.textContent = n.ssid + ' (' + n.signal_strength + '%)';
+      select.appendChild(option);
+    });
+  } catch (e) { msg(e.message); }
+}
+async function saveNickname() {
+  try {
+    await call('POST', '/api/nickname', { nickname: document.getElementById('nickname').value });
+    msg('Nickname saved');
+  } catch (e) { msg(e.message); }
+}
+async function connectWifi() {
+  const select = document.getElementById('ssid');
+  const option = select.options[select.selectedIndex];
+  try {
+    await call('POST', '/api/connect', {
+      ssid: select.value,
+      password: document.getElementById('wifiPassword').value,
+      security: option ? option.dataset.security : null,
+    });
+    msg('Connecting. The robot will leave setup mode; reconnect your phone to the same WiFi.');
+  } catch (e) { msg(e.message); }
+}
+</script>
+</body>
+</html>
+"#;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvisioningPortalStatus {
+    pub active: bool,
+    pub port: u16,
+    pub url: Option<String>,
+    pub pin: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ProvisioningPortalState(Arc<Mutex<ProvisioningPortalRuntime>>);
+
+#[derive(Default)]
+struct ProvisioningPortalRuntime {
+    shutdown_flag: Option<Arc<AtomicBool>>,
+    auth: Option<Arc<Mutex<ProvisioningPortalAuth>>>,
+    access_point_ip: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortalRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl PortalRequest {
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.headers
+            .get("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginBody {
+    password: String,
+    pin: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConnectBody {
+    ssid: String,
+    #[serde(default)]
+    password: String,
+    security: Option<String>,
+    #[serde(default)]
+    hidden: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NicknameBody {
+    nickname: String,
+}
+
+/// Login requires both the AP password (proves the client joined the robot's
+/// network knowingly) and the PIN on the kiosk screen (proves physical presence).
+/// Each PIN works once and is also rotated after repeated failures. A client
+/// that keeps failing is locked out for exponentially longer each time.
+pub struct ProvisioningPortalAuth {
+    password: String,
+    pin: String,
+    failed_attempts: u32,
+    sessions: HashMap<String, Instant>,
+    lockouts: HashMap<IpAddr, ClientLockout>,
+}
+
+#[derive(Default)]
+struct ClientLockout {
+    failures: u32,
+    locked_until: Option<Instant>,
+}
+
+impl ClientLockout {
+    /// `None` for the first few failures, then 30s doubling up to an hour.
+    fn backoff(failures: u32) -> Option<Duration> {
+        let doublings = failures.checked_sub(FREE_LOGIN_ATTEMPTS)?;
+        Some(
+            LOGIN_BACKOFF_BASE
+                .saturating_mul(2_u32.saturating_pow(doublings))
+                .min(LOGIN_BACKOFF_MAX),
+        )
+    }
+}
+
+impl ProvisioningPortalAuth {
+    pub fn new(password: String, pin: String) -> Self {
+        Self {
+            password,
+            pin,
+            failed_attempts: 0,
+            sessions: HashMap::new(),
+            lockouts: HashMap::new(),
+        }
+    }
+
+    pub fn pin(&self) -> &str {
+        &self.pin
+    }
+
+    /// Returns a session token on success. The PIN changes whenever this returns a
+    /// token or the failure limit is hit; callers should re-publish `pin()`.
+    /// Attempts from a locked-out `client` are refused without being checked.
+    pub fn login(
+        &mut self,
+        client: IpAddr,
+        password: &str,
+        pin: &str,
+        next_pin: String,
+        now: Instant,
+    ) -> Result<String, String> {
+        let lockout = self.lockouts.entry(client).or_default();
+        if let Some(remaining) = lockout
+            .locked_until
+            .and_then(|locked_until| locked_until.checked_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+        {
+            return Err(format!(
+                "Too many failed attempts; try again in {} seconds",
+                remaining.as_secs().max(1)
+            ));
+        }
+
+        let password_ok = constant_time_eq(password.as_bytes(), self.password.as_bytes());
+        let pin_ok = constant_time_eq(pin.trim().as_bytes(), self.pin.as_bytes());
+        if !(password_ok && pin_ok) {
+            lockout.failures += 1;
+            lockout.locked_until =
+                ClientLockout::backoff(lockout.failures).map(|backoff| now + backoff);
+            self.failed_attempts += 1;
+            if self.failed_attempts >= MAX_FAILED_LOGINS {
+                self.failed_attempts = 0;
+                self.pin = next_pin;
+            }
+            return Err("Invalid password or PIN".to_string());
+        }
+
+        let token = generate_token()?;
+        self.lockouts.remove(&client);
+        self.failed_attempts = 0;
+        self.pin = next_pin;
+        self.sessions
+            .retain(|_, created_at| now.duration_since(*created_at) < SESSION_TTL);
+        self.sessions.insert(token.clone(), now);
+        Ok(token)
+    }
+
+    pub fn authorize(&self, token: Option<&str>, now: Instant) -> bool {
+        token
+            .and_then(|token| self.sessions.get(token))
+            .map(|created_at| now.duration_since(*created_at) < SESSION_TTL)
+            .unwrap_or(false)
+    }
+}
+
+pub struct ProvisioningPortalService;
+
+impl ProvisioningPortalService {
+    pub fn init_provisioning_portal() -> ProvisioningPortalState {
+        ProvisioningPortalState(Arc::new(Mutex::new(ProvisioningPortalRuntime::default())))
+    }
+
+    pub fn get_status(state: &ProvisioningPortalState) -> Result<ProvisioningPortalStatus, String> {
+        let runtime = state
+            .0
+            .lock()
+            .map_err(|_| "Failed to lock provisioning portal state".to_string())?;
+        Ok(Self::status_from_runtime(&runtime))
+    }
+
+    /// Start the portal while the access point is up and stop it otherwise.
+    /// Called from the network watchdog on every tick.
+    pub fn sync(
+        app_handle: &AppHandle,
+        access_point_active: bool,
+        access_point_ip: Option<String>,
+    ) {
+        let state = app_handle
+            .state::<ProvisioningPortalState>()
+            .inner()
+            .clone();
+        let running = state
+            .0
+            .lock()
+            .map(|runtime| runtime.shutdown_flag.is_some())
+            .unwrap_or(false);
+
+        if access_point_active && !running {
+            if let Err(e) = Self::start(app_handle.clone(), &state, access_point_ip) {
+                eprintln!("[ProvisioningPortal] {}", e);
+            }
+        } else if !access_point_active && running {
+            Self::stop(app_handle, &state);
+        }
+    }
+
+    fn start(
+        app_handle: AppHandle,
+        state: &ProvisioningPortalState,
+        access_point_ip: Option<String>,
+    ) -> Result<(), String> {
+        let credentials = AccessPointService::get_saved_access_point_credentials()?.ok_or(
+            "Access point credentials are not saved; provisioning portal disabled".to_string(),
+        )?;
+
+        // Only the AP interface: the portal must not be reachable from an
+        // upstream LAN the kiosk is also connected to.
+        let address = match access_point_ip
+            .as_deref()
+            .and_then(|ip| ip.parse::<Ipv4Addr>().ok())
+        {
+            Some(address) => address,
+            None => AccessPointService::hotspot_address()?.ok_or(
+                "Access point has no address yet; provisioning portal not started".to_string(),
+            )?,
+        };
+        let listener = TcpListener::bind((address, PROVISIONING_PORTAL_PORT))
+            .map_err(|e| format!("Failed to bind provisioning portal to {}: {}", address, e))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("Failed to configure provisioning portal: {}", e))?;
+
+        let shutdown_flag = Arc::new(AtomicBool::new(false));
+        let auth = Arc::new(Mutex::new(ProvisioningPortalAuth::new(
+            credentials.password,
+            generate_pin()?,
+        )));
+        {
+            let mut runtime = state
+                .0
+                .lock()
+                .map_err(|_| "Failed to lock provisioning portal state".to_string())?;
+            runtime.shutdown_flag = Some(shutdown_flag.clone());
+            runtime.auth = Some(auth.clone());
+            runtime.access_point_ip = Some(address.to_string());
+        }
+        Self::emit_status(&app_handle, state);
+        println!(
+            "[ProvisioningPortal] Listening on {}:{}",
+            address, PROVISIONING_PORTAL_PORT
+        );
+
+        let state = state.clone();
+        thread::spawn(move || {
+            while !shutdown_flag.load(Ordering::SeqCst) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        // A slow WiFi scan or rename must not hold up other clients.
+                        let app_handle = app_handle.clone();
+                        let state = state.clone();
+                        let auth = auth.clone();
+                        thread::spawn(move || {
+                            if let Err(e) =
+                                Self::handle_connection(&app_handle, &state, &auth, stream)
+                            {
+                                eprintln!("[ProvisioningPortal] {}", e);
+                            }
+                        });
+                    }
+                    Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                        thread::sleep(ACCEPT_POLL_INTERVAL);
+                    }
+                    Err(error) => {
+                        eprintln!("[ProvisioningPortal] Accept failed: {}", error);
+                        thread::sleep(ACCEPT_POLL_INTERVAL);
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn stop(app_handle: &AppHandle, state: &ProvisioningPortalState) {
+        if let Ok(mut runtime) = state.0.lock() {
+            if let Some(shutdown_flag) = runtime.shutdown_flag.take() {
+                shutdown_flag.store(true, Ordering::SeqCst);
+            }
+            runtime.auth = None;
+            runtime.access_point_ip = None;
+        }
+        Self::emit_status(app_handle, state);
+        println!("[ProvisioningPortal] Stopped");
+    }
+
+    fn status_from_runtime(runtime: &ProvisioningPortalRuntime) -> ProvisioningPortalStatus {
+        ProvisioningPortalStatus {
+            active: runtime.shutdown_flag.is_some(),
+            port: PROVISIONING_PORTAL_PORT,
+            url: runtime
+                .access_point_ip
+                .as_ref()
+                .map(|ip| format!("http://{}:{}/", ip, PROVISIONING_PORTAL_PORT)),
+            pin: runtime
+                .auth
+                .as_ref()
+                .and_then(|auth| auth.lock().ok().map(|auth| auth.pin().to_string())),
+        }
+    }
+
+    fn emit_status(app_handle: &AppHandle, state: &ProvisioningPortalState) {
+        if let Ok(status) = Self::get_status(state) {
+            let _ = app_handle.emit(PROVISIONING_PORTAL_EVENT, status);
+        }
+    }
+
+    fn handle_connection(
+        app_handle: &AppHandle,
+        state: &ProvisioningPortalState,
+        auth: &Arc<Mutex<ProvisioningPortalAuth>>,
+        mut stream: TcpStream,
+    ) -> Result<(), String> {
+        stream
+            .set_nonblocking(false)
+            .and_then(|_| stream.set_read_timeout(Some(CLIENT_TIMEOUT)))
+            .and_then(|_| stream.set_write_timeout(Some(CLIENT_TIMEOUT)))
+            .map_err(|e| format!("Failed to configure client socket: {}", e))?;
+
+        let request = match Self::read_request(&mut stream) {
+            Ok(request) => request,
+            Err(e) => {
+                let _ = Self::write_json(&mut stream, 400, &json!({ "error": e }));
+                return Ok(());
+            }
+        };
+
+        let client = stream
+            .peer_addr()
+            .map_err(|e| format!("Failed to read client address: {}", e))?
+            .ip();
+        let (status, body) = Self::route(app_handle, state, auth, client, &request);
+        match body {
+            Some(body) => Self::write_json(&mut stream, status, &body),
+            None => Self::write_response(
+                &mut stream,
+                status,
+                "text/html; charset=utf-8",
+                PORTAL_PAGE.as_bytes(),
+            ),
+        }
+    }
+
+    /// Returns the status code and JSON body; `None` means serve the portal page.
+    fn route(
+        app_handle: &AppHandle,
+        state: &ProvisioningPortalState,
+        auth: &Arc<Mutex<ProvisioningPortalAuth>>,
+        client: IpAddr,
+        request: &PortalRequest,
+    ) -> (u16, Option<serde_json::Value>) {
+        let now = Instant::now();
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/") | ("GET", "/index.html") => return (200, None),
+            ("POST", "/api/session") => {
+                let body: LoginBody = match serde_json::from_slice(&request.body) {
+                    Ok(body) => body,
+                    Err(_) => return (400, Some(json!({ "error": "Invalid request body" }))),
+                };
+                let result = generate_pin().and_then(|next_pin| match auth.lock() {
+                    Ok(mut auth) => auth.login(client, &body.password, &body.pin, next_pin, now),
+                    Err(_) => Err("Portal unavailable".to_string()),
+                });
+                // Success and lockout both rotate the PIN shown on screen
+                Self::emit_status(app_handle, state);
+                return match result {
+                    Ok(token) => (200, Some(json!({ "token": token }))),
+                    Err(e) => (401, Some(json!({ "error": e }))),
+                };
+            }
+            _ => {}
+        }
+
+        let authorized = auth
+            .lock()
+            .map(|auth| auth.authorize(request.bearer_token(), now))
+            .unwrap_or(false);
+        if !authorized {
+            return (401, Some(json!({ "error": "Unauthorized" })));
+        }
+
+        let result = match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/api/networks") => WiFiService::backend()
+                .scan_networks()
+                .and_then(|networks| serde_json::to_value(networks).map_err(|e| e.to_string())),
+            // The kiosk is usually offline while the portal is up, so this never
+            // calls the relay.
+            ("GET", "/api/pairing") => {
+                let pairing_state = app_handle.state::<KioskPairingState>();
+                KioskPairingService::get_cached_kiosk_cloud_pairing_status(&pairing_state)
+                    .and_then(|info| serde_json::to_value(info).map_err(|e| e.to_string()))
+            }
+            ("POST", "/api/nickname") => serde_json::from_slice::<NicknameBody>(&request.body)
+                .map_err(|_| "Invalid request body".to_string())
+                .and_then(|body| Self::rename_kiosk(app_handle, &body.nickname))
+                .map(|nickname| json!({ "nickname": nickname })),
+            ("POST", "/api/connect") => serde_json::from_slice::<ConnectBody>(&request.body)
+                .map_err(|_| "Invalid request body".to_string())
+                .and_then(|body| {
+                    let connect_request = WiFiConnectRequest {
+                        ssid: body.ssid,
+                        password: body.password,
+                        security: body.security,
+                        hidden: body.hidden,
+                        priority: None,
+                        enterprise: None,
+                    };
+                    connect_request.validate()?;
+                    Self::connect_after_response(connect_request);
+                    Ok(json!({ "status": "connecting" }))
+                }),
+            _ => return (404, Some(json!({ "error": "Not found" }))),
+        };
+
+        match result {
+            Ok(body) => (200, Some(body)),
+            Err(e) => (400, Some(json!({ "error": e }))),
+        }
+    }
+
+    /// Persists the kiosk nickname and, when this kiosk's robot is registered
+    /// locally, renames it the same way `update_owned_robot_nickname` does.
+    fn rename_kiosk(app_handle: &AppHandle, nickname: &str) -> Result<String, String> {
+        let pairing_state = app_handle.state::<KioskPairingState>();
+        let old_nickname = KioskPairingService::get_kiosk_nickname(&pairing_state)?;
+        let nickname = KioskPairingService::set_kiosk_nickname(&pairing_state, nickname)?;
+        if old_nickname == nickname {
+            return Ok(nickname);
+        }
+
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            return Ok(nickname);
+        };
+        let owned_robot_service = OwnedRobotService::new(db_manager.get_connection().clone());
+        let renamed = tauri::async_runtime::block_on(async {
+            let owned_robot = owned_robot_service
+                .get_owned_robot_by_nickname(old_nickname.clone())
+                .await
+                .map_err(|e| e.to_string())?;
+            if let Some(owned_robot) = owned_robot {
+                owned_robot_service
+                    .update_owned_robot_nickname(owned_robot.owned_robot.id, nickname.clone())
+                    .await?;
+            }
+            Ok::<_, String>(())
+        });
+        if let Err(e) = renamed {
+            let _ = KioskPairingService::set_kiosk_nickname(&pairing_state, &old_nickname);
+            return Err(e);
+        }
+        Ok(nickname)
+    }
+
+    /// Joining a client network tears down the AP the phone is connected to, so the
+    /// response is sent first and the switch happens shortly after.
+    fn connect_after_response(request: WiFiConnectRequest) {
+        thread::spawn(move || {
+            thread::sleep(CONNECT_DELAY);
+            match WiFiService::backend().connect(&request) {
+                Ok(message) => println!("[ProvisioningPortal] {}", message),
+                Err(e) => eprintln!("[ProvisioningPortal] WiFi connect failed: {}", e),
+            }
+        });
+    }
+
+    fn read_request(stream: &mut TcpStream) -> Result<PortalRequest, String> {
+        let mut buffer = Vec::new();
+        let mut chunk = [0_u8; 2048];
+        loop {
+            let read = stream
+                .read(&mut chunk)
+                .map_err(|e| format!("Failed to read request: {}", e))?;
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if buffer.len() > MAX_REQUEST_BYTES {
+                return Err("Request too large".to_string());
+            }
+            if let Some(request) = parse_request(&buffer)? {
+                return Ok(request);
+            }
+        }
+        parse_request(&buffer)?.ok_or("Incomplete request".to_string())
+    }
+
+    fn write_json(
+        stream: &mut TcpStream,
+        status: u16,
+        body: &serde_json::Value,
+    ) -> Result<(), String> {
+        let body = serde_json::to_vec(body).map_err(|e| e.to_string())?;
+        Self::write_response(stream, status, "application/json", &body)
+    }
+
+    fn write_response(
+        stream: &mut TcpStream,
+        status: u16,
+        content_type: &str,
+        body: &[u8],
+    ) -> Result<(), String> {
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            _ => "Error",
+        };
+        let header = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+            status,
+            reason,
+            content_type,
+            body.len()
+        );
+        stream
+            .write_all(header.as_bytes())
+            .and_then(|_| stream.write_all(body))
+            .map_err(|e| format!("Failed to write response: {}", e))
+    }
+}
+
+/// Parse an HTTP/1.1 request. Returns `Ok(None)` until the headers and the full
+/// `Content-Length` body have arrived.
+pub fn parse_request(buffer: &[u8]) -> Result<Option<PortalRequest>, String> {
+    let Some(header_end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") else {
+        return Ok(None);
+    };
+    let head = std::str::from_utf8(&buffer[..header_end])
+        .map_err(|_| "Request headers are not valid UTF-8".to_string())?;
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().unwrap_or_default();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or("Missing request method".to_string())?;
+    let target = parts.next().ok_or("Missing request path".to_string())?;
+    let path = target.split('?').next().unwrap_or(target);
+
+    let mut headers = HashMap::new();
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = headers
+        .get("content-length")
+        .map(|value| {
+            value
+                .parse::<usize>()
+                .map_err(|_| "Invalid Content-Length".to_string())
+        })
+        .transpose()?
+        .unwrap_or(0);
+    if content_length > MAX_REQUEST_BYTES {
+        return Err("Request too large".to_string());
+    }
+
+    let body_start = header_end + 4;
+    if buffer.len() < body_start + content_length {
+        return Ok(None);
+    }
+
+    Ok(Some(PortalRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        body: buffer[body_start..body_start + content_length].to_vec(),
+    }))
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    left.iter()
+        .zip(right.iter())
+        .fold(0_u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// Six uniformly distributed digits: draws at or above the largest multiple of
+/// a million below 2^32 are rejected so no PIN is more likely than another.
+fn generate_pin() -> Result<String, String> {
+    const LIMIT: u32 = u32::MAX - u32::MAX % 1_000_000;
+    let rng = SystemRandom::new();
+    loop {
+        let mut bytes = [0_u8; 4];
+        rng.fill(&mut bytes)
+            .map_err(|_| "Failed to generate provisioning PIN".to_string())?;
+        let value = u32::from_le_bytes(bytes);
+        if value < LIMIT {
+            return Ok(format!("{:06}", value % 1_000_000));
+        }
+    }
+}
+
+fn generate_token() -> Result<String, String> {
+    let mut bytes = [0_u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| "Failed to generate session token".to_string())?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+#[cfg(test)]
+#[path = "tests/provisioning_portal_service_tests.rs"]
+mod provisioning_portal_service_tests;
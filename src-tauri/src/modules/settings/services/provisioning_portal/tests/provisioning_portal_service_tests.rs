@@ -0,0 +1,173 @@
+use super::{
+    constant_time_eq, generate_pin, generate_token, parse_request, ProvisioningPortalAuth,
+    FREE_LOGIN_ATTEMPTS, LOGIN_BACKOFF_BASE, MAX_FAILED_LOGINS,
+};
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::{Duration, Instant};
+
+fn client(last: u8) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(10, 42, 0, last))
+}
+
+#[test]
+fn parse_request_waits_for_full_body() {
+    let partial =
+        b"POST /api/session HTTP/1.1\r\nHost: 10.42.0.1\r\nContent-Length: 10\r\n\r\n{\"pin\"";
+    assert_eq!(parse_request(partial).unwrap(), None);
+
+    let complete = b"POST /api/session?x=1 HTTP/1.1\r\nContent-Length: 2\r\nAuthorization: Bearer abc\r\n\r\n{}";
+    let request = parse_request(complete).unwrap().expect("complete request");
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/api/session");
+    assert_eq!(request.body, b"{}");
+    assert_eq!(request.bearer_token(), Some("abc"));
+}
+
+#[test]
+fn parse_request_rejects_oversized_body() {
+    let request = b"POST / HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n";
+    assert!(parse_request(request).is_err());
+}
+
+#[test]
+fn login_requires_password_and_pin_and_pin_is_one_time() {
+    let now = Instant::now();
+    let mut auth = ProvisioningPortalAuth::new("ap-password".to_string(), "123456".to_string());
+
+    assert!(auth
+        .login(client(2), "wrong", "123456", "000000".to_string(), now)
+        .is_err());
+    assert!(auth
+        .login(
+            client(2),
+            "ap-password",
+            "654321",
+            "000000".to_string(),
+            now
+        )
+        .is_err());
+
+    let token = auth
+        .login(
+            client(2),
+            "ap-password",
+            "123456",
+            "222222".to_string(),
+            now,
+        )
+        .expect("valid login");
+    assert_eq!(auth.pin(), "222222");
+    assert!(auth.authorize(Some(&token), now));
+
+    assert!(auth
+        .login(
+            client(2),
+            "ap-password",
+            "123456",
+            "333333".to_string(),
+            now
+        )
+        .is_err());
+}
+
+#[test]
+fn repeated_failures_rotate_pin() {
+    let now = Instant::now();
+    let mut auth = ProvisioningPortalAuth::new("ap-password".to_string(), "123456".to_string());
+
+    // Rotation is global, so spreading guesses over clients does not avoid it
+    for attempt in 0..MAX_FAILED_LOGINS {
+        let _ = auth.login(
+            client(10 + attempt as u8),
+            "ap-password",
+            "999999",
+            "424242".to_string(),
+            now,
+        );
+    }
+    assert_eq!(auth.pin(), "424242");
+    assert!(auth
+        .login(
+            client(2),
+            "ap-password",
+            "123456",
+            "111111".to_string(),
+            now
+        )
+        .is_err());
+}
+
+#[test]
+fn failing_clients_are_locked_out_with_exponential_backoff() {
+    let now = Instant::now();
+    let mut auth = ProvisioningPortalAuth::new("ap-password".to_string(), "123456".to_string());
+    let guess = |auth: &mut ProvisioningPortalAuth, pin: &str, at: Instant| {
+        auth.login(client(2), "ap-password", pin, "123456".to_string(), at)
+    };
+
+    for _ in 0..FREE_LOGIN_ATTEMPTS {
+        assert_eq!(
+            guess(&mut auth, "999999", now).unwrap_err(),
+            "Invalid password or PIN"
+        );
+    }
+    // Locked: even the right PIN is refused, other clients are not
+    let locked = guess(&mut auth, "123456", now).unwrap_err();
+    assert!(locked.contains("try again in 30 seconds"), "{}", locked);
+    assert!(auth
+        .login(
+            client(3),
+            "ap-password",
+            "000000",
+            "123456".to_string(),
+            now
+        )
+        .is_err_and(|e| e == "Invalid password or PIN"));
+
+    // Each further failure doubles the wait
+    let after_first = now + LOGIN_BACKOFF_BASE;
+    assert!(guess(&mut auth, "999999", after_first).is_err());
+    let locked = guess(&mut auth, "123456", after_first + LOGIN_BACKOFF_BASE).unwrap_err();
+    assert!(locked.contains("try again in 30 seconds"), "{}", locked);
+
+    let token = guess(&mut auth, "123456", after_first + LOGIN_BACKOFF_BASE * 2)
+        .expect("lockout should expire");
+    assert!(auth.authorize(Some(&token), after_first + LOGIN_BACKOFF_BASE * 2));
+}
+
+#[test]
+fn pins_and_tokens_come_from_the_system_rng() {
+    let pin = generate_pin().unwrap();
+    assert_eq!(pin.len(), 6);
+    assert!(pin.chars().all(|c| c.is_ascii_digit()));
+
+    let token = generate_token().unwrap();
+    assert_eq!(token.len(), 64);
+    assert_ne!(token, generate_token().unwrap());
+}
+
+#[test]
+fn sessions_expire_and_unknown_tokens_are_rejected() {
+    let now = Instant::now();
+    let mut auth = ProvisioningPortalAuth::new("ap-password".to_string(), "123456".to_string());
+    let token = auth
+        .login(
+            client(2),
+            "ap-password",
+            "123456",
+            "222222".to_string(),
+            now,
+        )
+        .unwrap();
+
+    assert!(!auth.authorize(None, now));
+    assert!(!auth.authorize(Some("not-a-token"), now));
+    assert!(!auth.authorize(Some(&token), now + Duration::from_secs(31 * 60)));
+}
+
+#[test]
+fn constant_time_eq_compares_contents_and_length() {
+    assert!(constant_time_eq(b"abc", b"abc"));
+    assert!(!constant_time_eq(b"abc", b"abd"));
+    assert!(!constant_time_eq(b"abc", b"abcd"));
+}
@@ -1 +1,11 @@
+#[cfg(target_os = "windows")]
+pub mod netsh_backend;
+#[cfg(target_os = "linux")]
+pub mod network_manager_backend;
+#[cfg(target_os = "macos")]
+pub mod networksetup_backend;
+#[cfg(target_os = "linux")]
+pub mod nmcli_backend;
+pub mod wifi_backend;
 pub mod wifi_service;
+pub mod wifi_signal_service;
@@ -0,0 +1,249 @@
+use crate::modules::settings::services::wifi::wifi_backend::{
+    WiFiConnectRequest, WiFiNetwork, WifiBackend,
+};
+use std::process::Command;
+
+/// Windows backend built on `netsh wlan`.
+pub struct NetshBackend;
+
+impl WifiBackend for NetshBackend {
+    fn name(&self) -> &'static str {
+        "netsh"
+    }
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String> {
+        scan_wifi_windows()
+    }
+
+    fn connect(&self, request: &WiFiConnectRequest) -> Result<String, String> {
+        connect_wifi_windows(
+            request.ssid.clone(),
+            request.password.clone(),
+            request.security.clone(),
+        )
+    }
+
+    fn current_connection(&self) -> Result<Option<WiFiNetwork>, String> {
+        get_current_wifi_windows()
+    }
+
+    fn disconnect(&self) -> Result<String, String> {
+        disconnect_wifi_windows()
+    }
+}
+
+fn scan_wifi_windows() -> Result<Vec<WiFiNetwork>, String> {
+    // Use netsh to scan for networks
+    let output = Command::new("netsh")
+        .args(&["wlan", "show", "networks", "mode=Bssid"])
+        .output()
+        .map_err(|e| format!("Failed to scan WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "WiFi scan failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut networks = Vec::new();
+    let mut current_ssid = String::new();
+    let mut current_signal = 0;
+    let mut current_security = String::new();
+
+    for line in stdout.lines() {
+        let line = line.trim();
+        if line.starts_with("SSID") {
+            // Save previous network if we have one
+            if !current_ssid.is_empty() {
+                networks.push(WiFiNetwork {
+                    ssid: current_ssid.clone(),
+                    signal_strength: current_signal,
+                    security: current_security.clone(),
+                });
+            }
+
+            // Start new network
+            if let Some(colon_pos) = line.find(':') {
+                current_ssid = line[colon_pos + 1..].trim().to_string();
+                current_signal = 0;
+                current_security = String::new();
+            }
+        } else if line.starts_with("Signal") {
+            if let Some(colon_pos) = line.find(':') {
+                let signal_str = line[colon_pos + 1..].trim();
+                // Extract percentage from "85%"
+                if let Some(percent_pos) = signal_str.find('%') {
+                    current_signal = signal_str[..percent_pos].parse::<i32>().unwrap_or(0);
+                }
+            }
+        } else if line.starts_with("Security") {
+            if let Some(colon_pos) = line.find(':') {
+                current_security = line[colon_pos + 1..].trim().to_string();
+            }
+        }
+    }
+
+    // Add the last network
+    if !current_ssid.is_empty() {
+        networks.push(WiFiNetwork {
+            ssid: current_ssid,
+            signal_strength: current_signal,
+            security: current_security,
+        });
+    }
+
+    // Sort by signal strength (highest first)
+    networks.sort_by(|a, b| b.signal_strength.cmp(&a.signal_strength));
+
+    Ok(networks)
+}
+
+fn connect_wifi_windows(
+    ssid: String,
+    password: String,
+    security: Option<String>,
+) -> Result<String, String> {
+    // Create a temporary XML profile for the network
+    let profile_xml = format!(
+        r#"<?xml version="1.0"?>
+<WLANProfile xmlns="http://www.microsoft.com/networking/WLAN/profile/v1">
+    <name>{}</name>
+    <SSIDConfig>
+        <SSID>
+            <name>{}</name>
+        </SSID>
+    </SSIDConfig>
+    <connectionType>ESS</connectionType>
+    <connectionMode>auto</connectionMode>
+    <MSM>
+        <security>
+            <authEncryption>
+                <authentication>WPA2PSK</authentication>
+                <encryption>AES</encryption>
+                <useOneX>false</useOneX>
+            </authEncryption>
+            <sharedKey>
+                <keyType>passPhrase</keyType>
+                <protected>false</protected>
+                <keyMaterial>{}</keyMaterial>
+            </sharedKey>
+        </security>
+    </MSM>
+</WLANProfile>"#,
+        ssid, ssid, password
+    );
+
+    println!(
+        "Connecting to WiFi: {} with password: [REDACTED] and security: {:?}",
+        ssid, security
+    );
+
+    // Write profile to temp file
+    let profile_path = format!("C:\\temp_wifi_profile_{}.xml", ssid);
+    std::fs::write(&profile_path, profile_xml)
+        .map_err(|e| format!("Failed to create WiFi profile: {}", e))?;
+
+    // Add the profile
+    let add_output = Command::new("netsh")
+        .args(&[
+            "wlan",
+            "add",
+            "profile",
+            &format!("filename={}", profile_path),
+        ])
+        .output()
+        .map_err(|e| format!("Failed to add WiFi profile: {}", e))?;
+
+    // Clean up temp file
+    let _ = std::fs::remove_file(&profile_path);
+
+    if !add_output.status.success() {
+        return Err(format!(
+            "Failed to add profile: {}",
+            String::from_utf8_lossy(&add_output.stderr)
+        ));
+    }
+
+    // Connect to the network
+    let connect_output = Command::new("netsh")
+        .args(&["wlan", "connect", &format!("name={}", ssid)])
+        .output()
+        .map_err(|e| format!("Failed to connect to WiFi: {}", e))?;
+
+    if !connect_output.status.success() {
+        return Err(format!(
+            "Connection failed: {}",
+            String::from_utf8_lossy(&connect_output.stderr)
+        ));
+    }
+
+    Ok(format!("Successfully connected to {}", ssid))
+}
+
+fn get_current_wifi_windows() -> Result<Option<WiFiNetwork>, String> {
+    // Use netsh to get current connection
+    let output = Command::new("netsh")
+        .args(&["wlan", "show", "interfaces"])
+        .output()
+        .map_err(|e| format!("Failed to get current WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut ssid = String::new();
+    let mut signal = 0;
+    let mut state = String::new();
+
+    for line in stdout.lines() {
+        let line = line.trim();
+        if line.starts_with("SSID") {
+            if let Some(colon_pos) = line.find(':') {
+                ssid = line[colon_pos + 1..].trim().to_string();
+            }
+        } else if line.starts_with("Signal") {
+            if let Some(colon_pos) = line.find(':') {
+                let signal_str = line[colon_pos + 1..].trim();
+                // Extract percentage from "85%"
+                if let Some(percent_pos) = signal_str.find('%') {
+                    signal = signal_str[..percent_pos].parse::<i32>().unwrap_or(0);
+                }
+            }
+        } else if line.starts_with("State") {
+            if let Some(colon_pos) = line.find(':') {
+                state = line[colon_pos + 1..].trim().to_string();
+            }
+        }
+    }
+
+    // Only return if we're connected
+    if state.contains("connected") && !ssid.is_empty() {
+        Ok(Some(WiFiNetwork {
+            ssid,
+            signal_strength: signal,
+            security: "Unknown".to_string(), // Windows doesn't easily provide this info
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+fn disconnect_wifi_windows() -> Result<String, String> {
+    // Use netsh to disconnect
+    let output = Command::new("netsh")
+        .args(&["wlan", "disconnect"])
+        .output()
+        .map_err(|e| format!("Failed to disconnect from WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Disconnect failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok("Successfully disconnected from WiFi".to_string())
+}
@@ -0,0 +1,600 @@
+use crate::modules::settings::services::wifi::wifi_backend::{
+    dedupe_networks_by_ssid, SavedWiFiNetwork, WiFiConnectRequest, WiFiNetwork, WifiBackend,
+};
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+
+const NM_BUS_NAME: &str = "org.freedesktop.NetworkManager";
+const NM_PATH: &str = "/org/freedesktop/NetworkManager";
+const NM_SETTINGS_PATH: &str = "/org/freedesktop/NetworkManager/Settings";
+const NM_INTERFACE: &str = "org.freedesktop.NetworkManager";
+const NM_SETTINGS_INTERFACE: &str = "org.freedesktop.NetworkManager.Settings";
+const NM_CONNECTION_INTERFACE: &str = "org.freedesktop.NetworkManager.Settings.Connection";
+const NM_DEVICE_INTERFACE: &str = "org.freedesktop.NetworkManager.Device";
+const NM_WIRELESS_INTERFACE: &str = "org.freedesktop.NetworkManager.Device.Wireless";
+const NM_ACCESS_POINT_INTERFACE: &str = "org.freedesktop.NetworkManager.AccessPoint";
+const NM_ACTIVE_CONNECTION_INTERFACE: &str = "org.freedesktop.NetworkManager.Connection.Active";
+
+const NM_DEVICE_TYPE_WIFI: u32 = 2;
+const WIRELESS_CONNECTION_TYPE: &str = "802-11-wireless";
+const WIRELESS_SECURITY_SETTING: &str = "802-11-wireless-security";
+const ENTERPRISE_SETTING: &str = "802-1x";
+
+// NMActiveConnectionState
+const ACTIVE_CONNECTION_ACTIVATING: u32 = 1;
+const ACTIVE_CONNECTION_ACTIVATED: u32 = 2;
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(45);
+const ACTIVATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// NM80211ApFlags / NM80211ApSecurityFlags
+const AP_FLAGS_PRIVACY: u32 = 0x1;
+const AP_SEC_KEY_MGMT_PSK: u32 = 0x100;
+const AP_SEC_KEY_MGMT_802_1X: u32 = 0x200;
+const AP_SEC_KEY_MGMT_SAE: u32 = 0x400;
+const AP_SEC_KEY_MGMT_OWE: u32 = 0x800;
+
+type ConnectionSettings = HashMap<String, HashMap<String, OwnedValue>>;
+
+/// Talks to NetworkManager directly over the system D-Bus.
+pub struct NetworkManagerBackend {
+    connection: Connection,
+}
+
+impl NetworkManagerBackend {
+    /// Connect to the system bus and make sure NetworkManager is running.
+    pub fn connect() -> Result<Self, String> {
+        let connection = Connection::system()
+            .map_err(|e| format!("Failed to connect to system D-Bus: {}", e))?;
+        let backend = Self { connection };
+        backend
+            .proxy(NM_PATH, NM_INTERFACE)?
+            .get_property::<String>("Version")
+            .map_err(|e| format!("NetworkManager is not available over D-Bus: {}", e))?;
+        Ok(backend)
+    }
+
+    fn proxy<'a>(&'a self, path: &'a str, interface: &'a str) -> Result<Proxy<'a>, String> {
+        Proxy::new(&self.connection, NM_BUS_NAME, path, interface)
+            .map_err(|e| format!("Failed to create NetworkManager proxy: {}", e))
+    }
+
+    fn wifi_device(&self) -> Result<OwnedObjectPath, String> {
+        let devices: Vec<OwnedObjectPath> = self
+            .proxy(NM_PATH, NM_INTERFACE)?
+            .call("GetDevices", &())
+            .map_err(|e| format!("Failed to list network devices: {}", e))?;
+
+        for device in devices {
+            let device_type = self
+                .proxy(device.as_str(), NM_DEVICE_INTERFACE)?
+                .get_property::<u32>("DeviceType")
+                .unwrap_or(0);
+            if device_type == NM_DEVICE_TYPE_WIFI {
+                return Ok(device);
+            }
+        }
+        Err("No WiFi device found".to_string())
+    }
+
+    fn read_access_point(&self, path: &str) -> Result<WiFiNetwork, String> {
+        let proxy = self.proxy(path, NM_ACCESS_POINT_INTERFACE)?;
+        let ssid = proxy
+            .get_property::<Vec<u8>>("Ssid")
+            .map_err(|e| format!("Failed to read access point SSID: {}", e))?;
+        let strength = proxy.get_property::<u8>("Strength").unwrap_or(0);
+        let flags = proxy.get_property::<u32>("Flags").unwrap_or(0);
+        let wpa_flags = proxy.get_property::<u32>("WpaFlags").unwrap_or(0);
+        let rsn_flags = proxy.get_property::<u32>("RsnFlags").unwrap_or(0);
+
+        Ok(WiFiNetwork {
+            ssid: decode_ssid(&ssid),
+            signal_strength: strength as i32,
+            security: security_label_from_flags(flags, wpa_flags, rsn_flags),
+        })
+    }
+
+    fn saved_connection_paths(&self) -> Result<Vec<OwnedObjectPath>, String> {
+        self.proxy(NM_SETTINGS_PATH, NM_SETTINGS_INTERFACE)?
+            .call("ListConnections", &())
+            .map_err(|e| format!("Failed to list saved connections: {}", e))
+    }
+
+    fn connection_settings(&self, path: &str) -> Result<ConnectionSettings, String> {
+        self.proxy(path, NM_CONNECTION_INTERFACE)?
+            .call("GetSettings", &())
+            .map_err(|e| format!("Failed to read connection settings: {}", e))
+    }
+
+    fn saved_wifi_connections(&self) -> Result<Vec<(OwnedObjectPath, SavedWiFiNetwork)>, String> {
+        let mut saved = Vec::new();
+        for path in self.saved_connection_paths()? {
+            let Ok(settings) = self.connection_settings(path.as_str()) else {
+                continue;
+            };
+            if let Some(network) = saved_network_from_settings(&settings) {
+                saved.push((path, network));
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Saved profiles for exactly these SSID bytes.
+    fn saved_connections_for_ssid(&self, ssid: &[u8]) -> Result<Vec<OwnedObjectPath>, String> {
+        let mut matching = Vec::new();
+        for path in self.saved_connection_paths()? {
+            let Ok(settings) = self.connection_settings(path.as_str()) else {
+                continue;
+            };
+            if settings_ssid(&settings).as_deref() == Some(ssid) {
+                matching.push(path);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Raw SSIDs of the access points in range.
+    fn visible_ssids(&self) -> Vec<Vec<u8>> {
+        let Ok(device) = self.wifi_device() else {
+            return Vec::new();
+        };
+        let access_points: Vec<OwnedObjectPath> = self
+            .proxy(device.as_str(), NM_WIRELESS_INTERFACE)
+            .and_then(|wireless| {
+                wireless
+                    .call("GetAllAccessPoints", &())
+                    .map_err(|e| e.to_string())
+            })
+            .unwrap_or_default();
+        access_points
+            .iter()
+            .filter_map(|path| {
+                self.proxy(path.as_str(), NM_ACCESS_POINT_INTERFACE)
+                    .ok()?
+                    .get_property::<Vec<u8>>("Ssid")
+                    .ok()
+            })
+            .collect()
+    }
+
+    /// `AddAndActivateConnection` returns as soon as activation starts; wait
+    /// for NetworkManager to either bring the connection up or give up on it.
+    fn wait_for_activation(&self, active: &OwnedObjectPath) -> Result<(), String> {
+        let started = Instant::now();
+        loop {
+            let state = self
+                .proxy(active.as_str(), NM_ACTIVE_CONNECTION_INTERFACE)
+                .ok()
+                .and_then(|proxy| proxy.get_property::<u32>("State").ok());
+            match activation_result(state) {
+                Some(true) => return Ok(()),
+                Some(false) => {
+                    return Err(
+                        "Connection failed: check the password and that the network is in range"
+                            .to_string(),
+                    )
+                }
+                None if started.elapsed() >= ACTIVATION_TIMEOUT => {
+                    return Err(format!(
+                        "Connection failed: not connected after {} seconds",
+                        ACTIVATION_TIMEOUT.as_secs()
+                    ))
+                }
+                None => thread::sleep(ACTIVATION_POLL_INTERVAL),
+            }
+        }
+    }
+
+    fn find_saved_connection(&self, id: &str) -> Result<OwnedObjectPath, String> {
+        self.saved_wifi_connections()?
+            .into_iter()
+            .find(|(_, network)| network.id == id)
+            .map(|(path, _)| path)
+            .ok_or(format!("No saved network found with id {}", id))
+    }
+
+    /// GetSettings never returns secrets, so they are fetched separately before
+    /// writing a connection back with Update; otherwise the saved PSK would be dropped.
+    fn merge_secrets(&self, path: &str, settings: &mut ConnectionSettings) {
+        let proxy = match self.proxy(path, NM_CONNECTION_INTERFACE) {
+            Ok(proxy) => proxy,
+            Err(_) => return,
+        };
+        for setting_name in [WIRELESS_SECURITY_SETTING, ENTERPRISE_SETTING] {
+            if !settings.contains_key(setting_name) {
+                continue;
+            }
+            let secrets: Result<ConnectionSettings, _> = proxy.call("GetSecrets", &(setting_name,));
+            if let Ok(secrets) = secrets {
+                for (name, values) in secrets {
+                    settings.entry(name).or_default().extend(values);
+                }
+            }
+        }
+    }
+
+    fn delete_connection(&self, path: &str) -> Result<(), String> {
+        self.proxy(path, NM_CONNECTION_INTERFACE)?
+            .call::<_, _, ()>("Delete", &())
+            .map_err(|e| format!("Failed to forget network: {}", e))
+    }
+
+    /// Delete every saved profile whose connection id is `name` (e.g. the AP "Hotspot").
+    pub fn delete_connections_named(&self, name: &str) -> Result<(), String> {
+        for path in self.saved_connection_paths()? {
+            let Ok(settings) = self.connection_settings(path.as_str()) else {
+                continue;
+            };
+            let id = settings
+                .get("connection")
+                .and_then(|connection| connection.get("id"))
+                .and_then(value_string);
+            if id.as_deref() == Some(name) {
+                self.delete_connection(path.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn activate_connection(&self, path: &OwnedObjectPath) -> Result<(), String> {
+        let device = self.wifi_device()?;
+        let specific_object =
+            OwnedObjectPath::try_from("/").map_err(|e| format!("Invalid object path: {}", e))?;
+        self.proxy(NM_PATH, NM_INTERFACE)?
+            .call::<_, _, OwnedObjectPath>("ActivateConnection", &(path, &device, &specific_object))
+            .map_err(|e| format!("Connection failed: {}", e))?;
+        Ok(())
+    }
+}
+
+impl WifiBackend for NetworkManagerBackend {
+    fn name(&self) -> &'static str {
+        "NetworkManager"
+    }
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String> {
+        let device = self.wifi_device()?;
+        let wireless = self.proxy(device.as_str(), NM_WIRELESS_INTERFACE)?;
+
+        // NetworkManager rejects scans requested too soon after the previous one;
+        // the cached list is still fresh enough in that case.
+        let options: HashMap<&str, Value> = HashMap::new();
+        let _ = wireless.call::<_, _, ()>("RequestScan", &(options,));
+
+        let access_points: Vec<OwnedObjectPath> = wireless
+            .call("GetAllAccessPoints", &())
+            .map_err(|e| format!("WiFi scan failed: {}", e))?;
+
+        let networks = access_points
+            .iter()
+            .filter_map(|path| self.read_access_point(path.as_str()).ok())
+            .collect();
+        Ok(dedupe_networks_by_ssid(networks))
+    }
+
+    fn connect(&self, request: &WiFiConnectRequest) -> Result<String, String> {
+        request.validate()?;
+
+        let ssid = ssid_bytes_for(&request.ssid, &self.visible_ssids());
+        let previous = self.saved_connections_for_ssid(&ssid)?;
+        let device = self.wifi_device()?;
+        let specific_object =
+            OwnedObjectPath::try_from("/").map_err(|e| format!("Invalid object path: {}", e))?;
+        let settings = build_connection_settings(request, &ssid);
+
+        let (connection, active) = self
+            .proxy(NM_PATH, NM_INTERFACE)?
+            .call::<_, _, (OwnedObjectPath, OwnedObjectPath)>(
+                "AddAndActivateConnection",
+                &(settings, &device, &specific_object),
+            )
+            .map_err(|e| format!("Connection failed: {}", e))?;
+
+        // A mistyped password or an out-of-range network must not cost the
+        // credentials that worked before, so they are only replaced on success.
+        if let Err(e) = self.wait_for_activation(&active) {
+            let _ = self.delete_connection(connection.as_str());
+            return Err(e);
+        }
+        for path in previous {
+            if path != connection {
+                let _ = self.delete_connection(path.as_str());
+            }
+        }
+
+        Ok(format!("Successfully connected to {}", request.ssid))
+    }
+
+    fn current_connection(&self) -> Result<Option<WiFiNetwork>, String> {
+        let device = self.wifi_device()?;
+        let active_access_point = self
+            .proxy(device.as_str(), NM_WIRELESS_INTERFACE)?
+            .get_property::<OwnedObjectPath>("ActiveAccessPoint")
+            .map_err(|e| format!("Failed to get current WiFi: {}", e))?;
+
+        if active_access_point.as_str() == "/" {
+            return Ok(None);
+        }
+        Ok(self.read_access_point(active_access_point.as_str()).ok())
+    }
+
+    fn disconnect(&self) -> Result<String, String> {
+        let device = self.wifi_device()?;
+        self.proxy(device.as_str(), NM_DEVICE_INTERFACE)?
+            .call::<_, _, ()>("Disconnect", &())
+            .map_err(|e| format!("Disconnect failed: {}", e))?;
+        Ok("Successfully disconnected from WiFi".to_string())
+    }
+
+    fn list_saved_networks(&self) -> Result<Vec<SavedWiFiNetwork>, String> {
+        let mut saved: Vec<SavedWiFiNetwork> = self
+            .saved_wifi_connections()?
+            .into_iter()
+            .map(|(_, network)| network)
+            .collect();
+        saved.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.name.cmp(&b.name)));
+        Ok(saved)
+    }
+
+    fn forget_saved_network(&self, id: &str) -> Result<(), String> {
+        let path = self.find_saved_connection(id)?;
+        self.delete_connection(path.as_str())
+    }
+
+    fn set_saved_network_priority(&self, id: &str, priority: i32) -> Result<(), String> {
+        let path = self.find_saved_connection(id)?;
+        let mut settings = self.connection_settings(path.as_str())?;
+        self.merge_secrets(path.as_str(), &mut settings);
+
+        settings
+            .entry("connection".to_string())
+            .or_default()
+            .insert(
+                "autoconnect-priority".to_string(),
+                OwnedValue::from(priority),
+            );
+
+        // The deprecated address/route formats are echoed back by GetSettings but
+        // rejected when both old and new representations are present.
+        for ip_setting in ["ipv4", "ipv6"] {
+            if let Some(values) = settings.get_mut(ip_setting) {
+                values.remove("addresses");
+                values.remove("routes");
+            }
+        }
+
+        self.proxy(path.as_str(), NM_CONNECTION_INTERFACE)?
+            .call::<_, _, ()>("Update", &(settings,))
+            .map_err(|e| format!("Failed to update network priority: {}", e))?;
+        Ok(())
+    }
+
+    fn activate_saved_network(&self, ssid: &str) -> Result<String, String> {
+        let (path, _) = self
+            .saved_wifi_connections()?
+            .into_iter()
+            .filter(|(_, network)| network.ssid == ssid)
+            .max_by_key(|(_, network)| network.priority)
+            .ok_or(format!("No saved network found for {}", ssid))?;
+        self.activate_connection(&path)?;
+        Ok(format!("Successfully connected to {}", ssid))
+    }
+}
+
+fn build_connection_settings<'a>(
+    request: &'a WiFiConnectRequest,
+    ssid: &[u8],
+) -> HashMap<&'static str, HashMap<&'static str, Value<'a>>> {
+    let mut settings: HashMap<&'static str, HashMap<&'static str, Value>> = HashMap::new();
+    let security = request.security.as_deref().unwrap_or("");
+
+    let mut connection: HashMap<&'static str, Value> = HashMap::new();
+    connection.insert("id", Value::from(request.ssid.as_str()));
+    connection.insert("type", Value::from(WIRELESS_CONNECTION_TYPE));
+    connection.insert("autoconnect", Value::from(true));
+    if let Some(priority) = request.priority {
+        connection.insert("autoconnect-priority", Value::from(priority));
+    }
+    settings.insert("connection", connection);
+
+    let mut wireless: HashMap<&'static str, Value> = HashMap::new();
+    wireless.insert("ssid", Value::from(ssid.to_vec()));
+    wireless.insert("mode", Value::from("infrastructure"));
+    if request.hidden {
+        wireless.insert("hidden", Value::from(true));
+    }
+    settings.insert(WIRELESS_CONNECTION_TYPE, wireless);
+
+    let mut wireless_security: HashMap<&'static str, Value> = HashMap::new();
+    if request.is_enterprise() {
+        wireless_security.insert("key-mgmt", Value::from("wpa-eap"));
+
+        if let Some(credentials) = request.enterprise.as_ref() {
+            let mut enterprise: HashMap<&'static str, Value> = HashMap::new();
+            enterprise.insert("eap", Value::from(vec!["peap"]));
+            enterprise.insert("phase2-auth", Value::from("mschapv2"));
+            enterprise.insert("identity", Value::from(credentials.identity.as_str()));
+            enterprise.insert("password", Value::from(credentials.password.as_str()));
+            if let Some(anonymous_identity) = credentials.anonymous_identity.as_deref() {
+                enterprise.insert("anonymous-identity", Value::from(anonymous_identity));
+            }
+            if let Some(ca_cert_path) = credentials.ca_cert_path.as_deref() {
+                // NetworkManager expects certificate paths as a NUL-terminated file:// URI
+                let mut uri = format!("file://{}", ca_cert_path).into_bytes();
+                uri.push(0);
+                enterprise.insert("ca-cert", Value::from(uri));
+            }
+            settings.insert(ENTERPRISE_SETTING, enterprise);
+        }
+    } else if security.contains("WEP") {
+        wireless_security.insert("key-mgmt", Value::from("none"));
+        wireless_security.insert("wep-key-type", Value::from(1u32));
+        wireless_security.insert("wep-key0", Value::from(request.password.as_str()));
+    } else if request.is_owe() {
+        wireless_security.insert("key-mgmt", Value::from("owe"));
+    } else if !request.is_open() {
+        let key_mgmt = if security.contains("WPA3") && !security.contains("WPA2") {
+            "sae"
+        } else {
+            "wpa-psk"
+        };
+        wireless_security.insert("key-mgmt", Value::from(key_mgmt));
+        wireless_security.insert("psk", Value::from(request.password.as_str()));
+    }
+    if !wireless_security.is_empty() {
+        settings.insert(WIRELESS_SECURITY_SETTING, wireless_security);
+    }
+
+    let mut ipv4: HashMap<&'static str, Value> = HashMap::new();
+    ipv4.insert("method", Value::from("auto"));
+    settings.insert("ipv4", ipv4);
+    let mut ipv6: HashMap<&'static str, Value> = HashMap::new();
+    ipv6.insert("method", Value::from("auto"));
+    settings.insert("ipv6", ipv6);
+
+    settings
+}
+
+fn saved_network_from_settings(settings: &ConnectionSettings) -> Option<SavedWiFiNetwork> {
+    let connection = settings.get("connection")?;
+    if value_string(connection.get("type")?)? != WIRELESS_CONNECTION_TYPE {
+        return None;
+    }
+    let wireless = settings.get(WIRELESS_CONNECTION_TYPE);
+    let key_mgmt = settings
+        .get(WIRELESS_SECURITY_SETTING)
+        .and_then(|security| security.get("key-mgmt"))
+        .and_then(value_string)
+        .unwrap_or_default();
+    let name = connection
+        .get("id")
+        .and_then(value_string)
+        .unwrap_or_default();
+
+    Some(SavedWiFiNetwork {
+        id: connection.get("uuid").and_then(value_string)?,
+        ssid: settings_ssid(settings)
+            .map(|ssid| decode_ssid(&ssid))
+            .unwrap_or_else(|| name.clone()),
+        name,
+        security: security_from_key_mgmt(&key_mgmt),
+        hidden: wireless
+            .and_then(|wireless| wireless.get("hidden"))
+            .and_then(value_bool)
+            .unwrap_or(false),
+        autoconnect: connection
+            .get("autoconnect")
+            .and_then(value_bool)
+            .unwrap_or(true),
+        priority: connection
+            .get("autoconnect-priority")
+            .and_then(value_i32)
+            .unwrap_or(0),
+    })
+}
+
+fn settings_ssid(settings: &ConnectionSettings) -> Option<Vec<u8>> {
+    settings
+        .get(WIRELESS_CONNECTION_TYPE)?
+        .get("ssid")
+        .and_then(value_bytes)
+}
+
+/// `Some(true)` once activated, `Some(false)` once NetworkManager has given up
+/// (deactivating, deactivated, or the active connection is already gone), and
+/// `None` while it is still trying.
+fn activation_result(state: Option<u32>) -> Option<bool> {
+    match state {
+        Some(ACTIVE_CONNECTION_ACTIVATED) => Some(true),
+        Some(0) | Some(ACTIVE_CONNECTION_ACTIVATING) => None,
+        _ => Some(false),
+    }
+}
+
+/// The SSID the user picked is the lossy `decode_ssid` label; connect with the
+/// raw bytes of the visible network it came from so non-UTF-8 names still match.
+fn ssid_bytes_for(name: &str, visible: &[Vec<u8>]) -> Vec<u8> {
+    visible
+        .iter()
+        .find(|ssid| ssid.as_slice() == name.as_bytes())
+        .or_else(|| visible.iter().find(|ssid| decode_ssid(ssid) == name))
+        .cloned()
+        .unwrap_or_else(|| name.as_bytes().to_vec())
+}
+
+fn value_string(value: &OwnedValue) -> Option<String> {
+    match &**value {
+        Value::Str(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+fn value_bool(value: &OwnedValue) -> Option<bool> {
+    match &**value {
+        Value::Bool(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn value_i32(value: &OwnedValue) -> Option<i32> {
+    match &**value {
+        Value::I32(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn value_bytes(value: &OwnedValue) -> Option<Vec<u8>> {
+    match &**value {
+        Value::Array(array) => array
+            .inner()
+            .iter()
+            .map(|item| match item {
+                Value::U8(byte) => Some(*byte),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// SSIDs are raw bytes; most are UTF-8 but non-UTF-8 names are shown lossily
+/// rather than dropped.
+pub fn decode_ssid(ssid: &[u8]) -> String {
+    String::from_utf8_lossy(ssid).to_string()
+}
+
+/// Build the same security label `nmcli` prints (e.g. "WPA1 WPA2", "WPA2 802.1X").
+pub fn security_label_from_flags(flags: u32, wpa_flags: u32, rsn_flags: u32) -> String {
+    let mut labels: Vec<&str> = Vec::new();
+
+    if flags & AP_FLAGS_PRIVACY != 0 && wpa_flags == 0 && rsn_flags == 0 {
+        labels.push("WEP");
+    }
+    if wpa_flags != 0 {
+        labels.push("WPA1");
+    }
+    if rsn_flags & (AP_SEC_KEY_MGMT_PSK | AP_SEC_KEY_MGMT_802_1X) != 0 {
+        labels.push("WPA2");
+    }
+    if rsn_flags & AP_SEC_KEY_MGMT_SAE != 0 {
+        labels.push("WPA3");
+    }
+    if rsn_flags & AP_SEC_KEY_MGMT_OWE != 0 {
+        labels.push("OWE");
+    }
+    if (wpa_flags | rsn_flags) & AP_SEC_KEY_MGMT_802_1X != 0 {
+        labels.push("802.1X");
+    }
+
+    labels.join(" ")
+}
+
+pub fn security_from_key_mgmt(key_mgmt: &str) -> String {
+    crate::modules::settings::services::wifi::nmcli_backend::security_from_key_mgmt(key_mgmt)
+}
+
+#[cfg(test)]
+#[path = "tests/network_manager_backend_tests.rs"]
+mod network_manager_backend_tests;
@@ -0,0 +1,163 @@
+use crate::modules::settings::services::wifi::wifi_backend::{
+    WiFiConnectRequest, WiFiNetwork, WifiBackend,
+};
+use std::process::Command;
+
+/// macOS backend built on `airport` and `networksetup`.
+pub struct NetworkSetupBackend;
+
+impl WifiBackend for NetworkSetupBackend {
+    fn name(&self) -> &'static str {
+        "networksetup"
+    }
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String> {
+        scan_wifi_macos()
+    }
+
+    fn connect(&self, request: &WiFiConnectRequest) -> Result<String, String> {
+        connect_wifi_macos(
+            request.ssid.clone(),
+            request.password.clone(),
+            request.security.clone(),
+        )
+    }
+
+    fn current_connection(&self) -> Result<Option<WiFiNetwork>, String> {
+        get_current_wifi_macos()
+    }
+
+    fn disconnect(&self) -> Result<String, String> {
+        disconnect_wifi_macos()
+    }
+}
+
+fn scan_wifi_macos() -> Result<Vec<WiFiNetwork>, String> {
+    // Use airport utility to scan
+    let output = Command::new(
+        "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport",
+    )
+    .args(&["-s"])
+    .output()
+    .map_err(|e| format!("Failed to scan WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "WiFi scan failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut networks = Vec::new();
+
+    for line in stdout.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() >= 3 {
+            let ssid = parts[0].to_string();
+            let signal_str = parts[1];
+            let security = parts[2..].join(" ");
+
+            // Parse signal strength (it's in dBm, convert to percentage)
+            let signal = if let Ok(signal_dbm) = signal_str.parse::<i32>() {
+                // Convert dBm to percentage (rough approximation)
+                // -30 dBm = 100%, -90 dBm = 0%
+                ((signal_dbm + 90) * 100 / 60).max(0).min(100)
+            } else {
+                0
+            };
+
+            networks.push(WiFiNetwork {
+                ssid,
+                signal_strength: signal,
+                security,
+            });
+        }
+    }
+
+    // Sort by signal strength (highest first)
+    networks.sort_by(|a, b| b.signal_strength.cmp(&a.signal_strength));
+
+    Ok(networks)
+}
+
+fn connect_wifi_macos(
+    ssid: String,
+    password: String,
+    security: Option<String>,
+) -> Result<String, String> {
+    // Use networksetup to connect
+    println!(
+        "Connecting to WiFi: {} with password: [REDACTED] and security: {:?}",
+        ssid, security
+    );
+    let output = Command::new("networksetup")
+        .args(&["-setairportnetwork", "en0", &ssid, &password])
+        .output()
+        .map_err(|e| format!("Failed to connect to WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Connection failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(format!("Successfully connected to {}", ssid))
+}
+
+fn get_current_wifi_macos() -> Result<Option<WiFiNetwork>, String> {
+    // Use networksetup to get current connection
+    let output = Command::new("networksetup")
+        .args(&["-getairportnetwork", "en0"])
+        .output()
+        .map_err(|e| format!("Failed to get current WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout.trim();
+
+    // Check if we're connected (not "You are not associated with an AirPort network")
+    if line.contains("You are not associated") || line.is_empty() {
+        return Ok(None);
+    }
+
+    // Extract SSID from "Current Wi-Fi Network: NetworkName"
+    if let Some(colon_pos) = line.find(':') {
+        let ssid = line[colon_pos + 1..].trim().to_string();
+        if !ssid.is_empty() {
+            return Ok(Some(WiFiNetwork {
+                ssid,
+                signal_strength: 0, // macOS doesn't easily provide signal strength
+                security: "Unknown".to_string(),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+fn disconnect_wifi_macos() -> Result<String, String> {
+    // Use networksetup to disconnect
+    let output = Command::new("networksetup")
+        .args(&["-setairportpower", "en0", "off"])
+        .output()
+        .map_err(|e| format!("Failed to disconnect from WiFi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Disconnect failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Turn WiFi back on but disconnected
+    let _ = Command::new("networksetup")
+        .args(&["-setairportpower", "en0", "on"])
+        .output();
+
+    Ok("Successfully disconnected from WiFi".to_string())
+}
@@ -0,0 +1,399 @@
+use crate::modules::settings::services::wifi::wifi_backend::{
+    dedupe_networks_by_ssid, SavedWiFiNetwork, WiFiConnectRequest, WiFiNetwork, WifiBackend,
+};
+use std::process::{Command, Output};
+
+const WIRELESS_CONNECTION_TYPE: &str = "802-11-wireless";
+
+/// Fallback backend that drives the `nmcli` CLI. Used when NetworkManager's
+/// D-Bus API is unavailable (e.g. restricted system bus policy).
+pub struct NmcliBackend;
+
+impl NmcliBackend {
+    fn run(args: &[&str], action: &str) -> Result<Output, String> {
+        Command::new("nmcli")
+            .args(args)
+            .env("LC_ALL", "C.UTF-8")
+            .output()
+            .map_err(|e| format!("Failed to {}: {}", action, e))
+    }
+
+    fn run_checked(args: &[&str], action: &str, failure: &str) -> Result<String, String> {
+        let output = Self::run(args, action)?;
+        if !output.status.success() {
+            return Err(format!(
+                "{}: {}",
+                failure,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn wifi_device() -> Result<String, String> {
+        let stdout = Self::run_checked(
+            &["-t", "-f", "DEVICE,TYPE", "device"],
+            "get WiFi device",
+            "Failed to find WiFi device",
+        )?;
+
+        stdout
+            .lines()
+            .map(split_terse_line)
+            .find(|parts| parts.len() >= 2 && parts[1] == "wifi")
+            .map(|parts| parts[0].clone())
+            .ok_or("No WiFi device found".to_string())
+    }
+
+    fn saved_connection_details(uuid: &str) -> Result<(String, bool, String), String> {
+        let stdout = Self::run_checked(
+            &[
+                "-t",
+                "-f",
+                "802-11-wireless.ssid,802-11-wireless.hidden,802-11-wireless-security.key-mgmt",
+                "connection",
+                "show",
+                "uuid",
+                uuid,
+            ],
+            "read saved WiFi network",
+            "Failed to read saved network",
+        )?;
+
+        let mut ssid = String::new();
+        let mut hidden = false;
+        let mut key_mgmt = String::new();
+        for line in stdout.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = unescape_terse_value(value);
+            match key {
+                "802-11-wireless.ssid" => ssid = value,
+                "802-11-wireless.hidden" => hidden = value == "yes",
+                "802-11-wireless-security.key-mgmt" => key_mgmt = value,
+                _ => {}
+            }
+        }
+        Ok((ssid, hidden, key_mgmt))
+    }
+
+    fn delete_connections_for_ssid(&self, ssid: &str) -> Result<(), String> {
+        for saved in self.list_saved_networks()? {
+            if saved.ssid == ssid || saved.name == ssid {
+                let _ = Self::run(
+                    &["connection", "delete", "uuid", &saved.id],
+                    "delete WiFi network",
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WifiBackend for NmcliBackend {
+    fn name(&self) -> &'static str {
+        "nmcli"
+    }
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String> {
+        let stdout = Self::run_checked(
+            &[
+                "-t",
+                "-f",
+                "SSID,SIGNAL,SECURITY",
+                "dev",
+                "wifi",
+                "list",
+                "--rescan",
+                "auto",
+            ],
+            "scan WiFi",
+            "WiFi scan failed",
+        )?;
+        Ok(dedupe_networks_by_ssid(parse_wifi_list(&stdout)))
+    }
+
+    fn connect(&self, request: &WiFiConnectRequest) -> Result<String, String> {
+        request.validate()?;
+        let ssid = request.ssid.as_str();
+        let security = request.security.as_deref().unwrap_or("");
+
+        // Replace any existing profile for this SSID so stale credentials are not reused
+        self.delete_connections_for_ssid(ssid)?;
+
+        let priority = request.priority.map(|priority| priority.to_string());
+        let mut args: Vec<&str> = vec![
+            "connection",
+            "add",
+            "type",
+            "wifi",
+            "con-name",
+            ssid,
+            "ssid",
+            ssid,
+            "connection.autoconnect",
+            "yes",
+        ];
+        if let Some(priority) = priority.as_deref() {
+            args.extend(["connection.autoconnect-priority", priority]);
+        }
+        if request.hidden {
+            args.extend(["802-11-wireless.hidden", "yes"]);
+        }
+
+        if request.is_enterprise() {
+            let credentials = request
+                .enterprise
+                .as_ref()
+                .ok_or("Enterprise networks require an identity and password".to_string())?;
+            args.extend([
+                "wifi-sec.key-mgmt",
+                "wpa-eap",
+                "802-1x.eap",
+                "peap",
+                "802-1x.phase2-auth",
+                "mschapv2",
+                "802-1x.identity",
+                credentials.identity.as_str(),
+                "802-1x.password",
+                credentials.password.as_str(),
+            ]);
+            if let Some(anonymous_identity) = credentials.anonymous_identity.as_deref() {
+                args.extend(["802-1x.anonymous-identity", anonymous_identity]);
+            }
+            if let Some(ca_cert_path) = credentials.ca_cert_path.as_deref() {
+                args.extend(["802-1x.ca-cert", ca_cert_path]);
+            }
+        } else if security.contains("WEP") {
+            args.extend([
+                "wifi-sec.key-mgmt",
+                "none",
+                "wifi-sec.wep-key-type",
+                "key",
+                "wifi-sec.wep-key0",
+                request.password.as_str(),
+            ]);
+        } else if request.is_owe() {
+            args.extend(["wifi-sec.key-mgmt", "owe"]);
+        } else if !request.is_open() {
+            let key_mgmt = if security.contains("WPA3") && !security.contains("WPA2") {
+                "sae"
+            } else {
+                "wpa-psk"
+            };
+            args.extend([
+                "wifi-sec.key-mgmt",
+                key_mgmt,
+                "wifi-sec.psk",
+                request.password.as_str(),
+            ]);
+        }
+
+        Self::run_checked(
+            &args,
+            "create WiFi connection",
+            "Failed to create connection",
+        )?;
+        Self::run_checked(
+            &["connection", "up", "id", ssid],
+            "activate WiFi connection",
+            "Connection failed",
+        )?;
+
+        Ok(format!("Successfully connected to {}", ssid))
+    }
+
+    fn current_connection(&self) -> Result<Option<WiFiNetwork>, String> {
+        let output = Self::run(
+            &["-t", "-f", "ACTIVE,SSID,SIGNAL,SECURITY", "dev", "wifi"],
+            "get current WiFi",
+        )?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        Ok(parse_active_wifi(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    fn disconnect(&self) -> Result<String, String> {
+        let wifi_device = Self::wifi_device()?;
+        Self::run_checked(
+            &["device", "disconnect", &wifi_device],
+            "disconnect from WiFi",
+            "Disconnect failed",
+        )?;
+        Ok("Successfully disconnected from WiFi".to_string())
+    }
+
+    fn list_saved_networks(&self) -> Result<Vec<SavedWiFiNetwork>, String> {
+        let stdout = Self::run_checked(
+            &[
+                "-t",
+                "-f",
+                "NAME,UUID,TYPE,AUTOCONNECT,AUTOCONNECT-PRIORITY",
+                "connection",
+                "show",
+            ],
+            "list saved WiFi networks",
+            "Failed to list saved networks",
+        )?;
+
+        let mut saved = Vec::new();
+        for row in parse_saved_connections(&stdout) {
+            let (ssid, hidden, key_mgmt) = Self::saved_connection_details(&row.id)
+                .unwrap_or_else(|_| (row.name.clone(), false, String::new()));
+            saved.push(SavedWiFiNetwork {
+                ssid: if ssid.is_empty() {
+                    row.name.clone()
+                } else {
+                    ssid
+                },
+                hidden,
+                security: security_from_key_mgmt(&key_mgmt),
+                ..row
+            });
+        }
+        saved.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.name.cmp(&b.name)));
+        Ok(saved)
+    }
+
+    fn forget_saved_network(&self, id: &str) -> Result<(), String> {
+        Self::run_checked(
+            &["connection", "delete", "uuid", id],
+            "forget WiFi network",
+            "Failed to forget network",
+        )?;
+        Ok(())
+    }
+
+    fn set_saved_network_priority(&self, id: &str, priority: i32) -> Result<(), String> {
+        let priority = priority.to_string();
+        Self::run_checked(
+            &[
+                "connection",
+                "modify",
+                "uuid",
+                id,
+                "connection.autoconnect-priority",
+                &priority,
+            ],
+            "update WiFi network priority",
+            "Failed to update network priority",
+        )?;
+        Ok(())
+    }
+
+    fn activate_saved_network(&self, ssid: &str) -> Result<String, String> {
+        let saved = self
+            .list_saved_networks()?
+            .into_iter()
+            .find(|saved| saved.ssid == ssid)
+            .ok_or(format!("No saved network found for {}", ssid))?;
+        Self::run_checked(
+            &["connection", "up", "uuid", &saved.id],
+            "activate WiFi connection",
+            "Connection failed",
+        )?;
+        Ok(format!("Successfully connected to {}", ssid))
+    }
+}
+
+/// Split a line of `nmcli -t` output. In terse mode nmcli escapes `:` as `\:`
+/// and `\` as `\\` inside values, so a plain `split(':')` breaks on SSIDs that
+/// contain colons.
+pub fn split_terse_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some(next) => current.push(next),
+                None => current.push('\\'),
+            },
+            ':' => fields.push(std::mem::take(&mut current)),
+            _ => current.push(ch),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+pub fn unescape_terse_value(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' && matches!(chars.peek(), Some(':') | Some('\\')) {
+            if let Some(next) = chars.next() {
+                unescaped.push(next);
+            }
+        } else {
+            unescaped.push(ch);
+        }
+    }
+    unescaped
+}
+
+/// Parse `nmcli -t -f SSID,SIGNAL,SECURITY dev wifi` output.
+pub fn parse_wifi_list(stdout: &str) -> Vec<WiFiNetwork> {
+    stdout
+        .lines()
+        .map(split_terse_line)
+        .filter(|parts| parts.len() >= 3 && !parts[0].is_empty())
+        .map(|parts| WiFiNetwork {
+            ssid: parts[0].clone(),
+            signal_strength: parts[1].trim().parse::<i32>().unwrap_or(0),
+            security: parts[2].trim().to_string(),
+        })
+        .collect()
+}
+
+/// Parse `nmcli -t -f ACTIVE,SSID,SIGNAL,SECURITY dev wifi` output.
+pub fn parse_active_wifi(stdout: &str) -> Option<WiFiNetwork> {
+    stdout
+        .lines()
+        .map(split_terse_line)
+        .find(|parts| parts.len() >= 4 && parts[0] == "yes")
+        .map(|parts| WiFiNetwork {
+            ssid: parts[1].clone(),
+            signal_strength: parts[2].trim().parse::<i32>().unwrap_or(0),
+            security: parts[3].trim().to_string(),
+        })
+}
+
+/// Parse `nmcli -t -f NAME,UUID,TYPE,AUTOCONNECT,AUTOCONNECT-PRIORITY connection show`
+/// output, keeping only WiFi profiles. SSID and security are filled in separately.
+pub fn parse_saved_connections(stdout: &str) -> Vec<SavedWiFiNetwork> {
+    stdout
+        .lines()
+        .map(split_terse_line)
+        .filter(|parts| parts.len() >= 5 && parts[2] == WIRELESS_CONNECTION_TYPE)
+        .map(|parts| SavedWiFiNetwork {
+            id: parts[1].clone(),
+            name: parts[0].clone(),
+            ssid: String::new(),
+            security: String::new(),
+            hidden: false,
+            autoconnect: parts[3] == "yes",
+            priority: parts[4].trim().parse::<i32>().unwrap_or(0),
+        })
+        .collect()
+}
+
+pub fn security_from_key_mgmt(key_mgmt: &str) -> String {
+    match key_mgmt.trim() {
+        "" => "Open".to_string(),
+        "none" | "ieee8021x" => "WEP".to_string(),
+        "wpa-psk" => "WPA2".to_string(),
+        "sae" => "WPA3".to_string(),
+        "wpa-eap" | "wpa-eap-suite-b-192" => "WPA2 802.1X".to_string(),
+        "owe" => "OWE".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/nmcli_backend_tests.rs"]
+mod nmcli_backend_tests;
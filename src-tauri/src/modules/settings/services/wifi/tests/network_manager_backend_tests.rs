@@ -0,0 +1,131 @@
+use super::{
+    activation_result, build_connection_settings, decode_ssid, security_label_from_flags,
+    ssid_bytes_for,
+};
+use crate::modules::settings::services::wifi::wifi_backend::{
+    WiFiConnectRequest, WiFiEnterpriseCredentials,
+};
+use zbus::zvariant::Value;
+
+#[test]
+fn security_label_matches_nmcli_output() {
+    assert_eq!(security_label_from_flags(0, 0, 0), "");
+    assert_eq!(security_label_from_flags(0x1, 0, 0), "WEP");
+    assert_eq!(security_label_from_flags(0x1, 0, 0x188), "WPA2");
+    assert_eq!(security_label_from_flags(0x1, 0x188, 0x188), "WPA1 WPA2");
+    assert_eq!(security_label_from_flags(0x1, 0, 0x588), "WPA2 WPA3");
+    assert_eq!(security_label_from_flags(0x1, 0, 0x288), "WPA2 802.1X");
+}
+
+#[test]
+fn decode_ssid_keeps_unicode_and_tolerates_invalid_bytes() {
+    assert_eq!(decode_ssid("Café:Lab".as_bytes()), "Café:Lab");
+    assert_eq!(decode_ssid(&[0x66, 0xff, 0x6f]), "f\u{fffd}o");
+}
+
+#[test]
+fn build_connection_settings_for_hidden_psk_network() {
+    let request = WiFiConnectRequest {
+        ssid: "Robots".to_string(),
+        password: "supersecret".to_string(),
+        security: Some("WPA2".to_string()),
+        hidden: true,
+        priority: Some(20),
+        enterprise: None,
+    };
+    let settings = build_connection_settings(&request, request.ssid.as_bytes());
+
+    assert_eq!(
+        settings["connection"]["autoconnect-priority"],
+        Value::from(20)
+    );
+    assert_eq!(settings["802-11-wireless"]["hidden"], Value::from(true));
+    assert_eq!(
+        settings["802-11-wireless"]["ssid"],
+        Value::from(b"Robots".to_vec())
+    );
+    assert_eq!(
+        settings["802-11-wireless-security"]["key-mgmt"],
+        Value::from("wpa-psk")
+    );
+    assert!(!settings.contains_key("802-1x"));
+}
+
+#[test]
+fn build_connection_settings_for_peap_mschapv2() {
+    let request = WiFiConnectRequest {
+        ssid: "Campus".to_string(),
+        security: Some("WPA2 802.1X".to_string()),
+        enterprise: Some(WiFiEnterpriseCredentials {
+            identity: "student".to_string(),
+            password: "hunter2".to_string(),
+            anonymous_identity: Some("anonymous".to_string()),
+            ca_cert_path: None,
+        }),
+        ..Default::default()
+    };
+    let settings = build_connection_settings(&request, request.ssid.as_bytes());
+
+    assert_eq!(
+        settings["802-11-wireless-security"]["key-mgmt"],
+        Value::from("wpa-eap")
+    );
+    assert_eq!(settings["802-1x"]["eap"], Value::from(vec!["peap"]));
+    assert_eq!(settings["802-1x"]["phase2-auth"], Value::from("mschapv2"));
+    assert_eq!(settings["802-1x"]["identity"], Value::from("student"));
+}
+
+#[test]
+fn build_connection_settings_for_open_network_has_no_security() {
+    let request = WiFiConnectRequest {
+        ssid: "Guest".to_string(),
+        ..Default::default()
+    };
+    let settings = build_connection_settings(&request, request.ssid.as_bytes());
+
+    assert!(!settings.contains_key("802-11-wireless-security"));
+    assert!(!settings["802-11-wireless"].contains_key("hidden"));
+}
+
+#[test]
+fn owe_network_is_open_and_uses_owe_key_management() {
+    let request = WiFiConnectRequest {
+        ssid: "Cafe".to_string(),
+        security: Some("OWE".to_string()),
+        ..Default::default()
+    };
+    assert!(request.is_open());
+    assert!(request.validate().is_ok());
+
+    let settings = build_connection_settings(&request, request.ssid.as_bytes());
+    assert_eq!(
+        settings["802-11-wireless-security"]["key-mgmt"],
+        Value::from("owe")
+    );
+    assert!(!settings["802-11-wireless-security"].contains_key("psk"));
+}
+
+#[test]
+fn connect_uses_the_raw_bytes_of_the_visible_ssid() {
+    let raw = vec![0x66, 0xff, 0x6f];
+    let visible = vec![b"Robots".to_vec(), raw.clone()];
+
+    assert_eq!(ssid_bytes_for(&decode_ssid(&raw), &visible), raw);
+    assert_eq!(ssid_bytes_for("Robots", &visible), b"Robots".to_vec());
+    assert_eq!(ssid_bytes_for("Hidden", &visible), b"Hidden".to_vec());
+
+    let request = WiFiConnectRequest {
+        ssid: decode_ssid(&raw),
+        ..Default::default()
+    };
+    let settings = build_connection_settings(&request, &raw);
+    assert_eq!(settings["802-11-wireless"]["ssid"], Value::from(raw));
+}
+
+#[test]
+fn activation_waits_while_activating_and_fails_once_deactivated() {
+    assert_eq!(activation_result(Some(1)), None);
+    assert_eq!(activation_result(Some(2)), Some(true));
+    assert_eq!(activation_result(Some(4)), Some(false));
+    assert_eq!(activation_result(None), Some(false));
+}
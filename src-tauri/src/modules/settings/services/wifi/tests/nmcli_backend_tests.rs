@@ -0,0 +1,72 @@
+use super::{
+    parse_active_wifi, parse_saved_connections, parse_wifi_list, security_from_key_mgmt,
+    split_terse_line, unescape_terse_value,
+};
+
+#[test]
+fn split_terse_line_keeps_escaped_colons_in_ssid() {
+    let fields = split_terse_line(r"Cafe\: Guest:72:WPA2");
+    assert_eq!(fields, vec!["Cafe: Guest", "72", "WPA2"]);
+}
+
+#[test]
+fn split_terse_line_unescapes_backslashes_and_keeps_empty_fields() {
+    let fields = split_terse_line(r"back\\slash::");
+    assert_eq!(fields, vec![r"back\slash", "", ""]);
+}
+
+#[test]
+fn parse_wifi_list_handles_unicode_and_colon_ssids() {
+    let stdout = "Ünïcødé 📶:81:WPA2\nlab\\:net\\:5G:64:WPA1 WPA2\n:40:\nOpenCafe:55:\n";
+    let networks = parse_wifi_list(stdout);
+
+    assert_eq!(networks.len(), 3);
+    assert_eq!(networks[0].ssid, "Ünïcødé 📶");
+    assert_eq!(networks[0].signal_strength, 81);
+    assert_eq!(networks[1].ssid, "lab:net:5G");
+    assert_eq!(networks[1].security, "WPA1 WPA2");
+    assert_eq!(networks[2].ssid, "OpenCafe");
+    assert_eq!(networks[2].security, "");
+}
+
+#[test]
+fn parse_active_wifi_returns_only_active_row() {
+    let stdout = "no:Neighbour:90:WPA2\nyes:Home\\:Office:67:WPA3\n";
+    let active = parse_active_wifi(stdout).expect("active network");
+
+    assert_eq!(active.ssid, "Home:Office");
+    assert_eq!(active.signal_strength, 67);
+    assert_eq!(active.security, "WPA3");
+    assert!(parse_active_wifi("no:Neighbour:90:WPA2\n").is_none());
+}
+
+#[test]
+fn parse_saved_connections_filters_wifi_profiles() {
+    let stdout = concat!(
+        "Wired connection 1:1111:802-3-ethernet:yes:0\n",
+        "Home\\:Office:2222:802-11-wireless:yes:10\n",
+        "Hotspot:3333:802-11-wireless:no:-5\n",
+    );
+    let saved = parse_saved_connections(stdout);
+
+    assert_eq!(saved.len(), 2);
+    assert_eq!(saved[0].name, "Home:Office");
+    assert_eq!(saved[0].id, "2222");
+    assert!(saved[0].autoconnect);
+    assert_eq!(saved[0].priority, 10);
+    assert!(!saved[1].autoconnect);
+    assert_eq!(saved[1].priority, -5);
+}
+
+#[test]
+fn unescape_terse_value_leaves_other_backslashes() {
+    assert_eq!(unescape_terse_value(r"a\:b\\c\d"), r"a:b\c\d");
+}
+
+#[test]
+fn security_from_key_mgmt_maps_known_values() {
+    assert_eq!(security_from_key_mgmt(""), "Open");
+    assert_eq!(security_from_key_mgmt("wpa-psk"), "WPA2");
+    assert_eq!(security_from_key_mgmt("sae"), "WPA3");
+    assert_eq!(security_from_key_mgmt("wpa-eap"), "WPA2 802.1X");
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WiFiNetwork {
+    pub ssid: String,
+    pub signal_strength: i32,
+    pub security: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedWiFiNetwork {
+    pub id: String,
+    pub name: String,
+    pub ssid: String,
+    pub security: String,
+    pub hidden: bool,
+    pub autoconnect: bool,
+    pub priority: i32,
+}
+
+/// WPA2-Enterprise credentials. Only PEAP with MSCHAPv2 inner authentication is supported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WiFiEnterpriseCredentials {
+    pub identity: String,
+    pub password: String,
+    pub anonymous_identity: Option<String>,
+    pub ca_cert_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WiFiConnectRequest {
+    pub ssid: String,
+    pub password: String,
+    pub security: Option<String>,
+    pub hidden: bool,
+    pub priority: Option<i32>,
+    pub enterprise: Option<WiFiEnterpriseCredentials>,
+}
+
+impl WiFiConnectRequest {
+    /// Open and OWE (Enhanced Open) networks take no password.
+    pub fn is_open(&self) -> bool {
+        self.enterprise.is_none()
+            && (self.is_owe()
+                || matches!(
+                    self.security.as_deref().map(str::trim),
+                    None | Some("") | Some("Open")
+                ))
+    }
+
+    pub fn is_owe(&self) -> bool {
+        self.enterprise.is_none()
+            && self
+                .security
+                .as_deref()
+                .map(|security| {
+                    let mut labels = security.split_whitespace().peekable();
+                    labels.peek().is_some() && labels.all(|label| label == "OWE")
+                })
+                .unwrap_or(false)
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        self.enterprise.is_some()
+            || self
+                .security
+                .as_deref()
+                .map(|security| security.contains("802.1X"))
+                .unwrap_or(false)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let ssid = self.ssid.trim();
+        if ssid.is_empty() {
+            return Err("SSID is required".to_string());
+        }
+        if ssid.len() > 32 {
+            return Err("SSID cannot be longer than 32 bytes".to_string());
+        }
+        if self.ssid.contains('\0') || self.password.contains('\0') {
+            return Err("SSID/password cannot contain null bytes".to_string());
+        }
+
+        if self.is_enterprise() {
+            let credentials = self
+                .enterprise
+                .as_ref()
+                .ok_or("Enterprise networks require an identity and password".to_string())?;
+            if credentials.identity.trim().is_empty() {
+                return Err("Enterprise identity is required".to_string());
+            }
+            if credentials.password.is_empty() {
+                return Err("Enterprise password is required".to_string());
+            }
+            return Ok(());
+        }
+
+        if self.is_open() {
+            if !self.password.is_empty() {
+                return Err("Open network does not require a password".to_string());
+            }
+            return Ok(());
+        }
+
+        if self.password.len() < 8 || self.password.len() > 63 {
+            return Err("WPA passwords must be between 8 and 63 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A platform Wi-Fi implementation. Saved-network management is optional and
+/// backends that cannot support it return an error from those methods.
+pub trait WifiBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String>;
+
+    fn connect(&self, request: &WiFiConnectRequest) -> Result<String, String>;
+
+    fn current_connection(&self) -> Result<Option<WiFiNetwork>, String>;
+
+    fn disconnect(&self) -> Result<String, String>;
+
+    fn list_saved_networks(&self) -> Result<Vec<SavedWiFiNetwork>, String> {
+        Err(format!(
+            "Saved network management is not supported by the {} backend",
+            self.name()
+        ))
+    }
+
+    fn forget_saved_network(&self, _id: &str) -> Result<(), String> {
+        Err(format!(
+            "Saved network management is not supported by the {} backend",
+            self.name()
+        ))
+    }
+
+    fn set_saved_network_priority(&self, _id: &str, _priority: i32) -> Result<(), String> {
+        Err(format!(
+            "Saved network management is not supported by the {} backend",
+            self.name()
+        ))
+    }
+
+    fn activate_saved_network(&self, _ssid: &str) -> Result<String, String> {
+        Err(format!(
+            "Saved network activation is not supported by the {} backend",
+            self.name()
+        ))
+    }
+}
+
+/// Keep the strongest entry for each SSID; scans report one row per BSSID.
+pub fn dedupe_networks_by_ssid(networks: Vec<WiFiNetwork>) -> Vec<WiFiNetwork> {
+    let mut deduped: Vec<WiFiNetwork> = Vec::with_capacity(networks.len());
+    for network in networks {
+        if network.ssid.is_empty() {
+            continue;
+        }
+        match deduped
+            .iter_mut()
+            .find(|existing| existing.ssid == network.ssid)
+        {
+            Some(existing) if existing.signal_strength < network.signal_strength => {
+                *existing = network;
+            }
+            Some(_) => {}
+            None => deduped.push(network),
+        }
+    }
+    deduped.sort_by_key(|network| std::cmp::Reverse(network.signal_strength));
+    deduped
+}
@@ -1,9 +1,105 @@
+#[cfg(target_os = "linux")]
+use crate::modules::settings::services::access_point::access_point_service::HOTSPOT_CONNECTION_NAME;
+#[cfg(target_os = "windows")]
+use crate::modules::settings::services::wifi::netsh_backend::NetshBackend;
+#[cfg(target_os = "linux")]
+use crate::modules::settings::services::wifi::network_manager_backend::NetworkManagerBackend;
+#[cfg(target_os = "macos")]
+use crate::modules::settings::services::wifi::networksetup_backend::NetworkSetupBackend;
+#[cfg(target_os = "linux")]
+use crate::modules::settings::services::wifi::nmcli_backend::NmcliBackend;
+use crate::modules::settings::services::wifi::wifi_backend::{
+    SavedWiFiNetwork, WiFiConnectRequest, WiFiNetwork, WifiBackend,
+};
 use crate::services::directory::remote_directory_service::RemoteDirectoryService;
 use std::process::Command;
 
 pub struct WiFiService;
 
 impl WiFiService {
+    /// Pick the WiFi backend for this platform. On Linux NetworkManager's D-Bus
+    /// API is preferred, with `nmcli` as the fallback when the bus is unavailable.
+    pub fn backend() -> Box<dyn WifiBackend> {
+        #[cfg(target_os = "linux")]
+        {
+            match NetworkManagerBackend::connect() {
+                Ok(backend) => Box::new(backend),
+                Err(e) => {
+                    println!("[WiFi] {}; falling back to nmcli", e);
+                    Box::new(NmcliBackend)
+                }
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Box::new(NetshBackend)
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            Box::new(NetworkSetupBackend)
+        }
+    }
+
+    async fn run_blocking<T, F>(task: F) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: FnOnce(&dyn WifiBackend) -> Result<T, String> + Send + 'static,
+    {
+        tauri::async_runtime::spawn_blocking(move || {
+            let backend = Self::backend();
+            task(backend.as_ref())
+        })
+        .await
+        .map_err(|e| format!("WiFi task failed: {}", e))?
+    }
+
+    pub async fn scan_networks() -> Result<Vec<WiFiNetwork>, String> {
+        Self::run_blocking(|backend| backend.scan_networks()).await
+    }
+
+    pub async fn connect(request: WiFiConnectRequest) -> Result<String, String> {
+        request.validate()?;
+        Self::run_blocking(move |backend| backend.connect(&request)).await
+    }
+
+    pub async fn get_current_connection() -> Result<Option<WiFiNetwork>, String> {
+        Self::run_blocking(|backend| backend.current_connection()).await
+    }
+
+    pub async fn disconnect() -> Result<String, String> {
+        Self::run_blocking(|backend| backend.disconnect()).await
+    }
+
+    pub async fn list_saved_networks() -> Result<Vec<SavedWiFiNetwork>, String> {
+        Self::run_blocking(|backend| backend.list_saved_networks()).await
+    }
+
+    pub async fn forget_saved_network(id: String) -> Result<(), String> {
+        Self::run_blocking(move |backend| backend.forget_saved_network(&id)).await
+    }
+
+    pub async fn set_saved_network_priority(id: String, priority: i32) -> Result<(), String> {
+        Self::run_blocking(move |backend| backend.set_saved_network_priority(&id, priority)).await
+    }
+
+    /// Drop the hotspot profile and bring up the saved profile for `ssid` over D-Bus.
+    /// Like the script, a failed reconnect is not an error once AP mode is off.
+    #[cfg(target_os = "linux")]
+    fn set_wifi_with_network_manager(ssid: &str) -> Result<String, String> {
+        let backend = NetworkManagerBackend::connect()?;
+        backend.delete_connections_named(HOTSPOT_CONNECTION_NAME)?;
+        match backend.activate_saved_network(ssid) {
+            Ok(_) => println!("[WiFi] Access point disabled and reconnected to {}", ssid),
+            Err(e) => println!(
+                "[WiFi] Access point disabled. Reconnect to {} failed: {}",
+                ssid, e
+            ),
+        }
+        Ok("SUCCESS".to_string())
+    }
+
     /// Disable access point mode and attempt to reconnect to saved WiFi networks
     /// This disables AP mode and automatically attempts to reconnect to previously saved WiFi networks
     /// If no saved networks are found or connection fails, AP mode is still disabled
@@ -11,6 +107,20 @@ impl WiFiService {
     pub async fn set_wifi(ssid: String) -> Result<String, String> {
         println!("[WiFi] Disabling access point mode for robot");
 
+        #[cfg(target_os = "linux")]
+        {
+            let dbus_ssid = ssid.clone();
+            let result = tauri::async_runtime::spawn_blocking(move || {
+                Self::set_wifi_with_network_manager(&dbus_ssid)
+            })
+            .await
+            .map_err(|e| format!("WiFi task failed: {}", e))?;
+            match result {
+                Ok(status) => return Ok(status),
+                Err(e) => println!("[WiFi] {}; falling back to set_wifi.py", e),
+            }
+        }
+
         // Get the working directory on the robot (local path)
         let working_dir = RemoteDirectoryService::get_sourccey_desktop_root()?;
 
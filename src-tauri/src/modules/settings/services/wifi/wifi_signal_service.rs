@@ -0,0 +1,91 @@
+use crate::modules::settings::services::wifi::wifi_service::WiFiService;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+pub const WIFI_SIGNAL_EVENT: &str = "wifi-signal-strength";
+const DEFAULT_INTERVAL_MS: u64 = 2000;
+const MIN_INTERVAL_MS: u64 = 500;
+
+#[derive(Clone)]
+pub struct WiFiSignalStream(Arc<Mutex<Option<Arc<AtomicBool>>>>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WiFiSignalSample {
+    pub ssid: Option<String>,
+    pub signal_strength: i32,
+    pub connected: bool,
+    pub sampled_at_ms: u64,
+}
+
+pub struct WiFiSignalService;
+
+impl WiFiSignalService {
+    pub fn init_wifi_signal_stream() -> WiFiSignalStream {
+        WiFiSignalStream(Arc::new(Mutex::new(None)))
+    }
+
+    /// Poll the current connection and emit a `wifi-signal-strength` event per sample
+    /// until stopped. Starting an already running stream is a no-op.
+    pub fn start_stream(
+        app_handle: AppHandle,
+        state: &WiFiSignalStream,
+        interval_ms: Option<u64>,
+    ) -> Result<String, String> {
+        let mut running = state
+            .0
+            .lock()
+            .map_err(|_| "WiFi signal stream state poisoned".to_string())?;
+        if running.is_some() {
+            return Ok("WiFi signal stream already running".to_string());
+        }
+
+        let interval = Duration::from_millis(
+            interval_ms
+                .unwrap_or(DEFAULT_INTERVAL_MS)
+                .max(MIN_INTERVAL_MS),
+        );
+        let shutdown_flag = Arc::new(AtomicBool::new(false));
+        let thread_flag = shutdown_flag.clone();
+
+        std::thread::spawn(move || {
+            let backend = WiFiService::backend();
+            while !thread_flag.load(Ordering::SeqCst) {
+                let current = backend.current_connection().unwrap_or(None);
+                let sample = WiFiSignalSample {
+                    connected: current.is_some(),
+                    signal_strength: current
+                        .as_ref()
+                        .map(|network| network.signal_strength)
+                        .unwrap_or(0),
+                    ssid: current.map(|network| network.ssid),
+                    sampled_at_ms: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|duration| duration.as_millis() as u64)
+                        .unwrap_or(0),
+                };
+                let _ = app_handle.emit(WIFI_SIGNAL_EVENT, sample);
+                std::thread::sleep(interval);
+            }
+        });
+
+        *running = Some(shutdown_flag);
+        Ok("WiFi signal stream started".to_string())
+    }
+
+    pub fn stop_stream(state: &WiFiSignalStream) -> Result<String, String> {
+        let mut running = state
+            .0
+            .lock()
+            .map_err(|_| "WiFi signal stream state poisoned".to_string())?;
+        match running.take() {
+            Some(shutdown_flag) => {
+                shutdown_flag.store(true, Ordering::SeqCst);
+                Ok("WiFi signal stream stopped".to_string())
+            }
+            None => Ok("WiFi signal stream not running".to_string()),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod controllers;
+pub mod models;
 pub mod services;
@@ -1,7 +1,33 @@
+use crate::modules::status::models::battery_sample::BatterySample;
+use crate::modules::status::services::battery::battery_monitor_service::{
+    BatteryMonitorService, BatteryMonitorState, BatteryMonitorStatus,
+};
 use crate::modules::status::services::battery::battery_service::{BatteryData, BatteryService};
-use tauri::command;
+use chrono::{DateTime, Utc};
+use tauri::{command, AppHandle, State};
+
+pub fn init_battery_monitor() -> BatteryMonitorState {
+    BatteryMonitorService::init_battery_monitor()
+}
 
 #[command]
 pub fn get_battery_data() -> Result<BatteryData, String> {
     BatteryService::get_battery_data()
 }
+
+#[command]
+pub fn get_battery_monitor_status(
+    state: State<'_, BatteryMonitorState>,
+) -> Result<BatteryMonitorStatus, String> {
+    BatteryMonitorService::get_status(&state)
+}
+
+#[command]
+pub async fn get_battery_history(
+    app_handle: AppHandle,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    limit: Option<u64>,
+) -> Result<Vec<BatterySample>, String> {
+    BatteryMonitorService::get_history(&app_handle, since, until, limit).await
+}
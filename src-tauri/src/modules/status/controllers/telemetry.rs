@@ -0,0 +1 @@
+pub mod telemetry_controller;
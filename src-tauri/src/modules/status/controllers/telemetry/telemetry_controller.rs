@@ -0,0 +1,24 @@
+use crate::modules::status::services::telemetry::telemetry_service::{
+    TelemetrySample, TelemetryService, TelemetryState,
+};
+use tauri::State;
+
+pub fn init_telemetry() -> TelemetryState {
+    TelemetryService::init_telemetry()
+}
+
+#[tauri::command]
+pub fn get_latest_telemetry(
+    state: State<'_, TelemetryState>,
+) -> Result<Option<TelemetrySample>, String> {
+    TelemetryService::get_latest(&state)
+}
+
+#[tauri::command]
+pub fn get_telemetry_history(
+    state: State<'_, TelemetryState>,
+    since_ms: Option<u64>,
+    limit: Option<usize>,
+) -> Result<Vec<TelemetrySample>, String> {
+    TelemetryService::get_history(&state, since_ms, limit)
+}
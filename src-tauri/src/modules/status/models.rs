@@ -0,0 +1 @@
+pub mod battery_sample;
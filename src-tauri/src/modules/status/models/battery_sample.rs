@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use crate::modules::status::services::battery::battery_service::BatteryData;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "battery_sample")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    // Battery Data
+    pub voltage: f64,
+    pub current_a: f64,
+    pub remaining_capacity_ah: f64,
+    pub max_capacity_ah: f64,
+    pub state_of_charge: i32,
+    pub time_to_empty_secs: Option<i64>,
+
+    // Timestamps
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// Type aliases for better readability
+pub type BatterySample = Model;
+pub type ActiveBatterySample = ActiveModel;
+pub type BatterySampleColumn = Column;
+
+// Helper methods for ActiveModel
+impl ActiveModel {
+    pub fn new(data: &BatteryData, time_to_empty_secs: Option<i64>) -> Self {
+        Self {
+            id: Set(Uuid::now_v7().to_string()),
+            voltage: Set(data.voltage),
+            current_a: Set(data.current_a),
+            remaining_capacity_ah: Set(data.remaining_capacity_ah),
+            max_capacity_ah: Set(data.max_capacity_ah),
+            state_of_charge: Set(data.state_of_charge),
+            time_to_empty_secs: Set(time_to_empty_secs),
+            recorded_at: Set(Utc::now()),
+        }
+    }
+}
@@ -1 +1,3 @@
+pub mod battery_monitor_service;
+pub mod battery_sample_service;
 pub mod battery_service;
@@ -0,0 +1,414 @@
+use crate::database::connection::DatabaseManager;
+use crate::modules::control::services::kiosk_control::kiosk_host_service::{
+    KioskHostProcess, KioskHostService,
+};
+use crate::modules::control::services::kiosk_control::manual_drive_service::KioskManualDriveProcess;
+use crate::modules::control::services::kiosk_control::torque_service::KioskTorqueService;
+use crate::modules::control::services::remote_control::remote_inference_service::{
+    RemoteInferenceProcess, RemoteInferenceService,
+};
+use crate::modules::control::services::remote_control::remote_rollout_service::{
+    RemoteRolloutProcess, RemoteRolloutService,
+};
+use crate::modules::control::services::remote_control::remote_teleop_service::{
+    RemoteTeleopProcess, RemoteTeleopService,
+};
+use crate::modules::status::models::battery_sample::BatterySample;
+use crate::modules::status::services::battery::battery_sample_service::BatterySampleService;
+use crate::modules::status::services::battery::battery_service::{BatteryData, BatteryService};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const BATTERY_LOW_EVENT: &str = "battery-low";
+pub const BATTERY_CRITICAL_EVENT: &str = "battery-critical";
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const HISTORY_RETENTION_DAYS: i64 = 7;
+const DEFAULT_LOW_PERCENT: i32 = 20;
+const DEFAULT_CRITICAL_PERCENT: i32 = 10;
+// Charge has to climb this far above a threshold before the level is cleared
+const RECOVERY_MARGIN_PERCENT: i32 = 3;
+// Below this the pack is treated as idle and no estimate is given
+const MIN_DISCHARGE_CURRENT_A: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatteryLevel {
+    Normal,
+    Low,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatteryThresholds {
+    pub low_percent: i32,
+    pub critical_percent: i32,
+}
+
+impl Default for BatteryThresholds {
+    fn default() -> Self {
+        Self {
+            low_percent: DEFAULT_LOW_PERCENT,
+            critical_percent: DEFAULT_CRITICAL_PERCENT,
+        }
+    }
+}
+
+impl BatteryThresholds {
+    /// Read `SOURCCEY_BATTERY_LOW_PERCENT` / `SOURCCEY_BATTERY_CRITICAL_PERCENT`,
+    /// keeping the defaults for missing or out-of-range values.
+    pub fn from_env() -> Self {
+        let read = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|value| value.trim().parse::<i32>().ok())
+                .filter(|value| (1..100).contains(value))
+        };
+        let defaults = Self::default();
+        let thresholds = Self {
+            low_percent: read("SOURCCEY_BATTERY_LOW_PERCENT").unwrap_or(defaults.low_percent),
+            critical_percent: read("SOURCCEY_BATTERY_CRITICAL_PERCENT")
+                .unwrap_or(defaults.critical_percent),
+        };
+        if thresholds.critical_percent >= thresholds.low_percent {
+            return defaults;
+        }
+        thresholds
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryMonitorStatus {
+    pub level: BatteryLevel,
+    pub state_of_charge: Option<i32>,
+    pub voltage: Option<f64>,
+    pub current_a: Option<f64>,
+    pub time_to_empty_secs: Option<i64>,
+    pub last_sample_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct BatteryMonitorState(Arc<Mutex<BatteryMonitorStatus>>);
+
+/// Tracks the battery level with hysteresis so a pack hovering around a threshold
+/// doesn't flap between levels. Kept free of I/O so it can be tested directly.
+pub struct BatteryLevelMonitor {
+    thresholds: BatteryThresholds,
+    level: BatteryLevel,
+}
+
+impl BatteryLevelMonitor {
+    pub fn new(thresholds: BatteryThresholds) -> Self {
+        Self {
+            thresholds,
+            level: BatteryLevel::Normal,
+        }
+    }
+
+    pub fn level(&self) -> BatteryLevel {
+        self.level
+    }
+
+    /// Returns the new level when it changes.
+    pub fn observe(&mut self, state_of_charge: i32) -> Option<BatteryLevel> {
+        let next = if state_of_charge <= self.thresholds.critical_percent {
+            BatteryLevel::Critical
+        } else if state_of_charge <= self.thresholds.low_percent {
+            match self.level {
+                BatteryLevel::Critical
+                    if state_of_charge
+                        < self.thresholds.critical_percent + RECOVERY_MARGIN_PERCENT =>
+                {
+                    BatteryLevel::Critical
+                }
+                _ => BatteryLevel::Low,
+            }
+        } else if self.level != BatteryLevel::Normal
+            && state_of_charge < self.thresholds.low_percent + RECOVERY_MARGIN_PERCENT
+        {
+            self.level.min(BatteryLevel::Low)
+        } else {
+            BatteryLevel::Normal
+        };
+
+        if next == self.level {
+            return None;
+        }
+        self.level = next;
+        Some(next)
+    }
+}
+
+pub struct BatteryMonitorService;
+
+impl BatteryMonitorService {
+    pub fn init_battery_monitor() -> BatteryMonitorState {
+        BatteryMonitorState(Arc::new(Mutex::new(BatteryMonitorStatus {
+            level: BatteryLevel::Normal,
+            state_of_charge: None,
+            voltage: None,
+            current_a: None,
+            time_to_empty_secs: None,
+            last_sample_at: None,
+            last_error: None,
+        })))
+    }
+
+    pub fn get_status(state: &BatteryMonitorState) -> Result<BatteryMonitorStatus, String> {
+        state
+            .0
+            .lock()
+            .map(|status| status.clone())
+            .map_err(|_| "Failed to lock battery monitor state".to_string())
+    }
+
+    /// Start the kiosk battery monitor. Samples are recorded to the database every
+    /// 30s; `battery-low` and `battery-critical` fire when the level drops. On
+    /// reaching critical, running teleop/rollout/inference processes are stopped and
+    /// the arms untorqued unless `SOURCCEY_BATTERY_AUTO_STOP` is `0` or `false`.
+    pub fn start(app_handle: AppHandle, state: BatteryMonitorState) {
+        let thresholds = BatteryThresholds::from_env();
+        let auto_stop = std::env::var("SOURCCEY_BATTERY_AUTO_STOP")
+            .map(|value| !matches!(value.trim().to_lowercase().as_str(), "0" | "false"))
+            .unwrap_or(true);
+
+        thread::spawn(move || {
+            let mut monitor = BatteryLevelMonitor::new(thresholds);
+            let mut last_prune: Option<Instant> = None;
+            loop {
+                match Self::read_battery() {
+                    Ok(data) => {
+                        let time_to_empty_secs = Self::estimate_time_to_empty_secs(&data);
+                        Self::record_sample(&app_handle, &data, time_to_empty_secs);
+
+                        if last_prune
+                            .map(|last| last.elapsed() >= PRUNE_INTERVAL)
+                            .unwrap_or(true)
+                        {
+                            Self::prune_history(&app_handle);
+                            last_prune = Some(Instant::now());
+                        }
+
+                        // Only a drop in level raises an event; recovery is silent
+                        let previous_level = monitor.level();
+                        let dropped_to = monitor
+                            .observe(data.state_of_charge)
+                            .filter(|level| *level > previous_level);
+                        let status =
+                            Self::update_status(&state, monitor.level(), &data, time_to_empty_secs);
+
+                        match dropped_to {
+                            Some(BatteryLevel::Low) => {
+                                println!("[BatteryMonitor] Battery low: {}%", data.state_of_charge);
+                                let _ = app_handle.emit(BATTERY_LOW_EVENT, &status);
+                            }
+                            Some(BatteryLevel::Critical) => {
+                                println!(
+                                    "[BatteryMonitor] Battery critical: {}%",
+                                    data.state_of_charge
+                                );
+                                let _ = app_handle.emit(BATTERY_CRITICAL_EVENT, &status);
+                                if auto_stop {
+                                    Self::stop_robot_activity(&app_handle);
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    Err(e) => {
+                        if let Ok(mut status) = state.0.lock() {
+                            status.last_error = Some(e);
+                        }
+                    }
+                }
+
+                thread::sleep(SAMPLE_INTERVAL);
+            }
+        });
+    }
+
+    pub async fn get_history(
+        app_handle: &AppHandle,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+        limit: Option<u64>,
+    ) -> Result<Vec<BatterySample>, String> {
+        let db_manager = app_handle
+            .try_state::<DatabaseManager>()
+            .ok_or("Database is not initialized".to_string())?;
+        BatterySampleService::new(db_manager.get_connection().clone())
+            .get_history(since, until, limit)
+            .await
+            .map_err(|e| format!("Failed to load battery history: {}", e))
+    }
+
+    /// Seconds until the pack is empty at the present draw. Discharge is reported
+    /// by the battery script as negative `current_a`; charging or idle packs have no
+    /// estimate.
+    pub fn estimate_time_to_empty_secs(data: &BatteryData) -> Option<i64> {
+        if data.error.is_some() || data.remaining_capacity_ah <= 0.0 {
+            return None;
+        }
+        let discharge_a = -data.current_a;
+        if discharge_a < MIN_DISCHARGE_CURRENT_A {
+            return None;
+        }
+        Some((data.remaining_capacity_ah / discharge_a * 3600.0).round() as i64)
+    }
+
+    fn read_battery() -> Result<BatteryData, String> {
+        let data = BatteryService::get_battery_data()?;
+        if let Some(error) = &data.error {
+            return Err(error.clone());
+        }
+        if data.state_of_charge < 0 {
+            return Err("Battery reported an invalid state of charge".to_string());
+        }
+        Ok(data)
+    }
+
+    fn update_status(
+        state: &BatteryMonitorState,
+        level: BatteryLevel,
+        data: &BatteryData,
+        time_to_empty_secs: Option<i64>,
+    ) -> BatteryMonitorStatus {
+        let status = BatteryMonitorStatus {
+            level,
+            state_of_charge: Some(data.state_of_charge),
+            voltage: Some(data.voltage),
+            current_a: Some(data.current_a),
+            time_to_empty_secs,
+            last_sample_at: Some(Utc::now()),
+            last_error: None,
+        };
+        if let Ok(mut current) = state.0.lock() {
+            *current = status.clone();
+        }
+        status
+    }
+
+    fn record_sample(app_handle: &AppHandle, data: &BatteryData, time_to_empty_secs: Option<i64>) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            return;
+        };
+        let service = BatterySampleService::new(db_manager.get_connection().clone());
+        if let Err(e) = tauri::async_runtime::block_on(service.add_sample(data, time_to_empty_secs))
+        {
+            eprintln!("[BatteryMonitor] Failed to record battery sample: {}", e);
+        }
+    }
+
+    fn prune_history(app_handle: &AppHandle) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            return;
+        };
+        let service = BatterySampleService::new(db_manager.get_connection().clone());
+        let cutoff = Utc::now() - chrono::Duration::days(HISTORY_RETENTION_DAYS);
+        if let Err(e) = tauri::async_runtime::block_on(service.delete_samples_before(cutoff)) {
+            eprintln!("[BatteryMonitor] Failed to prune battery history: {}", e);
+        }
+    }
+
+    /// Stop teleop, rollout and inference so nothing is commanding the arms, then
+    /// untorque every robot the kiosk host is serving and stop the host. The arms
+    /// drop once untorqued, but they go limp instead of stalling the motors under
+    /// load as the pack cuts out.
+    fn stop_robot_activity(app_handle: &AppHandle) {
+        let Some(db_manager) = app_handle.try_state::<DatabaseManager>() else {
+            eprintln!("[BatteryMonitor] Database is not initialized; skipping critical stop");
+            return;
+        };
+        let db_connection = db_manager.get_connection().clone();
+
+        if let Some(teleop_state) = app_handle.try_state::<RemoteTeleopProcess>() {
+            for nickname in RemoteTeleopService::active_nicknames(&teleop_state) {
+                if let Err(e) = RemoteTeleopService::stop_teleop(
+                    app_handle,
+                    db_connection.clone(),
+                    &teleop_state,
+                    nickname,
+                ) {
+                    eprintln!("[BatteryMonitor] Failed to stop teleop: {}", e);
+                }
+            }
+        }
+
+        if let Some(rollout_state) = app_handle.try_state::<RemoteRolloutProcess>() {
+            for nickname in RemoteRolloutService::active_nicknames(&rollout_state) {
+                if let Err(e) = RemoteRolloutService::stop_rollout(
+                    app_handle,
+                    db_connection.clone(),
+                    &rollout_state,
+                    nickname,
+                ) {
+                    eprintln!("[BatteryMonitor] Failed to stop rollout: {}", e);
+                }
+            }
+        }
+
+        if let Some(inference_state) = app_handle.try_state::<RemoteInferenceProcess>() {
+            for nickname in RemoteInferenceService::active_nicknames(&inference_state) {
+                if let Err(e) = RemoteInferenceService::stop_inference(
+                    app_handle,
+                    db_connection.clone(),
+                    &inference_state,
+                    nickname,
+                ) {
+                    eprintln!("[BatteryMonitor] Failed to stop inference: {}", e);
+                }
+            }
+        }
+
+        // Release the arms before stopping the host that holds their torque
+        let host_state = app_handle.state::<KioskHostProcess>();
+        let manual_drive_state = app_handle.state::<KioskManualDriveProcess>();
+        for nickname in KioskHostService::active_nicknames(&host_state) {
+            println!(
+                "[BatteryMonitor] Untorquing {} on critical battery",
+                nickname
+            );
+            if let Err(e) =
+                tauri::async_runtime::block_on(KioskTorqueService::untorque_kiosk_robot_arms(
+                    app_handle.clone(),
+                    db_connection.clone(),
+                    &host_state,
+                    &manual_drive_state,
+                    nickname.clone(),
+                ))
+            {
+                eprintln!("[BatteryMonitor] Failed to untorque arms: {}", e);
+            }
+            if let Err(e) = KioskHostService::stop_kiosk_host(
+                app_handle.clone(),
+                db_connection.clone(),
+                &host_state,
+                nickname,
+            ) {
+                eprintln!("[BatteryMonitor] Failed to stop kiosk host: {}", e);
+            }
+        }
+
+        // A host started outside the app isn't tracked in state
+        match KioskHostService::stop_external_kiosk_host_processes() {
+            Ok(pids) if !pids.is_empty() => {
+                println!(
+                    "[BatteryMonitor] Stopped external kiosk host(s): {:?}",
+                    pids
+                )
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("[BatteryMonitor] Failed to stop external kiosk host: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/battery_monitor_service_tests.rs"]
+mod battery_monitor_service_tests;
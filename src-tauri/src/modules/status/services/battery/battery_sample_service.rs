@@ -0,0 +1,70 @@
+use crate::modules::status::models::battery_sample::{
+    ActiveBatterySample, BatterySample, BatterySampleColumn, Entity as BatterySampleEntity,
+};
+use crate::modules::status::services::battery::battery_service::BatteryData;
+use chrono::{DateTime, Utc};
+use sea_orm::*;
+
+const DEFAULT_HISTORY_LIMIT: u64 = 2000;
+
+pub struct BatterySampleService {
+    connection: DatabaseConnection,
+}
+
+impl BatterySampleService {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self { connection }
+    }
+
+    //-------------------------------------------------------------------------//
+    // Record Battery Sample
+    //-------------------------------------------------------------------------//
+    pub async fn add_sample(
+        &self,
+        data: &BatteryData,
+        time_to_empty_secs: Option<i64>,
+    ) -> Result<BatterySample, DbErr> {
+        ActiveBatterySample::new(data, time_to_empty_secs)
+            .insert(&self.connection)
+            .await
+    }
+
+    //-------------------------------------------------------------------------//
+    // Get Battery History
+    //-------------------------------------------------------------------------//
+    /// Samples recorded in `[since, until]`, oldest first. Without a limit the most
+    /// recent `DEFAULT_HISTORY_LIMIT` samples in the range are returned.
+    pub async fn get_history(
+        &self,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+        limit: Option<u64>,
+    ) -> Result<Vec<BatterySample>, DbErr> {
+        let mut query = BatterySampleEntity::find();
+        if let Some(since) = since {
+            query = query.filter(BatterySampleColumn::RecordedAt.gte(since));
+        }
+        if let Some(until) = until {
+            query = query.filter(BatterySampleColumn::RecordedAt.lte(until));
+        }
+
+        let mut samples = query
+            .order_by_desc(BatterySampleColumn::RecordedAt)
+            .limit(limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+            .all(&self.connection)
+            .await?;
+        samples.reverse();
+        Ok(samples)
+    }
+
+    //-------------------------------------------------------------------------//
+    // Prune Battery History
+    //-------------------------------------------------------------------------//
+    pub async fn delete_samples_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = BatterySampleEntity::delete_many()
+            .filter(BatterySampleColumn::RecordedAt.lt(cutoff))
+            .exec(&self.connection)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
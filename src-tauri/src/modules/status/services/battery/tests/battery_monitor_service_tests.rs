@@ -0,0 +1,87 @@
+use super::{BatteryLevel, BatteryLevelMonitor, BatteryMonitorService, BatteryThresholds};
+use crate::modules::status::services::battery::battery_service::BatteryData;
+
+fn monitor() -> BatteryLevelMonitor {
+    BatteryLevelMonitor::new(BatteryThresholds {
+        low_percent: 20,
+        critical_percent: 10,
+    })
+}
+
+fn discharging(remaining_capacity_ah: f64, current_a: f64) -> BatteryData {
+    BatteryData {
+        voltage: 12.1,
+        current_a,
+        remaining_capacity_ah,
+        max_capacity_ah: 10.0,
+        state_of_charge: 50,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn level_drops_through_low_and_critical() {
+    let mut monitor = monitor();
+    assert_eq!(monitor.observe(50), None);
+    assert_eq!(monitor.observe(20), Some(BatteryLevel::Low));
+    assert_eq!(monitor.observe(15), None);
+    assert_eq!(monitor.observe(10), Some(BatteryLevel::Critical));
+    assert_eq!(monitor.observe(5), None);
+}
+
+#[test]
+fn level_recovers_only_past_margin() {
+    let mut monitor = monitor();
+    monitor.observe(9);
+    assert_eq!(monitor.level(), BatteryLevel::Critical);
+
+    // Hovering just above the critical threshold stays critical
+    assert_eq!(monitor.observe(11), None);
+    assert_eq!(monitor.observe(13), Some(BatteryLevel::Low));
+
+    assert_eq!(monitor.observe(21), None);
+    assert_eq!(monitor.observe(23), Some(BatteryLevel::Normal));
+}
+
+#[test]
+fn charging_from_critical_past_low_margin_returns_to_normal() {
+    let mut monitor = monitor();
+    monitor.observe(5);
+    assert_eq!(monitor.observe(80), Some(BatteryLevel::Normal));
+}
+
+#[test]
+fn invalid_env_thresholds_fall_back_to_defaults() {
+    std::env::set_var("SOURCCEY_BATTERY_LOW_PERCENT", "5");
+    std::env::set_var("SOURCCEY_BATTERY_CRITICAL_PERCENT", "15");
+    assert_eq!(BatteryThresholds::from_env(), BatteryThresholds::default());
+    std::env::remove_var("SOURCCEY_BATTERY_LOW_PERCENT");
+    std::env::remove_var("SOURCCEY_BATTERY_CRITICAL_PERCENT");
+}
+
+#[test]
+fn estimates_time_to_empty_while_discharging() {
+    assert_eq!(
+        BatteryMonitorService::estimate_time_to_empty_secs(&discharging(5.0, -2.0)),
+        Some(9000)
+    );
+}
+
+#[test]
+fn no_estimate_while_charging_idle_or_failed() {
+    assert_eq!(
+        BatteryMonitorService::estimate_time_to_empty_secs(&discharging(5.0, 1.5)),
+        None
+    );
+    assert_eq!(
+        BatteryMonitorService::estimate_time_to_empty_secs(&discharging(5.0, -0.01)),
+        None
+    );
+
+    let mut failed = discharging(5.0, -2.0);
+    failed.error = Some("read failed".to_string());
+    assert_eq!(
+        BatteryMonitorService::estimate_time_to_empty_secs(&failed),
+        None
+    );
+}
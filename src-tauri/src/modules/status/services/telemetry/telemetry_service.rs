@@ -0,0 +1,535 @@
+use crate::modules::status::services::battery::battery_service::{BatteryData, BatteryService};
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+pub const TELEMETRY_SAMPLE_EVENT: &str = "telemetry-sample";
+pub const TELEMETRY_ALERT_EVENT: &str = "telemetry-alert";
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+// One hour of history at the default interval
+const HISTORY_CAPACITY: usize = 720;
+const THROTTLED_SYSFS_PATH: &str = "devices/platform/soc/soc:firmware/get_throttled";
+
+/// Roots the telemetry readers resolve `/proc` and `/sys` paths against, so tests
+/// can point them at a fixture directory.
+#[derive(Debug, Clone)]
+pub struct TelemetryPaths {
+    pub proc_root: PathBuf,
+    pub sys_root: PathBuf,
+    pub disk_path: PathBuf,
+}
+
+impl Default for TelemetryPaths {
+    fn default() -> Self {
+        Self {
+            proc_root: PathBuf::from("/proc"),
+            sys_root: PathBuf::from("/sys"),
+            disk_path: PathBuf::from("/"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MemoryUsage {
+    pub total_kb: u64,
+    pub available_kb: u64,
+    pub used_percent: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DiskUsage {
+    pub total_bytes: u64,
+    pub free_bytes: u64,
+}
+
+/// Raspberry Pi firmware throttling bits (`vcgencmd get_throttled`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ThrottleFlags {
+    pub raw: u32,
+    pub under_voltage: bool,
+    pub frequency_capped: bool,
+    pub throttled: bool,
+    pub soft_temperature_limit: bool,
+    pub under_voltage_occurred: bool,
+    pub throttling_occurred: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTimes {
+    pub idle: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetrySample {
+    pub timestamp_ms: u64,
+    pub load_average: Option<LoadAverage>,
+    pub cpu_usage_percent: Option<f64>,
+    pub memory: Option<MemoryUsage>,
+    pub disk: Option<DiskUsage>,
+    pub cpu_temperature_c: Option<f64>,
+    pub throttling: Option<ThrottleFlags>,
+    pub wifi_rssi_dbm: Option<i32>,
+    pub uptime_secs: Option<f64>,
+    pub battery: Option<BatteryData>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TelemetryAlertKind {
+    Overheat,
+    LowDisk,
+    LowBattery,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetryAlert {
+    pub kind: TelemetryAlertKind,
+    pub message: String,
+    pub timestamp_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TelemetryThresholds {
+    pub overheat_c: f64,
+    pub low_disk_bytes: u64,
+    pub low_battery_percent: i32,
+}
+
+impl Default for TelemetryThresholds {
+    fn default() -> Self {
+        Self {
+            overheat_c: 80.0,
+            low_disk_bytes: 1024 * 1024 * 1024,
+            low_battery_percent: 15,
+        }
+    }
+}
+
+/// Fixed-size sample history plus the set of alerts currently raised, so an alert
+/// is emitted once when its condition starts rather than on every sample.
+pub struct TelemetryHistory {
+    samples: VecDeque<TelemetrySample>,
+    capacity: usize,
+    active_alerts: HashSet<TelemetryAlertKind>,
+}
+
+impl TelemetryHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            active_alerts: HashSet::new(),
+        }
+    }
+
+    pub fn push(&mut self, sample: TelemetrySample) {
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn latest(&self) -> Option<&TelemetrySample> {
+        self.samples.back()
+    }
+
+    /// Samples at or after `since_ms`, oldest first, keeping only the newest `limit`.
+    pub fn query(&self, since_ms: Option<u64>, limit: Option<usize>) -> Vec<TelemetrySample> {
+        let matching: Vec<&TelemetrySample> = self
+            .samples
+            .iter()
+            .filter(|sample| {
+                since_ms
+                    .map(|since| sample.timestamp_ms >= since)
+                    .unwrap_or(true)
+            })
+            .collect();
+        let skip = limit
+            .map(|limit| matching.len().saturating_sub(limit))
+            .unwrap_or(0);
+        matching.into_iter().skip(skip).cloned().collect()
+    }
+
+    /// Returns alerts that became active with this sample.
+    pub fn evaluate_alerts(
+        &mut self,
+        sample: &TelemetrySample,
+        thresholds: &TelemetryThresholds,
+    ) -> Vec<TelemetryAlert> {
+        let mut raised = Vec::new();
+        let checks = [
+            (
+                TelemetryAlertKind::Overheat,
+                sample
+                    .cpu_temperature_c
+                    .filter(|temperature| *temperature >= thresholds.overheat_c)
+                    .map(|temperature| format!("CPU temperature is {:.1}°C", temperature)),
+            ),
+            (
+                TelemetryAlertKind::LowDisk,
+                sample
+                    .disk
+                    .filter(|disk| disk.free_bytes < thresholds.low_disk_bytes)
+                    .map(|disk| {
+                        format!(
+                            "Only {} MB of disk space left",
+                            disk.free_bytes / (1024 * 1024)
+                        )
+                    }),
+            ),
+            (
+                TelemetryAlertKind::LowBattery,
+                sample
+                    .battery
+                    .as_ref()
+                    .filter(|battery| {
+                        battery.error.is_none()
+                            && battery.state_of_charge >= 0
+                            && battery.state_of_charge <= thresholds.low_battery_percent
+                    })
+                    .map(|battery| format!("Battery is at {}%", battery.state_of_charge)),
+            ),
+        ];
+
+        for (kind, message) in checks {
+            match message {
+                Some(message) => {
+                    if self.active_alerts.insert(kind) {
+                        raised.push(TelemetryAlert {
+                            kind,
+                            message,
+                            timestamp_ms: sample.timestamp_ms,
+                        });
+                    }
+                }
+                None => {
+                    self.active_alerts.remove(&kind);
+                }
+            }
+        }
+        raised
+    }
+}
+
+#[derive(Clone)]
+pub struct TelemetryState(Arc<Mutex<TelemetryHistory>>);
+
+/// Reads one sample at a time; keeps the previous `/proc/stat` totals so CPU
+/// usage can be computed as a delta between samples.
+pub struct TelemetrySampler {
+    paths: TelemetryPaths,
+    previous_cpu_times: Option<CpuTimes>,
+}
+
+impl TelemetrySampler {
+    pub fn new(paths: TelemetryPaths) -> Self {
+        Self {
+            paths,
+            previous_cpu_times: None,
+        }
+    }
+
+    pub fn sample(&mut self, battery: Option<BatteryData>, timestamp_ms: u64) -> TelemetrySample {
+        let cpu_times = TelemetryService::read_cpu_times(&self.paths.proc_root);
+        let cpu_usage_percent = match (self.previous_cpu_times, cpu_times) {
+            (Some(previous), Some(current)) => {
+                TelemetryService::cpu_usage_percent(previous, current)
+            }
+            _ => None,
+        };
+        self.previous_cpu_times = cpu_times;
+
+        TelemetrySample {
+            timestamp_ms,
+            load_average: TelemetryService::read_load_average(&self.paths.proc_root),
+            cpu_usage_percent,
+            memory: TelemetryService::read_memory(&self.paths.proc_root),
+            disk: TelemetryService::read_disk_usage(&self.paths.disk_path),
+            cpu_temperature_c: TelemetryService::read_cpu_temperature(&self.paths.sys_root)
+                .or_else(TelemetryService::read_vcgencmd_temperature),
+            throttling: TelemetryService::read_throttling(&self.paths.sys_root),
+            wifi_rssi_dbm: TelemetryService::read_wifi_rssi(&self.paths.proc_root),
+            uptime_secs: TelemetryService::read_uptime(&self.paths.proc_root),
+            battery,
+        }
+    }
+}
+
+pub struct TelemetryService;
+
+impl TelemetryService {
+    pub fn init_telemetry() -> TelemetryState {
+        TelemetryState(Arc::new(Mutex::new(TelemetryHistory::new(
+            HISTORY_CAPACITY,
+        ))))
+    }
+
+    /// Start sampling on the kiosk. Each sample is stored in the ring buffer and
+    /// emitted as `telemetry-sample`; new threshold breaches emit `telemetry-alert`.
+    pub fn start(app_handle: AppHandle, state: TelemetryState) {
+        thread::spawn(move || {
+            let mut sampler = TelemetrySampler::new(TelemetryPaths::default());
+            let thresholds = TelemetryThresholds::default();
+            loop {
+                let battery = BatteryService::get_battery_data().ok();
+                let sample = sampler.sample(battery, Self::now_ms());
+
+                let alerts = match state.0.lock() {
+                    Ok(mut history) => {
+                        let alerts = history.evaluate_alerts(&sample, &thresholds);
+                        history.push(sample.clone());
+                        alerts
+                    }
+                    Err(_) => Vec::new(),
+                };
+
+                let _ = app_handle.emit(TELEMETRY_SAMPLE_EVENT, &sample);
+                for alert in alerts {
+                    println!("[Telemetry] {}", alert.message);
+                    let _ = app_handle.emit(TELEMETRY_ALERT_EVENT, alert);
+                }
+
+                thread::sleep(SAMPLE_INTERVAL);
+            }
+        });
+    }
+
+    pub fn get_latest(state: &TelemetryState) -> Result<Option<TelemetrySample>, String> {
+        let history = state
+            .0
+            .lock()
+            .map_err(|_| "Failed to lock telemetry state".to_string())?;
+        Ok(history.latest().cloned())
+    }
+
+    pub fn get_history(
+        state: &TelemetryState,
+        since_ms: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<Vec<TelemetrySample>, String> {
+        let history = state
+            .0
+            .lock()
+            .map_err(|_| "Failed to lock telemetry state".to_string())?;
+        Ok(history.query(since_ms, limit))
+    }
+
+    pub fn read_load_average(proc_root: &Path) -> Option<LoadAverage> {
+        let content = fs::read_to_string(proc_root.join("loadavg")).ok()?;
+        let mut fields = content.split_whitespace();
+        Some(LoadAverage {
+            one: fields.next()?.parse().ok()?,
+            five: fields.next()?.parse().ok()?,
+            fifteen: fields.next()?.parse().ok()?,
+        })
+    }
+
+    pub fn read_memory(proc_root: &Path) -> Option<MemoryUsage> {
+        let content = fs::read_to_string(proc_root.join("meminfo")).ok()?;
+        let value_kb = |key: &str| -> Option<u64> {
+            content
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .and_then(|rest| rest.trim_start_matches(':').split_whitespace().next())
+                .and_then(|value| value.parse().ok())
+        };
+
+        let total_kb = value_kb("MemTotal")?;
+        let available_kb = value_kb("MemAvailable").or_else(|| value_kb("MemFree"))?;
+        if total_kb == 0 {
+            return None;
+        }
+        Some(MemoryUsage {
+            total_kb,
+            available_kb,
+            used_percent: (total_kb.saturating_sub(available_kb)) as f64 * 100.0 / total_kb as f64,
+        })
+    }
+
+    pub fn read_uptime(proc_root: &Path) -> Option<f64> {
+        fs::read_to_string(proc_root.join("uptime"))
+            .ok()?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    }
+
+    pub fn read_cpu_times(proc_root: &Path) -> Option<CpuTimes> {
+        let content = fs::read_to_string(proc_root.join("stat")).ok()?;
+        let line = content.lines().find(|line| line.starts_with("cpu "))?;
+        let values: Vec<u64> = line
+            .split_whitespace()
+            .skip(1)
+            .filter_map(|value| value.parse().ok())
+            .collect();
+        if values.len() < 4 {
+            return None;
+        }
+        // idle + iowait
+        let idle = values[3] + values.get(4).copied().unwrap_or(0);
+        Some(CpuTimes {
+            idle,
+            total: values.iter().sum(),
+        })
+    }
+
+    pub fn cpu_usage_percent(previous: CpuTimes, current: CpuTimes) -> Option<f64> {
+        let total = current.total.checked_sub(previous.total)?;
+        let idle = current.idle.checked_sub(previous.idle)?;
+        if total == 0 {
+            return None;
+        }
+        Some((total.saturating_sub(idle)) as f64 * 100.0 / total as f64)
+    }
+
+    /// Thermal zones first, then hwmon sensors for kernels without a usable
+    /// thermal zone. Pair with `read_vcgencmd_temperature` on a Pi.
+    pub fn read_cpu_temperature(sys_root: &Path) -> Option<f64> {
+        Self::read_thermal_zone_temperature(sys_root)
+            .or_else(|| Self::read_hwmon_temperature(sys_root))
+    }
+
+    /// Prefer a thermal zone whose type names the CPU/SoC; otherwise use the first
+    /// readable zone. Values are in millidegrees Celsius.
+    fn read_thermal_zone_temperature(sys_root: &Path) -> Option<f64> {
+        let thermal_dir = sys_root.join("class").join("thermal");
+        let mut zones: Vec<PathBuf> = fs::read_dir(&thermal_dir)
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| name.starts_with("thermal_zone"))
+                    .unwrap_or(false)
+            })
+            .collect();
+        zones.sort();
+
+        let read_zone = |zone: &PathBuf| -> Option<f64> {
+            let millidegrees: f64 = fs::read_to_string(zone.join("temp"))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()?;
+            Some(millidegrees / 1000.0)
+        };
+
+        zones
+            .iter()
+            .find(|zone| {
+                fs::read_to_string(zone.join("type"))
+                    .map(|zone_type| {
+                        let zone_type = zone_type.trim().to_lowercase();
+                        zone_type.contains("cpu")
+                            || zone_type.contains("soc")
+                            || zone_type == "x86_pkg_temp"
+                    })
+                    .unwrap_or(false)
+            })
+            .and_then(read_zone)
+            .or_else(|| zones.iter().find_map(read_zone))
+    }
+
+    /// First `temp1_input` under `/sys/class/hwmon`, in millidegrees Celsius.
+    fn read_hwmon_temperature(sys_root: &Path) -> Option<f64> {
+        let mut sensors: Vec<PathBuf> = fs::read_dir(sys_root.join("class").join("hwmon"))
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path().join("temp1_input"))
+            .collect();
+        sensors.sort();
+        sensors.iter().find_map(|sensor| {
+            let millidegrees: f64 = fs::read_to_string(sensor).ok()?.trim().parse().ok()?;
+            Some(millidegrees / 1000.0)
+        })
+    }
+
+    /// Raspberry Pi firmware reading, for images without a thermal driver.
+    pub fn read_vcgencmd_temperature() -> Option<f64> {
+        let output = Command::new("vcgencmd").arg("measure_temp").output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Self::parse_vcgencmd_temperature(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Parse `temp=48.3'C`.
+    pub fn parse_vcgencmd_temperature(stdout: &str) -> Option<f64> {
+        stdout
+            .trim()
+            .strip_prefix("temp=")?
+            .trim_end_matches("'C")
+            .parse()
+            .ok()
+    }
+
+    pub fn read_throttling(sys_root: &Path) -> Option<ThrottleFlags> {
+        let content = fs::read_to_string(sys_root.join(THROTTLED_SYSFS_PATH)).ok()?;
+        Self::parse_throttled(&content)
+    }
+
+    pub fn parse_throttled(value: &str) -> Option<ThrottleFlags> {
+        let value = value.trim();
+        let value = value.split('=').next_back().unwrap_or(value).trim();
+        let hex = value.trim_start_matches("0x").trim_start_matches("0X");
+        let raw = u32::from_str_radix(hex, 16).ok()?;
+        Some(ThrottleFlags {
+            raw,
+            under_voltage: raw & 0x1 != 0,
+            frequency_capped: raw & 0x2 != 0,
+            throttled: raw & 0x4 != 0,
+            soft_temperature_limit: raw & 0x8 != 0,
+            under_voltage_occurred: raw & 0x10000 != 0,
+            throttling_occurred: raw & 0x40000 != 0,
+        })
+    }
+
+    /// Signal level of the first interface in `/proc/net/wireless`, in dBm.
+    pub fn read_wifi_rssi(proc_root: &Path) -> Option<i32> {
+        let content = fs::read_to_string(proc_root.join("net").join("wireless")).ok()?;
+        content.lines().skip(2).find_map(|line| {
+            let (_, stats) = line.split_once(':')?;
+            let level = stats.split_whitespace().nth(2)?;
+            level
+                .trim_end_matches('.')
+                .parse::<f64>()
+                .ok()
+                .map(|level| level as i32)
+        })
+    }
+
+    /// Total and available space of the filesystem holding `path`.
+    pub fn read_disk_usage(path: &Path) -> Option<DiskUsage> {
+        Some(DiskUsage {
+            total_bytes: fs2::total_space(path).ok()?,
+            free_bytes: fs2::available_space(path).ok()?,
+        })
+    }
+
+    fn now_ms() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/telemetry_service_tests.rs"]
+mod telemetry_service_tests;
@@ -0,0 +1,257 @@
+use super::{
+    CpuTimes, DiskUsage, TelemetryAlertKind, TelemetryHistory, TelemetryPaths, TelemetrySample,
+    TelemetrySampler, TelemetryService, TelemetryThresholds,
+};
+use crate::modules::status::services::battery::battery_service::BatteryData;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn fixture_root(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock should be after epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("{}_{}_telemetry", name, nonce))
+}
+
+fn write_file(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().expect("fixture file should have a parent"))
+        .expect("fixture directory should be created");
+    fs::write(path, content).expect("fixture file should be written");
+}
+
+fn fixture_paths(root: &Path) -> TelemetryPaths {
+    let proc_root = root.join("proc");
+    let sys_root = root.join("sys");
+
+    write_file(&proc_root.join("loadavg"), "0.52 0.41 0.30 2/345 6789\n");
+    write_file(
+        &proc_root.join("meminfo"),
+        "MemTotal:        4000000 kB\nMemFree:          500000 kB\nMemAvailable:    1000000 kB\n",
+    );
+    write_file(&proc_root.join("uptime"), "12345.67 40000.00\n");
+    write_file(
+        &proc_root.join("stat"),
+        "cpu  100 0 100 700 100 0 0 0 0 0\ncpu0 50 0 50 350 50 0 0 0 0 0\n",
+    );
+    write_file(
+        &proc_root.join("net").join("wireless"),
+        "Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE\n face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22\n wlan0: 0000   54.  -56.  -256        0      0      0      0     15        0\n",
+    );
+
+    let thermal = sys_root.join("class").join("thermal");
+    write_file(&thermal.join("thermal_zone0").join("type"), "gpu-thermal\n");
+    write_file(&thermal.join("thermal_zone0").join("temp"), "45000\n");
+    write_file(&thermal.join("thermal_zone1").join("type"), "cpu-thermal\n");
+    write_file(&thermal.join("thermal_zone1").join("temp"), "61234\n");
+    write_file(
+        &sys_root.join("devices/platform/soc/soc:firmware/get_throttled"),
+        "50005\n",
+    );
+
+    TelemetryPaths {
+        proc_root,
+        sys_root,
+        disk_path: root.to_path_buf(),
+    }
+}
+
+fn sample_at(timestamp_ms: u64) -> TelemetrySample {
+    TelemetrySample {
+        timestamp_ms,
+        load_average: None,
+        cpu_usage_percent: None,
+        memory: None,
+        disk: None,
+        cpu_temperature_c: None,
+        throttling: None,
+        wifi_rssi_dbm: None,
+        uptime_secs: None,
+        battery: None,
+    }
+}
+
+#[test]
+fn reads_proc_and_sys_fixtures() {
+    let root = fixture_root("reads_fixtures");
+    let paths = fixture_paths(&root);
+
+    let load = TelemetryService::read_load_average(&paths.proc_root).expect("load average");
+    assert_eq!((load.one, load.five, load.fifteen), (0.52, 0.41, 0.30));
+
+    let memory = TelemetryService::read_memory(&paths.proc_root).expect("memory");
+    assert_eq!(memory.total_kb, 4_000_000);
+    assert_eq!(memory.available_kb, 1_000_000);
+    assert!((memory.used_percent - 75.0).abs() < f64::EPSILON);
+
+    assert_eq!(
+        TelemetryService::read_uptime(&paths.proc_root),
+        Some(12345.67)
+    );
+    assert_eq!(
+        TelemetryService::read_wifi_rssi(&paths.proc_root),
+        Some(-56)
+    );
+    assert_eq!(
+        TelemetryService::read_cpu_temperature(&paths.sys_root),
+        Some(61.234)
+    );
+
+    let throttling = TelemetryService::read_throttling(&paths.sys_root).expect("throttling");
+    assert!(throttling.under_voltage);
+    assert!(throttling.throttled);
+    assert!(!throttling.frequency_capped);
+    assert!(throttling.under_voltage_occurred);
+    assert!(throttling.throttling_occurred);
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn missing_sources_read_as_none() {
+    let root = fixture_root("missing_sources");
+    assert!(TelemetryService::read_load_average(&root).is_none());
+    assert!(TelemetryService::read_memory(&root).is_none());
+    assert!(TelemetryService::read_cpu_temperature(&root).is_none());
+    assert!(TelemetryService::read_throttling(&root).is_none());
+    assert!(TelemetryService::read_wifi_rssi(&root).is_none());
+}
+
+#[test]
+fn sampler_computes_cpu_usage_between_samples() {
+    let root = fixture_root("cpu_usage");
+    let paths = fixture_paths(&root);
+    let mut sampler = TelemetrySampler::new(paths.clone());
+
+    let first = sampler.sample(None, 1);
+    assert!(first.cpu_usage_percent.is_none());
+
+    write_file(
+        &paths.proc_root.join("stat"),
+        "cpu  200 0 200 1000 200 0 0 0 0 0\n",
+    );
+    let second = sampler.sample(None, 2);
+    // 600 ticks elapsed, 400 of them idle or iowait
+    let usage = second.cpu_usage_percent.expect("cpu usage");
+    assert!((usage - 100.0 / 3.0).abs() < 1e-9);
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn cpu_usage_ignores_counter_reset() {
+    let previous = CpuTimes {
+        idle: 100,
+        total: 1000,
+    };
+    let current = CpuTimes {
+        idle: 10,
+        total: 50,
+    };
+    assert!(TelemetryService::cpu_usage_percent(previous, current).is_none());
+}
+
+#[test]
+fn parses_throttled_output_formats() {
+    assert_eq!(
+        TelemetryService::parse_throttled("throttled=0x0")
+            .expect("vcgencmd format")
+            .raw,
+        0
+    );
+    let flags = TelemetryService::parse_throttled("0x2\n").expect("hex format");
+    assert!(flags.frequency_capped);
+    assert!(TelemetryService::parse_throttled("not hex").is_none());
+}
+
+#[test]
+fn reads_disk_usage_of_existing_paths() {
+    let disk = TelemetryService::read_disk_usage(&std::env::temp_dir()).expect("disk usage");
+    assert!(disk.total_bytes > 0);
+    assert!(disk.free_bytes <= disk.total_bytes);
+    assert!(TelemetryService::read_disk_usage(Path::new("/definitely/not/here")).is_none());
+}
+
+#[test]
+fn falls_back_to_hwmon_and_firmware_temperatures() {
+    let root = fixture_root("hwmon");
+    let hwmon = root.join("class").join("hwmon");
+    write_file(&hwmon.join("hwmon0").join("name"), "cpu_thermal\n");
+    write_file(&hwmon.join("hwmon1").join("temp1_input"), "52500\n");
+    assert_eq!(TelemetryService::read_cpu_temperature(&root), Some(52.5));
+
+    assert_eq!(
+        TelemetryService::parse_vcgencmd_temperature("temp=48.3'C\n"),
+        Some(48.3)
+    );
+    assert!(TelemetryService::parse_vcgencmd_temperature("error").is_none());
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn history_drops_oldest_samples_and_filters_queries() {
+    let mut history = TelemetryHistory::new(3);
+    for timestamp in 1..=5 {
+        history.push(sample_at(timestamp));
+    }
+
+    let timestamps = |samples: Vec<TelemetrySample>| -> Vec<u64> {
+        samples.iter().map(|sample| sample.timestamp_ms).collect()
+    };
+    assert_eq!(timestamps(history.query(None, None)), vec![3, 4, 5]);
+    assert_eq!(timestamps(history.query(Some(4), None)), vec![4, 5]);
+    assert_eq!(timestamps(history.query(None, Some(1))), vec![5]);
+    assert_eq!(history.latest().map(|sample| sample.timestamp_ms), Some(5));
+}
+
+#[test]
+fn alerts_fire_once_per_breach() {
+    let thresholds = TelemetryThresholds::default();
+    let mut history = TelemetryHistory::new(10);
+
+    let mut hot = sample_at(1);
+    hot.cpu_temperature_c = Some(85.0);
+    hot.disk = Some(DiskUsage {
+        total_bytes: 32 * 1024 * 1024 * 1024,
+        free_bytes: 100 * 1024 * 1024,
+    });
+    hot.battery = Some(BatteryData {
+        state_of_charge: 10,
+        ..Default::default()
+    });
+
+    let kinds: Vec<TelemetryAlertKind> = history
+        .evaluate_alerts(&hot, &thresholds)
+        .into_iter()
+        .map(|alert| alert.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TelemetryAlertKind::Overheat,
+            TelemetryAlertKind::LowDisk,
+            TelemetryAlertKind::LowBattery
+        ]
+    );
+    assert!(history.evaluate_alerts(&hot, &thresholds).is_empty());
+
+    let cooled = sample_at(2);
+    assert!(history.evaluate_alerts(&cooled, &thresholds).is_empty());
+    assert_eq!(history.evaluate_alerts(&hot, &thresholds).len(), 3);
+}
+
+#[test]
+fn battery_errors_do_not_raise_low_battery() {
+    let mut history = TelemetryHistory::new(10);
+    let mut sample = sample_at(1);
+    sample.battery = Some(BatteryData {
+        state_of_charge: 0,
+        error: Some("battery script failed".to_string()),
+        ..Default::default()
+    });
+    assert!(history
+        .evaluate_alerts(&sample, &TelemetryThresholds::default())
+        .is_empty());
+}
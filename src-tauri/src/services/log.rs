@@ -1 +1,2 @@
+pub mod log_broker_service;
 pub mod log_service;
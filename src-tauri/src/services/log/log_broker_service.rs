@@ -0,0 +1,211 @@
+use crate::services::log::log_service::{LogEntry, LogLevel};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+pub const LOG_BATCH_EVENT: &str = "log-batch";
+const FLUSH_INTERVAL: Duration = Duration::from_millis(250);
+// At most MAX_BATCH_SIZE lines per subscription every FLUSH_INTERVAL reach the webview
+const MAX_BATCH_SIZE: usize = 200;
+const SUBSCRIPTION_BUFFER_SIZE: usize = 1000;
+
+lazy_static! {
+    static ref BROKER: Mutex<LogBroker> = Mutex::new(LogBroker::new(SUBSCRIPTION_BUFFER_SIZE));
+}
+static FLUSHER_STARTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSubscriptionFilter {
+    /// e.g. `teleop`, `record`, `rollout`, `inference`; all sources when empty.
+    pub sources: Option<Vec<String>>,
+    pub nickname: Option<String>,
+    /// Minimum level to deliver.
+    pub level: Option<LogLevel>,
+}
+
+impl LogSubscriptionFilter {
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.sources
+            .as_ref()
+            .is_none_or(|sources| sources.is_empty() || sources.contains(&entry.source))
+            && self
+                .nickname
+                .as_ref()
+                .is_none_or(|nickname| entry.nickname.as_ref() == Some(nickname))
+            && self.level.is_none_or(|level| entry.level >= level)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogBatch {
+    pub subscription_id: String,
+    /// Lines discarded for this subscriber since the previous batch because
+    /// it was paused or could not keep up. They are still in the log files.
+    pub dropped: u64,
+    pub entries: Vec<LogEntry>,
+}
+
+struct Subscription {
+    /// Label of the webview that subscribed; its subscriptions go away when
+    /// it reloads or closes.
+    owner: String,
+    filter: LogSubscriptionFilter,
+    buffer: VecDeque<LogEntry>,
+    dropped: u64,
+    paused: bool,
+}
+
+pub(crate) struct LogBroker {
+    subscriptions: HashMap<String, Subscription>,
+    buffer_size: usize,
+}
+
+impl LogBroker {
+    pub(crate) fn new(buffer_size: usize) -> Self {
+        Self {
+            subscriptions: HashMap::new(),
+            buffer_size: buffer_size.max(1),
+        }
+    }
+
+    pub(crate) fn subscribe(&mut self, owner: &str, filter: LogSubscriptionFilter) -> String {
+        let id = Uuid::now_v7().to_string();
+        self.subscriptions.insert(
+            id.clone(),
+            Subscription {
+                owner: owner.to_string(),
+                filter,
+                buffer: VecDeque::new(),
+                dropped: 0,
+                paused: false,
+            },
+        );
+        id
+    }
+
+    pub(crate) fn unsubscribe(&mut self, id: &str) -> bool {
+        self.subscriptions.remove(id).is_some()
+    }
+
+    /// Drop every subscription made by `owner`; returns how many there were.
+    pub(crate) fn unsubscribe_owner(&mut self, owner: &str) -> usize {
+        let before = self.subscriptions.len();
+        self.subscriptions
+            .retain(|_, subscription| subscription.owner != owner);
+        before - self.subscriptions.len()
+    }
+
+    pub(crate) fn set_paused(&mut self, id: &str, paused: bool) -> Result<(), String> {
+        let subscription = self
+            .subscriptions
+            .get_mut(id)
+            .ok_or_else(|| format!("Log subscription {} not found", id))?;
+        subscription.paused = paused;
+        Ok(())
+    }
+
+    /// Queue `entry` for every matching subscriber, discarding that
+    /// subscriber's oldest line when its buffer is full.
+    pub(crate) fn publish(&mut self, entry: &LogEntry) {
+        for subscription in self.subscriptions.values_mut() {
+            if !subscription.filter.matches(entry) {
+                continue;
+            }
+            if subscription.buffer.len() >= self.buffer_size {
+                subscription.buffer.pop_front();
+                subscription.dropped += 1;
+            }
+            subscription.buffer.push_back(entry.clone());
+        }
+    }
+
+    /// Take up to `max_entries` queued lines from each active subscriber.
+    pub(crate) fn drain(&mut self, max_entries: usize) -> Vec<LogBatch> {
+        let mut batches = Vec::new();
+        for (id, subscription) in self.subscriptions.iter_mut() {
+            if subscription.paused || (subscription.buffer.is_empty() && subscription.dropped == 0)
+            {
+                continue;
+            }
+            let count = subscription.buffer.len().min(max_entries);
+            batches.push(LogBatch {
+                subscription_id: id.clone(),
+                dropped: std::mem::take(&mut subscription.dropped),
+                entries: subscription.buffer.drain(..count).collect(),
+            });
+        }
+        batches
+    }
+}
+
+pub struct LogBrokerService;
+
+impl LogBrokerService {
+    /// Start delivering batches to the webview as `log-batch` events.
+    pub fn start(app_handle: AppHandle) {
+        if FLUSHER_STARTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        std::thread::spawn(move || loop {
+            std::thread::sleep(FLUSH_INTERVAL);
+            let batches = match BROKER.lock() {
+                Ok(mut broker) => broker.drain(MAX_BATCH_SIZE),
+                Err(_) => continue,
+            };
+            for batch in batches {
+                if let Err(e) = app_handle.emit(LOG_BATCH_EVENT, &batch) {
+                    eprintln!("Failed to emit log batch to frontend: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Offer a line to live subscribers. Never blocks on the webview; callers
+    /// write the entry to its log file separately.
+    pub fn publish(entry: &LogEntry) {
+        if let Ok(mut broker) = BROKER.lock() {
+            broker.publish(entry);
+        }
+    }
+
+    pub fn subscribe(owner: &str, filter: LogSubscriptionFilter) -> Result<String, String> {
+        BROKER
+            .lock()
+            .map(|mut broker| broker.subscribe(owner, filter))
+            .map_err(|e| format!("Failed to lock log broker: {}", e))
+    }
+
+    pub fn unsubscribe(id: &str) -> Result<bool, String> {
+        BROKER
+            .lock()
+            .map(|mut broker| broker.unsubscribe(id))
+            .map_err(|e| format!("Failed to lock log broker: {}", e))
+    }
+
+    /// Release the subscriptions of a webview that is reloading or closing;
+    /// its page can no longer unsubscribe them itself.
+    pub fn release_owner(owner: &str) {
+        if let Ok(mut broker) = BROKER.lock() {
+            broker.unsubscribe_owner(owner);
+        }
+    }
+
+    pub fn set_paused(id: &str, paused: bool) -> Result<(), String> {
+        BROKER
+            .lock()
+            .map_err(|e| format!("Failed to lock log broker: {}", e))?
+            .set_paused(id, paused)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/log_broker_service_tests.rs"]
+mod log_broker_service_tests;
@@ -1,13 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
-use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, OnceLock};
 use tauri::{AppHandle, Emitter, Manager};
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "trace" | "debug" => Some(Self::Debug),
+            "info" | "log" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "critical" | "fatal" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Guess the level of a line captured from a child process, looking for
+    /// Python logging's upper-case level names; anything else is info.
+    pub fn infer(line: &str) -> Self {
+        if line.starts_with("Traceback (most recent call last)") {
+            return Self::Error;
+        }
+        line.split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|token| token.len() >= 4 && token.chars().all(|c| c.is_ascii_uppercase()))
+            .find_map(Self::parse)
+            .unwrap_or(Self::Info)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// One line of a `.log` file, stored as a JSON object per line.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub level: LogLevel,
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_log_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    pub message: String,
+}
+
+impl LogEntry {
+    pub fn new(level: LogLevel, source: &str, message: &str) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            level,
+            source: source.to_string(),
+            nickname: None,
+            command_log_id: None,
+            pid: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Render as a single human readable line for the log viewers.
+    pub fn display_line(&self) -> String {
+        let mut line = format!(
+            "{} {:<5} [{}]",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.level.as_str(),
+            self.source
+        );
+        if let Some(nickname) = &self.nickname {
+            line.push_str(&format!(" [{}]", nickname));
+        }
+        line.push(' ');
+        line.push_str(&self.message);
+        line
+    }
+}
+
+/// Process log metadata shared by every line a managed process writes.
+#[derive(Clone, Debug, Default)]
+pub struct LogContext {
+    pub source: String,
+    pub nickname: Option<String>,
+    pub command_log_id: Option<String>,
+    pub pid: Option<u32>,
+}
+
+impl LogContext {
+    pub fn entry(&self, level: LogLevel, message: &str) -> LogEntry {
+        LogEntry {
+            nickname: self.nickname.clone(),
+            command_log_id: self.command_log_id.clone(),
+            pid: self.pid,
+            ..LogEntry::new(level, &self.source, message)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFilter {
+    /// Minimum level to include.
+    pub level: Option<LogLevel>,
+    pub source: Option<String>,
+    pub command_log_id: Option<String>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.level.is_none_or(|level| entry.level >= level)
+            && self
+                .source
+                .as_deref()
+                .is_none_or(|source| entry.source == source)
+            && self
+                .command_log_id
+                .as_deref()
+                .is_none_or(|id| entry.command_log_id.as_deref() == Some(id))
+    }
+}
+
+/// Receives a copy of every entry written to disk, e.g. the search index.
+static INDEX_SINK: OnceLock<mpsc::SyncSender<LogEntry>> = OnceLock::new();
+
 pub struct LogService;
 
 impl LogService {
@@ -182,11 +317,16 @@ impl LogService {
                     };
                 }
 
-                let timestamp = Self::get_timestamp();
-                let formatted = if let Some(prefix) = &format_prefix {
-                    format!("{} [{}] {}\n", timestamp, prefix, line)
-                } else {
-                    format!("{} {}\n", timestamp, line)
+                let source = format_prefix
+                    .clone()
+                    .unwrap_or_else(|| Self::default_source(&file_path));
+                let entry = LogEntry::new(LogLevel::infer(&line), &source, &line);
+                let formatted = match Self::serialize_entry(&entry) {
+                    Ok(formatted) => formatted,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        continue;
+                    }
                 };
 
                 if let Some(ref mut file) = file {
@@ -194,6 +334,8 @@ impl LogService {
                         eprintln!("Failed to write to log file {}: {}", file_path, e);
                     } else if let Err(e) = file.flush() {
                         eprintln!("Failed to flush log file {}: {}", file_path, e);
+                    } else {
+                        Self::forward_to_index(&entry);
                     }
                 } else {
                     eprintln!("Log file unavailable, dropping log line");
@@ -247,7 +389,19 @@ impl LogService {
             .map_err(|e| format!("Failed to open log file: {}", e))
     }
 
+    /// Append a line, inferring its level; `format_prefix` becomes the source
+    /// and defaults to the file name.
     pub fn write_log_line(file_path: &str, format_prefix: Option<&str>, line: &str) {
+        let source = format_prefix
+            .map(|prefix| prefix.to_string())
+            .unwrap_or_else(|| Self::default_source(file_path));
+        Self::write_log_entry(
+            file_path,
+            &LogEntry::new(LogLevel::infer(line), &source, line),
+        );
+    }
+
+    pub fn write_log_entry(file_path: &str, entry: &LogEntry) {
         if Self::should_rotate(file_path) {
             if let Err(e) = Self::rotate_log_files(file_path) {
                 eprintln!("Failed to rotate log file {}: {}", file_path, e);
@@ -262,16 +416,50 @@ impl LogService {
             }
         };
 
-        let timestamp = Self::get_timestamp();
-        let formatted = if let Some(prefix) = format_prefix {
-            format!("{} [{}] {}\n", timestamp, prefix, line)
-        } else {
-            format!("{} {}\n", timestamp, line)
+        let formatted = match Self::serialize_entry(entry) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
         };
 
         if let Err(e) = file.write_all(formatted.as_bytes()) {
             eprintln!("Failed to write to log file {}: {}", file_path, e);
+            return;
         }
+        Self::forward_to_index(entry);
+    }
+
+    /// Copy every entry written from now on to `sink`. Only the first call
+    /// takes effect; returns whether this one did.
+    pub fn set_index_sink(sink: mpsc::SyncSender<LogEntry>) -> bool {
+        INDEX_SINK.set(sink).is_ok()
+    }
+
+    /// Never blocks: when the sink is full the entry stays only in the file.
+    fn forward_to_index(entry: &LogEntry) {
+        if let Some(sink) = INDEX_SINK.get() {
+            let _ = sink.try_send(entry.clone());
+        }
+    }
+
+    fn serialize_entry(entry: &LogEntry) -> Result<String, String> {
+        serde_json::to_string(entry)
+            .map(|json| format!("{}\n", json))
+            .map_err(|e| format!("Failed to serialize log entry: {}", e))
+    }
+
+    fn default_source(file_path: &str) -> String {
+        let file_name = Path::new(file_path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("app");
+        file_name
+            .split(".log")
+            .next()
+            .unwrap_or(file_name)
+            .to_string()
     }
 
     fn should_rotate(file_path: &str) -> bool {
@@ -333,39 +521,58 @@ impl LogService {
             .unwrap_or(Self::DEFAULT_MAX_FILES)
     }
 
-    /// Get current timestamp in a readable format
-    fn get_timestamp() -> String {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default();
-
-        let secs = now.as_secs();
-        let millis = now.subsec_millis();
-
-        // Format as YYYY-MM-DD HH:MM:SS.mmm
-        let datetime = chrono::DateTime::from_timestamp(secs as i64, 0).unwrap_or_default();
-
-        format!("{}.{:03}", datetime.format("%Y-%m-%d %H:%M:%S"), millis)
-    }
-
-    fn parse_timestamp_ms(line: &str) -> Option<i64> {
-        if line.len() < 23 {
+    /// Parse one stored line. Files written before the switch to JSON lines
+    /// hold `YYYY-MM-DD HH:MM:SS.mmm [prefix] message`; lines without a
+    /// timestamp inherit `previous` so they stay next to what preceded them.
+    pub(crate) fn parse_line(
+        line: &str,
+        default_source: &str,
+        previous: Option<DateTime<Utc>>,
+    ) -> Option<LogEntry> {
+        if line.trim().is_empty() {
             return None;
         }
-
-        let date_part = line.get(0..19)?;
-        let dot = line.get(19..20)?;
-        let millis_part = line.get(20..23)?;
-        if dot != "." {
-            return None;
+        if line.starts_with('{') {
+            if let Ok(entry) = serde_json::from_str::<LogEntry>(line) {
+                return Some(entry);
+            }
         }
 
-        let dt = chrono::NaiveDateTime::parse_from_str(date_part, "%Y-%m-%d %H:%M:%S").ok()?;
-        let millis: i64 = millis_part.parse::<i64>().ok()?;
-        Some(dt.and_utc().timestamp_millis() + millis)
+        let (timestamp, rest) = match Self::parse_legacy_timestamp(line) {
+            Some(timestamp) => (Some(timestamp), line[23..].trim_start()),
+            None => (None, line),
+        };
+        let (source, message) = match rest
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "))
+        {
+            Some((source, message)) => (source, message),
+            None => (default_source, rest),
+        };
+        let mut entry = LogEntry::new(LogLevel::infer(message), source, message);
+        entry.timestamp = timestamp
+            .or(previous)
+            .unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+        Some(entry)
+    }
+
+    fn parse_legacy_timestamp(line: &str) -> Option<DateTime<Utc>> {
+        let timestamp = line.get(0..23)?;
+        chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S%.3f")
+            .ok()
+            .map(|dt| dt.and_utc())
+    }
+
+    pub fn read_log_tail(file_path: &str, max_lines: usize) -> Result<Vec<LogEntry>, String> {
+        Self::read_log_entries(file_path, max_lines, &LogFilter::default())
     }
 
-    pub fn read_log_tail(file_path: &str, max_lines: usize) -> Result<Vec<String>, String> {
+    /// The last `max_lines` entries of one file that match `filter`.
+    pub fn read_log_entries(
+        file_path: &str,
+        max_lines: usize,
+        filter: &LogFilter,
+    ) -> Result<Vec<LogEntry>, String> {
         if max_lines == 0 {
             return Ok(Vec::new());
         }
@@ -377,17 +584,26 @@ impl LogService {
 
         let file = File::open(path).map_err(|e| format!("Failed to open log file: {}", e))?;
         let reader = BufReader::new(file);
-        let mut lines = VecDeque::with_capacity(max_lines);
+        let default_source = Self::default_source(file_path);
+        let mut entries = VecDeque::with_capacity(max_lines);
+        let mut previous = None;
 
         for line in reader.lines() {
             let line = line.map_err(|e| format!("Failed to read log file: {}", e))?;
-            if lines.len() == max_lines {
-                lines.pop_front();
+            let Some(entry) = Self::parse_line(&line, &default_source, previous) else {
+                continue;
+            };
+            previous = Some(entry.timestamp);
+            if !filter.matches(&entry) {
+                continue;
+            }
+            if entries.len() == max_lines {
+                entries.pop_front();
             }
-            lines.push_back(line);
+            entries.push_back(entry);
         }
 
-        Ok(lines.into_iter().collect())
+        Ok(entries.into_iter().collect())
     }
 
     pub fn write_app_log_line(
@@ -396,6 +612,12 @@ impl LogService {
         format_prefix: Option<&str>,
         line: &str,
     ) -> Result<(), String> {
+        let log_path = Self::app_log_path(app_handle, file_name)?;
+        Self::write_log_line(log_path.to_string_lossy().as_ref(), format_prefix, line);
+        Ok(())
+    }
+
+    fn app_log_path(app_handle: &AppHandle, file_name: &str) -> Result<PathBuf, String> {
         let base_dir = app_handle
             .path()
             .app_data_dir()
@@ -406,9 +628,7 @@ impl LogService {
         let log_dir = base_dir.join("logs");
         std::fs::create_dir_all(&log_dir)
             .map_err(|e| format!("Failed to create log dir: {}", e))?;
-        let log_path = log_dir.join(file_name);
-        Self::write_log_line(log_path.to_string_lossy().as_ref(), format_prefix, line);
-        Ok(())
+        Ok(log_dir.join(file_name))
     }
 
     pub fn clear_log_dir(log_dir: &Path) -> Result<usize, String> {
@@ -446,7 +666,8 @@ impl LogService {
         log_dir: &Path,
         max_lines_total: usize,
         max_lines_per_file: usize,
-    ) -> Result<Vec<String>, String> {
+        filter: &LogFilter,
+    ) -> Result<Vec<LogEntry>, String> {
         if max_lines_total == 0 || max_lines_per_file == 0 {
             return Ok(Vec::new());
         }
@@ -455,8 +676,7 @@ impl LogService {
             return Ok(Vec::new());
         }
 
-        let mut entries: Vec<(i64, usize, String)> = Vec::new();
-        let mut order: usize = 0;
+        let mut entries: Vec<LogEntry> = Vec::new();
 
         for entry in
             std::fs::read_dir(log_dir).map_err(|e| format!("Failed to read log dir: {}", e))?
@@ -475,22 +695,21 @@ impl LogService {
                 continue;
             }
 
-            let tail = Self::read_log_tail(path.to_string_lossy().as_ref(), max_lines_per_file)?;
-            for line in tail {
-                let ts = Self::parse_timestamp_ms(&line).unwrap_or(i64::MIN);
-                entries.push((ts, order, line));
-                order += 1;
-            }
+            entries.extend(Self::read_log_entries(
+                path.to_string_lossy().as_ref(),
+                max_lines_per_file,
+                filter,
+            )?);
         }
 
-        entries.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        // Stable, so lines sharing a timestamp keep their file order.
+        entries.sort_by_key(|entry| entry.timestamp);
 
         let start = entries.len().saturating_sub(max_lines_total);
-        let mut combined = Vec::with_capacity(entries.len().saturating_sub(start));
-        for (_, _, line) in entries.into_iter().skip(start) {
-            combined.push(line);
-        }
-
-        Ok(combined)
+        Ok(entries.split_off(start))
     }
 }
+
+#[cfg(test)]
+#[path = "tests/log_service_tests.rs"]
+mod log_service_tests;
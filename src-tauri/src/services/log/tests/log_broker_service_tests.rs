@@ -0,0 +1,118 @@
+use super::*;
+
+fn line(source: &str, nickname: &str, level: LogLevel, message: &str) -> LogEntry {
+    LogEntry {
+        nickname: Some(nickname.to_string()),
+        ..LogEntry::new(level, source, message)
+    }
+}
+
+fn messages(batch: &LogBatch) -> Vec<&str> {
+    batch
+        .entries
+        .iter()
+        .map(|entry| entry.message.as_str())
+        .collect()
+}
+
+#[test]
+fn subscribers_only_receive_matching_lines() {
+    let mut broker = LogBroker::new(100);
+    let teleop = broker.subscribe(
+        "main",
+        LogSubscriptionFilter {
+            sources: Some(vec!["teleop".to_string()]),
+            nickname: Some("sourccey-1".to_string()),
+            level: None,
+        },
+    );
+    let warnings = broker.subscribe(
+        "main",
+        LogSubscriptionFilter {
+            level: Some(LogLevel::Warn),
+            ..LogSubscriptionFilter::default()
+        },
+    );
+
+    broker.publish(&line("teleop", "sourccey-1", LogLevel::Info, "t1"));
+    broker.publish(&line("teleop", "sourccey-2", LogLevel::Info, "other robot"));
+    broker.publish(&line("record", "sourccey-1", LogLevel::Error, "r1"));
+
+    let batches = broker.drain(10);
+    let find = |id: &str| batches.iter().find(|b| b.subscription_id == id).unwrap();
+    assert_eq!(messages(find(&teleop)), vec!["t1"]);
+    assert_eq!(messages(find(&warnings)), vec!["r1"]);
+    assert!(broker.drain(10).is_empty());
+}
+
+#[test]
+fn batches_are_bounded_and_keep_the_rest_queued() {
+    let mut broker = LogBroker::new(100);
+    let id = broker.subscribe("main", LogSubscriptionFilter::default());
+    for i in 0..5 {
+        broker.publish(&line("teleop", "a", LogLevel::Info, &i.to_string()));
+    }
+
+    let first = broker.drain(3);
+    let second = broker.drain(3);
+
+    assert_eq!(first[0].subscription_id, id);
+    assert_eq!(messages(&first[0]), vec!["0", "1", "2"]);
+    assert_eq!(messages(&second[0]), vec!["3", "4"]);
+}
+
+#[test]
+fn full_buffer_drops_oldest_and_reports_count() {
+    let mut broker = LogBroker::new(3);
+    broker.subscribe("main", LogSubscriptionFilter::default());
+    for i in 0..5 {
+        broker.publish(&line("teleop", "a", LogLevel::Info, &i.to_string()));
+    }
+
+    let batches = broker.drain(10);
+
+    assert_eq!(batches[0].dropped, 2);
+    assert_eq!(messages(&batches[0]), vec!["2", "3", "4"]);
+    broker.publish(&line("teleop", "a", LogLevel::Info, "5"));
+    assert_eq!(broker.drain(10)[0].dropped, 0);
+}
+
+#[test]
+fn paused_subscription_holds_lines_until_resumed() {
+    let mut broker = LogBroker::new(2);
+    let id = broker.subscribe("main", LogSubscriptionFilter::default());
+    broker
+        .set_paused(&id, true)
+        .expect("subscription should exist");
+    for i in 0..3 {
+        broker.publish(&line("teleop", "a", LogLevel::Info, &i.to_string()));
+    }
+    assert!(broker.drain(10).is_empty());
+
+    broker
+        .set_paused(&id, false)
+        .expect("subscription should exist");
+    let batches = broker.drain(10);
+    assert_eq!(batches[0].dropped, 1);
+    assert_eq!(messages(&batches[0]), vec!["1", "2"]);
+
+    assert!(broker.unsubscribe(&id));
+    assert!(!broker.unsubscribe(&id));
+    assert!(broker.set_paused(&id, true).is_err());
+}
+
+#[test]
+fn reloading_webview_releases_only_its_subscriptions() {
+    let mut broker = LogBroker::new(10);
+    broker.subscribe("main", LogSubscriptionFilter::default());
+    broker.subscribe("main", LogSubscriptionFilter::default());
+    let other = broker.subscribe("logs", LogSubscriptionFilter::default());
+
+    assert_eq!(broker.unsubscribe_owner("main"), 2);
+    broker.publish(&line("teleop", "sourccey-1", LogLevel::Info, "t1"));
+
+    let batches = broker.drain(10);
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].subscription_id, other);
+    assert_eq!(broker.unsubscribe_owner("main"), 0);
+}
@@ -0,0 +1,160 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_log_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_logs", name, nonce));
+    std::fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn entry_at(timestamp: &str, level: LogLevel, source: &str, message: &str) -> LogEntry {
+    LogEntry {
+        timestamp: timestamp.parse().expect("timestamp should parse"),
+        ..LogEntry::new(level, source, message)
+    }
+}
+
+#[test]
+fn entries_round_trip_as_json_lines() {
+    let dir = temp_log_dir("round_trip");
+    let path = dir.join("teleop.log");
+    let path = path.to_string_lossy();
+    let context = LogContext {
+        source: "teleop".to_string(),
+        nickname: Some("sourccey-1".to_string()),
+        command_log_id: Some("cmd-42".to_string()),
+        pid: Some(4242),
+    };
+    let entry = context.entry(LogLevel::Warn, "Leader arm not calibrated");
+
+    LogService::write_log_entry(&path, &entry);
+    LogService::write_log_line(&path, None, "plain line");
+
+    let raw = std::fs::read_to_string(path.as_ref()).expect("log should exist");
+    assert!(raw.lines().all(|line| line.starts_with('{')), "{}", raw);
+    assert!(raw.contains("\"commandLogId\":\"cmd-42\""), "{}", raw);
+
+    let entries = LogService::read_log_tail(&path, 10).expect("log should read");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0], entry);
+    assert_eq!(entries[1].source, "teleop");
+    assert_eq!(entries[1].level, LogLevel::Info);
+    assert_eq!(entries[1].pid, None);
+}
+
+#[test]
+fn legacy_text_lines_are_parsed() {
+    let entry = LogService::parse_line(
+        "2025-03-04 05:06:07.089 [record] ERROR: camera not found",
+        "record",
+        None,
+    )
+    .expect("line should parse");
+
+    assert_eq!(
+        entry.timestamp,
+        "2025-03-04T05:06:07.089Z"
+            .parse::<DateTime<Utc>>()
+            .expect("timestamp should parse")
+    );
+    assert_eq!(entry.source, "record");
+    assert_eq!(entry.level, LogLevel::Error);
+    assert_eq!(entry.message, "ERROR: camera not found");
+
+    let continuation =
+        LogService::parse_line("  File \"x.py\", line 3", "record", Some(entry.timestamp))
+            .expect("line should parse");
+    assert_eq!(continuation.timestamp, entry.timestamp);
+    assert_eq!(continuation.source, "record");
+    assert!(LogService::parse_line("   ", "record", None).is_none());
+}
+
+#[test]
+fn level_is_inferred_from_upper_case_names_only() {
+    assert_eq!(
+        LogLevel::infer("WARNING 2025-01-01 robot.py:12 low voltage"),
+        LogLevel::Warn
+    );
+    assert_eq!(
+        LogLevel::infer("Traceback (most recent call last):"),
+        LogLevel::Error
+    );
+    assert_eq!(
+        LogLevel::infer("no error detected while homing"),
+        LogLevel::Info
+    );
+    assert_eq!(LogLevel::parse("warning"), Some(LogLevel::Warn));
+    assert_eq!(LogLevel::parse("verbose"), None);
+}
+
+#[test]
+fn filter_matches_level_source_and_command_log_id() {
+    let mut entry = entry_at("2025-03-04T05:06:07Z", LogLevel::Warn, "teleop", "lagging");
+    entry.command_log_id = Some("cmd-1".to_string());
+
+    assert!(LogFilter::default().matches(&entry));
+    assert!(LogFilter {
+        level: Some(LogLevel::Warn),
+        source: Some("teleop".to_string()),
+        command_log_id: Some("cmd-1".to_string()),
+    }
+    .matches(&entry));
+    assert!(!LogFilter {
+        level: Some(LogLevel::Error),
+        ..LogFilter::default()
+    }
+    .matches(&entry));
+    assert!(!LogFilter {
+        source: Some("record".to_string()),
+        ..LogFilter::default()
+    }
+    .matches(&entry));
+    assert!(!LogFilter {
+        command_log_id: Some("cmd-2".to_string()),
+        ..LogFilter::default()
+    }
+    .matches(&entry));
+}
+
+#[test]
+fn tail_all_merges_files_by_timestamp_and_applies_filter() {
+    let dir = temp_log_dir("tail_all");
+    let teleop = dir.join("teleop.log");
+    let setup = dir.join("setup.log.1");
+    for entry in [
+        entry_at("2025-01-01T00:00:01Z", LogLevel::Info, "teleop", "t1"),
+        entry_at("2025-01-01T00:00:03Z", LogLevel::Error, "teleop", "t3"),
+    ] {
+        LogService::write_log_entry(&teleop.to_string_lossy(), &entry);
+    }
+    std::fs::write(
+        &setup,
+        "2025-01-01 00:00:02.000 [setup] s2\n2025-01-01 00:00:04.000 [setup] s4\n",
+    )
+    .expect("legacy log should be written");
+
+    let all = LogService::read_log_tail_all(&dir, 3, 10, &LogFilter::default())
+        .expect("logs should read");
+    let messages: Vec<&str> = all.iter().map(|entry| entry.message.as_str()).collect();
+    assert_eq!(messages, vec!["s2", "t3", "s4"]);
+
+    let errors = LogService::read_log_tail_all(
+        &dir,
+        10,
+        10,
+        &LogFilter {
+            level: Some(LogLevel::Error),
+            ..LogFilter::default()
+        },
+    )
+    .expect("logs should read");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].display_line(),
+        "2025-01-01 00:00:03.000 ERROR [teleop] t3"
+    );
+}
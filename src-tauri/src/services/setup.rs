@@ -1,2 +1,8 @@
+pub mod download_service;
+pub mod environment_doctor_service;
+pub mod kiosk_slot_service;
 pub mod kiosk_update_service;
 pub mod local_setup_service;
+pub mod offline_bundle_service;
+pub mod release_channel_service;
+pub mod update_signature_service;
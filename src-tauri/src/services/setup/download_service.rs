@@ -0,0 +1,524 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_RANGE, ETAG, IF_RANGE, RANGE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const PARTIAL_EXTENSION: &str = "part";
+const PARTIAL_SOURCE_EXTENSION: &str = "part.json";
+const CHUNK_SIZE: usize = 64 * 1024;
+/// Error returned when `DownloadOptions::cancel_flag` stops a transfer.
+pub const DOWNLOAD_CANCELLED: &str = "Download cancelled";
+
+/// Byte counts and transfer rate for one in-flight download, attached to
+/// `SetupProgress` events so the UI can show speed and time remaining.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadProgress {
+    pub downloaded_bytes: u64,
+    pub total_bytes: Option<u64>,
+    pub bytes_per_second: u64,
+    pub eta_seconds: Option<u64>,
+    pub attempt: u32,
+}
+
+impl DownloadProgress {
+    /// Human-readable summary, e.g. `45.2 MB of 310.0 MB (2.1 MB/s, 2m 6s left)`.
+    pub fn describe(&self) -> String {
+        let mut text = match self.total_bytes {
+            Some(total) => format!(
+                "{} of {}",
+                DownloadService::format_bytes(self.downloaded_bytes),
+                DownloadService::format_bytes(total)
+            ),
+            None => DownloadService::format_bytes(self.downloaded_bytes),
+        };
+        if self.bytes_per_second > 0 {
+            text.push_str(&format!(
+                " ({}/s",
+                DownloadService::format_bytes(self.bytes_per_second)
+            ));
+            if let Some(eta) = self.eta_seconds {
+                text.push_str(&format!(", {} left", DownloadService::format_duration(eta)));
+            }
+            text.push(')');
+        }
+        if self.attempt > 1 {
+            text.push_str(&format!(", attempt {}", self.attempt));
+        }
+        text
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DownloadOptions {
+    pub expected_sha256: Option<String>,
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Longest the connection may stall before the attempt is abandoned.
+    pub stall_timeout: Duration,
+    pub progress_interval: Duration,
+    /// Sent as `Authorization: Bearer`, e.g. for private Hugging Face repos.
+    pub bearer_token: Option<String>,
+    /// Checked between chunks; once set the transfer stops and keeps its
+    /// `.part` so a later call can resume it.
+    pub cancel_flag: Option<Arc<AtomicBool>>,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            expected_sha256: None,
+            max_attempts: 6,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            stall_timeout: Duration::from_secs(30),
+            progress_interval: Duration::from_millis(500),
+            bearer_token: None,
+            cancel_flag: None,
+        }
+    }
+}
+
+impl DownloadOptions {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadOutcome {
+    pub bytes: u64,
+    pub sha256: String,
+    pub attempts: u32,
+}
+
+/// What a `.part` file was downloaded from, so a later run only resumes it
+/// against the same URL and (when the server sent one) the same ETag.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PartialSource {
+    url: String,
+    etag: Option<String>,
+}
+
+enum AttemptError {
+    /// Worth retrying after a backoff: dropped connections, timeouts, 5xx.
+    Transient(String),
+    Fatal(String),
+    Cancelled,
+}
+
+struct Transfer<'a> {
+    url: &'a str,
+    part_path: &'a Path,
+    source_path: &'a Path,
+    options: &'a DownloadOptions,
+    attempt: u32,
+}
+
+pub struct DownloadService;
+
+impl DownloadService {
+    /// Download `url` to `dest`, resuming an interrupted `<dest>.part` with an
+    /// HTTP Range request and retrying dropped transfers with exponential
+    /// backoff. The SHA-256 is computed while streaming and checked against
+    /// `options.expected_sha256` before `dest` is written.
+    pub fn download(
+        url: &str,
+        dest: &Path,
+        options: &DownloadOptions,
+        on_progress: &mut dyn FnMut(&DownloadProgress),
+    ) -> Result<DownloadOutcome, String> {
+        let expected_sha256 = options
+            .expected_sha256
+            .as_deref()
+            .map(Self::normalize_sha256)
+            .transpose()?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {:?}: {}", parent, e))?;
+        }
+        let part_path = Self::sidecar_path(dest, PARTIAL_EXTENSION);
+        let source_path = Self::sidecar_path(dest, PARTIAL_SOURCE_EXTENSION);
+        let client = Client::builder()
+            .timeout(options.stall_timeout)
+            .connect_timeout(options.stall_timeout)
+            .build()
+            .map_err(|e| format!("Failed to build download client: {}", e))?;
+
+        let max_attempts = options.max_attempts.max(1);
+        let mut backoff = options.initial_backoff;
+        let mut attempt = 1;
+        let (bytes, sha256) = loop {
+            if options.is_cancelled() {
+                return Err(DOWNLOAD_CANCELLED.to_string());
+            }
+            let transfer = Transfer {
+                url,
+                part_path: &part_path,
+                source_path: &source_path,
+                options,
+                attempt,
+            };
+            match Self::attempt(&client, &transfer, on_progress) {
+                Ok(result) => break result,
+                Err(AttemptError::Cancelled) => return Err(DOWNLOAD_CANCELLED.to_string()),
+                Err(AttemptError::Fatal(error)) => return Err(error),
+                Err(AttemptError::Transient(error)) if attempt >= max_attempts => {
+                    return Err(format!(
+                        "Download of {} failed after {} attempts: {}",
+                        url, attempt, error
+                    ));
+                }
+                Err(AttemptError::Transient(error)) => {
+                    eprintln!(
+                        "[setup] Download attempt {} of {} failed, retrying in {:?}: {}",
+                        attempt, max_attempts, backoff, error
+                    );
+                    thread::sleep(backoff);
+                    backoff = (backoff * 2).min(options.max_backoff);
+                    attempt += 1;
+                }
+            }
+        };
+
+        if let Some(expected) = expected_sha256 {
+            if sha256 != expected {
+                Self::discard_partial(&part_path, &source_path);
+                return Err(format!(
+                    "Checksum verification failed for {}: expected {}, got {}",
+                    url, expected, sha256
+                ));
+            }
+        }
+
+        if dest.exists() {
+            fs::remove_file(dest).map_err(|e| format!("Failed to replace {:?}: {}", dest, e))?;
+        }
+        fs::rename(&part_path, dest)
+            .map_err(|e| format!("Failed to move download into {:?}: {}", dest, e))?;
+        let _ = fs::remove_file(&source_path);
+
+        Ok(DownloadOutcome {
+            bytes,
+            sha256,
+            attempts: attempt,
+        })
+    }
+
+    fn attempt(
+        client: &Client,
+        transfer: &Transfer,
+        on_progress: &mut dyn FnMut(&DownloadProgress),
+    ) -> Result<(u64, String), AttemptError> {
+        let saved_source = Self::read_partial_source(transfer.source_path);
+        let mut resume_from = match fs::metadata(transfer.part_path) {
+            Ok(metadata) if saved_source.url == transfer.url => metadata.len(),
+            _ => 0,
+        };
+
+        let mut request = client.get(transfer.url);
+        if let Some(token) = transfer.options.bearer_token.as_deref() {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        if resume_from > 0 {
+            request = request.header(RANGE, format!("bytes={}-", resume_from));
+            if let Some(etag) = saved_source.etag.as_deref() {
+                request = request.header(IF_RANGE, etag);
+            }
+        }
+        let response = request
+            .send()
+            .map_err(|e| AttemptError::Transient(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if status == StatusCode::RANGE_NOT_SATISFIABLE {
+            // The partial no longer matches what the server has; start over.
+            Self::discard_partial(transfer.part_path, transfer.source_path);
+            return Err(AttemptError::Transient(
+                "Server rejected the resume range".to_string(),
+            ));
+        }
+        if !status.is_success() {
+            let error = format!("Download failed ({}): {}", status, transfer.url);
+            return Err(
+                if status.is_server_error()
+                    || status == StatusCode::REQUEST_TIMEOUT
+                    || status == StatusCode::TOO_MANY_REQUESTS
+                {
+                    AttemptError::Transient(error)
+                } else {
+                    AttemptError::Fatal(error)
+                },
+            );
+        }
+
+        // A 200 means the server ignored the range (or If-Range saw a new
+        // ETag) and is sending the whole file again.
+        let resumed = resume_from > 0 && status == StatusCode::PARTIAL_CONTENT;
+        if resumed && Self::content_range_start(&response) != Some(resume_from) {
+            Self::discard_partial(transfer.part_path, transfer.source_path);
+            return Err(AttemptError::Transient(
+                "Server answered the resume request with an unexpected range".to_string(),
+            ));
+        }
+        if !resumed {
+            resume_from = 0;
+        }
+        let total_bytes = Self::total_bytes(&response, resume_from);
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Self::write_partial_source(
+            transfer.source_path,
+            &PartialSource {
+                url: transfer.url.to_string(),
+                etag,
+            },
+        )
+        .map_err(AttemptError::Fatal)?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(!resumed)
+            .open(transfer.part_path)
+            .map_err(|e| {
+                AttemptError::Fatal(format!("Failed to open {:?}: {}", transfer.part_path, e))
+            })?;
+        // Resumed transfers hash the bytes already on disk, then keep hashing
+        // the stream as it arrives.
+        let mut hasher = Sha256::new();
+        if resumed {
+            Self::hash_prefix(&mut file, resume_from, &mut hasher).map_err(|e| {
+                AttemptError::Fatal(format!("Failed to read {:?}: {}", transfer.part_path, e))
+            })?;
+        }
+        file.seek(SeekFrom::Start(resume_from)).map_err(|e| {
+            AttemptError::Fatal(format!("Failed to seek {:?}: {}", transfer.part_path, e))
+        })?;
+
+        let bytes = Self::stream_body(
+            response,
+            &mut file,
+            &mut hasher,
+            resume_from,
+            total_bytes,
+            transfer,
+            on_progress,
+        )?;
+        Ok((bytes, format!("{:x}", hasher.finalize())))
+    }
+
+    fn stream_body(
+        mut response: Response,
+        file: &mut fs::File,
+        hasher: &mut Sha256,
+        resume_from: u64,
+        total_bytes: Option<u64>,
+        transfer: &Transfer,
+        on_progress: &mut dyn FnMut(&DownloadProgress),
+    ) -> Result<u64, AttemptError> {
+        let started_at = Instant::now();
+        let mut last_report: Option<Instant> = None;
+        let mut downloaded = resume_from;
+        let mut buf = vec![0u8; CHUNK_SIZE];
+
+        loop {
+            if transfer.options.is_cancelled() {
+                let _ = file.flush();
+                return Err(AttemptError::Cancelled);
+            }
+            let read = match response.read(&mut buf) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    let _ = file.flush();
+                    return Err(AttemptError::Transient(format!(
+                        "Connection dropped after {} bytes: {}",
+                        downloaded, e
+                    )));
+                }
+            };
+            file.write_all(&buf[..read]).map_err(|e| {
+                AttemptError::Fatal(format!("Failed to write {:?}: {}", transfer.part_path, e))
+            })?;
+            hasher.update(&buf[..read]);
+            downloaded += read as u64;
+
+            let now = Instant::now();
+            if last_report.is_none_or(|last| now - last >= transfer.options.progress_interval) {
+                last_report = Some(now);
+                on_progress(&Self::progress(
+                    downloaded,
+                    resume_from,
+                    total_bytes,
+                    now - started_at,
+                    transfer.attempt,
+                ));
+            }
+        }
+        file.flush().map_err(|e| {
+            AttemptError::Fatal(format!("Failed to write {:?}: {}", transfer.part_path, e))
+        })?;
+
+        if let Some(total) = total_bytes {
+            if downloaded < total {
+                return Err(AttemptError::Transient(format!(
+                    "Connection closed after {} of {} bytes",
+                    downloaded, total
+                )));
+            }
+        }
+        on_progress(&Self::progress(
+            downloaded,
+            resume_from,
+            total_bytes,
+            started_at.elapsed(),
+            transfer.attempt,
+        ));
+        Ok(downloaded)
+    }
+
+    pub(crate) fn progress(
+        downloaded: u64,
+        resumed_from: u64,
+        total_bytes: Option<u64>,
+        elapsed: Duration,
+        attempt: u32,
+    ) -> DownloadProgress {
+        let transferred = downloaded.saturating_sub(resumed_from);
+        let seconds = elapsed.as_secs_f64();
+        let bytes_per_second = if seconds > 0.0 {
+            (transferred as f64 / seconds) as u64
+        } else {
+            0
+        };
+        let eta_seconds = match (total_bytes, bytes_per_second) {
+            (Some(total), rate) if rate > 0 => {
+                Some(total.saturating_sub(downloaded).div_ceil(rate))
+            }
+            _ => None,
+        };
+        DownloadProgress {
+            downloaded_bytes: downloaded,
+            total_bytes,
+            bytes_per_second,
+            eta_seconds,
+            attempt,
+        }
+    }
+
+    pub(crate) fn format_bytes(bytes: u64) -> String {
+        const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+        if bytes < 1000 {
+            return format!("{} B", bytes);
+        }
+        let mut value = bytes as f64;
+        let mut unit = "B";
+        for next in UNITS {
+            if value < 1000.0 {
+                break;
+            }
+            value /= 1000.0;
+            unit = next;
+        }
+        format!("{:.1} {}", value, unit)
+    }
+
+    pub(crate) fn format_duration(seconds: u64) -> String {
+        match seconds {
+            0..=59 => format!("{}s", seconds),
+            60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+            _ => format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60),
+        }
+    }
+
+    fn content_range_start(response: &Response) -> Option<u64> {
+        // `Content-Range: bytes 1000-1999/2000`
+        let value = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+        let range = value.trim().strip_prefix("bytes ")?;
+        range.split('-').next()?.trim().parse().ok()
+    }
+
+    fn total_bytes(response: &Response, resume_from: u64) -> Option<u64> {
+        if resume_from > 0 {
+            let value = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+            return value.rsplit('/').next()?.trim().parse().ok();
+        }
+        response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    fn hash_prefix(file: &mut fs::File, length: u64, hasher: &mut Sha256) -> io::Result<()> {
+        file.seek(SeekFrom::Start(0))?;
+        let mut prefix = Read::by_ref(file).take(length);
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        loop {
+            let read = prefix.read(&mut buf)?;
+            if read == 0 {
+                return Ok(());
+            }
+            hasher.update(&buf[..read]);
+        }
+    }
+
+    fn normalize_sha256(value: &str) -> Result<String, String> {
+        let normalized = value.trim().to_ascii_lowercase();
+        if normalized.len() != 64 || !normalized.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("Invalid SHA-256 checksum format: {}", value));
+        }
+        Ok(normalized)
+    }
+
+    fn sidecar_path(dest: &Path, extension: &str) -> PathBuf {
+        let mut name = dest
+            .file_name()
+            .map(|name| name.to_os_string())
+            .unwrap_or_default();
+        name.push(".");
+        name.push(extension);
+        dest.with_file_name(name)
+    }
+
+    fn read_partial_source(path: &Path) -> PartialSource {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn write_partial_source(path: &Path, source: &PartialSource) -> Result<(), String> {
+        let json = serde_json::to_string(source)
+            .map_err(|e| format!("Failed to serialize download state: {}", e))?;
+        fs::write(path, json).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+    }
+
+    fn discard_partial(part_path: &Path, source_path: &Path) {
+        let _ = fs::remove_file(part_path);
+        let _ = fs::remove_file(source_path);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/download_service_tests.rs"]
+mod download_service_tests;
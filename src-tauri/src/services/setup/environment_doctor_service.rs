@@ -0,0 +1,673 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::services::setup::local_setup_service::{LocalSetupService, SetupProgress};
+
+/// Modules every robot runtime needs; a failed import usually means a
+/// half-finished or interrupted `uv pip install`.
+const KEY_MODULES: [&str; 4] = ["lerobot", "torch", "numpy", "google.protobuf"];
+const LOW_DISK_WARNING_BYTES: u64 = 5 * 1024 * 1024 * 1024;
+const LOW_DISK_ERROR_BYTES: u64 = 1024 * 1024 * 1024;
+
+const PYTHON_VERSION_SCRIPT: &str = "import sys; print('%d.%d.%d' % sys.version_info[:3])";
+
+/// Loads each generated `*_pb2.py` so gencode/runtime version conflicts
+/// surface here instead of when the robot host starts.
+const PROTOBUF_CHECK_SCRIPT: &str = r#"
+import importlib.util, json, pathlib, sys
+root = pathlib.Path(sys.argv[1])
+sys.path.insert(0, str(root))
+modules = sorted(root.rglob("*_pb2.py"))
+errors = []
+for path in modules:
+    try:
+        spec = importlib.util.spec_from_file_location(path.stem, path)
+        module = importlib.util.module_from_spec(spec)
+        spec.loader.exec_module(module)
+    except Exception as error:
+        errors.append(f"{path.name}: {type(error).__name__}: {error}")
+print(json.dumps({"found": len(modules), "errors": errors}))
+"#;
+
+/// Lists requirements of each extra (argv) that are not installed in the venv.
+const EXTRAS_CHECK_SCRIPT: &str = r#"
+import json, re, sys
+from importlib import metadata
+try:
+    from packaging.requirements import Requirement
+except ImportError:
+    Requirement = None
+requires = metadata.distribution("lerobot").requires or []
+result = {}
+for extra in sys.argv[1:]:
+    missing = []
+    for raw in requires:
+        if ";" not in raw:
+            continue
+        spec, marker = raw.split(";", 1)
+        if Requirement is not None:
+            requirement = Requirement(raw)
+            if requirement.marker is None or not requirement.marker.evaluate({"extra": extra}):
+                continue
+            name = requirement.name
+        else:
+            if not re.search(r"extra\s*==\s*['\"]%s['\"]" % re.escape(extra), marker):
+                continue
+            name = re.match(r"[A-Za-z0-9._-]+", spec.strip()).group(0)
+        try:
+            metadata.distribution(name)
+        except metadata.PackageNotFoundError:
+            missing.append(name)
+    result[extra] = sorted(set(missing))
+print(json.dumps(result))
+"#;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DoctorSeverity {
+    Ok,
+    Warning,
+    Error,
+    /// Not checked because an earlier check it depends on failed.
+    Skipped,
+}
+
+/// Smallest fix for one finding. Each is much cheaper than a full reinstall,
+/// which takes over half an hour on a Pi.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DoctorRepairAction {
+    InstallUv,
+    RecreateVenv,
+    ReinstallRuntimeExtra,
+    ReinstallDesktopExtras,
+    RecompileProtobuf,
+    FullReinstall,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorCheck {
+    pub id: String,
+    pub label: String,
+    pub severity: DoctorSeverity,
+    pub detail: String,
+    pub repair: Option<DoctorRepairAction>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorReport {
+    pub healthy: bool,
+    pub checked_at: DateTime<Utc>,
+    pub checks: Vec<DoctorCheck>,
+}
+
+/// Result of loading the generated protobuf modules.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub(crate) struct ProtobufProbe {
+    pub found: usize,
+    pub errors: Vec<String>,
+}
+
+/// Raw observations of the runtime, gathered by `probe` and turned into
+/// checks by the side-effect free `evaluate`.
+#[derive(Clone, Debug)]
+pub(crate) struct DoctorProbe {
+    pub uv_version: Result<String, String>,
+    pub lerobot_dir_exists: bool,
+    /// `None` when the venv interpreter is missing or does not run.
+    pub python_version: Option<String>,
+    pub expected_python_version: String,
+    pub failed_imports: Vec<String>,
+    /// `None` when lerobot-vulcan ships no protobuf compile script.
+    pub protobuf: Option<Result<ProtobufProbe, String>>,
+    pub missing_runtime_requirements: Result<Vec<String>, String>,
+    /// `None` when the desktop extras were never installed.
+    pub missing_desktop_requirements: Option<Result<Vec<String>, String>>,
+    pub free_disk_bytes: Option<u64>,
+    pub lerobot_tag: Option<String>,
+    pub source_commit: Option<String>,
+    pub installed_commit: Option<String>,
+}
+
+pub struct EnvironmentDoctorService;
+
+impl EnvironmentDoctorService {
+    pub fn diagnose(app_handle: &AppHandle) -> Result<DoctorReport, String> {
+        let probe = Self::probe(app_handle)?;
+        Ok(Self::report(Self::evaluate(&probe)))
+    }
+
+    /// Apply one repair, then re-run the diagnosis so the caller sees whether
+    /// it worked.
+    pub fn repair(
+        app_handle: &AppHandle,
+        action: DoctorRepairAction,
+    ) -> Result<DoctorReport, String> {
+        let emit = |progress: SetupProgress| {
+            let _ = app_handle.emit("setup:progress", progress);
+        };
+        let lerobot_dir = LocalSetupService::resolve_lerobot_dir(app_handle)
+            .ok_or_else(|| "Failed to resolve the lerobot-vulcan directory".to_string())?;
+        let python_path = LocalSetupService::python_path_for(&lerobot_dir);
+
+        match action {
+            DoctorRepairAction::InstallUv => {
+                LocalSetupService::resolve_uv_binary(app_handle)?;
+            }
+            DoctorRepairAction::RecreateVenv => {
+                LocalSetupService::remove_virtual_env_dir(&lerobot_dir)?;
+                LocalSetupService::reinstall_dependencies(app_handle, Some(&emit))?;
+                if Self::desktop_extras_installed(app_handle) {
+                    Self::install_extra(
+                        app_handle,
+                        &lerobot_dir,
+                        LocalSetupService::SOURCCEY_DESKTOP_EXTRA,
+                    )?;
+                }
+            }
+            DoctorRepairAction::ReinstallRuntimeExtra => {
+                Self::install_extra(
+                    app_handle,
+                    &lerobot_dir,
+                    LocalSetupService::SOURCCEY_RUNTIME_EXTRA,
+                )?;
+            }
+            DoctorRepairAction::ReinstallDesktopExtras => {
+                Self::install_extra(
+                    app_handle,
+                    &lerobot_dir,
+                    LocalSetupService::SOURCCEY_DESKTOP_EXTRA,
+                )?;
+            }
+            DoctorRepairAction::RecompileProtobuf => {
+                let compile_script =
+                    LocalSetupService::protobuf_dir_for(&lerobot_dir).join("compile.py");
+                if !compile_script.exists() {
+                    return Err(format!(
+                        "Protobuf compile script not found at {}",
+                        compile_script.display()
+                    ));
+                }
+                let compile_script = compile_script.to_string_lossy().to_string();
+                LocalSetupService::run_command(
+                    &python_path,
+                    &[compile_script.as_str()],
+                    &lerobot_dir,
+                    "compile protobuf",
+                )
+                .map_err(|e| LocalSetupService::format_protobuf_error(&e))?;
+            }
+            DoctorRepairAction::FullReinstall => {
+                LocalSetupService::reset_modules(app_handle)?;
+                LocalSetupService::run_setup(app_handle, false)?;
+            }
+        }
+
+        Self::diagnose(app_handle)
+    }
+
+    pub(crate) fn evaluate(probe: &DoctorProbe) -> Vec<DoctorCheck> {
+        let mut checks = vec![match &probe.uv_version {
+            Ok(version) => Self::check(
+                "uv",
+                "uv package manager",
+                DoctorSeverity::Ok,
+                version,
+                None,
+            ),
+            Err(error) => Self::check(
+                "uv",
+                "uv package manager",
+                DoctorSeverity::Error,
+                error,
+                Some(DoctorRepairAction::InstallUv),
+            ),
+        }];
+
+        if !probe.lerobot_dir_exists {
+            checks.push(Self::check(
+                "lerobot-source",
+                "lerobot-vulcan source",
+                DoctorSeverity::Error,
+                "modules/lerobot-vulcan is missing",
+                Some(DoctorRepairAction::FullReinstall),
+            ));
+        }
+
+        let venv_ready = match probe.python_version.as_deref() {
+            None => {
+                checks.push(Self::check(
+                    "python",
+                    "Virtual environment Python",
+                    DoctorSeverity::Error,
+                    "The virtual environment interpreter is missing or does not run",
+                    Some(DoctorRepairAction::RecreateVenv),
+                ));
+                false
+            }
+            Some(version)
+                if !Self::python_version_matches(version, &probe.expected_python_version) =>
+            {
+                checks.push(Self::check(
+                    "python",
+                    "Virtual environment Python",
+                    DoctorSeverity::Error,
+                    &format!(
+                        "Python {} found, setup expects {}",
+                        version, probe.expected_python_version
+                    ),
+                    Some(DoctorRepairAction::RecreateVenv),
+                ));
+                true
+            }
+            Some(version) => {
+                checks.push(Self::check(
+                    "python",
+                    "Virtual environment Python",
+                    DoctorSeverity::Ok,
+                    &format!("Python {}", version),
+                    None,
+                ));
+                true
+            }
+        };
+
+        if venv_ready {
+            checks.extend(Self::evaluate_packages(probe));
+        } else {
+            for (id, label) in [
+                ("imports", "Key Python modules"),
+                ("protobuf", "Protobuf bindings"),
+                ("runtime-extra", "Sourccey runtime packages"),
+            ] {
+                checks.push(Self::check(
+                    id,
+                    label,
+                    DoctorSeverity::Skipped,
+                    "Needs a working virtual environment",
+                    None,
+                ));
+            }
+        }
+
+        checks.push(match probe.free_disk_bytes {
+            Some(free) if free < LOW_DISK_ERROR_BYTES => Self::check(
+                "disk-space",
+                "Free disk space",
+                DoctorSeverity::Error,
+                &format!("Only {} MB free; installs will fail", free / (1024 * 1024)),
+                None,
+            ),
+            Some(free) if free < LOW_DISK_WARNING_BYTES => Self::check(
+                "disk-space",
+                "Free disk space",
+                DoctorSeverity::Warning,
+                &format!(
+                    "{} MB free; reinstalling packages may run out of space",
+                    free / (1024 * 1024)
+                ),
+                None,
+            ),
+            Some(free) => Self::check(
+                "disk-space",
+                "Free disk space",
+                DoctorSeverity::Ok,
+                &format!("{} MB free", free / (1024 * 1024)),
+                None,
+            ),
+            None => Self::check(
+                "disk-space",
+                "Free disk space",
+                DoctorSeverity::Warning,
+                "Could not determine free disk space",
+                None,
+            ),
+        });
+
+        checks.push(Self::evaluate_revision(probe));
+        checks
+    }
+
+    fn evaluate_packages(probe: &DoctorProbe) -> Vec<DoctorCheck> {
+        let mut checks = Vec::new();
+
+        checks.push(if probe.failed_imports.is_empty() {
+            Self::check(
+                "imports",
+                "Key Python modules",
+                DoctorSeverity::Ok,
+                &format!("{} import cleanly", KEY_MODULES.join(", ")),
+                None,
+            )
+        } else {
+            Self::check(
+                "imports",
+                "Key Python modules",
+                DoctorSeverity::Error,
+                &format!("Cannot import {}", probe.failed_imports.join(", ")),
+                Some(DoctorRepairAction::ReinstallRuntimeExtra),
+            )
+        });
+
+        match &probe.protobuf {
+            None => {}
+            Some(Err(error)) => checks.push(Self::check(
+                "protobuf",
+                "Protobuf bindings",
+                DoctorSeverity::Warning,
+                &format!("Could not check generated protobuf modules: {}", error),
+                Some(DoctorRepairAction::RecompileProtobuf),
+            )),
+            Some(Ok(protobuf)) if protobuf.found == 0 => checks.push(Self::check(
+                "protobuf",
+                "Protobuf bindings",
+                DoctorSeverity::Error,
+                "Generated protobuf modules are missing",
+                Some(DoctorRepairAction::RecompileProtobuf),
+            )),
+            Some(Ok(protobuf)) if !protobuf.errors.is_empty() => checks.push(Self::check(
+                "protobuf",
+                "Protobuf bindings",
+                DoctorSeverity::Error,
+                &LocalSetupService::format_protobuf_error(&protobuf.errors.join("\n")),
+                Some(DoctorRepairAction::RecompileProtobuf),
+            )),
+            Some(Ok(protobuf)) => checks.push(Self::check(
+                "protobuf",
+                "Protobuf bindings",
+                DoctorSeverity::Ok,
+                &format!("{} generated modules load", protobuf.found),
+                None,
+            )),
+        }
+
+        checks.push(Self::evaluate_extra(
+            "runtime-extra",
+            "Sourccey runtime packages",
+            &probe.missing_runtime_requirements,
+            DoctorSeverity::Error,
+            DoctorRepairAction::ReinstallRuntimeExtra,
+        ));
+        if let Some(missing) = &probe.missing_desktop_requirements {
+            checks.push(Self::evaluate_extra(
+                "desktop-extras",
+                "Desktop AI packages",
+                missing,
+                DoctorSeverity::Warning,
+                DoctorRepairAction::ReinstallDesktopExtras,
+            ));
+        }
+        checks
+    }
+
+    fn evaluate_extra(
+        id: &str,
+        label: &str,
+        missing: &Result<Vec<String>, String>,
+        severity: DoctorSeverity,
+        repair: DoctorRepairAction,
+    ) -> DoctorCheck {
+        match missing {
+            Ok(missing) if missing.is_empty() => Self::check(
+                id,
+                label,
+                DoctorSeverity::Ok,
+                "All requirements installed",
+                None,
+            ),
+            Ok(missing) => Self::check(
+                id,
+                label,
+                severity,
+                &format!("Missing {}", missing.join(", ")),
+                Some(repair),
+            ),
+            Err(error) => Self::check(
+                id,
+                label,
+                severity,
+                &format!("Could not read installed packages: {}", error),
+                Some(repair),
+            ),
+        }
+    }
+
+    fn evaluate_revision(probe: &DoctorProbe) -> DoctorCheck {
+        let release = probe
+            .lerobot_tag
+            .clone()
+            .or_else(|| probe.source_commit.clone())
+            .or_else(|| probe.installed_commit.clone());
+        match (&probe.source_commit, &probe.installed_commit) {
+            (Some(source), Some(installed)) if !Self::same_commit(source, installed) => {
+                Self::check(
+                    "lerobot-release",
+                    "lerobot-vulcan revision",
+                    DoctorSeverity::Warning,
+                    &format!(
+                        "Source is at {} but dependencies were installed for {}",
+                        Self::short_commit(source),
+                        Self::short_commit(installed)
+                    ),
+                    Some(DoctorRepairAction::ReinstallRuntimeExtra),
+                )
+            }
+            _ => match release {
+                Some(release) => Self::check(
+                    "lerobot-release",
+                    "lerobot-vulcan revision",
+                    DoctorSeverity::Ok,
+                    &release,
+                    None,
+                ),
+                None => Self::check(
+                    "lerobot-release",
+                    "lerobot-vulcan revision",
+                    DoctorSeverity::Warning,
+                    "Installed revision is unknown",
+                    None,
+                ),
+            },
+        }
+    }
+
+    pub(crate) fn python_version_matches(actual: &str, expected: &str) -> bool {
+        let actual_parts: Vec<&str> = actual.trim().split('.').collect();
+        let expected_parts: Vec<&str> = expected.trim().split('.').collect();
+        expected_parts.len() <= actual_parts.len()
+            && expected_parts
+                .iter()
+                .zip(actual_parts.iter())
+                .all(|(expected, actual)| expected == actual)
+    }
+
+    fn same_commit(left: &str, right: &str) -> bool {
+        let length = left.len().min(right.len());
+        left[..length].eq_ignore_ascii_case(&right[..length])
+    }
+
+    fn short_commit(commit: &str) -> &str {
+        &commit[..commit.len().min(12)]
+    }
+
+    fn report(checks: Vec<DoctorCheck>) -> DoctorReport {
+        DoctorReport {
+            healthy: checks
+                .iter()
+                .all(|check| check.severity != DoctorSeverity::Error),
+            checked_at: Utc::now(),
+            checks,
+        }
+    }
+
+    fn check(
+        id: &str,
+        label: &str,
+        severity: DoctorSeverity,
+        detail: &str,
+        repair: Option<DoctorRepairAction>,
+    ) -> DoctorCheck {
+        DoctorCheck {
+            id: id.to_string(),
+            label: label.to_string(),
+            severity,
+            detail: detail.to_string(),
+            repair,
+        }
+    }
+
+    fn probe(app_handle: &AppHandle) -> Result<DoctorProbe, String> {
+        let app_data_dir = app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+        let lerobot_dir = LocalSetupService::resolve_lerobot_dir(app_handle)
+            .ok_or_else(|| "Failed to resolve the lerobot-vulcan directory".to_string())?;
+        let python_path = LocalSetupService::python_path_for(&lerobot_dir);
+        let lerobot_dir_exists = lerobot_dir.exists();
+
+        let uv_version = LocalSetupService::resolve_uv_binary(app_handle).and_then(|uv| {
+            LocalSetupService::run_command_output(
+                &uv,
+                &["--version"],
+                &app_data_dir,
+                "uv --version",
+            )
+        });
+
+        let python_version = if LocalSetupService::python_path_is_usable(&python_path) {
+            LocalSetupService::run_command_output(
+                &python_path,
+                &["-c", PYTHON_VERSION_SCRIPT],
+                &app_data_dir,
+                "python version",
+            )
+            .ok()
+        } else {
+            None
+        };
+
+        let mut probe = DoctorProbe {
+            uv_version,
+            lerobot_dir_exists,
+            python_version: None,
+            expected_python_version: LocalSetupService::UV_VENV_PYTHON_VERSION.to_string(),
+            failed_imports: Vec::new(),
+            protobuf: None,
+            missing_runtime_requirements: Ok(Vec::new()),
+            missing_desktop_requirements: None,
+            free_disk_bytes: Self::read_free_disk_bytes(&app_data_dir),
+            lerobot_tag: LocalSetupService::read_current_lerobot_tag_marker(app_handle),
+            source_commit: LocalSetupService::read_current_lerobot_git_commit(app_handle),
+            installed_commit: LocalSetupService::read_current_lerobot_commit_marker(app_handle),
+        };
+        if python_version.is_none() || !lerobot_dir_exists {
+            probe.python_version = python_version;
+            return Ok(probe);
+        }
+        probe.python_version = python_version;
+
+        probe.failed_imports = KEY_MODULES
+            .iter()
+            .filter(|module| !LocalSetupService::python_can_import(&python_path, module))
+            .map(|module| module.to_string())
+            .collect();
+
+        let protobuf_dir = LocalSetupService::protobuf_dir_for(&lerobot_dir);
+        if protobuf_dir.join("compile.py").exists() {
+            let protobuf_dir = protobuf_dir.to_string_lossy().to_string();
+            probe.protobuf = Some(
+                LocalSetupService::run_command_output(
+                    &python_path,
+                    &["-c", PROTOBUF_CHECK_SCRIPT, protobuf_dir.as_str()],
+                    &lerobot_dir,
+                    "protobuf check",
+                )
+                .and_then(|output| {
+                    serde_json::from_str(&output)
+                        .map_err(|e| format!("Unexpected protobuf check output: {}", e))
+                }),
+            );
+        }
+
+        let mut extras = vec![LocalSetupService::SOURCCEY_RUNTIME_EXTRA];
+        let desktop_extras: Vec<&str> = LocalSetupService::SOURCCEY_DESKTOP_EXTRA
+            .split(',')
+            .collect();
+        let check_desktop = Self::desktop_extras_installed(app_handle);
+        if check_desktop {
+            extras.extend(desktop_extras.iter().copied());
+        }
+        let missing = Self::missing_extra_requirements(&python_path, &lerobot_dir, &extras);
+        probe.missing_runtime_requirements = missing.clone().map(|missing| {
+            Self::collect_missing(&missing, &[LocalSetupService::SOURCCEY_RUNTIME_EXTRA])
+        });
+        if check_desktop {
+            probe.missing_desktop_requirements =
+                Some(missing.map(|missing| Self::collect_missing(&missing, &desktop_extras)));
+        }
+
+        Ok(probe)
+    }
+
+    /// Free space for installs under `path`, measured on its nearest existing
+    /// ancestor since the app data directory may not exist yet. fs2 queries the
+    /// filesystem directly, so this works on Windows as well as Unix.
+    pub(crate) fn read_free_disk_bytes(path: &Path) -> Option<u64> {
+        path.ancestors()
+            .find(|dir| dir.exists())
+            .and_then(|dir| fs2::available_space(dir).ok())
+    }
+
+    fn missing_extra_requirements(
+        python_path: &Path,
+        lerobot_dir: &Path,
+        extras: &[&str],
+    ) -> Result<HashMap<String, Vec<String>>, String> {
+        let mut args = vec!["-c", EXTRAS_CHECK_SCRIPT];
+        args.extend(extras.iter().copied());
+        let output =
+            LocalSetupService::run_command_output(python_path, &args, lerobot_dir, "extras check")?;
+        serde_json::from_str(&output).map_err(|e| format!("Unexpected extras check output: {}", e))
+    }
+
+    fn collect_missing(missing: &HashMap<String, Vec<String>>, extras: &[&str]) -> Vec<String> {
+        let mut names: Vec<String> = extras
+            .iter()
+            .filter_map(|extra| missing.get(*extra))
+            .flatten()
+            .cloned()
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    fn install_extra(
+        app_handle: &AppHandle,
+        lerobot_dir: &Path,
+        extra: &str,
+    ) -> Result<(), String> {
+        let uv = LocalSetupService::resolve_uv_binary(app_handle)?;
+        let python_path = LocalSetupService::python_path_for(lerobot_dir);
+        LocalSetupService::install_lerobot_extra(&uv, lerobot_dir, &python_path, extra, None)
+    }
+
+    fn desktop_extras_installed(app_handle: &AppHandle) -> bool {
+        app_handle
+            .path()
+            .app_data_dir()
+            .map(|dir| LocalSetupService::desktop_extras_marker_path(&dir.join("setup")).exists())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/environment_doctor_service_tests.rs"]
+mod environment_doctor_service_tests;
@@ -0,0 +1,282 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+const SLOT_STATE_FILE: &str = "slot_state.json";
+const UPDATE_HISTORY_FILE: &str = "update_history.json";
+const PACKAGES_DIR: &str = "packages";
+const MAX_HISTORY_ENTRIES: usize = 50;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KioskSlot {
+    A,
+    B,
+}
+
+impl KioskSlot {
+    pub fn other(self) -> Self {
+        match self {
+            KioskSlot::A => KioskSlot::B,
+            KioskSlot::B => KioskSlot::A,
+        }
+    }
+
+    pub fn dir_name(self) -> &'static str {
+        match self {
+            KioskSlot::A => "a",
+            KioskSlot::B => "b",
+        }
+    }
+}
+
+/// Which checkout the project root points at, and whether it still has to prove
+/// it can boot after an update switched to it. Boots are counted and verified
+/// by the session's boot guard (`setup/kiosk/components/kiosk_boot_guard.py`),
+/// which shares this file with the app. Kiosks without the guard never enter
+/// probation.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KioskSlotState {
+    pub active: KioskSlot,
+    pub previous: Option<KioskSlot>,
+    pub pending_verification: bool,
+    pub boot_attempts: u32,
+    pub update_id: Option<String>,
+}
+
+impl Default for KioskSlotState {
+    fn default() -> Self {
+        Self {
+            active: KioskSlot::A,
+            previous: None,
+            pending_verification: false,
+            boot_attempts: 0,
+            update_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KioskUpdateOutcome {
+    InProgress,
+    PendingBoot,
+    Succeeded,
+    Failed,
+    RolledBack,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KioskUpdateRecord {
+    pub id: String,
+    pub from_slot: KioskSlot,
+    pub to_slot: KioskSlot,
+    pub from_tag: Option<String>,
+    pub to_tag: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    pub outcome: KioskUpdateOutcome,
+    pub error: Option<String>,
+}
+
+/// On-disk layout for A/B kiosk checkouts. The project root is a symlink into
+/// `slots_dir/<a|b>`; slot state, update history and each slot's installed
+/// package live alongside the slots.
+#[derive(Clone, Debug)]
+pub struct KioskSlotLayout {
+    pub root_link: PathBuf,
+    pub slots_dir: PathBuf,
+}
+
+impl KioskSlotLayout {
+    /// Slots for `root_link` live in a sibling `<root>-slots` directory unless
+    /// `SOURCCEY_KIOSK_SLOTS_DIR` says otherwise.
+    pub fn for_project_root(root_link: PathBuf) -> Self {
+        let slots_dir = std::env::var("SOURCCEY_KIOSK_SLOTS_DIR")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Self::default_slots_dir(&root_link));
+        Self {
+            root_link,
+            slots_dir,
+        }
+    }
+
+    fn default_slots_dir(root_link: &Path) -> PathBuf {
+        let name = root_link
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "sourccey-desktop".to_string());
+        root_link.with_file_name(format!("{}-slots", name))
+    }
+
+    pub fn slot_dir(&self, slot: KioskSlot) -> PathBuf {
+        self.slots_dir.join(slot.dir_name())
+    }
+
+    pub fn slot_package_path(&self, slot: KioskSlot) -> PathBuf {
+        self.slots_dir
+            .join(PACKAGES_DIR)
+            .join(format!("{}.deb", slot.dir_name()))
+    }
+
+    fn state_path(&self) -> PathBuf {
+        self.slots_dir.join(SLOT_STATE_FILE)
+    }
+
+    fn history_path(&self) -> PathBuf {
+        self.slots_dir.join(UPDATE_HISTORY_FILE)
+    }
+}
+
+pub struct KioskSlotService;
+
+impl KioskSlotService {
+    /// Convert a plain checkout at the project root into slot A the first time an
+    /// A/B update runs. The rename keeps the checkout on the same filesystem, so
+    /// paths under the project root keep resolving through the new symlink.
+    pub fn ensure_layout(layout: &KioskSlotLayout) -> Result<KioskSlotState, String> {
+        fs::create_dir_all(&layout.slots_dir)
+            .map_err(|e| format!("Failed to create kiosk slots directory: {}", e))?;
+
+        let metadata = fs::symlink_metadata(&layout.root_link).map_err(|e| {
+            format!(
+                "Kiosk project root {} is missing: {}",
+                layout.root_link.display(),
+                e
+            )
+        })?;
+
+        if metadata.file_type().is_symlink() {
+            return Self::load_state(layout);
+        }
+
+        let slot_a = layout.slot_dir(KioskSlot::A);
+        if slot_a.exists() {
+            return Err(format!(
+                "Cannot convert {} into slot A: {} already exists",
+                layout.root_link.display(),
+                slot_a.display()
+            ));
+        }
+        fs::rename(&layout.root_link, &slot_a)
+            .map_err(|e| format!("Failed to move kiosk checkout into slot A: {}", e))?;
+        Self::symlink_dir(&slot_a, &layout.root_link)?;
+
+        let state = KioskSlotState::default();
+        Self::save_state(layout, &state)?;
+        Ok(state)
+    }
+
+    pub fn load_state(layout: &KioskSlotLayout) -> Result<KioskSlotState, String> {
+        match fs::read_to_string(layout.state_path()) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse kiosk slot state: {}", e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(KioskSlotState::default()),
+            Err(e) => Err(format!("Failed to read kiosk slot state: {}", e)),
+        }
+    }
+
+    pub fn save_state(layout: &KioskSlotLayout, state: &KioskSlotState) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(state)
+            .map_err(|e| format!("Failed to serialize kiosk slot state: {}", e))?;
+        Self::write_atomically(&layout.state_path(), &content)
+    }
+
+    /// Point the project root at `slot`. The new link is created beside the old
+    /// one and renamed over it, so readers see either the old or new slot.
+    pub fn switch_active(layout: &KioskSlotLayout, slot: KioskSlot) -> Result<(), String> {
+        let target = layout.slot_dir(slot);
+        if !target.exists() {
+            return Err(format!("Kiosk slot {} does not exist", target.display()));
+        }
+
+        let staging_link = layout.root_link.with_file_name(format!(
+            ".{}.switching",
+            layout
+                .root_link
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        ));
+        let _ = fs::remove_file(&staging_link);
+        Self::symlink_dir(&target, &staging_link)?;
+        fs::rename(&staging_link, &layout.root_link)
+            .map_err(|e| format!("Failed to switch active kiosk slot: {}", e))
+    }
+
+    pub fn load_history(layout: &KioskSlotLayout) -> Result<Vec<KioskUpdateRecord>, String> {
+        match fs::read_to_string(layout.history_path()) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse kiosk update history: {}", e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(format!("Failed to read kiosk update history: {}", e)),
+        }
+    }
+
+    /// Insert or replace the record with the same id, keeping the newest
+    /// `MAX_HISTORY_ENTRIES` records.
+    pub fn save_record(layout: &KioskSlotLayout, record: &KioskUpdateRecord) -> Result<(), String> {
+        let mut history = Self::load_history(layout).unwrap_or_default();
+        match history.iter_mut().find(|existing| existing.id == record.id) {
+            Some(existing) => *existing = record.clone(),
+            None => history.push(record.clone()),
+        }
+        if history.len() > MAX_HISTORY_ENTRIES {
+            let excess = history.len() - MAX_HISTORY_ENTRIES;
+            history.drain(..excess);
+        }
+
+        let content = serde_json::to_string_pretty(&history)
+            .map_err(|e| format!("Failed to serialize kiosk update history: {}", e))?;
+        Self::write_atomically(&layout.history_path(), &content)
+    }
+
+    pub fn finish_record(
+        record: &mut KioskUpdateRecord,
+        outcome: KioskUpdateOutcome,
+        error: Option<String>,
+    ) {
+        let now = Utc::now();
+        record.outcome = outcome;
+        record.error = error;
+        record.completed_at = Some(now);
+        record.duration_ms = Some((now - record.started_at).num_milliseconds());
+    }
+
+    fn write_atomically(path: &Path, content: &str) -> Result<(), String> {
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, content)
+            .map_err(|e| format!("Failed to write {}: {}", tmp_path.display(), e))?;
+        fs::rename(&tmp_path, path)
+            .map_err(|e| format!("Failed to replace {}: {}", path.display(), e))
+    }
+
+    #[cfg(unix)]
+    fn symlink_dir(target: &Path, link: &Path) -> Result<(), String> {
+        std::os::unix::fs::symlink(target, link).map_err(|e| {
+            format!(
+                "Failed to link {} to {}: {}",
+                link.display(),
+                target.display(),
+                e
+            )
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn symlink_dir(_target: &Path, _link: &Path) -> Result<(), String> {
+        Err("A/B kiosk slots are only supported on Linux".to_string())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/kiosk_slot_service_tests.rs"]
+mod kiosk_slot_service_tests;
@@ -1,22 +1,38 @@
+use std::fs;
 use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::net::{Ipv4Addr, UdpSocket};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use chrono::Utc;
 use lazy_static::lazy_static;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
 
+use crate::modules::control::services::kiosk_control::discovery_responder_service::KioskDiscoveryResponderService;
+use crate::modules::control::services::robot_profiles::robot_profile_service::RobotProfileService;
+use crate::modules::settings::services::kiosk_environment::kiosk_environment_service::{
+    KioskEnvironmentService, KioskEnvironmentSettings,
+};
 use crate::services::directory::directory_service::DirectoryService;
+use crate::services::setup::kiosk_slot_service::{
+    KioskSlot, KioskSlotLayout, KioskSlotService, KioskSlotState, KioskUpdateOutcome,
+    KioskUpdateRecord,
+};
 use crate::services::setup::local_setup_service::SetupProgress;
+use crate::services::setup::release_channel_service::{
+    ReleaseChannel, ReleaseChannelService, ReleaseVersion,
+};
 
 pub struct KioskUpdateService;
 
 #[derive(Clone)]
 struct KioskTagCacheEntry {
     fetched_at: Instant,
-    latest_tag: Option<LatestTagInfo>,
+    tags: Vec<LatestTagInfo>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,11 +41,12 @@ struct LatestTagInfo {
     commit_sha: Option<String>,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct SimpleSemver {
-    major: u64,
-    minor: u64,
-    patch: u64,
+/// Where an update should move the app checkout and LeRobot submodule, as
+/// decided by the kiosk's release channel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct KioskUpdateTargets {
+    app_ref: String,
+    lerobot_tag: Option<String>,
 }
 
 lazy_static! {
@@ -47,6 +64,7 @@ pub struct KioskUpdateStatus {
     pub app_remote: Option<String>,
     pub lerobot_current: Option<String>,
     pub lerobot_remote: Option<String>,
+    pub release_channel: String,
     pub error: Option<String>,
 }
 
@@ -58,6 +76,11 @@ impl KioskUpdateService {
     const DEFAULT_KIOSK_APP_TAG_PREFIX: &str = "kiosk/";
     const DEFAULT_KIOSK_LEROBOT_TAG_PREFIX: &str = "vulcan/";
     const TAG_CACHE_TTL: Duration = Duration::from_secs(300);
+    /// Where kiosk session setup installs the guard the launcher runs the app through.
+    const BOOT_GUARD_PATH: &str = "/usr/local/bin/sourccey-kiosk-boot-guard";
+    const SMOKE_TEST_PORT_ENV: &str = "SOURCCEY_KIOSK_SMOKE_TEST_PORT";
+    const SMOKE_TEST_TIMEOUT: Duration = Duration::from_secs(120);
+    const SMOKE_TEST_PROBE_INTERVAL: Duration = Duration::from_secs(2);
 
     pub fn check_updates(_app_handle: &AppHandle) -> Result<KioskUpdateStatus, String> {
         let repo_root = DirectoryService::get_current_dir()?;
@@ -69,14 +92,22 @@ impl KioskUpdateService {
             "SOURCCEY_KIOSK_LEROBOT_TAG_PREFIX",
             Self::DEFAULT_KIOSK_LEROBOT_TAG_PREFIX,
         );
+        let settings = KioskEnvironmentService::get_settings()?;
+        let channel = ReleaseChannel::parse(&settings.release_channel)?;
+        let app_pin =
+            ReleaseChannelService::active_pin(channel, settings.pinned_app_tag.as_deref());
+        let lerobot_pin =
+            ReleaseChannelService::active_pin(channel, settings.pinned_lerobot_tag.as_deref());
 
         let mut error: Option<String> = None;
         let app_current = Self::resolve_current_repo_tag(&repo_root, &app_tag_prefix);
-        let app_remote_info = match Self::resolve_latest_tag_cached(
+        let app_remote_info = match Self::resolve_channel_tag(
             &KIOSK_APP_TAG_CACHE,
             "SOURCCEY_KIOSK_APP_TAGS_URL",
             Self::DEFAULT_KIOSK_APP_TAGS_URL,
             &app_tag_prefix,
+            channel,
+            app_pin,
         ) {
             Ok(tag) => tag,
             Err(err) => {
@@ -85,20 +116,23 @@ impl KioskUpdateService {
             }
         };
         let app_remote = app_remote_info.as_ref().map(|tag| tag.name.clone());
-        let app_update_available = !Self::is_repo_up_to_date(
+        let app_update_available = !Self::is_component_up_to_date(
             &repo_root,
             app_current.as_deref(),
             app_remote_info.as_ref(),
             &app_tag_prefix,
+            app_pin.is_some(),
         );
 
         let lerobot_dir = repo_root.join("modules").join("lerobot-vulcan");
         let lerobot_current = Self::resolve_current_repo_tag(&lerobot_dir, &lerobot_tag_prefix);
-        let lerobot_remote_info = match Self::resolve_latest_tag_cached(
+        let lerobot_remote_info = match Self::resolve_channel_tag(
             &KIOSK_LEROBOT_TAG_CACHE,
             "SOURCCEY_KIOSK_LEROBOT_TAGS_URL",
             Self::DEFAULT_KIOSK_LEROBOT_TAGS_URL,
             &lerobot_tag_prefix,
+            channel,
+            lerobot_pin,
         ) {
             Ok(tag) => tag,
             Err(err) => {
@@ -107,11 +141,12 @@ impl KioskUpdateService {
             }
         };
         let lerobot_remote = lerobot_remote_info.as_ref().map(|tag| tag.name.clone());
-        let lerobot_update_available = !Self::is_repo_up_to_date(
+        let lerobot_update_available = !Self::is_component_up_to_date(
             &lerobot_dir,
             lerobot_current.as_deref(),
             lerobot_remote_info.as_ref(),
             &lerobot_tag_prefix,
+            lerobot_pin.is_some(),
         );
 
         let update_available = app_update_available || lerobot_update_available;
@@ -124,10 +159,37 @@ impl KioskUpdateService {
             app_remote,
             lerobot_current,
             lerobot_remote,
+            release_channel: channel.as_str().to_string(),
             error,
         })
     }
 
+    /// Pin the kiosk to the app and LeRobot release tags it is running now.
+    pub fn pin_current_release() -> Result<KioskEnvironmentSettings, String> {
+        let repo_root = DirectoryService::get_current_dir()?;
+        let app_tag = Self::resolve_current_repo_tag(
+            &repo_root,
+            &Self::resolve_prefix_env(
+                "SOURCCEY_KIOSK_APP_TAG_PREFIX",
+                Self::DEFAULT_KIOSK_APP_TAG_PREFIX,
+            ),
+        );
+        let lerobot_tag = Self::resolve_current_repo_tag(
+            &repo_root.join("modules").join("lerobot-vulcan"),
+            &Self::resolve_prefix_env(
+                "SOURCCEY_KIOSK_LEROBOT_TAG_PREFIX",
+                Self::DEFAULT_KIOSK_LEROBOT_TAG_PREFIX,
+            ),
+        );
+        if app_tag.is_none() && lerobot_tag.is_none() {
+            return Err(
+                "The kiosk is not running a tagged release, so there is nothing to pin".to_string(),
+            );
+        }
+
+        KioskEnvironmentService::pin_release(app_tag, lerobot_tag)
+    }
+
     pub fn repair_lerobot(app_handle: &AppHandle) -> Result<(), String> {
         let emit = |progress: SetupProgress| {
             let _ = app_handle.emit("kiosk:setup-progress", progress);
@@ -178,12 +240,17 @@ impl KioskUpdateService {
         Ok(())
     }
 
+    /// Stage an update into the inactive A/B slot, build and health-check it
+    /// there, and only then install its package and switch the project root over
+    /// to it. The live checkout is never modified, so a failed update leaves the
+    /// kiosk on its current slot. The first boots of the new slot are verified by
+    /// the session's boot guard, which rolls back without relying on the new app.
     pub fn update_kiosk(app_handle: &AppHandle) -> Result<(), String> {
         let emit = |progress: SetupProgress| {
             let _ = app_handle.emit("kiosk:setup-progress", progress);
         };
 
-        let repo_root = DirectoryService::get_current_dir()?;
+        let layout = KioskSlotLayout::for_project_root(DirectoryService::get_current_dir()?);
         let update_ref = std::env::var("SOURCCEY_KIOSK_UPDATE_REF")
             .unwrap_or_else(|_| "origin/main".to_string())
             .trim()
@@ -193,9 +260,180 @@ impl KioskUpdateService {
         } else {
             update_ref
         };
+        let app_tag_prefix = Self::resolve_prefix_env(
+            "SOURCCEY_KIOSK_APP_TAG_PREFIX",
+            Self::DEFAULT_KIOSK_APP_TAG_PREFIX,
+        );
+        let targets = Self::resolve_update_targets(update_ref, &app_tag_prefix)?;
+
+        Self::emit_step(
+            Some(&emit),
+            "slots",
+            "started",
+            Some("Preparing update slot".to_string()),
+        );
+        let mut state = KioskSlotService::ensure_layout(&layout)?;
+        let guarded = Self::boot_guard_installed(Path::new(Self::BOOT_GUARD_PATH));
+        if state.pending_verification {
+            if guarded {
+                return Err(
+                    "The previous kiosk update has not been verified yet; restart the kiosk before updating again"
+                        .to_string(),
+                );
+            }
+            // Nothing in this session would ever clear the flag, and this app
+            // running from the slot shows it boots.
+            Self::verify_without_guard(&layout, &mut state)?;
+        }
+        let active_dir = layout.slot_dir(state.active);
+        let target = state.active.other();
+        let target_dir = layout.slot_dir(target);
+        // Keep the active slot's package around to put back if installing the
+        // staged build fails or the boot guard rolls the new slot back.
+        if let Err(e) = Self::preserve_slot_package(&layout, state.active, &active_dir) {
+            Self::emit_log(app_handle, e);
+        }
+        Self::emit_step(
+            Some(&emit),
+            "slots",
+            "success",
+            Some(format!("Staging update into slot {}", target.dir_name())),
+        );
+
+        let mut record = KioskUpdateRecord {
+            id: Uuid::now_v7().to_string(),
+            from_slot: state.active,
+            to_slot: target,
+            from_tag: Self::resolve_current_repo_tag(&active_dir, &app_tag_prefix),
+            to_tag: None,
+            started_at: Utc::now(),
+            completed_at: None,
+            duration_ms: None,
+            outcome: KioskUpdateOutcome::InProgress,
+            error: None,
+        };
+        let _ = KioskSlotService::save_record(&layout, &record);
+
+        let staged = Self::stage_slot(app_handle, &emit, &active_dir, &target_dir, &targets)
+            .and_then(|_| {
+                Self::emit_step(
+                    Some(&emit),
+                    "health",
+                    "started",
+                    Some("Checking staged kiosk build".to_string()),
+                );
+                Self::check_slot_health(&target_dir)?;
+                Self::emit_step(Some(&emit), "health", "success", None);
+                Self::preserve_slot_package(&layout, target, &target_dir)
+            });
+        record.to_tag = Self::resolve_current_repo_tag(&target_dir, &app_tag_prefix);
+
+        let fail = |record: &mut KioskUpdateRecord, error: String| {
+            KioskSlotService::finish_record(
+                record,
+                KioskUpdateOutcome::Failed,
+                Some(error.clone()),
+            );
+            let _ = KioskSlotService::save_record(&layout, record);
+            Err(error)
+        };
+        if let Err(error) = staged {
+            return fail(&mut record, error);
+        }
+
+        // Nothing outside the staged slot has changed until its package is installed
+        Self::emit_step(
+            Some(&emit),
+            "install",
+            "started",
+            Some("Installing staged kiosk app".to_string()),
+        );
+        let installed = Self::install_slot_package(app_handle, &layout, target).and_then(|_| {
+            Self::emit_step(Some(&emit), "install", "success", None);
+            Self::emit_step(
+                Some(&emit),
+                "switch",
+                "started",
+                Some(format!("Switching to slot {}", target.dir_name())),
+            );
+            KioskSlotService::switch_active(&layout, target)
+        });
+        if let Err(error) = installed {
+            Self::reinstall_slot_package(app_handle, &layout, state.active);
+            return fail(&mut record, error);
+        }
+
+        Self::mark_switched(&mut state, &mut record, target, guarded);
+        KioskSlotService::save_state(&layout, &state)?;
+        let _ = KioskSlotService::save_record(&layout, &record);
+        Self::emit_step(Some(&emit), "switch", "success", None);
 
+        let message = if guarded {
+            "Kiosk update complete; it will be verified on the next start"
+        } else {
+            "Kiosk update complete"
+        };
         Self::emit_step(
             Some(&emit),
+            "complete",
+            "success",
+            Some(message.to_string()),
+        );
+        Ok(())
+    }
+
+    pub fn get_update_history() -> Result<Vec<KioskUpdateRecord>, String> {
+        let layout = KioskSlotLayout::for_project_root(DirectoryService::get_current_dir()?);
+        let mut history = KioskSlotService::load_history(&layout)?;
+        history.reverse();
+        Ok(history)
+    }
+
+    fn stage_slot(
+        app_handle: &AppHandle,
+        emit: &dyn Fn(SetupProgress),
+        active_dir: &Path,
+        target_dir: &Path,
+        targets: &KioskUpdateTargets,
+    ) -> Result<(), String> {
+        if !target_dir.join(".git").exists() {
+            Self::emit_step(
+                Some(emit),
+                "clone",
+                "started",
+                Some("Creating update slot checkout".to_string()),
+            );
+            if target_dir.exists() {
+                fs::remove_dir_all(target_dir)
+                    .map_err(|e| format!("Failed to clear stale update slot: {}", e))?;
+            }
+            let origin_url = Self::git_output(active_dir, &["remote", "get-url", "origin"])?;
+            let parent = target_dir
+                .parent()
+                .ok_or("Update slot has no parent directory".to_string())?;
+            let active = active_dir.to_string_lossy().to_string();
+            let target = target_dir.to_string_lossy().to_string();
+            // Borrow objects from the active checkout, then dissociate so either
+            // slot can be removed without breaking the other
+            Self::run_command(
+                app_handle,
+                "git",
+                &[
+                    "clone",
+                    "--reference-if-able",
+                    active.as_str(),
+                    "--dissociate",
+                    origin_url.as_str(),
+                    target.as_str(),
+                ],
+                parent,
+                "git clone",
+            )?;
+            Self::emit_step(Some(emit), "clone", "success", None);
+        }
+
+        Self::emit_step(
+            Some(emit),
             "fetch",
             "started",
             Some("Fetching latest kiosk code".to_string()),
@@ -203,29 +441,29 @@ impl KioskUpdateService {
         Self::run_command(
             app_handle,
             "git",
-            &["fetch", "--prune"],
-            &repo_root,
+            &["fetch", "--prune", "--tags"],
+            target_dir,
             "git fetch",
         )?;
-        Self::emit_step(Some(&emit), "fetch", "success", None);
+        Self::emit_step(Some(emit), "fetch", "success", None);
 
         Self::emit_step(
-            Some(&emit),
+            Some(emit),
             "reset",
             "started",
-            Some(format!("Resetting to {}", update_ref)),
+            Some(format!("Resetting update slot to {}", targets.app_ref)),
         );
         Self::run_command(
             app_handle,
             "git",
-            &["reset", "--hard", update_ref.as_str()],
-            &repo_root,
+            &["reset", "--hard", targets.app_ref.as_str()],
+            target_dir,
             "git reset",
         )?;
-        Self::emit_step(Some(&emit), "reset", "success", None);
+        Self::emit_step(Some(emit), "reset", "success", None);
 
         Self::emit_step(
-            Some(&emit),
+            Some(emit),
             "submodules",
             "started",
             Some("Updating submodules".to_string()),
@@ -234,36 +472,305 @@ impl KioskUpdateService {
             app_handle,
             "git",
             &["submodule", "sync", "--recursive"],
-            &repo_root,
+            target_dir,
             "git submodule sync",
         )?;
         Self::run_command(
             app_handle,
             "git",
             &["submodule", "update", "--init", "--recursive"],
-            &repo_root,
+            target_dir,
             "git submodule update",
         )?;
-        Self::emit_step(Some(&emit), "submodules", "success", None);
+        if let Some(lerobot_tag) = targets.lerobot_tag.as_deref() {
+            let lerobot_dir = target_dir.join("modules").join("lerobot-vulcan");
+            let tag_ref = format!("refs/tags/{}", lerobot_tag);
+            Self::run_command(
+                app_handle,
+                "git",
+                &["fetch", "--tags", "origin"],
+                &lerobot_dir,
+                "git fetch lerobot-vulcan tags",
+            )?;
+            Self::run_command(
+                app_handle,
+                "git",
+                &["checkout", "--detach", tag_ref.as_str()],
+                &lerobot_dir,
+                "git checkout lerobot-vulcan release",
+            )?;
+        }
+        Self::emit_step(Some(emit), "submodules", "success", None);
 
         Self::emit_step(
-            Some(&emit),
+            Some(emit),
             "setup",
             "started",
             Some("Running kiosk setup (this can take 30-40 minutes)".to_string()),
         );
-        Self::run_kiosk_setup(app_handle, &repo_root)?;
-        Self::emit_step(Some(&emit), "setup", "success", None);
+        Self::run_kiosk_setup(app_handle, target_dir)?;
+        Self::emit_step(Some(emit), "setup", "success", None);
+        Ok(())
+    }
 
-        Self::emit_step(
-            Some(&emit),
-            "complete",
-            "success",
-            Some("Kiosk update complete".to_string()),
-        );
+    /// Checks that run against a staged slot before anything is installed.
+    fn check_slot_health(slot_dir: &Path) -> Result<(), String> {
+        Self::find_built_package(slot_dir)?;
+        Self::check_host_import(slot_dir)?;
+        Self::smoke_test_app(
+            &slot_dir
+                .join("src-tauri/target/release")
+                .join(env!("CARGO_PKG_NAME")),
+            slot_dir,
+        )
+    }
+
+    /// The discovery port a staged build answers on while `smoke_test_app`
+    /// runs it; `None` for a normal start.
+    pub fn smoke_test_port() -> Option<u16> {
+        std::env::var(Self::SMOKE_TEST_PORT_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Start the staged app in kiosk mode and wait for its discovery responder,
+    /// the same check the boot guard makes after the switch. It runs with its
+    /// own data directory and discovery port so the live kiosk is untouched.
+    fn smoke_test_app(binary: &Path, slot_dir: &Path) -> Result<(), String> {
+        let port = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+            .and_then(|socket| socket.local_addr())
+            .map_err(|e| format!("Failed to reserve a smoke test port: {}", e))?
+            .port();
+        let data_dir = slot_dir.join(".smoke-test");
+        let _ = fs::remove_dir_all(&data_dir);
+        fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create {}: {}", data_dir.display(), e))?;
+
+        let mut app = Command::new(binary)
+            .arg("--kiosk")
+            .env("SOURCCEY_APP_MODE", "kiosk")
+            .env(Self::SMOKE_TEST_PORT_ENV, port.to_string())
+            .env("XDG_DATA_HOME", &data_dir)
+            .current_dir(slot_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to start staged app {}: {}", binary.display(), e))?;
+
+        let started = Instant::now();
+        let result = loop {
+            match app.try_wait() {
+                Ok(Some(status)) => {
+                    break Err(format!(
+                        "Staged app exited during its smoke test ({})",
+                        status
+                    ))
+                }
+                Ok(None) => {}
+                Err(e) => break Err(format!("Failed to watch staged app: {}", e)),
+            }
+            if KioskDiscoveryResponderService::probe_local(port, Self::SMOKE_TEST_PROBE_INTERVAL) {
+                break Ok(());
+            }
+            if started.elapsed() >= Self::SMOKE_TEST_TIMEOUT {
+                break Err(format!(
+                    "Staged app did not answer discovery within {} seconds",
+                    Self::SMOKE_TEST_TIMEOUT.as_secs()
+                ));
+            }
+        };
+
+        let _ = app.kill();
+        let _ = app.wait();
+        let _ = fs::remove_dir_all(&data_dir);
+        result
+    }
+
+    /// The launcher only runs the app through the guard when it is executable.
+    fn boot_guard_installed(path: &Path) -> bool {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::metadata(path)
+                .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+                .unwrap_or(false)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = path;
+            false
+        }
+    }
+
+    /// Make `target` active. With a boot guard the slot stays on probation
+    /// until it boots healthy; without one the staged smoke test was its
+    /// verification, since nothing would ever clear the flag.
+    fn mark_switched(
+        state: &mut KioskSlotState,
+        record: &mut KioskUpdateRecord,
+        target: KioskSlot,
+        guarded: bool,
+    ) {
+        state.previous = Some(state.active);
+        state.active = target;
+        state.pending_verification = guarded;
+        state.boot_attempts = 0;
+        state.update_id = Some(record.id.clone());
+        if guarded {
+            record.outcome = KioskUpdateOutcome::PendingBoot;
+        } else {
+            KioskSlotService::finish_record(record, KioskUpdateOutcome::Succeeded, None);
+        }
+    }
+
+    fn verify_without_guard(
+        layout: &KioskSlotLayout,
+        state: &mut KioskSlotState,
+    ) -> Result<(), String> {
+        state.pending_verification = false;
+        state.boot_attempts = 0;
+        KioskSlotService::save_state(layout, state)?;
+        let pending = KioskSlotService::load_history(layout)?
+            .into_iter()
+            .find(|record| Some(&record.id) == state.update_id.as_ref());
+        if let Some(mut record) = pending {
+            KioskSlotService::finish_record(&mut record, KioskUpdateOutcome::Succeeded, None);
+            KioskSlotService::save_record(layout, &record)?;
+        }
+        Ok(())
+    }
+
+    fn check_host_import(root: &Path) -> Result<(), String> {
+        let Some(host_module) = RobotProfileService::kiosk().host_module() else {
+            return Ok(());
+        };
+        let lerobot_dir = root.join("modules").join("lerobot-vulcan");
+        let python_path = lerobot_dir.join(".venv").join("bin").join("python");
+        let output = Command::new(&python_path)
+            .args(["-c", &format!("import {}", host_module)])
+            .current_dir(&lerobot_dir)
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", python_path.display(), e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} failed to import: {}",
+                host_module,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
         Ok(())
     }
 
+    /// The `.deb` the slot's own build produced for the version it checks out.
+    /// Kiosk setup points `CARGO_TARGET_DIR` into the slot, so packages left in
+    /// other build directories are never picked up.
+    fn find_built_package(slot_dir: &Path) -> Result<PathBuf, String> {
+        let version = Self::read_slot_app_version(slot_dir)?;
+        let bundle_dir = slot_dir.join("src-tauri/target/release/bundle/deb");
+        let version_marker = format!("_{}_", version);
+
+        fs::read_dir(&bundle_dir)
+            .map_err(|e| format!("Failed to read {}: {}", bundle_dir.display(), e))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|ext| ext == "deb").unwrap_or(false))
+            .filter(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().contains(&version_marker))
+                    .unwrap_or(false)
+            })
+            .filter_map(|path| {
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                Some((modified, path))
+            })
+            .max_by_key(|(modified, _)| *modified)
+            .map(|(_, path)| path)
+            .ok_or_else(|| {
+                format!(
+                    "Kiosk setup did not produce an app package for version {} in {}",
+                    version,
+                    bundle_dir.display()
+                )
+            })
+    }
+
+    fn read_slot_app_version(slot_dir: &Path) -> Result<String, String> {
+        let config_path = slot_dir.join("src-tauri/tauri.conf.json");
+        let content = fs::read_to_string(&config_path)
+            .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+        let config: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", config_path.display(), e))?;
+        config["version"]
+            .as_str()
+            .map(|version| version.to_string())
+            .ok_or_else(|| format!("{} has no app version", config_path.display()))
+    }
+
+    fn preserve_slot_package(
+        layout: &KioskSlotLayout,
+        slot: KioskSlot,
+        slot_dir: &Path,
+    ) -> Result<(), String> {
+        let package = Self::find_built_package(slot_dir)?;
+        let destination = layout.slot_package_path(slot);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::copy(&package, &destination)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to keep package for slot {}: {}", slot.dir_name(), e))
+    }
+
+    fn install_slot_package(
+        app_handle: &AppHandle,
+        layout: &KioskSlotLayout,
+        slot: KioskSlot,
+    ) -> Result<(), String> {
+        let package = layout.slot_package_path(slot);
+        if !package.exists() {
+            return Err(format!("No saved package for slot {}", slot.dir_name()));
+        }
+        let package = package.to_string_lossy().to_string();
+        Self::run_command(
+            app_handle,
+            "sudo",
+            &["-n", "dpkg", "-i", package.as_str()],
+            &layout.slots_dir,
+            "install kiosk package",
+        )
+    }
+
+    fn reinstall_slot_package(app_handle: &AppHandle, layout: &KioskSlotLayout, slot: KioskSlot) {
+        if let Err(e) = Self::install_slot_package(app_handle, layout, slot) {
+            Self::emit_log(
+                app_handle,
+                format!(
+                    "Failed to reinstall package for slot {}: {}",
+                    slot.dir_name(),
+                    e
+                ),
+            );
+        }
+    }
+
+    fn git_output(repo_dir: &Path, args: &[&str]) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_dir)
+            .output()
+            .map_err(|e| format!("Failed to run git {}: {}", args.join(" "), e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     fn run_kiosk_setup(app_handle: &AppHandle, repo_root: &Path) -> Result<(), String> {
         let mut command = Command::new("sudo");
         command.args([
@@ -273,6 +780,7 @@ impl KioskUpdateService {
             "--skip-system",
             "--no-clean",
             "--use-https",
+            "--no-install",
         ]);
         Self::run_streaming_command(app_handle, &mut command, repo_root, "kiosk setup")
     }
@@ -375,6 +883,7 @@ impl KioskUpdateService {
                 step: "log".to_string(),
                 status: "log".to_string(),
                 message: Some(message),
+                download: None,
             },
         );
     }
@@ -426,52 +935,123 @@ impl KioskUpdateService {
                 commit_sha: None,
             })
             .collect::<Vec<_>>();
-        Self::select_latest_prefixed_tag(tags, prefix).map(|tag| tag.name)
+        // Whatever release HEAD carries counts, pre-release or not
+        Self::select_latest_prefixed_tag(tags, prefix, ReleaseChannel::Beta).map(|tag| tag.name)
+    }
+
+    /// Every channel resets to the same tags `check_updates` reports: the pin
+    /// when one is active, otherwise the newest tag the channel admits (pinned
+    /// with no pin selects like stable). `default_ref` is only used when no
+    /// such app tag exists upstream.
+    fn resolve_update_targets(
+        default_ref: String,
+        app_tag_prefix: &str,
+    ) -> Result<KioskUpdateTargets, String> {
+        let settings = KioskEnvironmentService::get_settings()?;
+        let channel = ReleaseChannel::parse(&settings.release_channel)?;
+        let lerobot_tag_prefix = Self::resolve_prefix_env(
+            "SOURCCEY_KIOSK_LEROBOT_TAG_PREFIX",
+            Self::DEFAULT_KIOSK_LEROBOT_TAG_PREFIX,
+        );
+
+        let app_tag = Self::resolve_channel_tag(
+            &KIOSK_APP_TAG_CACHE,
+            "SOURCCEY_KIOSK_APP_TAGS_URL",
+            Self::DEFAULT_KIOSK_APP_TAGS_URL,
+            app_tag_prefix,
+            channel,
+            ReleaseChannelService::active_pin(channel, settings.pinned_app_tag.as_deref()),
+        )?;
+        let lerobot_tag = Self::resolve_channel_tag(
+            &KIOSK_LEROBOT_TAG_CACHE,
+            "SOURCCEY_KIOSK_LEROBOT_TAGS_URL",
+            Self::DEFAULT_KIOSK_LEROBOT_TAGS_URL,
+            &lerobot_tag_prefix,
+            channel,
+            ReleaseChannelService::active_pin(channel, settings.pinned_lerobot_tag.as_deref()),
+        )?;
+
+        Ok(Self::update_targets_from_tags(
+            default_ref,
+            app_tag.as_ref(),
+            lerobot_tag.as_ref(),
+        ))
+    }
+
+    /// Without a LeRobot tag the app checkout's submodule pointer is kept.
+    fn update_targets_from_tags(
+        default_ref: String,
+        app_tag: Option<&LatestTagInfo>,
+        lerobot_tag: Option<&LatestTagInfo>,
+    ) -> KioskUpdateTargets {
+        KioskUpdateTargets {
+            app_ref: app_tag
+                .map(|tag| format!("refs/tags/{}", tag.name))
+                .unwrap_or(default_ref),
+            lerobot_tag: lerobot_tag.map(|tag| tag.name.clone()),
+        }
     }
 
-    fn resolve_latest_tag_cached(
+    fn resolve_channel_tag(
         cache: &Mutex<Option<KioskTagCacheEntry>>,
         url_env_key: &str,
         default_url: &str,
         prefix: &str,
+        channel: ReleaseChannel,
+        pin: Option<&str>,
     ) -> Result<Option<LatestTagInfo>, String> {
+        if let Some(pin) = pin {
+            return Ok(Some(LatestTagInfo {
+                name: pin.to_string(),
+                commit_sha: None,
+            }));
+        }
+
+        let tags = Self::resolve_tags_cached(cache, url_env_key, default_url)?;
+        Ok(Self::select_latest_prefixed_tag(tags, prefix, channel))
+    }
+
+    fn resolve_tags_cached(
+        cache: &Mutex<Option<KioskTagCacheEntry>>,
+        url_env_key: &str,
+        default_url: &str,
+    ) -> Result<Vec<LatestTagInfo>, String> {
         let now = Instant::now();
         let mut cache_guard = cache
             .lock()
             .map_err(|_| "Failed to lock kiosk tag cache".to_string())?;
 
-        Self::resolve_latest_tag_with_cache_entry(&mut cache_guard, now, || {
-            Self::fetch_latest_tag_from_api(url_env_key, default_url, prefix)
+        Self::resolve_tags_with_cache_entry(&mut cache_guard, now, || {
+            Self::fetch_tags_from_api(url_env_key, default_url)
         })
     }
 
-    fn resolve_latest_tag_with_cache_entry<F>(
+    fn resolve_tags_with_cache_entry<F>(
         cache: &mut Option<KioskTagCacheEntry>,
         now: Instant,
-        fetch_latest_tag: F,
-    ) -> Result<Option<LatestTagInfo>, String>
+        fetch_tags: F,
+    ) -> Result<Vec<LatestTagInfo>, String>
     where
-        F: FnOnce() -> Result<Option<LatestTagInfo>, String>,
+        F: FnOnce() -> Result<Vec<LatestTagInfo>, String>,
     {
         if let Some(entry) = cache.as_ref() {
             if now.duration_since(entry.fetched_at) <= Self::TAG_CACHE_TTL {
-                return Ok(entry.latest_tag.clone());
+                return Ok(entry.tags.clone());
             }
         }
 
-        let latest_tag = fetch_latest_tag()?;
+        let tags = fetch_tags()?;
         *cache = Some(KioskTagCacheEntry {
             fetched_at: now,
-            latest_tag: latest_tag.clone(),
+            tags: tags.clone(),
         });
-        Ok(latest_tag)
+        Ok(tags)
     }
 
-    fn fetch_latest_tag_from_api(
+    fn fetch_tags_from_api(
         url_env_key: &str,
         default_url: &str,
-        prefix: &str,
-    ) -> Result<Option<LatestTagInfo>, String> {
+    ) -> Result<Vec<LatestTagInfo>, String> {
         let url = std::env::var(url_env_key).unwrap_or_else(|_| default_url.to_string());
         let client = reqwest::blocking::Client::builder()
             .build()
@@ -493,8 +1073,7 @@ impl KioskUpdateService {
         let payload: serde_json::Value = response
             .json()
             .map_err(|e| format!("Invalid tag payload for {}: {}", url_env_key, e))?;
-        let tags = Self::extract_tag_refs_from_value(&payload);
-        Ok(Self::select_latest_prefixed_tag(tags, prefix))
+        Ok(Self::extract_tag_refs_from_value(&payload))
     }
 
     fn extract_tag_refs_from_value(value: &serde_json::Value) -> Vec<LatestTagInfo> {
@@ -546,31 +1125,21 @@ impl KioskUpdateService {
         }
     }
 
-    fn select_latest_prefixed_tag(tags: Vec<LatestTagInfo>, prefix: &str) -> Option<LatestTagInfo> {
-        let mut best_semver: Option<(SimpleSemver, LatestTagInfo)> = None;
-
-        for tag in tags {
-            let normalized_name = match Self::normalize_prefixed_tag(&tag.name, prefix) {
-                Some(value) => value,
-                None => continue,
-            };
-            if let Some(version) = Self::parse_prefixed_semver(&normalized_name, prefix) {
-                match best_semver.as_ref() {
-                    Some((best_version, _)) if &version <= best_version => {}
-                    _ => {
-                        best_semver = Some((
-                            version,
-                            LatestTagInfo {
-                                name: normalized_name.clone(),
-                                commit_sha: tag.commit_sha.clone(),
-                            },
-                        ))
-                    }
-                }
-            }
-        }
-
-        best_semver.map(|(_, tag)| tag)
+    fn select_latest_prefixed_tag(
+        tags: Vec<LatestTagInfo>,
+        prefix: &str,
+        channel: ReleaseChannel,
+    ) -> Option<LatestTagInfo> {
+        let tags = tags
+            .into_iter()
+            .filter_map(|tag| {
+                Self::normalize_prefixed_tag(&tag.name, prefix).map(|name| LatestTagInfo {
+                    name,
+                    commit_sha: tag.commit_sha,
+                })
+            })
+            .collect();
+        ReleaseChannelService::select_latest(tags, |tag| tag.name.as_str(), prefix, channel)
     }
 
     fn normalize_prefixed_tag(tag: &str, prefix: &str) -> Option<String> {
@@ -584,26 +1153,27 @@ impl KioskUpdateService {
         Some(trimmed.to_string())
     }
 
-    fn parse_prefixed_semver(tag: &str, prefix: &str) -> Option<SimpleSemver> {
+    fn parse_prefixed_semver(tag: &str, prefix: &str) -> Option<ReleaseVersion> {
         let normalized = Self::normalize_prefixed_tag(tag, prefix)?;
-        let raw_version = normalized.strip_prefix(prefix)?;
-        let version = raw_version.strip_prefix('v').unwrap_or(raw_version);
-        Self::parse_semver_core(version)
+        ReleaseChannelService::parse_tag_version(&normalized, prefix)
     }
 
-    fn parse_semver_core(value: &str) -> Option<SimpleSemver> {
-        let mut parts = value.split('.');
-        let major = parts.next()?.parse::<u64>().ok()?;
-        let minor = parts.next()?.parse::<u64>().ok()?;
-        let patch = parts.next()?.parse::<u64>().ok()?;
-        if parts.next().is_some() {
-            return None;
+    /// A pinned component is current only when it sits exactly on its pin, so
+    /// a robot ahead of the pin is moved back to it.
+    fn is_component_up_to_date(
+        repo_dir: &Path,
+        current: Option<&str>,
+        latest: Option<&LatestTagInfo>,
+        prefix: &str,
+        pinned: bool,
+    ) -> bool {
+        if pinned {
+            return match latest {
+                Some(pin) => current == Some(pin.name.as_str()),
+                None => true,
+            };
         }
-        Some(SimpleSemver {
-            major,
-            minor,
-            patch,
-        })
+        Self::is_repo_up_to_date(repo_dir, current, latest, prefix)
     }
 
     fn is_repo_up_to_date(
@@ -687,6 +1257,7 @@ impl KioskUpdateService {
                 step: step.to_string(),
                 status: status.to_string(),
                 message,
+                download: None,
             });
         }
     }
@@ -12,9 +12,19 @@ use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
 
+use crate::modules::settings::services::desktop_environment::desktop_environment_service::{
+    DesktopEnvironmentService, DesktopEnvironmentSettings,
+};
 use crate::services::directory::directory_service::DirectoryService;
 use crate::services::environment::build_service::BuildService;
 use crate::services::log::log_service::LogService;
+use crate::services::setup::download_service::{
+    DownloadOptions, DownloadProgress, DownloadService,
+};
+use crate::services::setup::release_channel_service::{
+    ReleaseChannel, ReleaseChannelService, ReleaseVersion,
+};
+use crate::services::setup::update_signature_service::UpdateSignatureService;
 use crate::utils::windows_process::configure_std_command;
 
 pub struct LocalSetupService;
@@ -24,6 +34,8 @@ pub struct SetupProgress {
     pub step: String,
     pub status: String,
     pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download: Option<DownloadProgress>,
 }
 
 #[derive(Clone, Serialize)]
@@ -32,6 +44,14 @@ pub struct SetupStatus {
     pub missing: Vec<String>,
 }
 
+/// Local replacements for the network sources `ensure_installed` normally
+/// uses, taken from a verified offline bundle.
+pub(crate) struct OfflineSetupSource {
+    pub lerobot_zip: PathBuf,
+    pub wheels_dir: PathBuf,
+    pub python_executable: PathBuf,
+}
+
 #[derive(Clone, Serialize)]
 pub struct DesktopExtrasStatus {
     pub installed: bool,
@@ -47,6 +67,7 @@ pub struct LerobotUpdateStatus {
     pub latest_commit: Option<String>,
     pub current_tag: Option<String>,
     pub latest_tag: Option<String>,
+    pub release_channel: String,
     pub message: Option<String>,
 }
 
@@ -74,6 +95,8 @@ struct ModulesManifest {
 struct LerobotModuleManifest {
     pub commit: Option<String>,
     pub tag: Option<String>,
+    // Archive for `tag`, when it isn't at the usual CDN path
+    pub url: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -85,7 +108,7 @@ struct LerobotCommitMarker {
 #[derive(Clone)]
 struct LerobotTagCacheEntry {
     fetched_at: Instant,
-    latest_tag: Option<LatestLerobotTagInfo>,
+    tags: Vec<LatestLerobotTagInfo>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -115,22 +138,15 @@ struct CurrentLerobotReleaseInfo {
     commit: Option<String>,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct SimpleSemver {
-    major: u64,
-    minor: u64,
-    patch: u64,
-}
-
 lazy_static! {
     static ref LEROBOT_TAG_CACHE: Mutex<Option<LerobotTagCacheEntry>> = Mutex::new(None);
 }
 
 impl LocalSetupService {
-    const SOURCCEY_RUNTIME_EXTRA: &str = "sourccey";
+    pub(crate) const SOURCCEY_RUNTIME_EXTRA: &str = "sourccey";
     // Match lerobot-vulcan/setup/setup_modules/setup_desktop.py, which installs
     // both extras explicitly for desktop AI content.
-    const SOURCCEY_DESKTOP_EXTRA: &str = "sourccey-desktop,xvla";
+    pub(crate) const SOURCCEY_DESKTOP_EXTRA: &str = "sourccey-desktop,xvla";
 
     pub fn resolve_uv_binary(app_handle: &AppHandle) -> Result<PathBuf, String> {
         let app_data_dir = app_handle
@@ -140,16 +156,16 @@ impl LocalSetupService {
         Self::ensure_uv_binary(app_handle, &app_data_dir)
     }
 
+    const LEROBOT_ZIP_BASE_URL: &str =
+        "https://sourccey.nyc3.cdn.digitaloceanspaces.com/updater/lerobot-vulcan";
     const DEFAULT_LEROBOT_ZIP_URL: &str =
         "https://sourccey.nyc3.cdn.digitaloceanspaces.com/updater/lerobot-vulcan/lerobot-vulcan_vulcan-0.1.10.zip";
     const DEFAULT_LEROBOT_ZIP_SHA256_URL: &str =
         "https://sourccey.nyc3.cdn.digitaloceanspaces.com/updater/lerobot-vulcan/lerobot-vulcan_vulcan-0.1.10.zip.sha256";
-    const DEFAULT_UPDATER_URL: &str =
-        "https://sourccey.nyc3.cdn.digitaloceanspaces.com/updater/latest.json";
     const DEFAULT_LEROBOT_TAGS_URL: &str =
         "https://api.github.com/repos/vulcan-forge/lerobot-vulcan/tags?per_page=100";
     const LEROBOT_TAG_CACHE_TTL: Duration = Duration::from_secs(300);
-    const UV_VENV_PYTHON_VERSION: &str = "3.12";
+    pub(crate) const UV_VENV_PYTHON_VERSION: &str = "3.12";
     #[allow(dead_code)]
     pub fn maybe_start(app_handle: AppHandle, kiosk: bool) {
         if kiosk || BuildService::is_dev_mode() {
@@ -157,7 +173,7 @@ impl LocalSetupService {
         }
 
         std::thread::spawn(move || {
-            if let Err(e) = Self::ensure_installed(&app_handle, None, true) {
+            if let Err(e) = Self::ensure_installed(&app_handle, None, true, None) {
                 eprintln!("[setup] {}", e);
                 app_handle
                     .dialog()
@@ -263,7 +279,25 @@ impl LocalSetupService {
                 return result;
             }
         }
-        if let Err(error) = Self::ensure_installed(app_handle, Some(&emit), false) {
+        if let Err(error) = Self::ensure_installed(app_handle, Some(&emit), false, None) {
+            Self::write_setup_log(app_handle, &error);
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Install the runtime built only from `source`, for machines that cannot
+    /// reach GitHub or the package index. The caller moves any previous
+    /// install and its marker out of the way first so it can restore them.
+    pub(crate) fn run_offline_setup(
+        app_handle: &AppHandle,
+        source: &OfflineSetupSource,
+    ) -> Result<(), String> {
+        let emit = |progress: SetupProgress| {
+            let _ = app_handle.emit("setup:progress", progress);
+        };
+
+        if let Err(error) = Self::ensure_installed(app_handle, Some(&emit), false, Some(source)) {
             Self::write_setup_log(app_handle, &error);
             return Err(error);
         }
@@ -271,18 +305,19 @@ impl LocalSetupService {
     }
 
     pub fn check_lerobot_update(app_handle: &AppHandle) -> Result<LerobotUpdateStatus, String> {
+        let settings = DesktopEnvironmentService::get_settings()?;
+        let channel = ReleaseChannel::parse(&settings.release_channel)?;
+        let pin =
+            ReleaseChannelService::active_pin(channel, settings.pinned_lerobot_tag.as_deref());
         let current_release = Self::resolve_current_lerobot_release_info(app_handle);
-        let latest_release = Self::resolve_latest_lerobot_tag();
-        let manifest_release = match Self::read_manifest_lerobot_release_info() {
-            Ok(release) => release,
-            Err(error) => {
-                eprintln!(
-                    "[setup] Failed to read updater manifest release metadata: {}",
-                    error
-                );
-                None
-            }
+        let latest_release = match pin {
+            Some(pin) => Some(LatestLerobotTagInfo {
+                name: pin.to_string(),
+                commit_sha: None,
+            }),
+            None => Self::resolve_latest_lerobot_tag(channel),
         };
+        let manifest_release = Self::read_manifest_lerobot_release_info();
         let latest_tag = latest_release.as_ref().map(|release| release.name.clone());
         let latest_commit = latest_release
             .as_ref()
@@ -292,11 +327,14 @@ impl LocalSetupService {
                     .as_ref()
                     .and_then(|release| release.commit.clone())
             });
-        let (state, message) = Self::resolve_lerobot_release_state(
-            &current_release,
-            latest_release.as_ref(),
-            manifest_release.as_ref(),
-        );
+        let (state, message) = match pin {
+            Some(pin) => Self::resolve_pinned_lerobot_release_state(&current_release, pin),
+            None => Self::resolve_lerobot_release_state(
+                &current_release,
+                latest_release.as_ref(),
+                manifest_release.as_ref(),
+            ),
+        };
         let up_to_date = matches!(state, LerobotReleaseState::UpToDate);
 
         Ok(LerobotUpdateStatus {
@@ -306,10 +344,23 @@ impl LocalSetupService {
             latest_commit,
             current_tag: current_release.tag,
             latest_tag,
+            release_channel: channel.as_str().to_string(),
             message,
         })
     }
 
+    /// Pin LeRobot to the release tag installed now.
+    pub fn pin_current_lerobot_release(
+        app_handle: &AppHandle,
+    ) -> Result<DesktopEnvironmentSettings, String> {
+        let current_release = Self::resolve_current_lerobot_release_info(app_handle);
+        let tag = current_release.tag.ok_or(
+            "The installed LeRobot runtime is not a tagged release, so there is nothing to pin"
+                .to_string(),
+        )?;
+        DesktopEnvironmentService::pin_lerobot_release(&tag)
+    }
+
     pub fn reset_modules(app_handle: &AppHandle) -> Result<(), String> {
         let emit = |progress: SetupProgress| {
             let _ = app_handle.emit("setup:progress", progress);
@@ -364,7 +415,7 @@ impl LocalSetupService {
             Some("Reset complete. Reinstalling modules.".to_string()),
         );
 
-        if let Err(error) = Self::ensure_installed(app_handle, Some(&emit), false) {
+        if let Err(error) = Self::ensure_installed(app_handle, Some(&emit), false, None) {
             Self::write_setup_log(app_handle, &error);
             return Err(error);
         }
@@ -435,6 +486,7 @@ impl LocalSetupService {
                 &lerobot_dir,
                 &python_path,
                 Self::SOURCCEY_DESKTOP_EXTRA,
+                None,
             )
             .map_err(|e| {
                 Self::emit_step(Some(&emit), "deps", "error", Some(e.clone()));
@@ -484,6 +536,7 @@ impl LocalSetupService {
         app_handle: &AppHandle,
         emit: Option<&dyn Fn(SetupProgress)>,
         show_dialogs: bool,
+        offline: Option<&OfflineSetupSource>,
     ) -> Result<(), String> {
         let app_data_dir = app_handle
             .path()
@@ -518,70 +571,80 @@ impl LocalSetupService {
             .map_err(|e| format!("Failed to create setup directory: {}", e))?;
 
         let mut downloaded_zip_url: Option<String> = None;
-        if !lerobot_dir.exists() {
+        if let (false, Some(offline)) = (lerobot_dir.exists(), offline) {
+            fs::create_dir_all(&install_root)
+                .map_err(|e| format!("Failed to create install root: {}", e))?;
             Self::emit_step(
                 emit,
                 "download",
+                "success",
+                Some("Using lerobot-vulcan from offline bundle".to_string()),
+            );
+            Self::emit_step(
+                emit,
+                "verify",
+                "success",
+                Some("Verified by offline bundle manifest".to_string()),
+            );
+
+            Self::emit_step(
+                emit,
+                "extract",
                 "started",
-                Some("Downloading lerobot-vulcan".to_string()),
+                Some("Extracting lerobot-vulcan".to_string()),
             );
-            let zip_url = std::env::var("SOURCCEY_LEROBOT_ZIP_URL")
-                .unwrap_or_else(|_| Self::DEFAULT_LEROBOT_ZIP_URL.to_string());
-            if zip_url.trim().is_empty() {
-                Self::emit_step(
-                    emit,
-                    "download",
-                    "error",
-                    Some("Missing lerobot-vulcan zip URL".to_string()),
-                );
-                return Err(
-                    "SOURCCEY_LEROBOT_ZIP_URL is not set. Provide a zip URL for lerobot-vulcan."
-                        .to_string(),
+            Self::extract_zip(&offline.lerobot_zip, &install_root).inspect_err(|e| {
+                Self::emit_step(emit, "extract", "error", Some(e.clone()));
+            })?;
+            if !lerobot_dir.exists() {
+                let error = format!(
+                    "lerobot-vulcan not found after extracting bundle archive into {:?}",
+                    install_root
                 );
+                Self::emit_step(emit, "extract", "error", Some(error.clone()));
+                return Err(error);
             }
+            Self::emit_step(emit, "extract", "success", None);
+
+            // Bundles keep the release archive name, which carries the tag.
+            downloaded_zip_url = offline
+                .lerobot_zip
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string());
+        } else if !lerobot_dir.exists() {
+            Self::emit_step(
+                emit,
+                "download",
+                "started",
+                Some("Downloading lerobot-vulcan".to_string()),
+            );
+            let zip_url = Self::lerobot_zip_url().inspect_err(|e| {
+                Self::emit_step(emit, "download", "error", Some(e.clone()));
+            })?;
 
             fs::create_dir_all(&install_root)
                 .map_err(|e| format!("Failed to create install root: {}", e))?;
 
-            let zip_path = setup_dir.join("lerobot-vulcan.zip");
-            Self::download_file(&zip_url, &zip_path).map_err(|e| {
-                Self::emit_step(emit, "download", "error", Some(e.clone()));
-                e
+            // Resolve the checksum first so the archive is hashed as it streams.
+            let expected_sha256 = Self::resolve_lerobot_zip_sha256(&zip_url).inspect_err(|e| {
+                Self::emit_step(emit, "verify", "error", Some(e.clone()));
             })?;
+
+            let zip_path = setup_dir.join("lerobot-vulcan.zip");
+            Self::download_file(&zip_url, &zip_path, Some(&expected_sha256), emit).inspect_err(
+                |e| {
+                    Self::emit_step(emit, "download", "error", Some(e.clone()));
+                },
+            )?;
             downloaded_zip_url = Some(zip_url.clone());
             Self::emit_step(emit, "download", "success", None);
 
-            let enforce_checksum = std::env::var("SOURCCEY_ENFORCE_LEROBOT_CHECKSUM")
-                .ok()
-                .map(|v| {
-                    let normalized = v.trim().to_ascii_lowercase();
-                    normalized == "1" || normalized == "true" || normalized == "yes"
-                })
-                .unwrap_or(false);
-            if enforce_checksum {
-                Self::emit_step(
-                    emit,
-                    "verify",
-                    "started",
-                    Some("Verifying lerobot-vulcan archive integrity".to_string()),
-                );
-                let expected_sha256 = Self::resolve_lerobot_zip_sha256(&zip_url).map_err(|e| {
-                    Self::emit_step(emit, "verify", "error", Some(e.clone()));
-                    e
-                })?;
-                Self::verify_file_sha256(&zip_path, &expected_sha256).map_err(|e| {
-                    Self::emit_step(emit, "verify", "error", Some(e.clone()));
-                    e
-                })?;
-                Self::emit_step(emit, "verify", "success", None);
-            } else {
-                Self::emit_step(
-                    emit,
-                    "verify",
-                    "success",
-                    Some("Checksum verification skipped (SOURCCEY_ENFORCE_LEROBOT_CHECKSUM is disabled)".to_string()),
-                );
-            }
+            Self::emit_step(
+                emit,
+                "verify",
+                "success",
+                Some("Archive checksum verified during download".to_string()),
+            );
 
             Self::emit_step(
                 emit,
@@ -644,8 +707,12 @@ impl LocalSetupService {
             Self::emit_step(emit, "venv", "error", Some(e.clone()));
             e
         })?;
-        let venv_args = Self::uv_venv_args();
-        Self::run_command(&uv_target, &venv_args, &lerobot_dir, "uv venv").map_err(|e| {
+        let venv_args: Vec<String> = match offline {
+            Some(offline) => Self::uv_offline_venv_args(&offline.python_executable),
+            None => Self::uv_venv_args().map(str::to_string).to_vec(),
+        };
+        let venv_arg_refs: Vec<&str> = venv_args.iter().map(|arg| arg.as_str()).collect();
+        Self::run_command(&uv_target, &venv_arg_refs, &lerobot_dir, "uv venv").map_err(|e| {
             Self::emit_step(emit, "venv", "error", Some(e.clone()));
             e
         })?;
@@ -670,6 +737,7 @@ impl LocalSetupService {
             &lerobot_dir,
             &python_path,
             Self::SOURCCEY_RUNTIME_EXTRA,
+            offline.map(|offline| offline.wheels_dir.as_path()),
         )
         .map_err(|e| {
             Self::emit_step(emit, "deps", "error", Some(e.clone()));
@@ -677,14 +745,7 @@ impl LocalSetupService {
         })?;
         Self::emit_step(emit, "deps", "success", None);
 
-        let compile_script = lerobot_dir
-            .join("src")
-            .join("lerobot")
-            .join("robots")
-            .join("sourccey")
-            .join("sourccey")
-            .join("protobuf")
-            .join("compile.py");
+        let compile_script = Self::protobuf_dir_for(&lerobot_dir).join("compile.py");
         if compile_script.exists() {
             Self::emit_step(
                 emit,
@@ -741,48 +802,48 @@ impl LocalSetupService {
     }
 
     fn read_updater_manifest() -> Result<UpdaterManifest, String> {
-        let url = std::env::var("SOURCCEY_UPDATER_URL")
-            .unwrap_or_else(|_| Self::DEFAULT_UPDATER_URL.to_string());
-        let response = reqwest::blocking::get(&url)
-            .map_err(|e| format!("Failed to download updater manifest: {}", e))?;
-        if !response.status().is_success() {
-            return Err(format!(
-                "Updater manifest download failed ({}): {}",
-                response.status(),
-                url
-            ));
-        }
-        response
-            .json()
+        let url = DesktopEnvironmentService::resolve_updater_manifest_url();
+        let manifest = UpdateSignatureService::fetch_verified_manifest_blocking(
+            &url,
+            env!("CARGO_PKG_VERSION"),
+        )?;
+        serde_json::from_value(manifest)
             .map_err(|e| format!("Invalid updater manifest JSON: {}", e))
     }
 
-    fn read_manifest_lerobot_release_info() -> Result<Option<ManifestLerobotReleaseInfo>, String> {
-        let manifest = Self::read_updater_manifest()?;
-        Ok(manifest
-            .modules
-            .and_then(|modules| modules.lerobot_vulcan)
-            .map(|module| ManifestLerobotReleaseInfo {
-                tag: module.tag.and_then(|tag| Self::normalize_vulcan_tag(&tag)),
-                commit: module
-                    .commit
-                    .and_then(|commit| Self::normalize_git_commit_sha(&commit)),
-            }))
+    fn read_manifest_lerobot_release_info() -> Option<ManifestLerobotReleaseInfo> {
+        Self::read_manifest_lerobot_module().map(|module| ManifestLerobotReleaseInfo {
+            tag: module.tag.and_then(|tag| Self::normalize_vulcan_tag(&tag)),
+            commit: module
+                .commit
+                .and_then(|commit| Self::normalize_git_commit_sha(&commit)),
+        })
     }
 
-    fn resolve_latest_lerobot_tag() -> Option<LatestLerobotTagInfo> {
-        let now = Instant::now();
-        let mut cache_guard = match LEROBOT_TAG_CACHE.lock() {
-            Ok(guard) => guard,
-            Err(_) => {
-                return Self::fetch_latest_lerobot_tag_from_api().ok().flatten();
+    fn read_manifest_lerobot_module() -> Option<LerobotModuleManifest> {
+        match Self::read_updater_manifest() {
+            Ok(manifest) => manifest.modules.and_then(|modules| modules.lerobot_vulcan),
+            Err(error) => {
+                eprintln!(
+                    "[setup] Failed to read updater manifest release metadata: {}",
+                    error
+                );
+                None
             }
+        }
+    }
+
+    fn resolve_latest_lerobot_tag(channel: ReleaseChannel) -> Option<LatestLerobotTagInfo> {
+        let now = Instant::now();
+        let tags = match LEROBOT_TAG_CACHE.lock() {
+            Ok(mut cache_guard) => Self::resolve_tags_with_cache(&mut cache_guard, now, || {
+                Self::fetch_lerobot_tags_from_api()
+            }),
+            Err(_) => Self::fetch_lerobot_tags_from_api(),
         };
 
-        match Self::resolve_latest_tag_with_cache(&mut cache_guard, now, || {
-            Self::fetch_latest_lerobot_tag_from_api()
-        }) {
-            Ok(tag) => tag,
+        match tags {
+            Ok(tags) => Self::select_latest_vulcan_tag(tags, channel),
             Err(error) => {
                 eprintln!("[setup] Failed to resolve latest lerobot tag: {}", error);
                 None
@@ -790,29 +851,29 @@ impl LocalSetupService {
         }
     }
 
-    fn resolve_latest_tag_with_cache<F>(
+    fn resolve_tags_with_cache<F>(
         cache: &mut Option<LerobotTagCacheEntry>,
         now: Instant,
-        fetch_latest_tag: F,
-    ) -> Result<Option<LatestLerobotTagInfo>, String>
+        fetch_tags: F,
+    ) -> Result<Vec<LatestLerobotTagInfo>, String>
     where
-        F: FnOnce() -> Result<Option<LatestLerobotTagInfo>, String>,
+        F: FnOnce() -> Result<Vec<LatestLerobotTagInfo>, String>,
     {
         if let Some(entry) = cache.as_ref() {
             if now.duration_since(entry.fetched_at) <= Self::LEROBOT_TAG_CACHE_TTL {
-                return Ok(entry.latest_tag.clone());
+                return Ok(entry.tags.clone());
             }
         }
 
-        let latest_tag = fetch_latest_tag()?;
+        let tags = fetch_tags()?;
         *cache = Some(LerobotTagCacheEntry {
             fetched_at: now,
-            latest_tag: latest_tag.clone(),
+            tags: tags.clone(),
         });
-        Ok(latest_tag)
+        Ok(tags)
     }
 
-    fn fetch_latest_lerobot_tag_from_api() -> Result<Option<LatestLerobotTagInfo>, String> {
+    fn fetch_lerobot_tags_from_api() -> Result<Vec<LatestLerobotTagInfo>, String> {
         let url = std::env::var("SOURCCEY_LEROBOT_TAGS_URL")
             .unwrap_or_else(|_| Self::DEFAULT_LEROBOT_TAGS_URL.to_string());
 
@@ -839,8 +900,7 @@ impl LocalSetupService {
         let payload: serde_json::Value = response
             .json()
             .map_err(|e| format!("Invalid lerobot tags payload: {}", e))?;
-        let tags = Self::extract_tag_infos_from_value(&payload);
-        Ok(Self::select_latest_vulcan_tag(tags))
+        Ok(Self::extract_tag_infos_from_value(&payload))
     }
 
     fn extract_tag_infos_from_value(value: &serde_json::Value) -> Vec<LatestLerobotTagInfo> {
@@ -894,54 +954,25 @@ impl LocalSetupService {
         }
     }
 
-    fn select_latest_vulcan_tag(tags: Vec<LatestLerobotTagInfo>) -> Option<LatestLerobotTagInfo> {
-        let mut best_semver: Option<(SimpleSemver, LatestLerobotTagInfo)> = None;
-
-        for tag in tags {
-            let trimmed = tag.name.trim();
-            if !trimmed.starts_with("vulcan/") {
-                continue;
-            }
-            let normalized = trimmed.to_string();
-            if let Some(version) = Self::parse_vulcan_semver(&normalized) {
-                match best_semver.as_ref() {
-                    Some((best_version, _)) if &version <= best_version => {}
-                    _ => {
-                        best_semver = Some((
-                            version,
-                            LatestLerobotTagInfo {
-                                name: normalized.clone(),
-                                commit_sha: tag.commit_sha.clone(),
-                            },
-                        ));
-                    }
-                }
-            }
-        }
-
-        best_semver.map(|(_, tag)| tag)
+    fn select_latest_vulcan_tag(
+        tags: Vec<LatestLerobotTagInfo>,
+        channel: ReleaseChannel,
+    ) -> Option<LatestLerobotTagInfo> {
+        let tags = tags
+            .into_iter()
+            .filter_map(|tag| {
+                Self::normalize_vulcan_tag(&tag.name).map(|name| LatestLerobotTagInfo {
+                    name,
+                    commit_sha: tag.commit_sha,
+                })
+            })
+            .collect();
+        ReleaseChannelService::select_latest(tags, |tag| tag.name.as_str(), "vulcan/", channel)
     }
 
-    fn parse_vulcan_semver(tag: &str) -> Option<SimpleSemver> {
+    fn parse_vulcan_semver(tag: &str) -> Option<ReleaseVersion> {
         let normalized = Self::normalize_vulcan_tag(tag)?;
-        let raw_version = normalized.strip_prefix("vulcan/")?;
-        let version = raw_version.strip_prefix('v').unwrap_or(raw_version);
-        Self::parse_semver_core(version)
-    }
-
-    fn parse_semver_core(value: &str) -> Option<SimpleSemver> {
-        let mut segments = value.split('.');
-        let major = segments.next()?.parse::<u64>().ok()?;
-        let minor = segments.next()?.parse::<u64>().ok()?;
-        let patch = segments.next()?.parse::<u64>().ok()?;
-        if segments.next().is_some() {
-            return None;
-        }
-        Some(SimpleSemver {
-            major,
-            minor,
-            patch,
-        })
+        ReleaseChannelService::parse_tag_version(&normalized, "vulcan/")
     }
 
     fn normalize_vulcan_tag(tag: &str) -> Option<String> {
@@ -1020,7 +1051,34 @@ impl LocalSetupService {
         }
     }
 
-    fn lerobot_tag_from_zip_url(zip_url: &str) -> Option<String> {
+    fn resolve_pinned_lerobot_release_state(
+        current_release: &CurrentLerobotReleaseInfo,
+        pinned_tag: &str,
+    ) -> (LerobotReleaseState, Option<String>) {
+        match current_release.tag.as_deref() {
+            Some(tag) if tag == pinned_tag => (
+                LerobotReleaseState::UpToDate,
+                Some(format!("LeRobot is pinned to {}.", pinned_tag)),
+            ),
+            Some(tag) => (
+                LerobotReleaseState::UpdateAvailable,
+                Some(format!(
+                    "LeRobot is pinned to {} but {} is installed.",
+                    pinned_tag, tag
+                )),
+            ),
+            None if current_release.source == CurrentLerobotReleaseSource::GitUntagged => (
+                LerobotReleaseState::CustomBuild,
+                Some("This runtime is on an untagged local checkout.".to_string()),
+            ),
+            None => (
+                LerobotReleaseState::Unknown,
+                Some("Installed LeRobot release metadata could not be determined.".to_string()),
+            ),
+        }
+    }
+
+    pub(crate) fn lerobot_tag_from_zip_url(zip_url: &str) -> Option<String> {
         let filename = zip_url.split(['?', '#']).next()?.rsplit('/').next()?;
         let version = filename
             .strip_prefix("lerobot-vulcan_vulcan-")?
@@ -1035,7 +1093,7 @@ impl LocalSetupService {
         app_handle: &AppHandle,
         downloaded_zip_url: Option<&str>,
     ) {
-        let manifest_release = Self::read_manifest_lerobot_release_info().ok().flatten();
+        let manifest_release = Self::read_manifest_lerobot_release_info();
         let existing_tag = Self::read_current_lerobot_tag_marker(app_handle);
         let existing_commit = Self::read_current_lerobot_commit_marker(app_handle);
         let marker_tag = Self::read_current_lerobot_git_tag(app_handle)
@@ -1124,12 +1182,12 @@ impl LocalSetupService {
         serde_json::from_str(&contents).ok()
     }
 
-    fn read_current_lerobot_commit_marker(app_handle: &AppHandle) -> Option<String> {
+    pub(crate) fn read_current_lerobot_commit_marker(app_handle: &AppHandle) -> Option<String> {
         let marker = Self::read_current_lerobot_marker(app_handle)?;
         Self::normalize_git_commit_sha(&marker.commit)
     }
 
-    fn read_current_lerobot_tag_marker(app_handle: &AppHandle) -> Option<String> {
+    pub(crate) fn read_current_lerobot_tag_marker(app_handle: &AppHandle) -> Option<String> {
         let marker = Self::read_current_lerobot_marker(app_handle)?;
         marker
             .tag
@@ -1157,6 +1215,7 @@ impl LocalSetupService {
             .map(|line| line.trim().to_string())
             .filter(|line| !line.is_empty())
             .collect::<Vec<_>>();
+        // Whatever release HEAD carries counts, pre-release or not
         Self::select_latest_vulcan_tag(
             tags.into_iter()
                 .map(|name| LatestLerobotTagInfo {
@@ -1164,11 +1223,12 @@ impl LocalSetupService {
                     commit_sha: None,
                 })
                 .collect(),
+            ReleaseChannel::Beta,
         )
         .map(|tag| tag.name)
     }
 
-    fn resolve_lerobot_dir(app_handle: &AppHandle) -> Option<PathBuf> {
+    pub(crate) fn resolve_lerobot_dir(app_handle: &AppHandle) -> Option<PathBuf> {
         if BuildService::is_dev_mode() {
             DirectoryService::get_current_dir_dev()
                 .ok()
@@ -1179,7 +1239,7 @@ impl LocalSetupService {
         }
     }
 
-    fn read_current_lerobot_git_commit(app_handle: &AppHandle) -> Option<String> {
+    pub(crate) fn read_current_lerobot_git_commit(app_handle: &AppHandle) -> Option<String> {
         let lerobot_dir = Self::resolve_lerobot_dir(app_handle)?;
         if !lerobot_dir.exists() {
             return None;
@@ -1214,7 +1274,7 @@ impl LocalSetupService {
         Some(trimmed.to_ascii_lowercase())
     }
 
-    fn reinstall_dependencies(
+    pub(crate) fn reinstall_dependencies(
         app_handle: &AppHandle,
         emit: Option<&dyn Fn(SetupProgress)>,
     ) -> Result<(), String> {
@@ -1234,7 +1294,7 @@ impl LocalSetupService {
         };
 
         if !lerobot_dir.exists() {
-            return Self::ensure_installed(app_handle, emit, false);
+            return Self::ensure_installed(app_handle, emit, false, None);
         }
 
         fs::create_dir_all(&setup_dir)
@@ -1281,6 +1341,7 @@ impl LocalSetupService {
             &lerobot_dir,
             &python_path,
             Self::SOURCCEY_RUNTIME_EXTRA,
+            None,
         )
         .map_err(|e| {
             Self::emit_step(emit, "deps", "error", Some(e.clone()));
@@ -1288,14 +1349,7 @@ impl LocalSetupService {
         })?;
         Self::emit_step(emit, "deps", "success", None);
 
-        let compile_script = lerobot_dir
-            .join("src")
-            .join("lerobot")
-            .join("robots")
-            .join("sourccey")
-            .join("sourccey")
-            .join("protobuf")
-            .join("compile.py");
+        let compile_script = Self::protobuf_dir_for(&lerobot_dir).join("compile.py");
         if compile_script.exists() {
             Self::emit_step(
                 emit,
@@ -1342,12 +1396,22 @@ impl LocalSetupService {
         Ok(())
     }
 
-    fn run_command(
+    pub(crate) fn run_command(
         exe: &Path,
         args: &[&str],
         working_dir: &Path,
         label: &str,
     ) -> Result<(), String> {
+        Self::run_command_output(exe, args, working_dir, label).map(|_| ())
+    }
+
+    /// Like `run_command`, returning the trimmed stdout on success.
+    pub(crate) fn run_command_output(
+        exe: &Path,
+        args: &[&str],
+        working_dir: &Path,
+        label: &str,
+    ) -> Result<String, String> {
         if !exe.exists() {
             return Err(format!("{} executable not found at: {:?}", label, exe));
         }
@@ -1376,7 +1440,7 @@ impl LocalSetupService {
             ));
         }
 
-        Ok(())
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
     fn run_uv_pip_install(
@@ -1384,18 +1448,20 @@ impl LocalSetupService {
         working_dir: &Path,
         python_path: &Path,
         extras: Option<&str>,
+        find_links: Option<&Path>,
         label: &str,
     ) -> Result<(), String> {
-        let args = Self::uv_pip_install_args(python_path, extras);
+        let args = Self::uv_pip_install_args(python_path, extras, find_links);
         let arg_refs: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
         Self::run_command(uv_target, &arg_refs, working_dir, label)
     }
 
-    fn install_lerobot_extra(
+    pub(crate) fn install_lerobot_extra(
         uv_target: &Path,
         working_dir: &Path,
         python_path: &Path,
         extra: &str,
+        find_links: Option<&Path>,
     ) -> Result<(), String> {
         let label = format!("uv pip install {}", extra);
         Self::run_uv_pip_install(
@@ -1403,6 +1469,7 @@ impl LocalSetupService {
             working_dir,
             python_path,
             Some(extra),
+            find_links,
             label.as_str(),
         )
         .map_err(|error| {
@@ -1413,11 +1480,23 @@ impl LocalSetupService {
         })
     }
 
-    fn uv_pip_install_args(python_path: &Path, extras: Option<&str>) -> Vec<String> {
+    fn uv_pip_install_args(
+        python_path: &Path,
+        extras: Option<&str>,
+        find_links: Option<&Path>,
+    ) -> Vec<String> {
         let mut args = vec!["pip".to_string(), "install".to_string()];
         args.push("--python".to_string());
         args.push(python_path.to_string_lossy().to_string());
 
+        if let Some(find_links) = find_links {
+            // Offline bundles ship every wheel; never fall back to the index.
+            args.push("--offline".to_string());
+            args.push("--no-index".to_string());
+            args.push("--find-links".to_string());
+            args.push(find_links.to_string_lossy().to_string());
+        }
+
         #[cfg(windows)]
         {
             // NumPy has a Windows wheel for our Python 3.12 venv; forcing binary avoids
@@ -1439,6 +1518,16 @@ impl LocalSetupService {
         ["venv", "--clear", "--python", Self::UV_VENV_PYTHON_VERSION]
     }
 
+    fn uv_offline_venv_args(python_executable: &Path) -> Vec<String> {
+        vec![
+            "venv".to_string(),
+            "--clear".to_string(),
+            "--offline".to_string(),
+            "--python".to_string(),
+            python_executable.to_string_lossy().to_string(),
+        ]
+    }
+
     fn ensure_virtual_env_ready(
         uv_target: &Path,
         lerobot_dir: &Path,
@@ -1468,14 +1557,14 @@ impl LocalSetupService {
         Ok(python_path)
     }
 
-    fn format_protobuf_error(error: &str) -> String {
+    pub(crate) fn format_protobuf_error(error: &str) -> String {
         format!(
             "Compile protobuf failed.\n\nCompiler output:\n{}",
             error.trim()
         )
     }
 
-    fn python_can_import(python_path: &Path, module: &str) -> bool {
+    pub(crate) fn python_can_import(python_path: &Path, module: &str) -> bool {
         if !Self::python_path_is_usable(python_path) {
             return false;
         }
@@ -1490,7 +1579,7 @@ impl LocalSetupService {
             .unwrap_or(false)
     }
 
-    fn python_path_is_usable(python_path: &Path) -> bool {
+    pub(crate) fn python_path_is_usable(python_path: &Path) -> bool {
         if !python_path.exists() {
             return false;
         }
@@ -1505,7 +1594,7 @@ impl LocalSetupService {
             .unwrap_or(false)
     }
 
-    fn remove_virtual_env_dir(lerobot_dir: &Path) -> Result<(), String> {
+    pub(crate) fn remove_virtual_env_dir(lerobot_dir: &Path) -> Result<(), String> {
         let virtual_env_dir = Self::virtual_env_dir_for(lerobot_dir);
         if virtual_env_dir.exists() {
             fs::remove_dir_all(&virtual_env_dir).map_err(|e| {
@@ -1548,23 +1637,96 @@ impl LocalSetupService {
         normalized.ends_with("\\git\\usr\\bin")
     }
 
-    fn download_file(url: &str, dest: &Path) -> Result<(), String> {
-        let response = reqwest::blocking::get(url)
-            .map_err(|e| format!("Failed to download {}: {}", url, e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("Download failed ({}): {}", response.status(), url));
+    /// Archive of the LeRobot release the selected channel installs: the pinned
+    /// tag, else the channel's newest tag, else the updater manifest's tag.
+    /// `SOURCCEY_LEROBOT_ZIP_URL` overrides the lookup.
+    pub(crate) fn lerobot_zip_url() -> Result<String, String> {
+        if let Ok(url) = std::env::var("SOURCCEY_LEROBOT_ZIP_URL") {
+            if !url.trim().is_empty() {
+                return Ok(url.trim().to_string());
+            }
         }
 
-        let mut file =
-            fs::File::create(dest).map_err(|e| format!("Failed to create {:?}: {}", dest, e))?;
-        let mut content = io::BufReader::new(response);
-        io::copy(&mut content, &mut file)
-            .map_err(|e| format!("Failed to write download to {:?}: {}", dest, e))?;
-        Ok(())
+        let settings = DesktopEnvironmentService::get_settings()?;
+        let channel = ReleaseChannel::parse(&settings.release_channel)?;
+        if let Some(pin) =
+            ReleaseChannelService::active_pin(channel, settings.pinned_lerobot_tag.as_deref())
+        {
+            return Self::select_lerobot_zip_url(Some(pin), None);
+        }
+        let latest_tag = Self::resolve_latest_lerobot_tag(channel).map(|tag| tag.name);
+        let manifest_module = Self::read_manifest_lerobot_module();
+        Self::select_lerobot_zip_url(latest_tag.as_deref(), manifest_module.as_ref())
+    }
+
+    fn select_lerobot_zip_url(
+        channel_tag: Option<&str>,
+        manifest_module: Option<&LerobotModuleManifest>,
+    ) -> Result<String, String> {
+        let manifest_tag = manifest_module
+            .and_then(|module| module.tag.as_deref())
+            .and_then(Self::normalize_vulcan_tag);
+        let Some(tag) = channel_tag
+            .and_then(Self::normalize_vulcan_tag)
+            .or(manifest_tag.clone())
+        else {
+            eprintln!("[setup] No LeRobot release resolved; installing the bundled default");
+            return Ok(Self::DEFAULT_LEROBOT_ZIP_URL.to_string());
+        };
+
+        let manifest_url = manifest_module
+            .and_then(|module| module.url.as_deref())
+            .map(str::trim)
+            .filter(|url| !url.is_empty());
+        if let (Some(url), true) = (manifest_url, manifest_tag.as_deref() == Some(tag.as_str())) {
+            return Ok(url.to_string());
+        }
+
+        let version = tag
+            .strip_prefix("vulcan/")
+            .filter(|_| Self::parse_vulcan_semver(&tag).is_some())
+            .ok_or_else(|| format!("LeRobot release tag {} is not a vulcan/ release", tag))?;
+        Ok(format!(
+            "{}/lerobot-vulcan_vulcan-{}.zip",
+            Self::LEROBOT_ZIP_BASE_URL,
+            version
+        ))
+    }
+
+    /// Download with resume and retry, reporting rate and ETA as `download`
+    /// progress events. `expected_sha256` is checked while streaming.
+    pub(crate) fn download_file(
+        url: &str,
+        dest: &Path,
+        expected_sha256: Option<&str>,
+        emit: Option<&dyn Fn(SetupProgress)>,
+    ) -> Result<(), String> {
+        let file_name = dest
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| url.to_string());
+        let options = DownloadOptions {
+            expected_sha256: expected_sha256.map(str::to_string),
+            ..DownloadOptions::default()
+        };
+        let mut on_progress = |progress: &DownloadProgress| {
+            if let Some(emit) = emit {
+                emit(SetupProgress {
+                    step: "download".to_string(),
+                    status: "started".to_string(),
+                    message: Some(format!(
+                        "Downloading {}: {}",
+                        file_name,
+                        progress.describe()
+                    )),
+                    download: Some(progress.clone()),
+                });
+            }
+        };
+        DownloadService::download(url, dest, &options, &mut on_progress).map(|_| ())
     }
 
-    fn resolve_lerobot_zip_sha256(zip_url: &str) -> Result<String, String> {
+    pub(crate) fn resolve_lerobot_zip_sha256(zip_url: &str) -> Result<String, String> {
         if let Ok(sha_from_env) = std::env::var("SOURCCEY_LEROBOT_ZIP_SHA256") {
             let trimmed = sha_from_env.trim();
             if !trimmed.is_empty() {
@@ -1580,19 +1742,14 @@ impl LocalSetupService {
             }
         });
 
-        let response = reqwest::blocking::get(&sha_url)
-            .map_err(|e| format!("Failed to download zip checksum {}: {}", sha_url, e))?;
-        if !response.status().is_success() {
-            return Err(format!(
-                "Checksum download failed ({}): {}. Set SOURCCEY_LEROBOT_ZIP_SHA256 or provide a valid SOURCCEY_LEROBOT_ZIP_SHA256_URL.",
-                response.status(),
-                sha_url
-            ));
-        }
-
-        let body = response
-            .text()
-            .map_err(|e| format!("Failed to read checksum payload {}: {}", sha_url, e))?;
+        let body = UpdateSignatureService::fetch_verified_blocking(&sha_url, "lerobot-vulcan checksum")
+            .map_err(|e| {
+                format!(
+                    "{}. Set SOURCCEY_LEROBOT_ZIP_SHA256 or provide a valid SOURCCEY_LEROBOT_ZIP_SHA256_URL.",
+                    e
+                )
+            })?;
+        let body = String::from_utf8_lossy(&body);
         let checksum = body
             .split_whitespace()
             .next()
@@ -1608,8 +1765,22 @@ impl LocalSetupService {
         Ok(normalized)
     }
 
-    fn verify_file_sha256(file_path: &Path, expected_sha256: &str) -> Result<(), String> {
+    pub(crate) fn verify_file_sha256(
+        file_path: &Path,
+        expected_sha256: &str,
+    ) -> Result<(), String> {
         let normalized_expected = Self::normalize_sha256(expected_sha256)?;
+        let actual_sha256 = Self::file_sha256(file_path)?;
+        if actual_sha256 != normalized_expected {
+            return Err(format!(
+                "Checksum verification failed for {:?}: expected {}, got {}",
+                file_path, normalized_expected, actual_sha256
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn file_sha256(file_path: &Path) -> Result<String, String> {
         let file = fs::File::open(file_path).map_err(|e| {
             format!(
                 "Failed to open {:?} for checksum verification: {}",
@@ -1631,17 +1802,10 @@ impl LocalSetupService {
             }
             hasher.update(&buf[..bytes_read]);
         }
-        let actual_sha256 = format!("{:x}", hasher.finalize());
-        if actual_sha256 != normalized_expected {
-            return Err(format!(
-                "Checksum verification failed for {:?}: expected {}, got {}",
-                file_path, normalized_expected, actual_sha256
-            ));
-        }
-        Ok(())
+        Ok(format!("{:x}", hasher.finalize()))
     }
 
-    fn extract_zip(zip_path: &Path, target_dir: &Path) -> Result<(), String> {
+    pub(crate) fn extract_zip(zip_path: &Path, target_dir: &Path) -> Result<(), String> {
         let file = fs::File::open(zip_path)
             .map_err(|e| format!("Failed to open {:?}: {}", zip_path, e))?;
         let mut archive =
@@ -1669,6 +1833,16 @@ impl LocalSetupService {
                     .map_err(|e| format!("Failed to create {:?}: {}", out_path, e))?;
                 io::copy(&mut entry, &mut outfile)
                     .map_err(|e| format!("Failed to write {:?}: {}", out_path, e))?;
+
+                // Keep executables (bundled interpreters, scripts) runnable.
+                #[cfg(unix)]
+                if let Some(mode) = entry.unix_mode() {
+                    use std::os::unix::fs::PermissionsExt;
+                    fs::set_permissions(&out_path, fs::Permissions::from_mode(mode & 0o777))
+                        .map_err(|e| {
+                            format!("Failed to set permissions on {:?}: {}", out_path, e)
+                        })?;
+                }
             }
         }
 
@@ -1729,7 +1903,7 @@ impl LocalSetupService {
     }
 
     #[cfg(unix)]
-    fn ensure_executable(path: &Path) -> Result<(), String> {
+    pub(crate) fn ensure_executable(path: &Path) -> Result<(), String> {
         use std::os::unix::fs::PermissionsExt;
         let metadata = fs::metadata(path)
             .map_err(|e| format!("Failed to read uv binary permissions: {}", e))?;
@@ -1744,7 +1918,7 @@ impl LocalSetupService {
     }
 
     #[cfg(not(unix))]
-    fn ensure_executable(_path: &Path) -> Result<(), String> {
+    pub(crate) fn ensure_executable(_path: &Path) -> Result<(), String> {
         Ok(())
     }
 
@@ -1884,11 +2058,11 @@ impl LocalSetupService {
         Err("Bundled uv binary not found for this platform. Install uv or add it to src-tauri/resources/uv.".to_string())
     }
 
-    fn desktop_extras_marker_path(setup_dir: &Path) -> PathBuf {
+    pub(crate) fn desktop_extras_marker_path(setup_dir: &Path) -> PathBuf {
         setup_dir.join("lerobot_vulcan_desktop_extras_installed")
     }
 
-    fn python_path_for(lerobot_dir: &Path) -> PathBuf {
+    pub(crate) fn python_path_for(lerobot_dir: &Path) -> PathBuf {
         #[cfg(windows)]
         {
             Self::virtual_env_dir_for(lerobot_dir)
@@ -1903,6 +2077,16 @@ impl LocalSetupService {
         }
     }
 
+    pub(crate) fn protobuf_dir_for(lerobot_dir: &Path) -> PathBuf {
+        lerobot_dir
+            .join("src")
+            .join("lerobot")
+            .join("robots")
+            .join("sourccey")
+            .join("sourccey")
+            .join("protobuf")
+    }
+
     fn virtual_env_dir_for(lerobot_dir: &Path) -> PathBuf {
         lerobot_dir.join(".venv")
     }
@@ -1918,6 +2102,7 @@ impl LocalSetupService {
                 step: step.to_string(),
                 status: status.to_string(),
                 message,
+                download: None,
             });
         }
     }
@@ -0,0 +1,792 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::services::environment::build_service::BuildService;
+use crate::services::setup::local_setup_service::{
+    LocalSetupService, OfflineSetupSource, SetupProgress,
+};
+use crate::services::setup::update_signature_service::UpdateSignatureService;
+
+pub const BUNDLE_MANIFEST_FILE: &str = "sourccey-bundle.json";
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+const LEROBOT_DIR: &str = "lerobot";
+const WHEELS_DIR: &str = "wheels";
+const UV_DIR: &str = "uv";
+const PYTHON_ARCHIVE: &str = "python/python.zip";
+const OFFLINE_STAGING_DIR: &str = "offline-bundle";
+const PYTHON_STAGING_DIR: &str = "python.staging";
+
+/// One file shipped in a bundle, addressed relative to the bundle root with `/`
+/// separators so manifests are portable between the machine that built them
+/// and the one installing them.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineBundleFile {
+    pub path: String,
+    pub sha256: String,
+    pub size: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineBundleManifest {
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+    /// `<os>-<arch>` of the machine the wheels and interpreter were built for.
+    pub platform: String,
+    pub lerobot_tag: Option<String>,
+    pub lerobot_archive: String,
+    pub uv_binary: String,
+    pub python_archive: String,
+    /// Interpreter path inside the extracted python archive.
+    pub python_executable: String,
+    pub files: Vec<OfflineBundleFile>,
+    /// Base64 Ed25519 signature over the manifest serialized without this field.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineBundleSummary {
+    pub path: String,
+    pub platform: String,
+    pub lerobot_tag: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub file_count: usize,
+    pub signed: bool,
+}
+
+/// Offline bundles carry the desktop robot runtime: lerobot-vulcan, its wheels,
+/// uv and a Python interpreter. Kiosk app updates are built from source on the
+/// robot by `KioskUpdateService` and are not covered; kiosks still need a
+/// network connection to update the app itself.
+pub struct OfflineBundleService;
+
+impl OfflineBundleService {
+    /// Build a bundle in `output_dir` from the sources a normal online setup
+    /// would use. Must run on a connected machine with the same OS and
+    /// architecture as the air-gapped targets.
+    pub fn create_bundle(
+        app_handle: &AppHandle,
+        output_dir: &Path,
+    ) -> Result<OfflineBundleSummary, String> {
+        let emit = |status: &str, message: String| {
+            let _ = app_handle.emit(
+                "setup:progress",
+                SetupProgress {
+                    step: "bundle".to_string(),
+                    status: status.to_string(),
+                    message: Some(message),
+                    download: None,
+                },
+            );
+        };
+
+        if output_dir.exists()
+            && fs::read_dir(output_dir)
+                .map_err(|e| format!("Failed to read {:?}: {}", output_dir, e))?
+                .next()
+                .is_some()
+        {
+            return Err(format!(
+                "Bundle output directory {:?} must be empty",
+                output_dir
+            ));
+        }
+        fs::create_dir_all(output_dir)
+            .map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+
+        emit("started", "Downloading lerobot-vulcan".to_string());
+        let zip_url = LocalSetupService::lerobot_zip_url()?;
+        let archive_name = zip_url
+            .split(['?', '#'])
+            .next()
+            .and_then(|url| url.rsplit('/').next())
+            .filter(|name| name.ends_with(".zip"))
+            .ok_or_else(|| format!("Cannot derive an archive name from {}", zip_url))?
+            .to_string();
+        let lerobot_archive = format!("{}/{}", LEROBOT_DIR, archive_name);
+        let lerobot_zip = output_dir.join(LEROBOT_DIR).join(&archive_name);
+        fs::create_dir_all(output_dir.join(LEROBOT_DIR))
+            .map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+        let expected_sha256 = LocalSetupService::resolve_lerobot_zip_sha256(&zip_url)?;
+        let emit_download = |progress: SetupProgress| {
+            let _ = app_handle.emit("setup:progress", progress);
+        };
+        LocalSetupService::download_file(
+            &zip_url,
+            &lerobot_zip,
+            Some(&expected_sha256),
+            Some(&emit_download),
+        )?;
+
+        emit("started", "Copying uv".to_string());
+        let uv_source = LocalSetupService::resolve_uv_binary(app_handle)?;
+        let uv_name = if cfg!(windows) { "uv.exe" } else { "uv" };
+        let uv_binary = format!("{}/{}", UV_DIR, uv_name);
+        fs::create_dir_all(output_dir.join(UV_DIR))
+            .map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+        fs::copy(&uv_source, output_dir.join(UV_DIR).join(uv_name))
+            .map_err(|e| format!("Failed to copy uv binary: {}", e))?;
+
+        emit(
+            "started",
+            format!(
+                "Packaging Python {}",
+                LocalSetupService::UV_VENV_PYTHON_VERSION
+            ),
+        );
+        let python_exe = Self::install_managed_python(&uv_source, output_dir)?;
+        let python_executable =
+            Self::package_python(&python_exe, &output_dir.join(PYTHON_ARCHIVE))?;
+
+        emit("started", "Building wheel cache".to_string());
+        let staging_dir = Self::staging_dir()?;
+        let wheels = Self::build_wheels(
+            &uv_source,
+            &python_exe,
+            &lerobot_zip,
+            &staging_dir,
+            &output_dir.join(WHEELS_DIR),
+        );
+        let _ = fs::remove_dir_all(&staging_dir);
+        wheels?;
+
+        let mut manifest = OfflineBundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            created_at: Utc::now(),
+            platform: Self::current_platform(),
+            lerobot_tag: LocalSetupService::lerobot_tag_from_zip_url(&archive_name),
+            lerobot_archive,
+            uv_binary,
+            python_archive: PYTHON_ARCHIVE.to_string(),
+            python_executable,
+            files: Self::describe_files(output_dir)?,
+            signature: None,
+        };
+        if let Ok(encoded_key) = std::env::var("SOURCCEY_UPDATE_SIGNING_KEY") {
+            let pkcs8 = BASE64
+                .decode(encoded_key.trim())
+                .map_err(|e| format!("SOURCCEY_UPDATE_SIGNING_KEY is not valid base64: {}", e))?;
+            Self::sign_manifest(&mut manifest, &pkcs8)?;
+        }
+        Self::write_manifest(output_dir, &manifest)?;
+
+        emit(
+            "success",
+            format!("Bundle written to {}", output_dir.display()),
+        );
+        Ok(Self::summarize(output_dir, &manifest))
+    }
+
+    /// Verify the bundle at `bundle_dir` and reinstall the robot runtime from it
+    /// without touching the network. Nothing on disk is replaced until every
+    /// file has been copied off the bundle's drive and re-verified, and the
+    /// previous runtime is put back if the install fails.
+    pub fn install_bundle(
+        app_handle: &AppHandle,
+        bundle_dir: &Path,
+    ) -> Result<OfflineBundleSummary, String> {
+        if BuildService::is_dev_mode() {
+            return Err(
+                "Offline bundles install into the app data directory and are not supported in dev mode"
+                    .to_string(),
+            );
+        }
+        let public_key = UpdateSignatureService::required_public_key()?;
+        if public_key.is_none() {
+            eprintln!(
+                "No update public key embedded in this debug build; installing offline bundle without signature verification"
+            );
+        }
+        let manifest = Self::verify_bundle(bundle_dir, public_key.as_deref())?;
+
+        let app_data_dir = app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+        // Copy off the removable drive, then re-check: the drive may change
+        // between verification and installation.
+        let staging_dir = app_data_dir.join("setup").join(OFFLINE_STAGING_DIR);
+        let python_staging = app_data_dir.join(PYTHON_STAGING_DIR);
+        let result = Self::stage_bundle(bundle_dir, &manifest, &staging_dir, &python_staging)
+            .and_then(|()| {
+                Self::swap_in_runtime(app_handle, &app_data_dir, &manifest, &staging_dir)
+            });
+        let _ = fs::remove_dir_all(&staging_dir);
+        let _ = fs::remove_dir_all(&python_staging);
+        result?;
+
+        Ok(Self::summarize(bundle_dir, &manifest))
+    }
+
+    pub fn read_manifest(bundle_dir: &Path) -> Result<OfflineBundleManifest, String> {
+        let manifest_path = bundle_dir.join(BUNDLE_MANIFEST_FILE);
+        let contents = fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Failed to read bundle manifest {:?}: {}", manifest_path, e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse bundle manifest {:?}: {}", manifest_path, e))
+    }
+
+    /// Check the manifest signature (when a key is given), the platform, and
+    /// that the bundle holds exactly the listed files with matching hashes.
+    pub(crate) fn verify_bundle(
+        bundle_dir: &Path,
+        public_key: Option<&[u8]>,
+    ) -> Result<OfflineBundleManifest, String> {
+        let manifest = Self::read_manifest(bundle_dir)?;
+
+        if manifest.format_version != BUNDLE_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported bundle format version {} (expected {})",
+                manifest.format_version, BUNDLE_FORMAT_VERSION
+            ));
+        }
+        if let Some(public_key) = public_key {
+            Self::verify_signature(&manifest, public_key)?;
+        }
+        let platform = Self::current_platform();
+        if manifest.platform != platform {
+            return Err(format!(
+                "Bundle was built for {} but this machine is {}",
+                manifest.platform, platform
+            ));
+        }
+
+        for required in [
+            &manifest.lerobot_archive,
+            &manifest.uv_binary,
+            &manifest.python_archive,
+        ] {
+            Self::manifest_entry(&manifest, required)?;
+        }
+        if !Self::is_safe_relative_path(&manifest.python_executable) {
+            return Err(format!(
+                "Bundle manifest has an unsafe interpreter path: {}",
+                manifest.python_executable
+            ));
+        }
+
+        let mut listed = HashSet::new();
+        for file in &manifest.files {
+            if !Self::is_safe_relative_path(&file.path) {
+                return Err(format!("Bundle manifest has an unsafe path: {}", file.path));
+            }
+            if !listed.insert(file.path.as_str()) {
+                return Err(format!("Bundle manifest lists {} twice", file.path));
+            }
+            Self::verify_entry(&Self::bundle_path(bundle_dir, &file.path), file)?;
+        }
+
+        let mut present = Vec::new();
+        Self::collect_relative_files(bundle_dir, bundle_dir, &mut present)?;
+        if let Some(unlisted) = present
+            .iter()
+            .find(|path| path.as_str() != BUNDLE_MANIFEST_FILE && !listed.contains(path.as_str()))
+        {
+            return Err(format!(
+                "Bundle contains {} which is not listed in the manifest",
+                unlisted
+            ));
+        }
+
+        Ok(manifest)
+    }
+
+    /// Copy every listed file into `staging_dir`, checking each copy against
+    /// the manifest, and extract the bundled interpreter into `python_staging`.
+    pub(crate) fn stage_bundle(
+        bundle_dir: &Path,
+        manifest: &OfflineBundleManifest,
+        staging_dir: &Path,
+        python_staging: &Path,
+    ) -> Result<(), String> {
+        for dir in [staging_dir, python_staging] {
+            if dir.exists() {
+                fs::remove_dir_all(dir)
+                    .map_err(|e| format!("Failed to clear staging directory {:?}: {}", dir, e))?;
+            }
+        }
+
+        for file in &manifest.files {
+            let target = Self::bundle_path(staging_dir, &file.path);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+            }
+            fs::copy(Self::bundle_path(bundle_dir, &file.path), &target)
+                .map_err(|e| format!("Failed to copy bundle file {}: {}", file.path, e))?;
+            Self::verify_entry(&target, file)?;
+        }
+
+        fs::create_dir_all(python_staging)
+            .map_err(|e| format!("Failed to create Python directory: {}", e))?;
+        LocalSetupService::extract_zip(
+            &Self::bundle_path(staging_dir, &manifest.python_archive),
+            python_staging,
+        )?;
+        let python_executable = Self::bundle_path(python_staging, &manifest.python_executable);
+        if !python_executable.exists() {
+            return Err(format!(
+                "Bundled Python interpreter not found at {}",
+                python_executable.display()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Move the staged interpreter and uv into place and reinstall
+    /// lerobot-vulcan from the staged archive and wheels. Whatever was there
+    /// before is set aside first and restored if any step fails.
+    fn swap_in_runtime(
+        app_handle: &AppHandle,
+        app_data_dir: &Path,
+        manifest: &OfflineBundleManifest,
+        staging_dir: &Path,
+    ) -> Result<(), String> {
+        let mut replaced = Vec::new();
+        let result = Self::replace_runtime(
+            app_handle,
+            app_data_dir,
+            manifest,
+            staging_dir,
+            &mut replaced,
+        );
+        match result {
+            Ok(()) => replaced.into_iter().for_each(ReplacedPath::discard_backup),
+            Err(_) => replaced.into_iter().rev().for_each(ReplacedPath::restore),
+        }
+        result
+    }
+
+    fn replace_runtime(
+        app_handle: &AppHandle,
+        app_data_dir: &Path,
+        manifest: &OfflineBundleManifest,
+        staging_dir: &Path,
+        replaced: &mut Vec<ReplacedPath>,
+    ) -> Result<(), String> {
+        let python_root = app_data_dir.join("python");
+        replaced.push(ReplacedPath::set_aside(&python_root)?);
+        fs::rename(app_data_dir.join(PYTHON_STAGING_DIR), &python_root)
+            .map_err(|e| format!("Failed to move bundled Python into place: {}", e))?;
+        let python_executable = Self::bundle_path(&python_root, &manifest.python_executable);
+        LocalSetupService::ensure_executable(&python_executable)?;
+
+        // ensure_uv_binary prefers <app data>/bin, so later online runs keep
+        // using the bundled uv as well.
+        let bin_dir = app_data_dir.join("bin");
+        fs::create_dir_all(&bin_dir)
+            .map_err(|e| format!("Failed to create bin directory: {}", e))?;
+        let uv_source = Self::bundle_path(staging_dir, &manifest.uv_binary);
+        let uv_target = bin_dir.join(
+            uv_source
+                .file_name()
+                .ok_or_else(|| "Bundled uv binary has no filename".to_string())?,
+        );
+        replaced.push(ReplacedPath::set_aside(&uv_target)?);
+        fs::copy(&uv_source, &uv_target).map_err(|e| format!("Failed to copy uv binary: {}", e))?;
+        LocalSetupService::ensure_executable(&uv_target)?;
+
+        replaced.push(ReplacedPath::set_aside(
+            &app_data_dir.join("modules").join("lerobot-vulcan"),
+        )?);
+        replaced.push(ReplacedPath::set_aside(
+            &app_data_dir.join("setup").join("lerobot_vulcan_installed"),
+        )?);
+
+        let source = OfflineSetupSource {
+            lerobot_zip: Self::bundle_path(staging_dir, &manifest.lerobot_archive),
+            wheels_dir: staging_dir.join(WHEELS_DIR),
+            python_executable,
+        };
+        LocalSetupService::run_offline_setup(app_handle, &source)
+    }
+
+    pub(crate) fn sign_manifest(
+        manifest: &mut OfflineBundleManifest,
+        pkcs8: &[u8],
+    ) -> Result<(), String> {
+        let payload = Self::signing_payload(manifest)?;
+        manifest.signature = Some(UpdateSignatureService::sign(&payload, pkcs8)?);
+        Ok(())
+    }
+
+    pub(crate) fn describe_files(bundle_dir: &Path) -> Result<Vec<OfflineBundleFile>, String> {
+        let mut paths = Vec::new();
+        Self::collect_relative_files(bundle_dir, bundle_dir, &mut paths)?;
+        paths.sort();
+
+        paths
+            .into_iter()
+            .filter(|path| path != BUNDLE_MANIFEST_FILE)
+            .map(|path| {
+                let full_path = Self::bundle_path(bundle_dir, &path);
+                let size = fs::metadata(&full_path)
+                    .map_err(|e| format!("Failed to read {:?}: {}", full_path, e))?
+                    .len();
+                Ok(OfflineBundleFile {
+                    sha256: LocalSetupService::file_sha256(&full_path)?,
+                    path,
+                    size,
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn write_manifest(
+        bundle_dir: &Path,
+        manifest: &OfflineBundleManifest,
+    ) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(manifest)
+            .map_err(|e| format!("Failed to serialize bundle manifest: {}", e))?;
+        fs::write(bundle_dir.join(BUNDLE_MANIFEST_FILE), json)
+            .map_err(|e| format!("Failed to write bundle manifest: {}", e))
+    }
+
+    pub(crate) fn current_platform() -> String {
+        format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH)
+    }
+
+    fn verify_signature(manifest: &OfflineBundleManifest, public_key: &[u8]) -> Result<(), String> {
+        let signature = manifest
+            .signature
+            .as_deref()
+            .ok_or_else(|| "Bundle manifest is not signed".to_string())?;
+        let payload = Self::signing_payload(manifest)?;
+        UpdateSignatureService::verify(&payload, signature, public_key, "Bundle")
+    }
+
+    fn signing_payload(manifest: &OfflineBundleManifest) -> Result<Vec<u8>, String> {
+        let unsigned = OfflineBundleManifest {
+            signature: None,
+            ..manifest.clone()
+        };
+        serde_json::to_vec(&unsigned)
+            .map_err(|e| format!("Failed to serialize bundle manifest: {}", e))
+    }
+
+    fn verify_entry(path: &Path, file: &OfflineBundleFile) -> Result<(), String> {
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Bundle file {} is missing: {}", file.path, e))?;
+        if metadata.len() != file.size {
+            return Err(format!(
+                "Bundle file {} has size {} but the manifest expects {}",
+                file.path,
+                metadata.len(),
+                file.size
+            ));
+        }
+        LocalSetupService::verify_file_sha256(path, &file.sha256)
+    }
+
+    fn manifest_entry<'a>(
+        manifest: &'a OfflineBundleManifest,
+        path: &str,
+    ) -> Result<&'a OfflineBundleFile, String> {
+        manifest
+            .files
+            .iter()
+            .find(|file| file.path == path)
+            .ok_or_else(|| format!("Bundle manifest does not list {}", path))
+    }
+
+    fn is_safe_relative_path(path: &str) -> bool {
+        !path.is_empty()
+            && !path.contains('\\')
+            && Path::new(path)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+    }
+
+    fn bundle_path(root: &Path, relative: &str) -> PathBuf {
+        relative
+            .split('/')
+            .fold(root.to_path_buf(), |path, part| path.join(part))
+    }
+
+    fn collect_relative_files(
+        root: &Path,
+        dir: &Path,
+        files: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read {:?}: {}", dir, e))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e| format!("Failed to read {:?}: {}", dir, e))?
+                .path();
+            if path.is_dir() {
+                Self::collect_relative_files(root, &path, files)?;
+            } else {
+                let relative = path
+                    .strip_prefix(root)
+                    .map_err(|e| format!("Failed to resolve {:?}: {}", path, e))?;
+                let parts: Vec<String> = relative
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy().to_string())
+                    .collect();
+                files.push(parts.join("/"));
+            }
+        }
+        Ok(())
+    }
+
+    fn install_managed_python(uv: &Path, working_dir: &Path) -> Result<PathBuf, String> {
+        let version = LocalSetupService::UV_VENV_PYTHON_VERSION;
+        LocalSetupService::run_command(
+            uv,
+            &["python", "install", version],
+            working_dir,
+            "uv python install",
+        )?;
+        let found = LocalSetupService::run_command_output(
+            uv,
+            &[
+                "python",
+                "find",
+                "--python-preference",
+                "only-managed",
+                version,
+            ],
+            working_dir,
+            "uv python find",
+        )?;
+        let python_exe = PathBuf::from(found.lines().last().unwrap_or_default().trim());
+        if !python_exe.exists() {
+            return Err(format!(
+                "uv did not report a managed Python {} interpreter",
+                version
+            ));
+        }
+        Ok(python_exe)
+    }
+
+    /// Zip the uv-managed interpreter directory holding `python_exe` and return
+    /// the interpreter's path inside the archive.
+    fn package_python(python_exe: &Path, archive_path: &Path) -> Result<String, String> {
+        // Unix installs keep the interpreter in `<install>/bin/`.
+        let install_dir = if cfg!(windows) {
+            python_exe.parent()
+        } else {
+            python_exe.parent().and_then(Path::parent)
+        }
+        .ok_or_else(|| format!("Unexpected Python location {}", python_exe.display()))?;
+        let archive_root = install_dir
+            .parent()
+            .ok_or_else(|| format!("Unexpected Python location {}", python_exe.display()))?;
+
+        let mut files = Vec::new();
+        Self::collect_relative_files(archive_root, install_dir, &mut files)?;
+        files.sort();
+
+        if let Some(parent) = archive_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+        }
+        let archive = fs::File::create(archive_path)
+            .map_err(|e| format!("Failed to create {:?}: {}", archive_path, e))?;
+        let mut writer = zip::ZipWriter::new(archive);
+        for relative in &files {
+            let source = Self::bundle_path(archive_root, relative);
+            let mut options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated)
+                .large_file(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = fs::metadata(&source)
+                    .map_err(|e| format!("Failed to read {:?}: {}", source, e))?
+                    .permissions()
+                    .mode();
+                options = options.unix_permissions(mode & 0o777);
+            }
+            writer
+                .start_file(relative.as_str(), options)
+                .map_err(|e| format!("Failed to add {} to Python archive: {}", relative, e))?;
+            let mut input = fs::File::open(&source)
+                .map_err(|e| format!("Failed to open {:?}: {}", source, e))?;
+            io::copy(&mut input, &mut writer)
+                .map_err(|e| format!("Failed to add {} to Python archive: {}", relative, e))?;
+        }
+        writer
+            .finish()
+            .map_err(|e| format!("Failed to finish Python archive: {}", e))?
+            .flush()
+            .map_err(|e| format!("Failed to finish Python archive: {}", e))?;
+
+        let executable = python_exe
+            .strip_prefix(archive_root)
+            .map_err(|e| format!("Failed to resolve {:?}: {}", python_exe, e))?;
+        Ok(executable
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/"))
+    }
+
+    /// Build wheels for the runtime extra and for lerobot-vulcan's build
+    /// backend, so the offline editable install resolves from `wheels_dir`.
+    fn build_wheels(
+        uv: &Path,
+        python_exe: &Path,
+        lerobot_zip: &Path,
+        staging_dir: &Path,
+        wheels_dir: &Path,
+    ) -> Result<(), String> {
+        LocalSetupService::extract_zip(lerobot_zip, staging_dir)?;
+        let lerobot_dir = staging_dir.join("lerobot-vulcan");
+        if !lerobot_dir.exists() {
+            return Err("lerobot-vulcan not found in downloaded archive".to_string());
+        }
+        fs::create_dir_all(wheels_dir)
+            .map_err(|e| format!("Failed to create wheel directory: {}", e))?;
+
+        let python_exe = python_exe.to_string_lossy().to_string();
+        LocalSetupService::run_command(
+            uv,
+            &["venv", "--clear", "--python", python_exe.as_str()],
+            &lerobot_dir,
+            "uv venv",
+        )?;
+        let venv_python = LocalSetupService::python_path_for(&lerobot_dir);
+        let venv_python_str = venv_python.to_string_lossy().to_string();
+        LocalSetupService::run_command(
+            uv,
+            &[
+                "pip",
+                "install",
+                "--python",
+                venv_python_str.as_str(),
+                "pip",
+            ],
+            &lerobot_dir,
+            "uv pip install pip",
+        )?;
+
+        let build_requires = LocalSetupService::run_command_output(
+            &venv_python,
+            &[
+                "-c",
+                "import tomllib; print('\\n'.join(tomllib.load(open('pyproject.toml', 'rb')).get('build-system', {}).get('requires', [])))",
+            ],
+            &lerobot_dir,
+            "read build requirements",
+        )?;
+        let target = format!(".[{}]", LocalSetupService::SOURCCEY_RUNTIME_EXTRA);
+        let wheels_dir = wheels_dir.to_string_lossy().to_string();
+        let mut args = vec![
+            "-m",
+            "pip",
+            "wheel",
+            "--wheel-dir",
+            wheels_dir.as_str(),
+            target.as_str(),
+        ];
+        args.extend(
+            build_requires
+                .lines()
+                .map(str::trim)
+                .filter(|requirement| !requirement.is_empty()),
+        );
+        LocalSetupService::run_command(&venv_python, &args, &lerobot_dir, "pip wheel")
+    }
+
+    fn staging_dir() -> Result<PathBuf, String> {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let dir = std::env::temp_dir().join(format!("sourccey-bundle-{}", nonce));
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+        Ok(dir)
+    }
+
+    fn summarize(bundle_dir: &Path, manifest: &OfflineBundleManifest) -> OfflineBundleSummary {
+        OfflineBundleSummary {
+            path: bundle_dir.to_string_lossy().to_string(),
+            platform: manifest.platform.clone(),
+            lerobot_tag: manifest.lerobot_tag.clone(),
+            created_at: manifest.created_at,
+            file_count: manifest.files.len(),
+            signed: manifest.signature.is_some(),
+        }
+    }
+}
+
+/// A file or directory an install is about to overwrite, moved to a sibling
+/// `.previous` path so it can be put back.
+pub(crate) struct ReplacedPath {
+    path: PathBuf,
+    backup: Option<PathBuf>,
+}
+
+impl ReplacedPath {
+    pub(crate) fn set_aside(path: &Path) -> Result<Self, String> {
+        if fs::symlink_metadata(path).is_err() {
+            return Ok(Self {
+                path: path.to_path_buf(),
+                backup: None,
+            });
+        }
+        let mut backup_name = path
+            .file_name()
+            .ok_or_else(|| format!("Cannot set aside {:?}", path))?
+            .to_os_string();
+        backup_name.push(".previous");
+        let backup = path.with_file_name(backup_name);
+        Self::remove(&backup)?;
+        fs::rename(path, &backup).map_err(|e| format!("Failed to set aside {:?}: {}", path, e))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            backup: Some(backup),
+        })
+    }
+
+    /// Drop whatever the failed install left at the path and move the
+    /// previous contents back.
+    pub(crate) fn restore(self) {
+        if let Err(error) = Self::remove(&self.path) {
+            eprintln!(
+                "Failed to clear {:?} before restoring it: {}",
+                self.path, error
+            );
+            return;
+        }
+        if let Some(backup) = self.backup {
+            if let Err(error) = fs::rename(&backup, &self.path) {
+                eprintln!("Failed to restore {:?}: {}", self.path, error);
+            }
+        }
+    }
+
+    pub(crate) fn discard_backup(self) {
+        if let Some(backup) = self.backup {
+            let _ = Self::remove(&backup);
+        }
+    }
+
+    fn remove(path: &Path) -> Result<(), String> {
+        match fs::symlink_metadata(path) {
+            Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(path),
+            Ok(_) => fs::remove_file(path),
+            Err(_) => return Ok(()),
+        }
+        .map_err(|e| format!("Failed to remove {:?}: {}", path, e))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/offline_bundle_service_tests.rs"]
+mod offline_bundle_service_tests;
@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseChannel {
+    /// Newest release tag without a pre-release suffix.
+    #[default]
+    Stable,
+    /// Newest release tag, including `-beta.N`/`-rc.N` style pre-releases.
+    Beta,
+    /// Exactly the pinned tag; components without a pin follow stable.
+    Pinned,
+}
+
+impl ReleaseChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Pinned => "pinned",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            "pinned" | "pinned-tag" | "pin" => Ok(Self::Pinned),
+            other => Err(format!(
+                "Unknown release channel '{}'. Expected stable, beta, or pinned.",
+                other
+            )),
+        }
+    }
+}
+
+/// Semantic version parsed from a release tag such as `kiosk/1.2.0-beta.1`.
+/// Releases order after their pre-releases, as in semver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Option<String>,
+}
+
+impl ReleaseVersion {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre_release: None,
+        }
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        self.pre_release.is_some()
+    }
+}
+
+impl Ord for ReleaseVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(left), Some(right)) => {
+                    ReleaseChannelService::compare_pre_release(left, right)
+                }
+            })
+    }
+}
+
+impl PartialOrd for ReleaseVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct ReleaseChannelService;
+
+impl ReleaseChannelService {
+    pub fn parse_tag_version(tag: &str, prefix: &str) -> Option<ReleaseVersion> {
+        let raw_version = tag.trim().strip_prefix(prefix)?;
+        let version = raw_version.strip_prefix('v').unwrap_or(raw_version);
+        Self::parse_version(version)
+    }
+
+    pub fn parse_version(value: &str) -> Option<ReleaseVersion> {
+        let (core, pre_release) = match value.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (value, None),
+        };
+
+        let mut parts = core.split('.');
+        let major = parts.next()?.parse::<u64>().ok()?;
+        let minor = parts.next()?.parse::<u64>().ok()?;
+        let patch = parts.next()?.parse::<u64>().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let pre_release = match pre_release {
+            Some(value) => {
+                let valid = !value.is_empty()
+                    && value.split('.').all(|identifier| {
+                        !identifier.is_empty()
+                            && identifier
+                                .chars()
+                                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+                    });
+                if !valid {
+                    return None;
+                }
+                Some(value.to_string())
+            }
+            None => None,
+        };
+
+        Some(ReleaseVersion {
+            pre_release,
+            ..ReleaseVersion::new(major, minor, patch)
+        })
+    }
+
+    /// The pin that applies to one component, if the channel is pinned and that
+    /// component has a pinned tag.
+    pub fn active_pin(channel: ReleaseChannel, pinned_tag: Option<&str>) -> Option<&str> {
+        if channel != ReleaseChannel::Pinned {
+            return None;
+        }
+        pinned_tag.map(str::trim).filter(|tag| !tag.is_empty())
+    }
+
+    /// Highest `prefix` tag the channel may install. Pinned channels select like
+    /// stable here; callers resolve the pin itself with `active_pin`.
+    pub fn select_latest<T, F>(
+        tags: Vec<T>,
+        tag_name: F,
+        prefix: &str,
+        channel: ReleaseChannel,
+    ) -> Option<T>
+    where
+        F: Fn(&T) -> &str,
+    {
+        let mut best: Option<(ReleaseVersion, T)> = None;
+
+        for tag in tags {
+            let version = match Self::parse_tag_version(tag_name(&tag), prefix) {
+                Some(version) => version,
+                None => continue,
+            };
+            if version.is_pre_release() && channel != ReleaseChannel::Beta {
+                continue;
+            }
+            match best.as_ref() {
+                Some((best_version, _)) if &version <= best_version => {}
+                _ => best = Some((version, tag)),
+            }
+        }
+
+        best.map(|(_, tag)| tag)
+    }
+
+    pub fn normalize_pinned_tag(value: Option<&str>) -> Option<String> {
+        value
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+    }
+
+    fn compare_pre_release(left: &str, right: &str) -> Ordering {
+        let mut left_parts = left.split('.');
+        let mut right_parts = right.split('.');
+        loop {
+            match (left_parts.next(), right_parts.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(left), Some(right)) => {
+                    let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+                        (Ok(left), Ok(right)) => left.cmp(&right),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => left.cmp(right),
+                    };
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/release_channel_service_tests.rs"]
+mod release_channel_service_tests;
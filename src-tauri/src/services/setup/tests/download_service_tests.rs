@@ -0,0 +1,347 @@
+use super::*;
+use std::io::BufRead;
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How the test server answers one request.
+#[derive(Clone, Copy)]
+enum Reply {
+    /// Send the (possibly ranged) body in full.
+    Full,
+    /// Advertise the full length, send this many body bytes, then hang up.
+    DropAfter(usize),
+    /// Ignore any Range header and resend the whole payload with 200.
+    IgnoreRange,
+    Status(u16),
+}
+
+struct TestServer {
+    url: String,
+    ranges: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+/// Minimal HTTP/1.1 file server on localhost; request `n` is answered with
+/// `replies[n]`, and requests past the end get `Reply::Full`.
+fn serve(payload: Vec<u8>, replies: Vec<Reply>) -> TestServer {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("test server should bind");
+    let url = format!(
+        "http://{}/lerobot-vulcan.zip",
+        listener
+            .local_addr()
+            .expect("test server should have an address")
+    );
+    let ranges = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&ranges);
+
+    thread::spawn(move || {
+        for (index, stream) in listener.incoming().enumerate() {
+            let Ok(stream) = stream else { continue };
+            let reply = replies.get(index).copied().unwrap_or(Reply::Full);
+            answer(stream, &payload, reply, &recorded);
+        }
+    });
+
+    TestServer { url, ranges }
+}
+
+fn answer(
+    mut stream: TcpStream,
+    payload: &[u8],
+    reply: Reply,
+    recorded: &Mutex<Vec<Option<String>>>,
+) {
+    let mut reader = io::BufReader::new(stream.try_clone().expect("stream should clone"));
+    let mut range = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+    // Record before replying so the client never finishes ahead of the log.
+    recorded.lock().expect("ranges lock").push(range.clone());
+
+    let start = match (reply, range.as_deref()) {
+        (Reply::IgnoreRange, _) | (_, None) => 0,
+        (_, Some(range)) => range
+            .trim_start_matches("bytes=")
+            .trim_end_matches('-')
+            .parse::<usize>()
+            .expect("range should be numeric"),
+    };
+    let head = match reply {
+        Reply::Status(code) => format!(
+            "HTTP/1.1 {} Test\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            code
+        ),
+        _ if start > 0 => format!(
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n",
+            payload.len() - start,
+            start,
+            payload.len() - 1,
+            payload.len()
+        ),
+        _ => format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n",
+            payload.len()
+        ),
+    };
+    let _ = stream.write_all(head.as_bytes());
+    let body = match reply {
+        Reply::Status(_) => &payload[..0],
+        Reply::DropAfter(bytes) => &payload[start..(start + bytes).min(payload.len())],
+        Reply::Full | Reply::IgnoreRange => &payload[start..],
+    };
+    let _ = stream.write_all(body);
+    let _ = stream.flush();
+    let _ = stream.shutdown(std::net::Shutdown::Both);
+}
+
+fn sample_payload() -> Vec<u8> {
+    (0..200_000u32).map(|value| (value % 251) as u8).collect()
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn temp_dest(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_download", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir.join("lerobot-vulcan.zip")
+}
+
+fn fast_options(expected_sha256: Option<String>) -> DownloadOptions {
+    DownloadOptions {
+        expected_sha256,
+        max_attempts: 4,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        stall_timeout: Duration::from_secs(5),
+        progress_interval: Duration::ZERO,
+        bearer_token: None,
+        cancel_flag: None,
+    }
+}
+
+#[test]
+fn download_resumes_after_dropped_connections() {
+    let payload = sample_payload();
+    let server = serve(
+        payload.clone(),
+        vec![Reply::DropAfter(50_000), Reply::DropAfter(60_000)],
+    );
+    let dest = temp_dest("resume");
+    let mut reports = Vec::new();
+
+    let outcome = DownloadService::download(
+        &server.url,
+        &dest,
+        &fast_options(Some(sha256_hex(&payload))),
+        &mut |progress| reports.push(progress.clone()),
+    )
+    .expect("download should succeed after resuming");
+
+    assert_eq!(fs::read(&dest).expect("dest should exist"), payload);
+    assert_eq!(outcome.bytes, payload.len() as u64);
+    assert_eq!(outcome.sha256, sha256_hex(&payload));
+    assert_eq!(outcome.attempts, 3);
+    assert_eq!(
+        *server.ranges.lock().expect("ranges lock"),
+        vec![
+            None,
+            Some("bytes=50000-".to_string()),
+            Some("bytes=110000-".to_string()),
+        ]
+    );
+    assert!(!DownloadService::sidecar_path(&dest, PARTIAL_EXTENSION).exists());
+    assert!(!DownloadService::sidecar_path(&dest, PARTIAL_SOURCE_EXTENSION).exists());
+
+    let last = reports.last().expect("progress should be reported");
+    assert_eq!(last.downloaded_bytes, payload.len() as u64);
+    assert_eq!(last.total_bytes, Some(payload.len() as u64));
+    assert_eq!(last.attempt, 3);
+    assert!(reports.iter().any(|report| report.attempt == 1));
+}
+
+#[test]
+fn download_resumes_partial_file_left_by_earlier_run() {
+    let payload = sample_payload();
+    let server = serve(payload.clone(), Vec::new());
+    let dest = temp_dest("resume_previous_run");
+    fs::write(
+        DownloadService::sidecar_path(&dest, PARTIAL_EXTENSION),
+        &payload[..120_000],
+    )
+    .expect("partial should be written");
+    DownloadService::write_partial_source(
+        &DownloadService::sidecar_path(&dest, PARTIAL_SOURCE_EXTENSION),
+        &PartialSource {
+            url: server.url.clone(),
+            etag: Some("\"v1\"".to_string()),
+        },
+    )
+    .expect("source should be written");
+
+    let outcome = DownloadService::download(
+        &server.url,
+        &dest,
+        &fast_options(Some(sha256_hex(&payload))),
+        &mut |_| {},
+    )
+    .expect("download should resume");
+
+    assert_eq!(outcome.attempts, 1);
+    assert_eq!(fs::read(&dest).expect("dest should exist"), payload);
+    assert_eq!(
+        *server.ranges.lock().expect("ranges lock"),
+        vec![Some("bytes=120000-".to_string())]
+    );
+}
+
+#[test]
+fn download_restarts_when_server_ignores_range() {
+    let payload = sample_payload();
+    let server = serve(
+        payload.clone(),
+        vec![Reply::DropAfter(70_000), Reply::IgnoreRange],
+    );
+    let dest = temp_dest("ignore_range");
+
+    let outcome = DownloadService::download(
+        &server.url,
+        &dest,
+        &fast_options(Some(sha256_hex(&payload))),
+        &mut |_| {},
+    )
+    .expect("download should restart from scratch");
+
+    assert_eq!(outcome.attempts, 2);
+    assert_eq!(fs::read(&dest).expect("dest should exist"), payload);
+}
+
+#[test]
+fn download_discards_partial_on_checksum_mismatch() {
+    let payload = sample_payload();
+    let server = serve(payload.clone(), Vec::new());
+    let dest = temp_dest("checksum");
+
+    let error = DownloadService::download(
+        &server.url,
+        &dest,
+        &fast_options(Some("0".repeat(64))),
+        &mut |_| {},
+    )
+    .unwrap_err();
+
+    assert!(
+        error.starts_with("Checksum verification failed"),
+        "{}",
+        error
+    );
+    assert!(!dest.exists());
+    assert!(!DownloadService::sidecar_path(&dest, PARTIAL_EXTENSION).exists());
+}
+
+#[test]
+fn download_gives_up_after_max_attempts() {
+    let payload = sample_payload();
+    let server = serve(payload, vec![Reply::DropAfter(0); 8]);
+    let dest = temp_dest("give_up");
+    let options = DownloadOptions {
+        max_attempts: 3,
+        ..fast_options(None)
+    };
+
+    let error = DownloadService::download(&server.url, &dest, &options, &mut |_| {}).unwrap_err();
+
+    assert!(error.contains("failed after 3 attempts"), "{}", error);
+    assert_eq!(server.ranges.lock().expect("ranges lock").len(), 3);
+    assert!(!dest.exists());
+}
+
+#[test]
+fn cancelled_download_keeps_partial_for_resume() {
+    let payload = sample_payload();
+    let server = serve(payload.clone(), Vec::new());
+    let dest = temp_dest("cancel");
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let options = DownloadOptions {
+        cancel_flag: Some(Arc::clone(&cancel_flag)),
+        ..fast_options(Some(sha256_hex(&payload)))
+    };
+
+    let error = DownloadService::download(&server.url, &dest, &options, &mut |_| {
+        cancel_flag.store(true, Ordering::SeqCst)
+    })
+    .unwrap_err();
+
+    assert_eq!(error, DOWNLOAD_CANCELLED);
+    assert!(!dest.exists());
+    let kept = fs::metadata(DownloadService::sidecar_path(&dest, PARTIAL_EXTENSION))
+        .expect("partial should be kept")
+        .len();
+    assert!(kept > 0 && kept < payload.len() as u64);
+
+    let outcome = DownloadService::download(
+        &server.url,
+        &dest,
+        &fast_options(Some(sha256_hex(&payload))),
+        &mut |_| {},
+    )
+    .expect("download should resume");
+    assert_eq!(outcome.bytes, payload.len() as u64);
+    assert_eq!(fs::read(&dest).unwrap(), payload);
+    let ranges = server.ranges.lock().expect("ranges lock").clone();
+    assert_eq!(ranges, vec![None, Some(format!("bytes={}-", kept))]);
+}
+
+#[test]
+fn download_does_not_retry_client_errors() {
+    let server = serve(sample_payload(), vec![Reply::Status(404)]);
+    let dest = temp_dest("not_found");
+
+    let error = DownloadService::download(&server.url, &dest, &fast_options(None), &mut |_| {})
+        .unwrap_err();
+
+    assert!(error.contains("404"), "{}", error);
+    assert_eq!(server.ranges.lock().expect("ranges lock").len(), 1);
+}
+
+#[test]
+fn progress_reports_rate_and_eta_for_this_attempt_only() {
+    let progress = DownloadService::progress(
+        60_000_000,
+        40_000_000,
+        Some(100_000_000),
+        Duration::from_secs(10),
+        2,
+    );
+
+    assert_eq!(progress.bytes_per_second, 2_000_000);
+    assert_eq!(progress.eta_seconds, Some(20));
+    assert_eq!(
+        progress.describe(),
+        "60.0 MB of 100.0 MB (2.0 MB/s, 20s left), attempt 2"
+    );
+}
+
+#[test]
+fn progress_without_length_has_no_eta() {
+    let progress = DownloadService::progress(1_500, 0, None, Duration::from_secs(1), 1);
+
+    assert_eq!(progress.eta_seconds, None);
+    assert_eq!(progress.describe(), "1.5 KB (1.5 KB/s)");
+    assert_eq!(DownloadService::format_duration(126), "2m 6s");
+    assert_eq!(DownloadService::format_duration(3_720), "1h 2m");
+}
@@ -0,0 +1,206 @@
+use super::*;
+
+fn healthy_probe() -> DoctorProbe {
+    DoctorProbe {
+        uv_version: Ok("uv 0.7.2".to_string()),
+        lerobot_dir_exists: true,
+        python_version: Some("3.12.9".to_string()),
+        expected_python_version: "3.12".to_string(),
+        failed_imports: Vec::new(),
+        protobuf: Some(Ok(ProtobufProbe {
+            found: 2,
+            errors: Vec::new(),
+        })),
+        missing_runtime_requirements: Ok(Vec::new()),
+        missing_desktop_requirements: None,
+        free_disk_bytes: Some(20 * 1024 * 1024 * 1024),
+        lerobot_tag: Some("vulcan/0.1.10".to_string()),
+        source_commit: Some("0123456789abcdef0123".to_string()),
+        installed_commit: Some("0123456789abcdef0123".to_string()),
+    }
+}
+
+fn find<'a>(checks: &'a [DoctorCheck], id: &str) -> &'a DoctorCheck {
+    checks
+        .iter()
+        .find(|check| check.id == id)
+        .unwrap_or_else(|| panic!("check {} should be reported", id))
+}
+
+#[test]
+fn healthy_environment_reports_no_repairs() {
+    let checks = EnvironmentDoctorService::evaluate(&healthy_probe());
+
+    assert!(
+        checks
+            .iter()
+            .all(|check| check.severity == DoctorSeverity::Ok && check.repair.is_none()),
+        "{:?}",
+        checks
+    );
+    assert_eq!(find(&checks, "lerobot-release").detail, "vulcan/0.1.10");
+    assert!(checks.iter().all(|check| check.id != "desktop-extras"));
+}
+
+#[test]
+fn missing_uv_offers_install() {
+    let probe = DoctorProbe {
+        uv_version: Err("uv executable not found".to_string()),
+        ..healthy_probe()
+    };
+
+    let check = find(&EnvironmentDoctorService::evaluate(&probe), "uv").clone();
+
+    assert_eq!(check.severity, DoctorSeverity::Error);
+    assert_eq!(check.repair, Some(DoctorRepairAction::InstallUv));
+}
+
+#[test]
+fn wrong_python_version_offers_venv_recreation() {
+    let probe = DoctorProbe {
+        python_version: Some("3.11.4".to_string()),
+        ..healthy_probe()
+    };
+
+    let checks = EnvironmentDoctorService::evaluate(&probe);
+    let check = find(&checks, "python");
+
+    assert_eq!(check.severity, DoctorSeverity::Error);
+    assert_eq!(check.detail, "Python 3.11.4 found, setup expects 3.12");
+    assert_eq!(check.repair, Some(DoctorRepairAction::RecreateVenv));
+}
+
+#[test]
+fn broken_venv_skips_package_checks() {
+    let probe = DoctorProbe {
+        python_version: None,
+        ..healthy_probe()
+    };
+
+    let checks = EnvironmentDoctorService::evaluate(&probe);
+
+    assert_eq!(
+        find(&checks, "python").repair,
+        Some(DoctorRepairAction::RecreateVenv)
+    );
+    for id in ["imports", "protobuf", "runtime-extra"] {
+        assert_eq!(find(&checks, id).severity, DoctorSeverity::Skipped);
+        assert_eq!(find(&checks, id).repair, None);
+    }
+}
+
+#[test]
+fn import_and_extra_failures_offer_extra_reinstall() {
+    let probe = DoctorProbe {
+        failed_imports: vec!["torch".to_string()],
+        missing_runtime_requirements: Ok(vec!["pyzmq".to_string()]),
+        missing_desktop_requirements: Some(Ok(vec!["transformers".to_string()])),
+        ..healthy_probe()
+    };
+
+    let checks = EnvironmentDoctorService::evaluate(&probe);
+
+    assert_eq!(find(&checks, "imports").detail, "Cannot import torch");
+    assert_eq!(
+        find(&checks, "imports").repair,
+        Some(DoctorRepairAction::ReinstallRuntimeExtra)
+    );
+    assert_eq!(find(&checks, "runtime-extra").detail, "Missing pyzmq");
+    assert_eq!(
+        find(&checks, "runtime-extra").severity,
+        DoctorSeverity::Error
+    );
+    let desktop = find(&checks, "desktop-extras");
+    assert_eq!(desktop.severity, DoctorSeverity::Warning);
+    assert_eq!(
+        desktop.repair,
+        Some(DoctorRepairAction::ReinstallDesktopExtras)
+    );
+}
+
+#[test]
+fn protobuf_conflict_offers_recompile() {
+    let probe = DoctorProbe {
+        protobuf: Some(Ok(ProtobufProbe {
+            found: 1,
+            errors: vec![
+                "sourccey_pb2.py: VersionError: Detected incompatible Protobuf Gencode/Runtime versions"
+                    .to_string(),
+            ],
+        })),
+        ..healthy_probe()
+    };
+
+    let checks = EnvironmentDoctorService::evaluate(&probe);
+    let check = find(&checks, "protobuf");
+
+    assert_eq!(check.severity, DoctorSeverity::Error);
+    assert_eq!(check.repair, Some(DoctorRepairAction::RecompileProtobuf));
+    assert!(check.detail.contains("sourccey_pb2.py"), "{}", check.detail);
+}
+
+#[test]
+fn low_disk_space_warns_then_errors() {
+    let warning = DoctorProbe {
+        free_disk_bytes: Some(3 * 1024 * 1024 * 1024),
+        ..healthy_probe()
+    };
+    let error = DoctorProbe {
+        free_disk_bytes: Some(512 * 1024 * 1024),
+        ..healthy_probe()
+    };
+
+    assert_eq!(
+        find(&EnvironmentDoctorService::evaluate(&warning), "disk-space").severity,
+        DoctorSeverity::Warning
+    );
+    assert_eq!(
+        find(&EnvironmentDoctorService::evaluate(&error), "disk-space").severity,
+        DoctorSeverity::Error
+    );
+}
+
+#[test]
+fn source_ahead_of_installed_commit_warns() {
+    let probe = DoctorProbe {
+        source_commit: Some("fedcba9876543210fedc".to_string()),
+        ..healthy_probe()
+    };
+
+    let checks = EnvironmentDoctorService::evaluate(&probe);
+    let check = find(&checks, "lerobot-release");
+
+    assert_eq!(check.severity, DoctorSeverity::Warning);
+    assert_eq!(
+        check.detail,
+        "Source is at fedcba987654 but dependencies were installed for 0123456789ab"
+    );
+    assert_eq!(
+        check.repair,
+        Some(DoctorRepairAction::ReinstallRuntimeExtra)
+    );
+}
+
+#[test]
+fn python_version_matches_on_expected_components() {
+    assert!(EnvironmentDoctorService::python_version_matches(
+        "3.12.9", "3.12"
+    ));
+    assert!(!EnvironmentDoctorService::python_version_matches(
+        "3.1.2", "3.12"
+    ));
+    assert!(!EnvironmentDoctorService::python_version_matches(
+        "3", "3.12"
+    ));
+}
+
+#[test]
+fn free_disk_space_is_read_from_nearest_existing_directory() {
+    let missing = std::env::temp_dir()
+        .join("sourccey_doctor_missing")
+        .join("app_data");
+
+    let free = EnvironmentDoctorService::read_free_disk_bytes(&missing);
+
+    assert!(free.is_some_and(|bytes| bytes > 0));
+}
@@ -0,0 +1,128 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_layout(name: &str) -> KioskSlotLayout {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock should be after epoch")
+        .as_nanos();
+    let base = std::env::temp_dir().join(format!("{}_{}_kiosk_slots", name, nonce));
+    fs::create_dir_all(&base).expect("temp dir should be created");
+    KioskSlotLayout {
+        root_link: base.join("sourccey-desktop"),
+        slots_dir: base.join("sourccey-desktop-slots"),
+    }
+}
+
+fn cleanup(layout: &KioskSlotLayout) {
+    if let Some(base) = layout.root_link.parent() {
+        let _ = fs::remove_dir_all(base);
+    }
+}
+
+fn record(id: &str) -> KioskUpdateRecord {
+    KioskUpdateRecord {
+        id: id.to_string(),
+        from_slot: KioskSlot::A,
+        to_slot: KioskSlot::B,
+        from_tag: Some("kiosk/1.0.0".to_string()),
+        to_tag: Some("kiosk/1.1.0".to_string()),
+        started_at: Utc::now(),
+        completed_at: None,
+        duration_ms: None,
+        outcome: KioskUpdateOutcome::InProgress,
+        error: None,
+    }
+}
+
+#[test]
+fn default_slots_dir_is_sibling_of_project_root() {
+    let layout = KioskSlotLayout::for_project_root(PathBuf::from(
+        "/home/sourccey/Projects/sourccey-desktop",
+    ));
+    if std::env::var("SOURCCEY_KIOSK_SLOTS_DIR").is_err() {
+        assert_eq!(
+            layout.slots_dir,
+            PathBuf::from("/home/sourccey/Projects/sourccey-desktop-slots")
+        );
+    }
+    assert!(layout
+        .slot_dir(KioskSlot::B)
+        .ends_with("sourccey-desktop-slots/b"));
+}
+
+#[test]
+fn ensure_layout_moves_plain_checkout_into_slot_a() {
+    let layout = temp_layout("ensure_layout");
+    fs::create_dir_all(&layout.root_link).expect("checkout should be created");
+    fs::write(layout.root_link.join("package.json"), "{}").expect("marker should be written");
+
+    let state = KioskSlotService::ensure_layout(&layout).expect("layout should be created");
+    assert_eq!(state.active, KioskSlot::A);
+    assert!(fs::symlink_metadata(&layout.root_link)
+        .expect("root should exist")
+        .file_type()
+        .is_symlink());
+    assert!(layout.root_link.join("package.json").exists());
+    assert!(layout.slot_dir(KioskSlot::A).join("package.json").exists());
+
+    // A second call leaves the symlinked layout alone
+    let again = KioskSlotService::ensure_layout(&layout).expect("layout should load");
+    assert_eq!(again, state);
+
+    cleanup(&layout);
+}
+
+#[test]
+fn switch_active_repoints_project_root() {
+    let layout = temp_layout("switch_active");
+    fs::create_dir_all(&layout.root_link).expect("checkout should be created");
+    KioskSlotService::ensure_layout(&layout).expect("layout should be created");
+
+    let slot_b = layout.slot_dir(KioskSlot::B);
+    fs::create_dir_all(&slot_b).expect("slot b should be created");
+    fs::write(slot_b.join("marker"), "b").expect("marker should be written");
+
+    KioskSlotService::switch_active(&layout, KioskSlot::B).expect("switch should succeed");
+    assert_eq!(
+        fs::read_link(&layout.root_link).expect("root should be a link"),
+        slot_b
+    );
+    assert!(layout.root_link.join("marker").exists());
+
+    cleanup(&layout);
+}
+
+#[test]
+fn switch_active_rejects_missing_slot() {
+    let layout = temp_layout("switch_missing");
+    assert!(KioskSlotService::switch_active(&layout, KioskSlot::B).is_err());
+    cleanup(&layout);
+}
+
+#[test]
+fn history_records_are_upserted_and_capped() {
+    let layout = temp_layout("history");
+    fs::create_dir_all(&layout.slots_dir).expect("slots dir should be created");
+
+    let mut first = record("update-0");
+    KioskSlotService::save_record(&layout, &first).expect("record should save");
+    KioskSlotService::finish_record(&mut first, KioskUpdateOutcome::Succeeded, None);
+    KioskSlotService::save_record(&layout, &first).expect("record should update");
+
+    let history = KioskSlotService::load_history(&layout).expect("history should load");
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].outcome, KioskUpdateOutcome::Succeeded);
+    assert!(history[0].duration_ms.is_some());
+
+    for index in 1..=MAX_HISTORY_ENTRIES {
+        KioskSlotService::save_record(&layout, &record(&format!("update-{}", index)))
+            .expect("record should save");
+    }
+    let history = KioskSlotService::load_history(&layout).expect("history should load");
+    assert_eq!(history.len(), MAX_HISTORY_ENTRIES);
+    assert!(history.iter().all(|record| record.id != "update-0"));
+    assert!(history.iter().any(|record| record.id == "update-50"));
+
+    cleanup(&layout);
+}
@@ -4,19 +4,11 @@ use super::*;
 fn parses_prefixed_semver_tags() {
     assert_eq!(
         KioskUpdateService::parse_prefixed_semver("kiosk/1.2.3", "kiosk/"),
-        Some(SimpleSemver {
-            major: 1,
-            minor: 2,
-            patch: 3
-        })
+        Some(ReleaseVersion::new(1, 2, 3))
     );
     assert_eq!(
         KioskUpdateService::parse_prefixed_semver("kiosk/v0.5.0", "kiosk/"),
-        Some(SimpleSemver {
-            major: 0,
-            minor: 5,
-            patch: 0
-        })
+        Some(ReleaseVersion::new(0, 5, 0))
     );
     assert_eq!(
         KioskUpdateService::parse_prefixed_semver("kiosk/latest", "kiosk/"),
@@ -62,6 +54,7 @@ fn selects_latest_tag_for_prefix() {
             },
         ],
         "vulcan/",
+        ReleaseChannel::Stable,
     );
     assert_eq!(
         selected,
@@ -90,6 +83,7 @@ fn ignores_non_semver_prefixed_tags_when_selecting_latest() {
             },
         ],
         "vulcan/",
+        ReleaseChannel::Stable,
     );
     assert_eq!(selected, None);
 }
@@ -150,67 +144,293 @@ fn compares_current_and_latest_tags() {
 }
 
 #[test]
-fn reuses_cached_tag_inside_ttl() {
+fn reuses_cached_tags_inside_ttl() {
     let mut cache: Option<KioskTagCacheEntry> = None;
     let start = Instant::now();
     let mut calls = 0;
+    let tag = |name: &str, sha: &str| LatestTagInfo {
+        name: name.to_string(),
+        commit_sha: Some(sha.to_string()),
+    };
 
-    let first = KioskUpdateService::resolve_latest_tag_with_cache_entry(&mut cache, start, || {
+    let first = KioskUpdateService::resolve_tags_with_cache_entry(&mut cache, start, || {
         calls += 1;
-        Ok(Some(LatestTagInfo {
-            name: "kiosk/1.0.0".to_string(),
-            commit_sha: Some("100".to_string()),
-        }))
+        Ok(vec![tag("kiosk/1.0.0", "100")])
     })
     .expect("first call should succeed");
-    assert_eq!(
-        first,
-        Some(LatestTagInfo {
-            name: "kiosk/1.0.0".to_string(),
-            commit_sha: Some("100".to_string()),
-        })
-    );
+    assert_eq!(first, vec![tag("kiosk/1.0.0", "100")]);
     assert_eq!(calls, 1);
 
-    let second = KioskUpdateService::resolve_latest_tag_with_cache_entry(
+    let second = KioskUpdateService::resolve_tags_with_cache_entry(
         &mut cache,
         start + Duration::from_secs(120),
         || {
             calls += 1;
-            Ok(Some(LatestTagInfo {
-                name: "kiosk/2.0.0".to_string(),
-                commit_sha: Some("200".to_string()),
-            }))
+            Ok(vec![tag("kiosk/2.0.0", "200")])
         },
     )
     .expect("second call should reuse cache");
-    assert_eq!(
-        second,
-        Some(LatestTagInfo {
-            name: "kiosk/1.0.0".to_string(),
-            commit_sha: Some("100".to_string()),
-        })
-    );
+    assert_eq!(second, vec![tag("kiosk/1.0.0", "100")]);
     assert_eq!(calls, 1);
 
-    let third = KioskUpdateService::resolve_latest_tag_with_cache_entry(
+    let third = KioskUpdateService::resolve_tags_with_cache_entry(
         &mut cache,
         start + KioskUpdateService::TAG_CACHE_TTL + Duration::from_secs(1),
         || {
             calls += 1;
-            Ok(Some(LatestTagInfo {
-                name: "kiosk/2.0.0".to_string(),
-                commit_sha: Some("200".to_string()),
-            }))
+            Ok(vec![tag("kiosk/2.0.0", "200")])
         },
     )
     .expect("third call should refresh after ttl");
+    assert_eq!(third, vec![tag("kiosk/2.0.0", "200")]);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn beta_channel_selects_pre_release_tags() {
+    let tags = vec![
+        LatestTagInfo {
+            name: "kiosk/1.1.0".to_string(),
+            commit_sha: Some("110".to_string()),
+        },
+        LatestTagInfo {
+            name: "kiosk/1.2.0-beta.1".to_string(),
+            commit_sha: Some("121".to_string()),
+        },
+    ];
+
+    let stable = KioskUpdateService::select_latest_prefixed_tag(
+        tags.clone(),
+        "kiosk/",
+        ReleaseChannel::Stable,
+    );
+    assert_eq!(stable.map(|tag| tag.name).as_deref(), Some("kiosk/1.1.0"));
+
+    let beta = KioskUpdateService::select_latest_prefixed_tag(tags, "kiosk/", ReleaseChannel::Beta);
     assert_eq!(
-        third,
+        beta.map(|tag| tag.name).as_deref(),
+        Some("kiosk/1.2.0-beta.1")
+    );
+}
+
+#[test]
+fn stable_update_targets_match_the_reported_stable_tags() {
+    let cached = |names: &[&str]| {
+        Mutex::new(Some(KioskTagCacheEntry {
+            fetched_at: Instant::now(),
+            tags: names
+                .iter()
+                .map(|name| LatestTagInfo {
+                    name: name.to_string(),
+                    commit_sha: None,
+                })
+                .collect(),
+        }))
+    };
+    let app_cache = cached(&["kiosk/1.1.0", "kiosk/1.2.0-beta.1"]);
+    let lerobot_cache = cached(&["vulcan/0.3.0", "vulcan/0.4.0-rc.1"]);
+    let reported = |cache: &Mutex<Option<KioskTagCacheEntry>>, prefix: &str| {
+        KioskUpdateService::resolve_channel_tag(
+            cache,
+            "SOURCCEY_KIOSK_TEST_UNUSED_TAGS_URL",
+            "http://127.0.0.1:9/unreachable",
+            prefix,
+            ReleaseChannel::Stable,
+            None,
+        )
+        .expect("cached tags should resolve")
+    };
+    let app_tag = reported(&app_cache, "kiosk/");
+    let lerobot_tag = reported(&lerobot_cache, "vulcan/");
+
+    let targets = KioskUpdateService::update_targets_from_tags(
+        "origin/main".to_string(),
+        app_tag.as_ref(),
+        lerobot_tag.as_ref(),
+    );
+    assert_eq!(targets.app_ref, "refs/tags/kiosk/1.1.0");
+    assert_eq!(targets.lerobot_tag.as_deref(), Some("vulcan/0.3.0"));
+
+    // Only a missing stable tag falls back to the configured ref
+    let untagged =
+        KioskUpdateService::update_targets_from_tags("origin/main".to_string(), None, None);
+    assert_eq!(untagged.app_ref, "origin/main");
+    assert_eq!(untagged.lerobot_tag, None);
+}
+
+#[test]
+fn pinned_tag_resolves_without_fetching_tags() {
+    let cache = Mutex::new(None);
+    let pinned = KioskUpdateService::resolve_channel_tag(
+        &cache,
+        "SOURCCEY_KIOSK_TEST_UNUSED_TAGS_URL",
+        "http://127.0.0.1:9/unreachable",
+        "vulcan/",
+        ReleaseChannel::Pinned,
+        Some("vulcan/0.2.0"),
+    )
+    .expect("pin should resolve locally");
+    assert_eq!(
+        pinned,
         Some(LatestTagInfo {
-            name: "kiosk/2.0.0".to_string(),
-            commit_sha: Some("200".to_string()),
+            name: "vulcan/0.2.0".to_string(),
+            commit_sha: None,
         })
     );
-    assert_eq!(calls, 2);
+    assert!(cache.lock().unwrap().is_none());
+}
+
+#[test]
+fn pinned_component_must_match_pin_exactly() {
+    let pin = LatestTagInfo {
+        name: "vulcan/0.2.0".to_string(),
+        commit_sha: None,
+    };
+    let missing_repo = Path::new("/nonexistent/lerobot-vulcan");
+
+    assert!(KioskUpdateService::is_component_up_to_date(
+        missing_repo,
+        Some("vulcan/0.2.0"),
+        Some(&pin),
+        "vulcan/",
+        true,
+    ));
+    assert!(!KioskUpdateService::is_component_up_to_date(
+        missing_repo,
+        Some("vulcan/0.3.0"),
+        Some(&pin),
+        "vulcan/",
+        true,
+    ));
+    assert!(KioskUpdateService::is_component_up_to_date(
+        missing_repo,
+        Some("vulcan/0.3.0"),
+        Some(&pin),
+        "vulcan/",
+        false,
+    ));
+}
+
+#[test]
+fn built_package_comes_from_the_slot_build_for_its_version() {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after epoch")
+        .as_nanos();
+    let slot_dir = std::env::temp_dir().join(format!("kiosk_slot_package_{}", nonce));
+    let bundle_dir = slot_dir.join("src-tauri/target/release/bundle/deb");
+    fs::create_dir_all(&bundle_dir).expect("bundle dir should be created");
+    fs::create_dir_all(slot_dir.join(".tauri-target/release/bundle/deb"))
+        .expect("other target dir should be created");
+    fs::write(
+        slot_dir.join("src-tauri/tauri.conf.json"),
+        r#"{ "productName": "Vulcan Studio", "version": "0.0.21" }"#,
+    )
+    .expect("config should be written");
+
+    assert!(KioskUpdateService::find_built_package(&slot_dir).is_err());
+
+    // Stale builds and packages outside the slot's target dir don't count
+    fs::write(bundle_dir.join("Vulcan Studio_0.0.20_arm64.deb"), "old")
+        .expect("stale package should be written");
+    fs::write(
+        slot_dir.join(".tauri-target/release/bundle/deb/Vulcan Studio_0.0.21_arm64.deb"),
+        "elsewhere",
+    )
+    .expect("package should be written");
+    assert!(KioskUpdateService::find_built_package(&slot_dir).is_err());
+
+    fs::write(bundle_dir.join("Vulcan Studio_0.0.21_arm64.deb"), "new")
+        .expect("package should be written");
+    assert_eq!(
+        KioskUpdateService::find_built_package(&slot_dir).expect("package should be found"),
+        bundle_dir.join("Vulcan Studio_0.0.21_arm64.deb")
+    );
+
+    let _ = fs::remove_dir_all(&slot_dir);
+}
+
+fn smoke_dir(name: &str) -> PathBuf {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("kiosk_{}_{}", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+#[cfg(unix)]
+fn write_script(path: &Path, body: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    fs::write(path, format!("#!/bin/sh\n{}\n", body)).expect("script should be written");
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .expect("script should be executable");
+}
+
+#[cfg(unix)]
+#[test]
+fn boot_guard_counts_only_when_executable() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = smoke_dir("boot_guard");
+    let guard = dir.join("sourccey-kiosk-boot-guard");
+    assert!(!KioskUpdateService::boot_guard_installed(&guard));
+
+    write_script(&guard, "exit 0");
+    assert!(KioskUpdateService::boot_guard_installed(&guard));
+    fs::set_permissions(&guard, fs::Permissions::from_mode(0o644)).unwrap();
+    assert!(!KioskUpdateService::boot_guard_installed(&guard));
+}
+
+#[test]
+fn switching_without_a_boot_guard_does_not_leave_the_slot_on_probation() {
+    let record = KioskUpdateRecord {
+        id: "update-1".to_string(),
+        from_slot: KioskSlot::A,
+        to_slot: KioskSlot::B,
+        from_tag: None,
+        to_tag: None,
+        started_at: Utc::now(),
+        completed_at: None,
+        duration_ms: None,
+        outcome: KioskUpdateOutcome::InProgress,
+        error: None,
+    };
+
+    let mut guarded_state = KioskSlotState::default();
+    let mut guarded_record = record.clone();
+    KioskUpdateService::mark_switched(&mut guarded_state, &mut guarded_record, KioskSlot::B, true);
+    assert!(guarded_state.pending_verification);
+    assert_eq!(guarded_state.active, KioskSlot::B);
+    assert_eq!(guarded_state.previous, Some(KioskSlot::A));
+    assert_eq!(guarded_record.outcome, KioskUpdateOutcome::PendingBoot);
+
+    let mut state = KioskSlotState::default();
+    let mut unguarded_record = record;
+    KioskUpdateService::mark_switched(&mut state, &mut unguarded_record, KioskSlot::B, false);
+    assert!(!state.pending_verification);
+    assert_eq!(state.active, KioskSlot::B);
+    assert_eq!(state.update_id.as_deref(), Some("update-1"));
+    assert_eq!(unguarded_record.outcome, KioskUpdateOutcome::Succeeded);
+    assert!(unguarded_record.completed_at.is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn staged_app_that_exits_fails_the_smoke_test() {
+    let slot_dir = smoke_dir("smoke_test");
+    let binary = slot_dir.join("VulcanStudio");
+    write_script(&binary, "exit 3");
+
+    let error = KioskUpdateService::smoke_test_app(&binary, &slot_dir).unwrap_err();
+    assert!(error.contains("exited during its smoke test"), "{}", error);
+    assert!(!slot_dir.join(".smoke-test").exists());
+
+    let missing =
+        KioskUpdateService::smoke_test_app(&slot_dir.join("missing"), &slot_dir).unwrap_err();
+    assert!(
+        missing.contains("Failed to start staged app"),
+        "{}",
+        missing
+    );
 }
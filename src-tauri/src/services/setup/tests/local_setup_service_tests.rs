@@ -4,19 +4,11 @@ use super::*;
 fn parses_vulcan_semver_tags() {
     assert_eq!(
         LocalSetupService::parse_vulcan_semver("vulcan/0.1.0"),
-        Some(SimpleSemver {
-            major: 0,
-            minor: 1,
-            patch: 0
-        })
+        Some(ReleaseVersion::new(0, 1, 0))
     );
     assert_eq!(
         LocalSetupService::parse_vulcan_semver("vulcan/v1.2.3"),
-        Some(SimpleSemver {
-            major: 1,
-            minor: 2,
-            patch: 3
-        })
+        Some(ReleaseVersion::new(1, 2, 3))
     );
     assert_eq!(
         LocalSetupService::parse_vulcan_semver("vulcan/not-semver"),
@@ -48,30 +40,75 @@ fn extracts_release_tag_from_versioned_archive_url() {
     );
 }
 
+#[test]
+fn install_url_follows_the_channel_tag_and_manifest() {
+    let manifest = LerobotModuleManifest {
+        commit: None,
+        tag: Some("vulcan/0.1.12".to_string()),
+        url: Some("https://mirror.example.com/lerobot-vulcan-0.1.12.zip".to_string()),
+    };
+    let base = LocalSetupService::LEROBOT_ZIP_BASE_URL;
+
+    // A pin or channel tag picks the archive, whatever the manifest advertises
+    assert_eq!(
+        LocalSetupService::select_lerobot_zip_url(Some("vulcan/0.2.0-beta.1"), Some(&manifest)),
+        Ok(format!("{}/lerobot-vulcan_vulcan-0.2.0-beta.1.zip", base))
+    );
+    assert_eq!(
+        LocalSetupService::select_lerobot_zip_url(Some("vulcan/0.1.11"), None),
+        Ok(format!("{}/lerobot-vulcan_vulcan-0.1.11.zip", base))
+    );
+    // The manifest's own archive is used for the tag it describes
+    assert_eq!(
+        LocalSetupService::select_lerobot_zip_url(Some("vulcan/0.1.12"), Some(&manifest)),
+        Ok("https://mirror.example.com/lerobot-vulcan-0.1.12.zip".to_string())
+    );
+    assert_eq!(
+        LocalSetupService::select_lerobot_zip_url(None, Some(&manifest)),
+        Ok("https://mirror.example.com/lerobot-vulcan-0.1.12.zip".to_string())
+    );
+    assert_eq!(
+        LocalSetupService::select_lerobot_zip_url(None, None),
+        Ok(LocalSetupService::DEFAULT_LEROBOT_ZIP_URL.to_string())
+    );
+    assert!(LocalSetupService::select_lerobot_zip_url(Some("vulcan/latest"), None).is_err());
+
+    // Archive names round-trip to the tag they were resolved from
+    let url = LocalSetupService::select_lerobot_zip_url(Some("vulcan/0.2.0-beta.1"), None)
+        .expect("url should resolve");
+    assert_eq!(
+        LocalSetupService::lerobot_tag_from_zip_url(&url),
+        Some("vulcan/0.2.0-beta.1".to_string())
+    );
+}
+
 #[test]
 fn selects_highest_vulcan_semver_and_ignores_other_tags() {
-    let selected = LocalSetupService::select_latest_vulcan_tag(vec![
-        LatestLerobotTagInfo {
-            name: "release/5.0.0".to_string(),
-            commit_sha: None,
-        },
-        LatestLerobotTagInfo {
-            name: "1.9.9".to_string(),
-            commit_sha: None,
-        },
-        LatestLerobotTagInfo {
-            name: "vulcan/0.1.0".to_string(),
-            commit_sha: Some("1111111".to_string()),
-        },
-        LatestLerobotTagInfo {
-            name: "vulcan/0.3.0".to_string(),
-            commit_sha: Some("3333333".to_string()),
-        },
-        LatestLerobotTagInfo {
-            name: "vulcan/0.2.5".to_string(),
-            commit_sha: Some("2222222".to_string()),
-        },
-    ]);
+    let selected = LocalSetupService::select_latest_vulcan_tag(
+        vec![
+            LatestLerobotTagInfo {
+                name: "release/5.0.0".to_string(),
+                commit_sha: None,
+            },
+            LatestLerobotTagInfo {
+                name: "1.9.9".to_string(),
+                commit_sha: None,
+            },
+            LatestLerobotTagInfo {
+                name: "vulcan/0.1.0".to_string(),
+                commit_sha: Some("1111111".to_string()),
+            },
+            LatestLerobotTagInfo {
+                name: "vulcan/0.3.0".to_string(),
+                commit_sha: Some("3333333".to_string()),
+            },
+            LatestLerobotTagInfo {
+                name: "vulcan/0.2.5".to_string(),
+                commit_sha: Some("2222222".to_string()),
+            },
+        ],
+        ReleaseChannel::Stable,
+    );
 
     assert_eq!(
         selected,
@@ -84,20 +121,23 @@ fn selects_highest_vulcan_semver_and_ignores_other_tags() {
 
 #[test]
 fn ignores_non_semver_vulcan_tags_when_selecting_latest() {
-    let selected = LocalSetupService::select_latest_vulcan_tag(vec![
-        LatestLerobotTagInfo {
-            name: "vulcan/latest".to_string(),
-            commit_sha: None,
-        },
-        LatestLerobotTagInfo {
-            name: "vulcan/release".to_string(),
-            commit_sha: None,
-        },
-        LatestLerobotTagInfo {
-            name: "other/1.0.0".to_string(),
-            commit_sha: None,
-        },
-    ]);
+    let selected = LocalSetupService::select_latest_vulcan_tag(
+        vec![
+            LatestLerobotTagInfo {
+                name: "vulcan/latest".to_string(),
+                commit_sha: None,
+            },
+            LatestLerobotTagInfo {
+                name: "vulcan/release".to_string(),
+                commit_sha: None,
+            },
+            LatestLerobotTagInfo {
+                name: "other/1.0.0".to_string(),
+                commit_sha: None,
+            },
+        ],
+        ReleaseChannel::Stable,
+    );
     assert_eq!(selected, None);
 }
 
@@ -241,68 +281,45 @@ fn resolves_unknown_when_manifest_is_missing_release_tag() {
 }
 
 #[test]
-fn reuses_cached_latest_tag_inside_ttl() {
+fn reuses_cached_tags_inside_ttl() {
     let mut cache: Option<LerobotTagCacheEntry> = None;
     let start = Instant::now();
     let mut fetch_calls = 0;
+    let tag = |name: &str, sha: &str| LatestLerobotTagInfo {
+        name: name.to_string(),
+        commit_sha: Some(sha.to_string()),
+    };
 
-    let first = LocalSetupService::resolve_latest_tag_with_cache(&mut cache, start, || {
+    let first = LocalSetupService::resolve_tags_with_cache(&mut cache, start, || {
         fetch_calls += 1;
-        Ok(Some(LatestLerobotTagInfo {
-            name: "vulcan/0.2.0".to_string(),
-            commit_sha: Some("abc1234".to_string()),
-        }))
+        Ok(vec![tag("vulcan/0.2.0", "abc1234")])
     })
     .expect("first cache resolution should succeed");
-    assert_eq!(
-        first,
-        Some(LatestLerobotTagInfo {
-            name: "vulcan/0.2.0".to_string(),
-            commit_sha: Some("abc1234".to_string()),
-        })
-    );
+    assert_eq!(first, vec![tag("vulcan/0.2.0", "abc1234")]);
     assert_eq!(fetch_calls, 1);
 
-    let second = LocalSetupService::resolve_latest_tag_with_cache(
+    let second = LocalSetupService::resolve_tags_with_cache(
         &mut cache,
         start + Duration::from_secs(60),
         || {
             fetch_calls += 1;
-            Ok(Some(LatestLerobotTagInfo {
-                name: "vulcan/0.9.0".to_string(),
-                commit_sha: Some("def5678".to_string()),
-            }))
+            Ok(vec![tag("vulcan/0.9.0", "def5678")])
         },
     )
     .expect("second cache resolution should reuse cache");
-    assert_eq!(
-        second,
-        Some(LatestLerobotTagInfo {
-            name: "vulcan/0.2.0".to_string(),
-            commit_sha: Some("abc1234".to_string()),
-        })
-    );
+    assert_eq!(second, vec![tag("vulcan/0.2.0", "abc1234")]);
     assert_eq!(fetch_calls, 1);
 
-    let third = LocalSetupService::resolve_latest_tag_with_cache(
+    let third = LocalSetupService::resolve_tags_with_cache(
         &mut cache,
         start + LocalSetupService::LEROBOT_TAG_CACHE_TTL + Duration::from_secs(1),
         || {
             fetch_calls += 1;
-            Ok(Some(LatestLerobotTagInfo {
-                name: "vulcan/0.9.0".to_string(),
-                commit_sha: Some("def5678".to_string()),
-            }))
+            Ok(vec![tag("vulcan/0.9.0", "def5678")])
         },
     )
     .expect("third cache resolution should refresh after ttl");
-    assert_eq!(
-        third,
-        Some(LatestLerobotTagInfo {
-            name: "vulcan/0.9.0".to_string(),
-            commit_sha: Some("def5678".to_string()),
-        })
-    );
+    assert_eq!(third, vec![tag("vulcan/0.9.0", "def5678")]);
     assert_eq!(fetch_calls, 2);
 }
 
@@ -348,7 +365,79 @@ fn uv_pip_install_args_target_specific_python_and_extra() {
     expected.push(".[sourccey-desktop,xvla]".to_string());
 
     assert_eq!(
-        LocalSetupService::uv_pip_install_args(python_path, Some("sourccey-desktop,xvla")),
+        LocalSetupService::uv_pip_install_args(python_path, Some("sourccey-desktop,xvla"), None),
         expected
     );
 }
+
+#[test]
+fn uv_pip_install_args_use_only_bundled_wheels_when_offline() {
+    let python_path = Path::new("/tmp/venv/bin/python");
+    let wheels_dir = Path::new("/media/usb/sourccey-bundle/wheels");
+
+    let args =
+        LocalSetupService::uv_pip_install_args(python_path, Some("sourccey"), Some(wheels_dir));
+
+    let find_links = args
+        .iter()
+        .position(|arg| arg == "--find-links")
+        .expect("--find-links should be passed");
+    assert_eq!(args[find_links + 1], wheels_dir.to_string_lossy());
+    assert!(args.iter().any(|arg| arg == "--offline"));
+    assert!(args.iter().any(|arg| arg == "--no-index"));
+    assert_eq!(args.last().map(String::as_str), Some(".[sourccey]"));
+}
+
+#[test]
+fn beta_channel_selects_vulcan_pre_releases() {
+    let tags = vec![
+        LatestLerobotTagInfo {
+            name: "vulcan/0.3.0".to_string(),
+            commit_sha: None,
+        },
+        LatestLerobotTagInfo {
+            name: "vulcan/0.4.0-rc.1".to_string(),
+            commit_sha: None,
+        },
+    ];
+
+    assert_eq!(
+        LocalSetupService::select_latest_vulcan_tag(tags.clone(), ReleaseChannel::Stable)
+            .map(|tag| tag.name)
+            .as_deref(),
+        Some("vulcan/0.3.0")
+    );
+    assert_eq!(
+        LocalSetupService::select_latest_vulcan_tag(tags, ReleaseChannel::Beta)
+            .map(|tag| tag.name)
+            .as_deref(),
+        Some("vulcan/0.4.0-rc.1")
+    );
+    assert_eq!(
+        LocalSetupService::compare_vulcan_release_tags("vulcan/0.4.0-rc.1", "vulcan/0.4.0"),
+        Some(Ordering::Less)
+    );
+}
+
+#[test]
+fn pinned_release_state_requires_exact_tag() {
+    let release = |tag: &str| CurrentLerobotReleaseInfo {
+        source: CurrentLerobotReleaseSource::Marker,
+        tag: Some(tag.to_string()),
+        commit: None,
+    };
+
+    let (state, _) = LocalSetupService::resolve_pinned_lerobot_release_state(
+        &release("vulcan/0.3.0"),
+        "vulcan/0.3.0",
+    );
+    assert_eq!(state, LerobotReleaseState::UpToDate);
+
+    // Newer than the pin still has to move back to it
+    let (state, message) = LocalSetupService::resolve_pinned_lerobot_release_state(
+        &release("vulcan/0.4.0"),
+        "vulcan/0.3.0",
+    );
+    assert_eq!(state, LerobotReleaseState::UpdateAvailable);
+    assert!(message.unwrap().contains("vulcan/0.3.0"));
+}
@@ -0,0 +1,210 @@
+use super::*;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_bundle_dir(name: &str) -> PathBuf {
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be after unix epoch")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("{}_{}_offline_bundle", name, nonce));
+    fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn write_bundle_file(bundle_dir: &Path, relative: &str, contents: &[u8]) {
+    let path = OfflineBundleService::bundle_path(bundle_dir, relative);
+    fs::create_dir_all(path.parent().expect("bundle file should have a parent"))
+        .expect("bundle subdirectory should be created");
+    fs::write(path, contents).expect("bundle file should be written");
+}
+
+fn sample_bundle(name: &str) -> (PathBuf, OfflineBundleManifest) {
+    let bundle_dir = temp_bundle_dir(name);
+    write_bundle_file(
+        &bundle_dir,
+        "lerobot/lerobot-vulcan_vulcan-0.1.10.zip",
+        b"lerobot archive",
+    );
+    write_bundle_file(&bundle_dir, "uv/uv", b"uv binary");
+    write_bundle_file(&bundle_dir, "python/python.zip", b"python archive");
+    write_bundle_file(
+        &bundle_dir,
+        "wheels/numpy-2.0.0-cp312-none-any.whl",
+        b"numpy wheel",
+    );
+
+    let manifest = OfflineBundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        created_at: Utc::now(),
+        platform: OfflineBundleService::current_platform(),
+        lerobot_tag: Some("vulcan/0.1.10".to_string()),
+        lerobot_archive: "lerobot/lerobot-vulcan_vulcan-0.1.10.zip".to_string(),
+        uv_binary: "uv/uv".to_string(),
+        python_archive: PYTHON_ARCHIVE.to_string(),
+        python_executable: "cpython-3.12.7-linux-x86_64-gnu/bin/python3.12".to_string(),
+        files: OfflineBundleService::describe_files(&bundle_dir).expect("files should hash"),
+        signature: None,
+    };
+    OfflineBundleService::write_manifest(&bundle_dir, &manifest)
+        .expect("manifest should be written");
+    (bundle_dir, manifest)
+}
+
+fn generated_key_pair() -> (Vec<u8>, Vec<u8>) {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).expect("key should generate");
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("key should parse");
+    (
+        pkcs8.as_ref().to_vec(),
+        key_pair.public_key().as_ref().to_vec(),
+    )
+}
+
+#[test]
+fn verify_bundle_accepts_listed_files_with_matching_hashes() {
+    let (bundle_dir, manifest) = sample_bundle("verify_ok");
+
+    let verified =
+        OfflineBundleService::verify_bundle(&bundle_dir, None).expect("bundle should verify");
+
+    assert_eq!(verified, manifest);
+    assert_eq!(verified.files.len(), 4);
+    assert!(verified
+        .files
+        .iter()
+        .all(|file| !file.path.contains('\\') && file.path != BUNDLE_MANIFEST_FILE));
+}
+
+#[test]
+fn verify_bundle_rejects_tampered_file() {
+    let (bundle_dir, _) = sample_bundle("verify_tampered");
+    write_bundle_file(&bundle_dir, "uv/uv", b"uv binarY");
+
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, None).unwrap_err();
+
+    assert!(error.contains("Checksum verification failed"), "{}", error);
+}
+
+#[test]
+fn verify_bundle_rejects_unlisted_wheel() {
+    let (bundle_dir, _) = sample_bundle("verify_unlisted");
+    write_bundle_file(&bundle_dir, "wheels/evil-1.0-py3-none-any.whl", b"evil");
+
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, None).unwrap_err();
+
+    assert!(
+        error.contains("wheels/evil-1.0-py3-none-any.whl"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn verify_bundle_rejects_paths_outside_bundle() {
+    let (bundle_dir, mut manifest) = sample_bundle("verify_traversal");
+    manifest.files.push(OfflineBundleFile {
+        path: "../outside.whl".to_string(),
+        sha256: "0".repeat(64),
+        size: 0,
+    });
+    OfflineBundleService::write_manifest(&bundle_dir, &manifest)
+        .expect("manifest should be written");
+
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, None).unwrap_err();
+
+    assert!(error.contains("unsafe path"), "{}", error);
+}
+
+#[test]
+fn verify_bundle_rejects_other_platform() {
+    let (bundle_dir, mut manifest) = sample_bundle("verify_platform");
+    manifest.platform = "plan9-mips".to_string();
+    OfflineBundleService::write_manifest(&bundle_dir, &manifest)
+        .expect("manifest should be written");
+
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, None).unwrap_err();
+
+    assert!(error.contains("plan9-mips"), "{}", error);
+}
+
+#[test]
+fn verify_bundle_checks_signature_against_public_key() {
+    let (bundle_dir, mut manifest) = sample_bundle("verify_signed");
+    let (pkcs8, public_key) = generated_key_pair();
+    OfflineBundleService::sign_manifest(&mut manifest, &pkcs8).expect("manifest should sign");
+    OfflineBundleService::write_manifest(&bundle_dir, &manifest)
+        .expect("manifest should be written");
+
+    assert!(OfflineBundleService::verify_bundle(&bundle_dir, Some(&public_key)).is_ok());
+
+    let (_, other_public_key) = generated_key_pair();
+    let error =
+        OfflineBundleService::verify_bundle(&bundle_dir, Some(&other_public_key)).unwrap_err();
+    assert!(error.contains("signature does not match"), "{}", error);
+
+    manifest.lerobot_tag = Some("vulcan/9.9.9".to_string());
+    OfflineBundleService::write_manifest(&bundle_dir, &manifest)
+        .expect("manifest should be written");
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, Some(&public_key)).unwrap_err();
+    assert!(error.contains("signature does not match"), "{}", error);
+}
+
+#[test]
+fn verify_bundle_requires_signature_when_key_is_embedded() {
+    let (bundle_dir, _) = sample_bundle("verify_unsigned");
+    let (_, public_key) = generated_key_pair();
+
+    let error = OfflineBundleService::verify_bundle(&bundle_dir, Some(&public_key)).unwrap_err();
+
+    assert!(error.contains("not signed"), "{}", error);
+}
+
+#[test]
+fn stage_bundle_rechecks_wheels_copied_off_the_drive() {
+    let (bundle_dir, manifest) = sample_bundle("stage_wheels");
+    let staging_dir = bundle_dir.join("staging");
+    let python_staging = bundle_dir.join("python.staging");
+    OfflineBundleService::verify_bundle(&bundle_dir, None).expect("bundle should verify");
+    write_bundle_file(
+        &bundle_dir,
+        "wheels/numpy-2.0.0-cp312-none-any.whl",
+        b"numpy wheeL",
+    );
+
+    let error =
+        OfflineBundleService::stage_bundle(&bundle_dir, &manifest, &staging_dir, &python_staging)
+            .unwrap_err();
+
+    assert!(error.contains("Checksum verification failed"), "{}", error);
+    assert!(!python_staging.exists());
+}
+
+#[test]
+fn replaced_path_restores_previous_contents_on_failure() {
+    let root = temp_bundle_dir("replaced_restore");
+    let runtime = root.join("lerobot-vulcan");
+    write_bundle_file(&root, "lerobot-vulcan/pyproject.toml", b"previous");
+
+    let replaced = ReplacedPath::set_aside(&runtime).expect("runtime should be set aside");
+    assert!(!runtime.exists());
+    write_bundle_file(&root, "lerobot-vulcan/partial.txt", b"half installed");
+    replaced.restore();
+
+    assert_eq!(
+        fs::read(runtime.join("pyproject.toml")).expect("previous runtime should be back"),
+        b"previous"
+    );
+    assert!(!runtime.join("partial.txt").exists());
+    assert!(!root.join("lerobot-vulcan.previous").exists());
+
+    let replaced = ReplacedPath::set_aside(&runtime).expect("runtime should be set aside");
+    write_bundle_file(&root, "lerobot-vulcan/pyproject.toml", b"new");
+    replaced.discard_backup();
+
+    assert_eq!(
+        fs::read(runtime.join("pyproject.toml")).expect("new runtime should stay"),
+        b"new"
+    );
+    assert!(!root.join("lerobot-vulcan.previous").exists());
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+fn tags(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn parses_channels_with_aliases() {
+    assert_eq!(ReleaseChannel::parse("Beta"), Ok(ReleaseChannel::Beta));
+    assert_eq!(
+        ReleaseChannel::parse("pinned-tag"),
+        Ok(ReleaseChannel::Pinned)
+    );
+    assert_eq!(ReleaseChannel::parse(""), Ok(ReleaseChannel::Stable));
+    assert!(ReleaseChannel::parse("nightly").is_err());
+}
+
+#[test]
+fn parses_pre_release_tags() {
+    assert_eq!(
+        ReleaseChannelService::parse_tag_version("kiosk/v1.2.0-beta.1", "kiosk/"),
+        Some(ReleaseVersion {
+            major: 1,
+            minor: 2,
+            patch: 0,
+            pre_release: Some("beta.1".to_string()),
+        })
+    );
+    assert_eq!(
+        ReleaseChannelService::parse_tag_version("kiosk/1.2.0-", "kiosk/"),
+        None
+    );
+    assert_eq!(
+        ReleaseChannelService::parse_tag_version("kiosk/1.2.0-beta..1", "kiosk/"),
+        None
+    );
+}
+
+#[test]
+fn orders_pre_releases_before_their_release() {
+    let parse = |value: &str| ReleaseChannelService::parse_version(value).unwrap();
+    assert!(parse("1.2.0-beta.1") < parse("1.2.0-beta.2"));
+    assert!(parse("1.2.0-beta.2") < parse("1.2.0-beta.10"));
+    assert!(parse("1.2.0-beta.10") < parse("1.2.0-rc.1"));
+    assert!(parse("1.2.0-rc.1") < parse("1.2.0"));
+    assert!(parse("1.1.9") < parse("1.2.0-beta.1"));
+}
+
+#[test]
+fn stable_skips_pre_releases_and_beta_includes_them() {
+    let available = tags(&[
+        "vulcan/0.3.0",
+        "vulcan/0.4.0-beta.2",
+        "vulcan/0.4.0-beta.1",
+        "kiosk/9.0.0",
+    ]);
+
+    let stable = ReleaseChannelService::select_latest(
+        available.clone(),
+        |tag| tag.as_str(),
+        "vulcan/",
+        ReleaseChannel::Stable,
+    );
+    assert_eq!(stable.as_deref(), Some("vulcan/0.3.0"));
+
+    let beta = ReleaseChannelService::select_latest(
+        available.clone(),
+        |tag| tag.as_str(),
+        "vulcan/",
+        ReleaseChannel::Beta,
+    );
+    assert_eq!(beta.as_deref(), Some("vulcan/0.4.0-beta.2"));
+
+    let pinned = ReleaseChannelService::select_latest(
+        available,
+        |tag| tag.as_str(),
+        "vulcan/",
+        ReleaseChannel::Pinned,
+    );
+    assert_eq!(pinned.as_deref(), Some("vulcan/0.3.0"));
+}
+
+#[test]
+fn pins_apply_only_on_the_pinned_channel() {
+    assert_eq!(
+        ReleaseChannelService::active_pin(ReleaseChannel::Pinned, Some(" vulcan/0.2.0 ")),
+        Some("vulcan/0.2.0")
+    );
+    assert_eq!(
+        ReleaseChannelService::active_pin(ReleaseChannel::Pinned, Some("  ")),
+        None
+    );
+    assert_eq!(
+        ReleaseChannelService::active_pin(ReleaseChannel::Beta, Some("vulcan/0.2.0")),
+        None
+    );
+}
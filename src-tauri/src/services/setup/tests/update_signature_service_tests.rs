@@ -0,0 +1,189 @@
+use super::*;
+use ring::rand::SystemRandom;
+use ring::signature::KeyPair;
+
+const MANIFEST: &[u8] =
+    br#"{"version":"1.4.0","force":true,"modules":{"lerobot_vulcan":{"tag":"vulcan/0.1.10"}}}"#;
+const MANIFEST_URL: &str = "https://example.com/updater/latest.json";
+
+fn generated_key_pair() -> (Vec<u8>, Vec<u8>) {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).expect("key should generate");
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("key should parse");
+    (
+        pkcs8.as_ref().to_vec(),
+        key_pair.public_key().as_ref().to_vec(),
+    )
+}
+
+#[test]
+fn verify_download_accepts_matching_signature() {
+    let (pkcs8, public_key) = generated_key_pair();
+    let signature = UpdateSignatureService::sign(MANIFEST, &pkcs8).expect("payload should sign");
+
+    let result = UpdateSignatureService::verify_download(
+        MANIFEST,
+        Some(&format!("{}\n", signature)),
+        Some(&public_key),
+        "Updater manifest",
+        MANIFEST_URL,
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn verify_download_rejects_modified_payload() {
+    let (pkcs8, public_key) = generated_key_pair();
+    let signature = UpdateSignatureService::sign(MANIFEST, &pkcs8).expect("payload should sign");
+    let tampered = String::from_utf8_lossy(MANIFEST).replace("\"force\":true", "\"force\":false");
+
+    let error = UpdateSignatureService::verify_download(
+        tampered.as_bytes(),
+        Some(&signature),
+        Some(&public_key),
+        "Updater manifest",
+        MANIFEST_URL,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        format!(
+            "Updater manifest signature does not match the update signing key ({})",
+            MANIFEST_URL
+        )
+    );
+}
+
+#[test]
+fn verify_download_rejects_signature_from_other_key() {
+    let (other_pkcs8, _) = generated_key_pair();
+    let (_, public_key) = generated_key_pair();
+    let signature =
+        UpdateSignatureService::sign(MANIFEST, &other_pkcs8).expect("payload should sign");
+
+    let error = UpdateSignatureService::verify_download(
+        MANIFEST,
+        Some(&signature),
+        Some(&public_key),
+        "Updater manifest",
+        MANIFEST_URL,
+    )
+    .unwrap_err();
+
+    assert!(error.contains("does not match"), "{}", error);
+}
+
+#[test]
+fn verify_download_requires_signature_when_key_is_embedded() {
+    let (_, public_key) = generated_key_pair();
+
+    let error = UpdateSignatureService::verify_download(
+        MANIFEST,
+        None,
+        Some(&public_key),
+        "Updater manifest",
+        MANIFEST_URL,
+    )
+    .unwrap_err();
+
+    assert!(error.contains("is not signed"), "{}", error);
+    assert!(error.contains("latest.json.sig"), "{}", error);
+}
+
+#[test]
+fn verify_download_rejects_malformed_signature() {
+    let (_, public_key) = generated_key_pair();
+
+    let error = UpdateSignatureService::verify_download(
+        MANIFEST,
+        Some("not base64!"),
+        Some(&public_key),
+        "lerobot-vulcan checksum",
+        MANIFEST_URL,
+    )
+    .unwrap_err();
+
+    assert!(
+        error.starts_with("lerobot-vulcan checksum signature is not valid base64"),
+        "{}",
+        error
+    );
+}
+
+// Release builds fail closed instead; see verify_download.
+#[cfg(debug_assertions)]
+#[test]
+fn verify_download_skips_without_embedded_key_in_debug_builds() {
+    assert!(UpdateSignatureService::verify_download(
+        MANIFEST,
+        None,
+        None,
+        "Updater manifest",
+        MANIFEST_URL,
+    )
+    .is_ok());
+}
+
+#[test]
+fn signature_url_keeps_query_string_after_extension() {
+    assert_eq!(
+        UpdateSignatureService::signature_url(MANIFEST_URL),
+        "https://example.com/updater/latest.json.sig"
+    );
+    assert_eq!(
+        UpdateSignatureService::signature_url("https://example.com/latest.json?channel=beta"),
+        "https://example.com/latest.json.sig?channel=beta"
+    );
+}
+
+fn manifest_json(version: &str, expires_at: &str) -> serde_json::Value {
+    serde_json::json!({ "version": version, "expires_at": expires_at, "force": true })
+}
+
+#[test]
+fn manifest_freshness_accepts_current_unexpired_manifest() {
+    let now = DateTime::parse_from_rfc3339("2026-10-19T12:00:00Z")
+        .expect("timestamp should parse")
+        .with_timezone(&Utc);
+
+    for version in ["0.0.20", "0.0.21", "v0.1.0"] {
+        let manifest = manifest_json(version, "2026-11-18T12:00:00Z");
+        let result = UpdateSignatureService::check_manifest_freshness(
+            &manifest,
+            MANIFEST_URL,
+            "0.0.20",
+            now,
+        );
+        assert!(result.is_ok(), "{}: {:?}", version, result);
+    }
+}
+
+#[test]
+fn manifest_freshness_rejects_replayed_manifests() {
+    let now = DateTime::parse_from_rfc3339("2026-10-19T12:00:00Z")
+        .expect("timestamp should parse")
+        .with_timezone(&Utc);
+
+    let expired = manifest_json("0.0.21", "2026-10-01T00:00:00Z");
+    let error =
+        UpdateSignatureService::check_manifest_freshness(&expired, MANIFEST_URL, "0.0.20", now)
+            .unwrap_err();
+    assert!(error.contains("expired"), "{}", error);
+
+    let older = manifest_json("0.0.19", "2026-11-18T12:00:00Z");
+    let error =
+        UpdateSignatureService::check_manifest_freshness(&older, MANIFEST_URL, "0.0.20", now)
+            .unwrap_err();
+    assert!(
+        error.contains("older than the installed 0.0.20"),
+        "{}",
+        error
+    );
+
+    let unstamped = serde_json::json!({ "version": "0.0.21" });
+    let error =
+        UpdateSignatureService::check_manifest_freshness(&unstamped, MANIFEST_URL, "0.0.20", now)
+            .unwrap_err();
+    assert!(error.contains("no expires_at"), "{}", error);
+}
@@ -0,0 +1,306 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use ring::signature::{Ed25519KeyPair, UnparsedPublicKey, ED25519};
+
+/// Base64 of the raw 32-byte Ed25519 public key, baked in at compile time.
+/// build.rs refuses release builds without it; only debug builds may run
+/// without a key, and they skip verification.
+const EMBEDDED_PUBLIC_KEY: Option<&str> = option_env!("SOURCCEY_UPDATE_PUBLIC_KEY");
+
+/// Extension of the detached signature published next to each signed file,
+/// e.g. `latest.json.sig` holding the base64 signature of `latest.json`.
+const SIGNATURE_EXTENSION: &str = ".sig";
+
+pub struct UpdateSignatureService;
+
+impl UpdateSignatureService {
+    pub fn embedded_public_key() -> Result<Option<Vec<u8>>, String> {
+        match EMBEDDED_PUBLIC_KEY {
+            Some(encoded) if !encoded.trim().is_empty() => {
+                let key = BASE64.decode(encoded.trim()).map_err(|e| {
+                    format!("Embedded update public key is not valid base64: {}", e)
+                })?;
+                if key.len() != 32 {
+                    return Err(format!(
+                        "Embedded update public key must be 32 bytes, got {}",
+                        key.len()
+                    ));
+                }
+                Ok(Some(key))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The key every signed download and bundle is checked against. `None`
+    /// only in debug builds without a key; release builds fail closed.
+    pub fn required_public_key() -> Result<Option<Vec<u8>>, String> {
+        match Self::embedded_public_key()? {
+            Some(key) => Ok(Some(key)),
+            None if Self::unverified_allowed() => Ok(None),
+            None => Err(
+                "This build has no update public key; refusing to trust unsigned update files"
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn unverified_allowed() -> bool {
+        cfg!(debug_assertions)
+    }
+
+    pub fn sign(payload: &[u8], pkcs8: &[u8]) -> Result<String, String> {
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8)
+            .map_err(|e| format!("Invalid Ed25519 signing key: {}", e))?;
+        Ok(BASE64.encode(key_pair.sign(payload).as_ref()))
+    }
+
+    /// Check a base64 Ed25519 `signature` over `payload`. `label` names the
+    /// signed thing in errors, e.g. "Updater manifest".
+    pub fn verify(
+        payload: &[u8],
+        signature: &str,
+        public_key: &[u8],
+        label: &str,
+    ) -> Result<(), String> {
+        let signature = BASE64
+            .decode(signature.trim())
+            .map_err(|e| format!("{} signature is not valid base64: {}", label, e))?;
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(payload, &signature)
+            .map_err(|_| format!("{} signature does not match the update signing key", label))
+    }
+
+    /// Verify a downloaded file against its detached signature. Without a key
+    /// only debug builds let the file through; a missing signature is always
+    /// an error once a key exists.
+    pub fn verify_download(
+        payload: &[u8],
+        signature: Option<&str>,
+        public_key: Option<&[u8]>,
+        label: &str,
+        url: &str,
+    ) -> Result<(), String> {
+        let public_key = match public_key {
+            Some(public_key) => public_key,
+            None if !Self::unverified_allowed() => {
+                return Err(format!(
+                    "{} from {} cannot be verified: this build has no update public key",
+                    label, url
+                ));
+            }
+            None => {
+                eprintln!(
+                    "[setup] No update public key embedded; {} from {} is not verified",
+                    label, url
+                );
+                return Ok(());
+            }
+        };
+        let signature = signature.ok_or_else(|| {
+            format!(
+                "{} from {} is not signed (expected {})",
+                label,
+                url,
+                Self::signature_url(url)
+            )
+        })?;
+        Self::verify(payload, signature, public_key, label)
+            .map_err(|error| format!("{} ({})", error, url))
+    }
+
+    pub fn signature_url(url: &str) -> String {
+        match url.split_once('?') {
+            Some((path, query)) => format!("{}{}?{}", path, SIGNATURE_EXTENSION, query),
+            None => format!("{}{}", url, SIGNATURE_EXTENSION),
+        }
+    }
+
+    /// Download `url` and verify it against `<url>.sig` before returning the
+    /// body, so callers never parse unverified bytes.
+    pub fn fetch_verified_blocking(url: &str, label: &str) -> Result<Vec<u8>, String> {
+        let public_key = Self::required_public_key()?;
+        let payload = Self::fetch_bytes_blocking(url, label)?;
+        let signature = match public_key {
+            Some(_) => Some(Self::fetch_signature_blocking(url, label)?),
+            None => None,
+        };
+        Self::verify_download(
+            &payload,
+            signature.as_deref(),
+            public_key.as_deref(),
+            label,
+            url,
+        )?;
+        Ok(payload)
+    }
+
+    pub async fn fetch_verified(url: &str, label: &str) -> Result<Vec<u8>, String> {
+        let public_key = Self::required_public_key()?;
+        let payload = Self::fetch_bytes(url, label).await?;
+        let signature = match public_key {
+            Some(_) => {
+                let signature_url = Self::signature_url(url);
+                let bytes =
+                    Self::fetch_bytes(&signature_url, &format!("{} signature", label)).await?;
+                Some(String::from_utf8_lossy(&bytes).to_string())
+            }
+            None => None,
+        };
+        Self::verify_download(
+            &payload,
+            signature.as_deref(),
+            public_key.as_deref(),
+            label,
+            url,
+        )?;
+        Ok(payload)
+    }
+
+    /// Download and verify the updater manifest, then reject it if it has
+    /// expired or targets an older version than `installed_version`. Both
+    /// fields are inside the signed bytes, so an old signed manifest cannot be
+    /// replayed to force a downgrade.
+    pub fn fetch_verified_manifest_blocking(
+        url: &str,
+        installed_version: &str,
+    ) -> Result<serde_json::Value, String> {
+        let body = Self::fetch_verified_blocking(url, "Updater manifest")?;
+        Self::parse_fresh_manifest(&body, url, installed_version, Utc::now())
+    }
+
+    pub async fn fetch_verified_manifest(
+        url: &str,
+        installed_version: &str,
+    ) -> Result<serde_json::Value, String> {
+        let body = Self::fetch_verified(url, "Updater manifest").await?;
+        Self::parse_fresh_manifest(&body, url, installed_version, Utc::now())
+    }
+
+    pub(crate) fn parse_fresh_manifest(
+        body: &[u8],
+        url: &str,
+        installed_version: &str,
+        now: DateTime<Utc>,
+    ) -> Result<serde_json::Value, String> {
+        let manifest = serde_json::from_slice::<serde_json::Value>(body)
+            .map_err(|e| format!("Failed to parse updater manifest from {}: {}", url, e))?;
+        // Unsigned manifests are only accepted by debug builds without a key,
+        // and local test manifests rarely carry an expiry.
+        if Self::embedded_public_key()?.is_none() {
+            return Ok(manifest);
+        }
+        Self::check_manifest_freshness(&manifest, url, installed_version, now)?;
+        Ok(manifest)
+    }
+
+    pub(crate) fn check_manifest_freshness(
+        manifest: &serde_json::Value,
+        url: &str,
+        installed_version: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let version = manifest
+            .get("version")
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| format!("Updater manifest from {} has no version", url))?;
+        let expires_at = manifest
+            .get("expires_at")
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| format!("Updater manifest from {} has no expires_at", url))?;
+        let expires_at = DateTime::parse_from_rfc3339(expires_at)
+            .map_err(|e| {
+                format!(
+                    "Updater manifest from {} has an invalid expires_at: {}",
+                    url, e
+                )
+            })?
+            .with_timezone(&Utc);
+        if expires_at <= now {
+            return Err(format!(
+                "Updater manifest from {} expired at {}",
+                url,
+                expires_at.to_rfc3339()
+            ));
+        }
+        match (
+            Self::version_segments(version),
+            Self::version_segments(installed_version),
+        ) {
+            (Some(manifest_version), Some(installed)) if manifest_version < installed => {
+                Err(format!(
+                    "Updater manifest from {} is for version {}, older than the installed {}",
+                    url, version, installed_version
+                ))
+            }
+            (Some(_), Some(_)) => Ok(()),
+            _ => Err(format!(
+                "Cannot compare updater manifest version {} with the installed {}",
+                version, installed_version
+            )),
+        }
+    }
+
+    fn version_segments(version: &str) -> Option<Vec<u64>> {
+        let core = version
+            .trim()
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()?;
+        let mut segments = core
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        while segments.len() < 3 {
+            segments.push(0);
+        }
+        Some(segments)
+    }
+
+    fn fetch_signature_blocking(url: &str, label: &str) -> Result<String, String> {
+        let signature_url = Self::signature_url(url);
+        let bytes = Self::fetch_bytes_blocking(&signature_url, &format!("{} signature", label))?;
+        Ok(String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    fn fetch_bytes_blocking(url: &str, label: &str) -> Result<Vec<u8>, String> {
+        let response = reqwest::blocking::get(url)
+            .map_err(|e| format!("Failed to download {} from {}: {}", label, url, e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "{} download failed ({}): {}",
+                label,
+                response.status(),
+                url
+            ));
+        }
+        response
+            .bytes()
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| format!("Failed to read {} from {}: {}", label, url, e))
+    }
+
+    async fn fetch_bytes(url: &str, label: &str) -> Result<Vec<u8>, String> {
+        let response = reqwest::get(url)
+            .await
+            .map_err(|e| format!("Failed to download {} from {}: {}", label, url, e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "{} download failed ({}): {}",
+                label,
+                response.status(),
+                url
+            ));
+        }
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| format!("Failed to read {} from {}: {}", label, url, e))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/update_signature_service_tests.rs"]
+mod update_signature_service_tests;
@@ -0,0 +1,63 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+
+export type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+
+export interface LogEntry {
+    timestamp: string;
+    level: LogLevel;
+    source: string;
+    nickname?: string;
+    commandLogId?: string;
+    pid?: number;
+    message: string;
+}
+
+export interface LogSubscriptionFilter {
+    sources?: string[];
+    nickname?: string;
+    level?: LogLevel;
+}
+
+export interface LogBatch {
+    subscriptionId: string;
+    dropped: number;
+    entries: LogEntry[];
+}
+
+//-------------------------------------------------------------------------//
+// Subscribe Logs
+//-------------------------------------------------------------------------//
+export const subscribeLogs = async (filter: LogSubscriptionFilter, onBatch: (batch: LogBatch) => void) => {
+    const subscriptionId = await invoke<string>('subscribe_logs', { filter });
+    let unlisten: UnlistenFn;
+    try {
+        unlisten = await listen<LogBatch>('log-batch', (event) => {
+            if (event.payload.subscriptionId === subscriptionId) {
+                onBatch(event.payload);
+            }
+        });
+    } catch (error) {
+        await invoke('unsubscribe_logs', { subscriptionId });
+        throw error;
+    }
+
+    return {
+        subscriptionId,
+        unsubscribe: async () => {
+            unlisten();
+            await invoke('unsubscribe_logs', { subscriptionId });
+        },
+    };
+};
+
+//-------------------------------------------------------------------------//
+// Pause / Resume
+//-------------------------------------------------------------------------//
+export const pauseLogSubscription = async (subscriptionId: string) => {
+    await invoke('pause_log_subscription', { subscriptionId });
+};
+
+export const resumeLogSubscription = async (subscriptionId: string) => {
+    await invoke('resume_log_subscription', { subscriptionId });
+};
@@ -0,0 +1,16 @@
+import type { RobotUsageStats } from '@/types/Models/robot-usage';
+import { invoke } from '@tauri-apps/api/core';
+
+//-------------------------------------------------------------------------//
+// Get Robot Usage Stats
+//-------------------------------------------------------------------------//
+export const getRobotUsageStats = async (ownedRobotId: string, days?: number): Promise<RobotUsageStats> => {
+    return await invoke<RobotUsageStats>('get_robot_usage_stats', { ownedRobotId, days });
+};
+
+//-------------------------------------------------------------------------//
+// Get Fleet Usage Stats
+//-------------------------------------------------------------------------//
+export const getFleetUsageStats = async (days?: number): Promise<RobotUsageStats[]> => {
+    return await invoke<RobotUsageStats[]>('get_fleet_usage_stats', { days });
+};
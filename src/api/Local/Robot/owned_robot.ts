@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
+import { OwnedRobotPurgeResult } from '@/types/Models/owned-robot';
 
 //-------------------------------------------------//
 // GET Robot Functions
@@ -51,3 +52,22 @@ export const deleteOwnedRobot = async (id: string) => {
     const result = await invoke('delete_owned_robot', { id });
     return result;
 };
+
+export const getDeletedOwnedRobots = async () => {
+    const result = await invoke('get_deleted_owned_robots', {});
+    return result;
+};
+
+export const restoreOwnedRobot = async (id: string) => {
+    const result = await invoke('restore_owned_robot', { id });
+    return result;
+};
+
+// `confirmation` must repeat the robot's nickname
+export const purgeOwnedRobot = async (id: string, confirmation: string) => {
+    const request = {
+        id,
+        confirmation,
+    };
+    return await invoke<OwnedRobotPurgeResult>('purge_owned_robot', { request });
+};
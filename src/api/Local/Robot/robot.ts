@@ -1,5 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { Robot } from '@/types/Models/robot';
+import type { Robot, RobotCapabilities, RobotCatalogueSeedResult } from '@/types/Models/robot';
 
 export const getAllRobots = async (): Promise<Robot[]> => {
     const result = await invoke<Robot[]>('get_all_robots');
@@ -13,3 +13,13 @@ export const upsertRobotTemplate = async (robotType?: string | null, robotName?:
     });
     return result;
 };
+
+export const getRobotCapabilities = async (nickname: string): Promise<RobotCapabilities> => {
+    const result = await invoke<RobotCapabilities>('get_robot_capabilities', { nickname });
+    return result;
+};
+
+export const seedRobotCatalogue = async (): Promise<RobotCatalogueSeedResult> => {
+    const result = await invoke<RobotCatalogueSeedResult>('seed_robot_catalogue');
+    return result;
+};
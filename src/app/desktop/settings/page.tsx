@@ -4,6 +4,7 @@ import { invoke, isTauri } from '@tauri-apps/api/core';
 import { useEffect, useState } from 'react';
 import { LinkButton } from '@/components/Elements/Link/LinkButton';
 import { AIRuntimeCard } from '@/components/Elements/Setup/AIRuntimeCard';
+import { OfflineBundleCard } from '@/components/Elements/Setup/OfflineBundleCard';
 import { useDesktopAppUpdateStatus } from '@/hooks/System/desktop-app-update.hook';
 import { useDesktopEnvironmentSettings } from '@/hooks/System/desktop-environment.hook';
 import { useLerobotUpdateStatus } from '@/hooks/System/lerobot-update.hook';
@@ -144,6 +145,8 @@ export default function DesktopSettingsPage() {
                 </div>
 
                 <AIRuntimeCard showSettingsLink={false} />
+
+                <OfflineBundleCard />
             </div>
         </div>
     );
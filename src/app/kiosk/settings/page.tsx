@@ -17,6 +17,7 @@ import {
 import { useGetAccessPointPassword } from '@/hooks/WIFI/access-point.hook';
 import { toastSuccessDefaults } from '@/utils/toast/toast-utils';
 import { getSavedWiFiSSIDs } from '@/hooks/WIFI/wifi.hook';
+import { useGetNetworkWatchdogStatus } from '@/hooks/WIFI/network-watchdog.hook';
 import clsx from 'clsx';
 import { setSystemInfo, useGetSystemInfo, type BatteryData } from '@/hooks/System/system-info.hook';
 import Link from 'next/link';
@@ -43,6 +44,7 @@ export default function KioskSettingsPage() {
     const isAccessPointEnabled = (accessPointEnabledData as boolean) ?? false;
     const { data: accessPointSSID }: any = useGetAccessPointSSID();
     const { data: accessPointPassword }: any = useGetAccessPointPassword();
+    const { data: networkStatus } = useGetNetworkWatchdogStatus();
 
     const [isTogglingAccessPoint, setIsTogglingAccessPoint] = useState(false);
     const [isSavingAccessPoint, setIsSavingAccessPoint] = useState(false);
@@ -381,6 +383,50 @@ export default function KioskSettingsPage() {
                         </div>
                     ) : (
                         <div className="space-y-4">
+                            {/* Live network status from the watchdog */}
+                            {networkStatus && (
+                                <div className="rounded-lg border border-slate-600 bg-slate-700/50 p-4">
+                                    <div className="flex items-center justify-between">
+                                        <span className="text-sm font-medium text-slate-300">Network Status</span>
+                                        <span
+                                            className={clsx(
+                                                'text-sm font-semibold',
+                                                networkStatus.mode === 'client' && 'text-green-400',
+                                                networkStatus.mode === 'access_point' && 'text-blue-400',
+                                                networkStatus.mode === 'disconnected' && 'text-red-400'
+                                            )}
+                                        >
+                                            {networkStatus.mode === 'client'
+                                                ? `Connected to ${networkStatus.clientSsid ?? 'network'}`
+                                                : networkStatus.mode === 'access_point'
+                                                  ? networkStatus.fallbackActive
+                                                      ? 'Fallback access point'
+                                                      : 'Access point'
+                                                  : 'Disconnected'}
+                                        </span>
+                                    </div>
+                                    {networkStatus.mode === 'access_point' && (
+                                        <div className="mt-3 space-y-2">
+                                            <div className="flex items-center justify-between">
+                                                <span className="text-sm text-slate-400">Broadcasting SSID</span>
+                                                <span className="text-sm font-semibold text-slate-300">
+                                                    {networkStatus.accessPointSsid ?? 'Unknown'}
+                                                </span>
+                                            </div>
+                                            <div className="flex items-center justify-between">
+                                                <span className="text-sm text-slate-400">Access Point IP</span>
+                                                <span className="text-sm font-semibold text-slate-300">
+                                                    {networkStatus.accessPointIp ?? 'Unknown'}
+                                                </span>
+                                            </div>
+                                        </div>
+                                    )}
+                                    {networkStatus.lastError && (
+                                        <p className="mt-2 text-xs text-yellow-300">{networkStatus.lastError}</p>
+                                    )}
+                                </div>
+                            )}
+
                             {/* Toggle for Access Point Mode */}
                             <div className="flex items-center justify-between rounded-lg border border-slate-600 bg-slate-700/50 p-4">
                                 <div className="flex flex-col">
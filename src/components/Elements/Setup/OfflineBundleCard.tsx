@@ -0,0 +1,131 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { open } from '@tauri-apps/plugin-dialog';
+import { toast } from 'react-toastify';
+import { toastErrorDefaults, toastSuccessDefaults } from '@/utils/toast/toast-utils';
+import { useCreateOfflineBundle, useInstallOfflineBundle } from '@/hooks/System/offline-bundle.hook';
+
+type OfflineBundleCardProps = {
+    className?: string;
+};
+
+export const OfflineBundleCard = ({ className }: OfflineBundleCardProps) => {
+    const { mutateAsync: installBundle, isPending: isInstalling } = useInstallOfflineBundle();
+    const { mutateAsync: createBundle, isPending: isCreating } = useCreateOfflineBundle();
+    const [log, setLog] = useState<string[]>([]);
+    const [error, setError] = useState('');
+    const isBusy = isInstalling || isCreating;
+
+    useEffect(() => {
+        let unlisten: UnlistenFn | undefined;
+        let cancelled = false;
+        const startListener = async () => {
+            unlisten = await listen<{ step: string; status: string; message?: string | null }>('setup:progress', (event) => {
+                const { message } = event.payload;
+                if (message) {
+                    setLog((prev) => [...prev, message]);
+                }
+            });
+            if (cancelled && unlisten) {
+                unlisten();
+            }
+        };
+
+        void startListener();
+        return () => {
+            cancelled = true;
+            if (unlisten) {
+                unlisten();
+            }
+        };
+    }, []);
+
+    const chooseDirectory = async (title: string): Promise<string | null> => {
+        const selected = await open({ directory: true, multiple: false, title });
+        return typeof selected === 'string' ? selected : null;
+    };
+
+    const handleInstall = async () => {
+        const path = await chooseDirectory('Select offline bundle');
+        if (!path) return;
+        setLog([]);
+        setError('');
+        try {
+            const summary = await installBundle(path);
+            toast.success(`Runtime installed from bundle${summary?.lerobotTag ? ` (${summary.lerobotTag})` : ''}.`, {
+                ...toastSuccessDefaults,
+            });
+        } catch (installError) {
+            const message = installError instanceof Error ? installError.message : 'Failed to install offline bundle.';
+            setError(message);
+            toast.error(message, { ...toastErrorDefaults });
+        }
+    };
+
+    const handleCreate = async () => {
+        const outputDir = await chooseDirectory('Select an empty folder for the bundle');
+        if (!outputDir) return;
+        setLog([]);
+        setError('');
+        try {
+            const summary = await createBundle(outputDir);
+            toast.success(`Offline bundle written to ${summary?.path ?? outputDir}.`, { ...toastSuccessDefaults });
+        } catch (createError) {
+            const message = createError instanceof Error ? createError.message : 'Failed to create offline bundle.';
+            setError(message);
+            toast.error(message, { ...toastErrorDefaults });
+        }
+    };
+
+    return (
+        <div className={`rounded-2xl border-2 border-slate-700 bg-slate-900 p-6 shadow-xl ${className ?? ''}`}>
+            <div className="flex flex-col gap-4">
+                <div>
+                    <div className="text-xs font-semibold tracking-[0.2em] text-slate-500 uppercase">Offline Bundle</div>
+                    <p className="mt-2 text-sm text-slate-300">
+                        Install the robot runtime from a bundle on a USB drive, or build one on a connected machine for robots without
+                        internet access. Bundles cover the runtime only; kiosk app updates still need a network connection.
+                    </p>
+                </div>
+                <div className="flex flex-wrap items-center gap-3">
+                    <button
+                        type="button"
+                        onClick={handleInstall}
+                        disabled={isBusy}
+                        className={`inline-flex items-center justify-center rounded-lg border px-4 py-2 text-sm font-semibold transition ${
+                            isBusy
+                                ? 'cursor-not-allowed border-slate-700/60 bg-slate-800/60 text-slate-400'
+                                : 'cursor-pointer border-amber-500/50 bg-amber-500/10 text-amber-100 hover:border-amber-400/70'
+                        }`}
+                    >
+                        {isInstalling ? 'Installing...' : 'Install From Bundle'}
+                    </button>
+                    <button
+                        type="button"
+                        onClick={handleCreate}
+                        disabled={isBusy}
+                        className={`inline-flex items-center justify-center rounded-lg border px-4 py-2 text-sm font-semibold transition ${
+                            isBusy
+                                ? 'cursor-not-allowed border-slate-700/60 bg-slate-800/60 text-slate-400'
+                                : 'cursor-pointer border-slate-600 text-slate-100 hover:border-slate-300'
+                        }`}
+                    >
+                        {isCreating ? 'Creating...' : 'Create Bundle'}
+                    </button>
+                </div>
+                {isBusy && log.length > 0 && (
+                    <div className="max-h-40 space-y-2 overflow-y-auto rounded-2xl border border-slate-700/70 bg-slate-900/70 p-4 text-xs text-slate-300 shadow-inner">
+                        {log.map((line, index) => (
+                            <div key={`${line}-${index}`} className="rounded-md border border-slate-800/80 bg-slate-950/70 px-3 py-2 text-slate-200">
+                                {line}
+                            </div>
+                        ))}
+                    </div>
+                )}
+                {error && <div className="text-sm text-red-300">{error}</div>}
+            </div>
+        </div>
+    );
+};
@@ -6,6 +6,7 @@ import { FaExclamationTriangle } from 'react-icons/fa';
 import { toast } from 'react-toastify';
 import { hasLoadedSystemInfo, useGetSystemInfo, type BatteryData } from '@/hooks/System/system-info.hook';
 import { toastWarningDefaults } from '@/utils/toast/toast-utils';
+import { WelcomeProvisioningPortal } from './WelcomeProvisioningPortal';
 import { WelcomeRegistrationSection } from './WelcomeRegistrationSection';
 import { WelcomeSystemStatus } from './WelcomeSystemStatus';
 import {
@@ -118,6 +119,7 @@ export const HomeWelcome = () => {
     return (
         <>
             <WelcomeSystemStatus nickname={nickname} robotType={robotType} systemInfo={systemInfo} isLoadingSystemInfo={isSystemInfoLoading} />
+            <WelcomeProvisioningPortal />
             <WelcomeRegistrationSection
                 cloudPairing={cloudPairing}
                 isLoadingCloudPairing={isLoadingCloudPairing}
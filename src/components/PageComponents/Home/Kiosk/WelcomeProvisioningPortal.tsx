@@ -0,0 +1,41 @@
+'use client';
+
+import { FaWifi } from 'react-icons/fa';
+import { useGetProvisioningPortalStatus } from '@/hooks/WIFI/provisioning-portal.hook';
+import { useGetNetworkWatchdogStatus } from '@/hooks/WIFI/network-watchdog.hook';
+
+export const WelcomeProvisioningPortal = () => {
+    const { data: portal } = useGetProvisioningPortalStatus();
+    const { data: networkStatus } = useGetNetworkWatchdogStatus();
+
+    if (!portal?.active) return null;
+
+    return (
+        <div className="flex flex-col gap-4 rounded-xl border-2 border-blue-500/60 bg-slate-800 p-6 backdrop-blur-sm">
+            <div className="flex items-center gap-3">
+                <FaWifi className="h-6 w-6 text-blue-400" />
+                <div>
+                    <h2 className="text-xl font-semibold text-white">Setup Mode</h2>
+                    <p className="mt-1 text-sm text-slate-300">
+                        Join the robot&apos;s WiFi from your phone, then open the setup page and enter the PIN below.
+                    </p>
+                </div>
+            </div>
+
+            <div className="grid grid-cols-1 gap-4 md:grid-cols-3">
+                <div className="rounded-lg border border-slate-700 bg-slate-800/50 p-4">
+                    <div className="text-sm text-slate-400">WiFi Network</div>
+                    <div className="mt-1 text-lg font-semibold break-all text-white">{networkStatus?.accessPointSsid ?? '...'}</div>
+                </div>
+                <div className="rounded-lg border border-slate-700 bg-slate-800/50 p-4">
+                    <div className="text-sm text-slate-400">Setup Page</div>
+                    <div className="mt-1 text-lg font-semibold break-all text-white">{portal.url ?? `port ${portal.port}`}</div>
+                </div>
+                <div className="rounded-lg border border-slate-700 bg-slate-800/50 p-4">
+                    <div className="text-sm text-slate-400">PIN</div>
+                    <div className="mt-1 font-mono text-3xl font-bold tracking-widest text-white">{portal.pin ?? '------'}</div>
+                </div>
+            </div>
+        </div>
+    );
+};
@@ -255,6 +255,7 @@ export interface RemoteInferenceConfig {
     display_ip: string | null;
     display_port: number | null;
     display_compressed_images: boolean;
+    skip_compatibility_check?: boolean;
 }
 
 export interface RemoteRolloutConfig {
@@ -263,4 +264,5 @@ export interface RemoteRolloutConfig {
     model_path: string;
     task: string;
     duration: number;
+    skip_compatibility_check?: boolean;
 }
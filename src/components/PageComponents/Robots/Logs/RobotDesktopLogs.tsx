@@ -1,9 +1,9 @@
 'use client';
 
+import { pauseLogSubscription, resumeLogSubscription, subscribeLogs, type LogBatch } from '@/api/Local/Log/log-stream';
 import { useDesktopEnvironmentSettings } from '@/hooks/System/desktop-environment.hook';
 import type { DesktopEnvironmentSettings } from '@/types/desktop-environment';
 import { appendCondensedLogs } from '@/utils/logs/control-logs/log-stream';
-import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { useState, useEffect, useRef, useCallback } from 'react';
 import { FaTerminal, FaChevronDown, FaChevronUp, FaTrash } from 'react-icons/fa';
 
@@ -14,13 +14,6 @@ type RobotDesktopLogsProps = {
     mode?: 'teleoperation' | 'recording' | 'rollout' | 'inference';
 };
 
-type SshPayload = {
-    nickname?: string;
-    message?: string;
-    error?: string;
-    output?: string;
-};
-
 const LOG_LEVEL_PRIORITY: Record<DesktopEnvironmentSettings['teleopLogLevel'], number> = {
     debug: 10,
     info: 20,
@@ -62,7 +55,7 @@ const MODE_COPY = {
         idleLabel: 'Teleop idle',
         waitingLabel: 'Waiting for teleop output...',
         inactiveLabel: 'Teleop log stream is inactive',
-        eventNames: ['teleop-log'],
+        source: 'teleop',
     },
     recording: {
         title: 'Recording Logs',
@@ -70,7 +63,7 @@ const MODE_COPY = {
         idleLabel: 'Recording idle',
         waitingLabel: 'Waiting for recording output...',
         inactiveLabel: 'Recording log stream is inactive',
-        eventNames: ['record-log'],
+        source: 'record',
     },
     rollout: {
         title: 'Rollout Logs',
@@ -78,7 +71,7 @@ const MODE_COPY = {
         idleLabel: 'Rollout idle',
         waitingLabel: 'Waiting for rollout output...',
         inactiveLabel: 'Rollout log stream is inactive',
-        eventNames: ['rollout-log'],
+        source: 'rollout',
     },
     inference: {
         title: 'Inference Logs',
@@ -86,7 +79,7 @@ const MODE_COPY = {
         idleLabel: 'Inference idle',
         waitingLabel: 'Waiting for inference output...',
         inactiveLabel: 'Inference log stream is inactive',
-        eventNames: ['inference-log'],
+        source: 'inference',
     },
 } as const;
 
@@ -102,6 +95,7 @@ export const RobotDesktopLogs = ({
     const logsRef = useRef<string[]>([]);
     const pendingLogsRef = useRef<string[]>([]);
     const flushTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
+    const subscriptionIdRef = useRef<string | null>(null);
     const teleopLogLevel = desktopEnvironmentSettings?.teleopLogLevel ?? 'warning';
     const modeCopy = MODE_COPY[mode];
 
@@ -179,34 +173,43 @@ export const RobotDesktopLogs = ({
         }
 
         let isActive = true;
-        const unlistenFns: UnlistenFn[] = [];
+        let unsubscribe: (() => Promise<void>) | null = null;
 
-        const matchesNickname = (payload?: SshPayload) => {
-            if (!nickname) return true;
-            return payload?.nickname === nickname;
+        const handleBatch = (batch: LogBatch) => {
+            if (!isActive) return;
+            if (batch.dropped > 0) {
+                appendLog(`[${nickname ?? 'logs'}] ... ${batch.dropped} lines skipped, see the log files for full output`);
+            }
+            for (const entry of batch.entries) {
+                appendLog(entry.nickname ? `[${entry.nickname}] ${entry.message}` : entry.message);
+            }
         };
 
-        const setupListeners = async () => {
-            for (const eventName of modeCopy.eventNames) {
-                const unlisten = await listen<string>(eventName, (event) => {
-                    if (!isActive) return;
-                    appendLog(event.payload);
-                });
+        subscribeLogs({ sources: [modeCopy.source], nickname }, handleBatch)
+            .then((subscription) => {
                 if (!isActive) {
-                    unlisten();
+                    void subscription.unsubscribe();
                     return;
                 }
-                unlistenFns.push(unlisten);
-            }
-        };
-
-        setupListeners();
+                subscriptionIdRef.current = subscription.subscriptionId;
+                unsubscribe = subscription.unsubscribe;
+            })
+            .catch((error) => console.error('Failed to subscribe to logs:', error));
 
         return () => {
             isActive = false;
-            unlistenFns.forEach((fn) => fn());
+            subscriptionIdRef.current = null;
+            void unsubscribe?.();
         };
-    }, [appendLog, isControlling, modeCopy.eventNames, nickname]);
+    }, [appendLog, isControlling, modeCopy.source, nickname]);
+
+    useEffect(() => {
+        const subscriptionId = subscriptionIdRef.current;
+        if (!subscriptionId) return;
+
+        const update = isExpanded ? resumeLogSubscription : pauseLogSubscription;
+        update(subscriptionId).catch((error) => console.error('Failed to update log subscription:', error));
+    }, [isExpanded]);
 
     const containerClassName = embedded
         ? 'overflow-hidden rounded-2xl border-2 border-slate-700/70 bg-slate-900/60 shadow-[0_18px_40px_rgba(15,23,42,0.22)]'
@@ -0,0 +1,128 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useMutation, useQuery } from '@tanstack/react-query';
+import { queryClient } from '@/hooks/default';
+import { AI_MODEL_KEY, AiModelCheckpoint } from '@/hooks/Models/AIModel/ai-model.hook';
+
+export type ModelStorageUsage = {
+    id: string;
+    name: string;
+    modelPath: string;
+    sizeBytes: number;
+    // Oldest first
+    checkpoints: AiModelCheckpoint[];
+    lastUsedAt: string | null;
+};
+
+export type DatasetStorageUsage = {
+    repoId: string;
+    path: string;
+    sizeBytes: number;
+    lastModifiedAt: string | null;
+};
+
+export type StorageReport = {
+    modelsRoot: string;
+    datasetsRoot: string;
+    diskTotalBytes: number | null;
+    diskFreeBytes: number | null;
+    modelBytes: number;
+    datasetBytes: number;
+    // Largest first
+    models: ModelStorageUsage[];
+    datasets: DatasetStorageUsage[];
+};
+
+export type StorageCleanupPolicy = {
+    enabled: boolean;
+    minFreeBytes: number;
+    targetFreeBytes: number;
+    keepCheckpoints: number | null;
+    includeDatasets: boolean;
+    protectRecentHours: number;
+};
+
+export type StorageCleanupResult = {
+    triggered: boolean;
+    freeBytesBefore: number;
+    freeBytesAfter: number | null;
+    freedBytes: number;
+    prunedCheckpoints: number;
+    removedModels: string[];
+    removedDatasets: string[];
+    errors: string[];
+};
+
+export const STORAGE_REPORT_KEY = ['storage', 'report'];
+export const STORAGE_CLEANUP_POLICY_KEY = ['storage', 'cleanup-policy'];
+export const STORAGE_CLEANUP_EVENT = 'storage-cleanup';
+
+export const getStorageReport = async (): Promise<StorageReport> => {
+    return await invoke<StorageReport>('get_storage_report');
+};
+
+export const deleteDataset = async (repoId: string): Promise<number> => {
+    return await invoke<number>('delete_dataset', { repoId });
+};
+
+export const pruneAiModelCheckpoints = async (id: string, keep: number): Promise<AiModelCheckpoint[]> => {
+    return await invoke<AiModelCheckpoint[]>('prune_ai_model_checkpoints', { id, keep });
+};
+
+export const getStorageCleanupPolicy = async (): Promise<StorageCleanupPolicy> => {
+    return await invoke<StorageCleanupPolicy>('get_storage_cleanup_policy');
+};
+
+export const saveStorageCleanupPolicy = async (policy: StorageCleanupPolicy): Promise<StorageCleanupPolicy> => {
+    return await invoke<StorageCleanupPolicy>('save_storage_cleanup_policy', { policy });
+};
+
+export const runStorageCleanup = async (): Promise<StorageCleanupResult> => {
+    return await invoke<StorageCleanupResult>('run_storage_cleanup');
+};
+
+export const useGetStorageReport = () =>
+    useQuery({
+        queryKey: STORAGE_REPORT_KEY,
+        queryFn: async () => getStorageReport(),
+    });
+
+export const useDeleteDataset = () =>
+    useMutation({
+        mutationFn: async (repoId: string) => deleteDataset(repoId),
+        onSuccess: () => {
+            queryClient.invalidateQueries({ queryKey: STORAGE_REPORT_KEY });
+        },
+    });
+
+export const usePruneAiModelCheckpoints = () =>
+    useMutation({
+        mutationFn: async ({ id, keep }: { id: string; keep: number }) => pruneAiModelCheckpoints(id, keep),
+        onSuccess: (_removed, { id }) => {
+            queryClient.invalidateQueries({ queryKey: STORAGE_REPORT_KEY });
+            queryClient.invalidateQueries({ queryKey: AI_MODEL_KEY });
+            queryClient.invalidateQueries({ queryKey: [...AI_MODEL_KEY, 'id', id] });
+        },
+    });
+
+export const useGetStorageCleanupPolicy = () =>
+    useQuery({
+        queryKey: STORAGE_CLEANUP_POLICY_KEY,
+        queryFn: async () => getStorageCleanupPolicy(),
+    });
+
+export const useSaveStorageCleanupPolicy = () =>
+    useMutation({
+        mutationFn: async (policy: StorageCleanupPolicy) => saveStorageCleanupPolicy(policy),
+        onSuccess: (policy) => {
+            queryClient.setQueryData(STORAGE_CLEANUP_POLICY_KEY, policy);
+        },
+    });
+
+export const useRunStorageCleanup = () =>
+    useMutation({
+        mutationFn: async () => runStorageCleanup(),
+        onSuccess: () => {
+            queryClient.invalidateQueries({ queryKey: STORAGE_REPORT_KEY });
+            queryClient.invalidateQueries({ queryKey: AI_MODEL_KEY });
+        },
+    });
@@ -9,11 +9,48 @@ export type AiModel = {
     model_path: string;
     model_path_relative?: string | null;
     latest_checkpoint?: number | null;
+    policy_type?: string | null;
+    // JSON-encoded string arrays
+    input_features?: string | null;
+    output_features?: string | null;
+    camera_keys?: string | null;
+    dataset_repo_id?: string | null;
+    training_steps?: number | null;
+    source_repo_id?: string | null;
+    source_revision?: string | null;
+    // JSON-encoded AiModelCheckpoint[]
+    checkpoints?: string | null;
     created_at?: string | null;
     updated_at?: string | null;
+    last_used_at?: string | null;
     deleted_at?: string | null;
 };
 
+export type AiModelCheckpoint = {
+    step: number;
+    path: string;
+    sizeBytes: number;
+};
+
+export type AiModelFilters = {
+    search?: string;
+    policyType?: string;
+    datasetRepoId?: string;
+    sourceRepoId?: string;
+    cameraKey?: string;
+    hasCheckpoints?: boolean;
+};
+
+export const parseAiModelList = <T = string>(value?: string | null): T[] => {
+    if (!value) return [];
+    try {
+        const parsed = JSON.parse(value);
+        return Array.isArray(parsed) ? parsed : [];
+    } catch {
+        return [];
+    }
+};
+
 export const AI_MODEL_KEY = ['ai-models'];
 
 export const getAiModel = async (id: string): Promise<AiModel | null> => {
@@ -28,8 +65,9 @@ export const updateAiModel = async (model: AiModel): Promise<AiModel> => {
     return await invoke<AiModel>('update_ai_model', { model });
 };
 
-export const deleteAiModel = async (id: string): Promise<boolean> => {
-    return await invoke<boolean>('delete_ai_model', { id });
+// deleteFiles also removes the model directory from the cache.
+export const deleteAiModel = async (id: string, deleteFiles = false): Promise<boolean> => {
+    return await invoke<boolean>('delete_ai_model', { id, deleteFiles });
 };
 
 export const useGetAiModel = (id: string, enabled = true) =>
@@ -42,25 +80,27 @@ export const useGetAiModel = (id: string, enabled = true) =>
         enabled: enabled && !!id,
     });
 
-export const useGetAiModelsPaginated = (page: number = 1, pageSize: number = 20, enabled = true) =>
+export const useGetAiModelsPaginated = (page: number = 1, pageSize: number = 20, enabled = true, filters?: AiModelFilters) =>
     useQuery({
-        queryKey: [...AI_MODEL_KEY, 'paginated', page, pageSize],
+        queryKey: [...AI_MODEL_KEY, 'paginated', page, pageSize, filters],
         queryFn: async (): Promise<PaginatedResponse<AiModel>> => {
             return await invoke('get_ai_models_paginated', {
                 pagination: { page, page_size: pageSize },
+                filters,
             });
         },
         enabled,
     });
 
-export const useGetAiModelsInfinite = (pageSize: number = 20, enabled = true) =>
+export const useGetAiModelsInfinite = (pageSize: number = 20, enabled = true, filters?: AiModelFilters) =>
     useInfiniteQuery({
-        queryKey: [...AI_MODEL_KEY, 'infinite', pageSize],
+        queryKey: [...AI_MODEL_KEY, 'infinite', pageSize, filters],
         queryFn: async ({ pageParam }) => {
             if (!enabled) return null;
             const page = pageParam || 1;
             return await invoke<PaginatedResponse<AiModel>>('get_ai_models_paginated', {
                 pagination: { page, page_size: pageSize },
+                filters,
             });
         },
         initialPageParam: 1,
@@ -96,8 +136,9 @@ export const useUpdateAiModel = () =>
 
 export const useDeleteAiModel = () =>
     useMutation({
-        mutationFn: async (id: string) => deleteAiModel(id),
-        onSuccess: (_result, id) => {
+        mutationFn: async ({ id, deleteFiles }: { id: string; deleteFiles?: boolean }) =>
+            deleteAiModel(id, deleteFiles),
+        onSuccess: (_result, { id }) => {
             queryClient.invalidateQueries({ queryKey: AI_MODEL_KEY });
             queryClient.invalidateQueries({ queryKey: [...AI_MODEL_KEY, 'id', id] });
         },
@@ -123,6 +164,32 @@ export const useGetAiModelCachePath = () =>
         queryFn: async () => getAiModelCachePath(),
     });
 
+export type CompatibilityIssue = {
+    kind: string;
+    severity: 'error' | 'warning';
+    message: string;
+    expected: string | null;
+    actual: string | null;
+};
+
+export type CompatibilityReport = {
+    modelPath: string;
+    robotType: string;
+    compatible: boolean;
+    issues: CompatibilityIssue[];
+};
+
+export const checkAiModelCompatibility = async (nickname: string, modelPath: string): Promise<CompatibilityReport> => {
+    return await invoke<CompatibilityReport>('check_ai_model_compatibility', { nickname, modelPath });
+};
+
+export const useCheckAiModelCompatibility = (nickname: string, modelPath: string, enabled = true) =>
+    useQuery({
+        queryKey: [...AI_MODEL_KEY, 'compatibility', nickname, modelPath],
+        queryFn: async () => checkAiModelCompatibility(nickname, modelPath),
+        enabled: enabled && !!nickname && !!modelPath,
+    });
+
 export const downloadAiModelFromHuggingface = async (repoId: string, modelName?: string): Promise<string> => {
     return await invoke<string>('download_ai_model_from_huggingface', {
         repoId,
@@ -139,3 +206,64 @@ export const useDownloadAiModelFromHuggingface = () =>
             queryClient.invalidateQueries({ queryKey: AI_MODEL_KEY });
         },
     });
+
+export type AiModelDownloadStatus = 'queued' | 'downloading' | 'paused' | 'completed' | 'failed' | 'cancelled';
+
+export type AiModelDownload = {
+    id: string;
+    repo_id: string;
+    model_name?: string | null;
+    revision?: string | null;
+    allow_patterns?: string | null;
+    ignore_patterns?: string | null;
+    status: AiModelDownloadStatus;
+    error_message?: string | null;
+    created_at?: string | null;
+    updated_at?: string | null;
+    completed_at?: string | null;
+};
+
+export type AiModelDownloadRequest = {
+    repoId: string;
+    modelName?: string;
+    revision?: string;
+    allowPatterns?: string[];
+    ignorePatterns?: string[];
+};
+
+export const AI_MODEL_DOWNLOAD_KEY = [...AI_MODEL_KEY, 'downloads'];
+export const AI_MODEL_DOWNLOAD_QUEUE_EVENT = 'ai-model-download-queue';
+
+export const queueAiModelDownload = async (request: AiModelDownloadRequest): Promise<AiModelDownload> => {
+    return await invoke<AiModelDownload>('queue_ai_model_download', { request });
+};
+
+export const getAiModelDownloads = async (limit?: number): Promise<AiModelDownload[]> => {
+    return await invoke<AiModelDownload[]>('get_ai_model_downloads', { limit });
+};
+
+export const pauseAiModelDownload = async (repoId: string): Promise<AiModelDownload> => {
+    return await invoke<AiModelDownload>('pause_ai_model_download', { repoId });
+};
+
+export const resumeAiModelDownload = async (repoId: string): Promise<AiModelDownload> => {
+    return await invoke<AiModelDownload>('resume_ai_model_download', { repoId });
+};
+
+export const cancelAiModelDownload = async (repoId: string): Promise<AiModelDownload> => {
+    return await invoke<AiModelDownload>('cancel_ai_model_download', { repoId });
+};
+
+export const useGetAiModelDownloads = () =>
+    useQuery({
+        queryKey: AI_MODEL_DOWNLOAD_KEY,
+        queryFn: async () => getAiModelDownloads(),
+    });
+
+export const useQueueAiModelDownload = () =>
+    useMutation({
+        mutationFn: async (request: AiModelDownloadRequest) => queueAiModelDownload(request),
+        onSuccess: () => {
+            queryClient.invalidateQueries({ queryKey: AI_MODEL_DOWNLOAD_KEY });
+        },
+    });
@@ -0,0 +1,99 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useMutation, useQuery } from '@tanstack/react-query';
+import { queryClient } from '@/hooks/default';
+import { AI_MODEL_KEY } from '@/hooks/Models/AIModel/ai-model.hook';
+
+export type HuggingFaceTokenSource = 'app' | 'environment' | 'cli_login';
+
+export type HuggingFaceTokenStatus = {
+    hasToken: boolean;
+    source: HuggingFaceTokenSource | null;
+    maskedToken: string | null;
+};
+
+export type HubRepoType = 'model' | 'dataset';
+
+export type HubUploadRequest = {
+    repoId: string;
+    repoType?: HubRepoType;
+    localPath?: string;
+    commitMessage?: string;
+    private?: boolean;
+    revision?: string;
+};
+
+export type HubUploadOutcome = {
+    repoId: string;
+    repoType: HubRepoType;
+    commitOid: string;
+    commitUrl: string | null;
+    regularFiles: number;
+    lfsFiles: number;
+    reusedLfsFiles: number;
+    ignoredFiles: number;
+    uploadedBytes: number;
+};
+
+export type HubUploadPhase = 'preparing' | 'uploading' | 'committing' | 'completed';
+
+export type HubUploadProgressEvent = {
+    repoId: string;
+    repoType: HubRepoType;
+    status: 'uploading' | 'completed' | 'error';
+    progress: number | null;
+    phase: HubUploadPhase | null;
+    uploadedBytes: number | null;
+    totalBytes: number | null;
+    currentFile: string | null;
+    message: string | null;
+    updatedAtEpochMs: number;
+};
+
+export const HUGGING_FACE_TOKEN_KEY = ['hugging-face', 'token'];
+export const HUGGING_FACE_UPLOAD_EVENT = 'hugging-face-upload-progress';
+
+export const getHuggingFaceTokenStatus = async (): Promise<HuggingFaceTokenStatus> => {
+    return await invoke<HuggingFaceTokenStatus>('get_hugging_face_token_status');
+};
+
+export const saveHuggingFaceToken = async (token: string): Promise<HuggingFaceTokenStatus> => {
+    return await invoke<HuggingFaceTokenStatus>('save_hugging_face_token', { token });
+};
+
+export const clearHuggingFaceToken = async (): Promise<HuggingFaceTokenStatus> => {
+    return await invoke<HuggingFaceTokenStatus>('clear_hugging_face_token');
+};
+
+export const uploadToHuggingFace = async (request: HubUploadRequest): Promise<HubUploadOutcome> => {
+    return await invoke<HubUploadOutcome>('upload_to_hugging_face', { request });
+};
+
+export const useGetHuggingFaceTokenStatus = () =>
+    useQuery({
+        queryKey: HUGGING_FACE_TOKEN_KEY,
+        queryFn: async () => getHuggingFaceTokenStatus(),
+    });
+
+export const useSaveHuggingFaceToken = () =>
+    useMutation({
+        mutationFn: async (token: string) => saveHuggingFaceToken(token),
+        onSuccess: (status) => {
+            queryClient.setQueryData(HUGGING_FACE_TOKEN_KEY, status);
+        },
+    });
+
+export const useClearHuggingFaceToken = () =>
+    useMutation({
+        mutationFn: async () => clearHuggingFaceToken(),
+        onSuccess: (status) => {
+            queryClient.setQueryData(HUGGING_FACE_TOKEN_KEY, status);
+        },
+    });
+
+export const useUploadToHuggingFace = () =>
+    useMutation({
+        mutationFn: async (request: HubUploadRequest) => uploadToHuggingFace(request),
+        onSuccess: () => {
+            queryClient.invalidateQueries({ queryKey: AI_MODEL_KEY });
+        },
+    });
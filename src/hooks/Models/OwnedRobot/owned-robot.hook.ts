@@ -1,6 +1,14 @@
-import { getOwnedRobotById, getOwnedRobotByNickname, getOwnedRobots } from '@/api/Local/Robot/owned_robot';
+import {
+    deleteOwnedRobot,
+    getDeletedOwnedRobots,
+    getOwnedRobotById,
+    getOwnedRobotByNickname,
+    getOwnedRobots,
+    purgeOwnedRobot,
+    restoreOwnedRobot,
+} from '@/api/Local/Robot/owned_robot';
 import { queryClient } from '@/hooks/default';
-import { useQuery } from '@tanstack/react-query';
+import { useMutation, useQuery } from '@tanstack/react-query';
 
 export const BASE_OWNED_ROBOT_KEY = 'owned-robot';
 
@@ -46,6 +54,37 @@ export const useGetOwnedRobots = (enabled: boolean = true) => {
     });
 };
 
+//---------------------------------------------------------------------------------------------------//
+// Deleted Owned Robot Hooks and Functions
+//---------------------------------------------------------------------------------------------------//
+export const DELETED_OWNED_ROBOT_KEY = [BASE_OWNED_ROBOT_KEY, 'deleted'];
+
+const invalidateOwnedRobots = () => queryClient.invalidateQueries({ queryKey: [BASE_OWNED_ROBOT_KEY] });
+
+export const useGetDeletedOwnedRobots = () =>
+    useQuery({
+        queryKey: DELETED_OWNED_ROBOT_KEY,
+        queryFn: async () => getDeletedOwnedRobots(),
+    });
+
+export const useDeleteOwnedRobot = () =>
+    useMutation({
+        mutationFn: async (id: string) => deleteOwnedRobot(id),
+        onSuccess: invalidateOwnedRobots,
+    });
+
+export const useRestoreOwnedRobot = () =>
+    useMutation({
+        mutationFn: async (id: string) => restoreOwnedRobot(id),
+        onSuccess: invalidateOwnedRobots,
+    });
+
+export const usePurgeOwnedRobot = () =>
+    useMutation({
+        mutationFn: async ({ id, confirmation }: { id: string; confirmation: string }) => purgeOwnedRobot(id, confirmation),
+        onSuccess: invalidateOwnedRobots,
+    });
+
 //---------------------------------------------------------------------------------------------------//
 // Selected Owned Robot Hooks and Functions
 //---------------------------------------------------------------------------------------------------//
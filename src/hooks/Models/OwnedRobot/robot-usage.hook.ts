@@ -0,0 +1,35 @@
+import { getFleetUsageStats, getRobotUsageStats } from '@/api/Local/Log/robot-usage';
+import { useQuery } from '@tanstack/react-query';
+
+export const BASE_ROBOT_USAGE_KEY = 'robot-usage';
+
+//---------------------------------------------------------------------------------------------------//
+// Robot Usage Hooks and Functions
+//---------------------------------------------------------------------------------------------------//
+
+export const useGetRobotUsageStats = (ownedRobotId: string | null, days?: number, enabled: boolean = true) => {
+    return useQuery({
+        queryKey: [BASE_ROBOT_USAGE_KEY, ownedRobotId, days],
+        queryFn: async () => {
+            if (!ownedRobotId) return null;
+            return await getRobotUsageStats(ownedRobotId, days);
+        },
+        enabled: enabled && !!ownedRobotId,
+        staleTime: 60_000,
+        refetchOnWindowFocus: false,
+        placeholderData: (previousData) => previousData,
+    });
+};
+
+export const useGetFleetUsageStats = (days?: number, enabled: boolean = true) => {
+    return useQuery({
+        queryKey: [BASE_ROBOT_USAGE_KEY, 'fleet', days],
+        queryFn: async () => {
+            return await getFleetUsageStats(days);
+        },
+        enabled,
+        staleTime: 60_000,
+        refetchOnWindowFocus: false,
+        placeholderData: (previousData) => previousData,
+    });
+};
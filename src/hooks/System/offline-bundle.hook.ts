@@ -0,0 +1,46 @@
+import { invoke, isTauri } from '@tauri-apps/api/core';
+import { useMutation } from '@tanstack/react-query';
+import { queryClient } from '@/hooks/default';
+import { DESKTOP_EXTRAS_KEY, formatSetupInvokeError } from '@/hooks/System/setup-desktop-extras.hook';
+import { LEROBOT_UPDATE_KEY } from '@/hooks/System/lerobot-update.hook';
+
+export type OfflineBundleSummary = {
+    path: string;
+    platform: string;
+    lerobotTag?: string | null;
+    createdAt: string;
+    fileCount: number;
+    signed: boolean;
+};
+
+export const installOfflineBundle = async (path: string): Promise<OfflineBundleSummary | null> => {
+    if (!isTauri()) return null;
+    try {
+        return await invoke<OfflineBundleSummary>('setup_offline_bundle_install', { path });
+    } catch (error) {
+        throw new Error(formatSetupInvokeError(error));
+    }
+};
+
+export const createOfflineBundle = async (outputDir: string): Promise<OfflineBundleSummary | null> => {
+    if (!isTauri()) return null;
+    try {
+        return await invoke<OfflineBundleSummary>('setup_offline_bundle_create', { outputDir });
+    } catch (error) {
+        throw new Error(formatSetupInvokeError(error));
+    }
+};
+
+export const useInstallOfflineBundle = () =>
+    useMutation({
+        mutationFn: installOfflineBundle,
+        onSuccess: () => {
+            queryClient.invalidateQueries({ queryKey: DESKTOP_EXTRAS_KEY });
+            queryClient.invalidateQueries({ queryKey: LEROBOT_UPDATE_KEY });
+        },
+    });
+
+export const useCreateOfflineBundle = () =>
+    useMutation({
+        mutationFn: createOfflineBundle,
+    });
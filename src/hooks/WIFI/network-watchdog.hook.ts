@@ -0,0 +1,50 @@
+import { queryClient } from '@/hooks/default';
+import { useQuery } from '@tanstack/react-query';
+import { invoke, isTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useEffect } from 'react';
+
+export const BASE_NETWORK_WATCHDOG_KEY = 'network-watchdog';
+
+export const NETWORK_WATCHDOG_STATUS_KEY = [BASE_NETWORK_WATCHDOG_KEY, 'status'];
+
+const NETWORK_MODE_EVENT = 'kiosk:network-mode';
+
+export type NetworkMode = 'client' | 'access_point' | 'disconnected';
+
+export interface NetworkWatchdogStatus {
+    mode: NetworkMode;
+    fallbackActive: boolean;
+    clientSsid: string | null;
+    accessPointSsid: string | null;
+    accessPointIp: string | null;
+    lastError: string | null;
+}
+
+//---------------------------------------------------------------------------------------------------//
+// Network Watchdog Functions
+//---------------------------------------------------------------------------------------------------//
+
+export const getNetworkWatchdogStatus = async (): Promise<NetworkWatchdogStatus | null> => {
+    if (!isTauri()) return null;
+    return await invoke<NetworkWatchdogStatus>('get_network_watchdog_status');
+};
+
+export const useGetNetworkWatchdogStatus = () => {
+    useEffect(() => {
+        if (!isTauri()) return;
+        const unlisten = listen<NetworkWatchdogStatus>(NETWORK_MODE_EVENT, (event) => {
+            queryClient.setQueryData(NETWORK_WATCHDOG_STATUS_KEY, event.payload);
+        });
+        return () => {
+            unlisten.then((fn) => fn());
+        };
+    }, []);
+
+    return useQuery({
+        queryKey: NETWORK_WATCHDOG_STATUS_KEY,
+        queryFn: getNetworkWatchdogStatus,
+        refetchInterval: 15_000,
+        refetchOnWindowFocus: false,
+    });
+};
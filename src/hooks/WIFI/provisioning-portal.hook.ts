@@ -0,0 +1,47 @@
+import { queryClient } from '@/hooks/default';
+import { useQuery } from '@tanstack/react-query';
+import { invoke, isTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useEffect } from 'react';
+
+export const BASE_PROVISIONING_PORTAL_KEY = 'provisioning-portal';
+
+export const PROVISIONING_PORTAL_STATUS_KEY = [BASE_PROVISIONING_PORTAL_KEY, 'status'];
+
+const PROVISIONING_PORTAL_EVENT = 'kiosk:provisioning-portal';
+
+export interface ProvisioningPortalStatus {
+    active: boolean;
+    port: number;
+    url: string | null;
+    pin: string | null;
+}
+
+//---------------------------------------------------------------------------------------------------//
+// Provisioning Portal Functions
+//---------------------------------------------------------------------------------------------------//
+
+export const getProvisioningPortalStatus = async (): Promise<ProvisioningPortalStatus | null> => {
+    if (!isTauri()) return null;
+    return await invoke<ProvisioningPortalStatus>('get_provisioning_portal_status');
+};
+
+// The PIN rotates after every login, so the event keeps the screen current.
+export const useGetProvisioningPortalStatus = () => {
+    useEffect(() => {
+        if (!isTauri()) return;
+        const unlisten = listen<ProvisioningPortalStatus>(PROVISIONING_PORTAL_EVENT, (event) => {
+            queryClient.setQueryData(PROVISIONING_PORTAL_STATUS_KEY, event.payload);
+        });
+        return () => {
+            unlisten.then((fn) => fn());
+        };
+    }, []);
+
+    return useQuery({
+        queryKey: PROVISIONING_PORTAL_STATUS_KEY,
+        queryFn: getProvisioningPortalStatus,
+        refetchInterval: 15_000,
+        refetchOnWindowFocus: false,
+    });
+};
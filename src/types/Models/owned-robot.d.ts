@@ -10,3 +10,11 @@ export interface OwnedRobot {
     updated_at: string; // DateTime in C# maps to string in TypeScript (ISO format)
     deleted_at: string; // DateTime in C# maps to string in TypeScript (ISO format)
 }
+
+export interface OwnedRobotPurgeResult {
+    id: string;
+    nickname: string | null;
+    detached_command_logs: number;
+    removed_calibration_files: number;
+    removed_config_files: number;
+}
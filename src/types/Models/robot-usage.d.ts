@@ -0,0 +1,37 @@
+export type RobotActivity = 'teleop' | 'record' | 'rollout' | 'inference' | 'calibrate' | 'host' | 'other';
+
+export interface ActivityStats {
+    activity: RobotActivity;
+    runs: number;
+    succeeded: number;
+    failed: number;
+    cancelled: number;
+    running: number;
+    active_seconds: number;
+    failure_rate: number | null; // null until a run has finished
+}
+
+export interface DailyActivity {
+    date: string; // YYYY-MM-DD, UTC
+    runs: number;
+    failed: number;
+    active_seconds: number;
+}
+
+export interface RobotUsageStats {
+    owned_robot_id: string;
+    nickname: string | null;
+    since: string | null; // null when the totals cover all time
+    total_runs: number;
+    failed_runs: number;
+    failure_rate: number | null;
+    active_hours: number;
+    teleop_hours: number;
+    recording_sessions: number;
+    recorded_episodes: number;
+    rollout_runs: number;
+    last_active_at: string | null;
+    last_calibrated_at: string | null;
+    by_activity: ActivityStats[];
+    daily: DailyActivity[];
+}
@@ -7,6 +7,14 @@ export interface Robot {
     image: string | null;
     github_url: string | null;
     robot_type: string | null;
+    catalogue_version?: number | null;
+    arm_count?: number | null;
+    // JSON-encoded RobotPorts
+    default_ports?: string | null;
+    client_robot_type?: string | null;
+    // JSON-encoded string arrays
+    teleop_types?: string | null;
+    lerobot_commands?: string | null;
     created_at: string | null;
     updated_at: string | null;
     deleted_at: string | null;
@@ -14,6 +22,29 @@ export interface Robot {
     media: RobotMedia[];
 }
 
+export type RobotCommand = 'teleop' | 'record' | 'rollout' | 'inference' | 'calibrate';
+
+export interface RobotPorts {
+    command: number | null;
+    observation: number | null;
+}
+
+export interface RobotCapabilities {
+    arm_count: number;
+    default_ports: RobotPorts;
+    client_robot_type: string | null;
+    teleop_types: string[];
+    lerobot_commands: RobotCommand[];
+}
+
+export interface RobotCatalogueSeedResult {
+    inserted: number;
+    updated: number;
+    unchanged: number;
+    removed: number;
+    adopted: number;
+}
+
 export interface RobotMedia {
     id?: string | null;
     name: string;
@@ -26,107 +26,6 @@ export const filterLogs = (logs: string[]) => {
     });
 };
 
-//----------------------------------------------------------------
-// Log Listener Functions - BULLETPROOF VERSION
-//----------------------------------------------------------------
-
-class LogListenerManager {
-    private static instance: LogListenerManager | null = null;
-    private listeners: Map<string, () => void> = new Map();
-    private isSetup = false;
-    private setupPromise: Promise<void> | null = null;
-    private onLogReceived: ((log: string) => void) | null = null;
-
-    private constructor() {}
-
-    public static getInstance(): LogListenerManager {
-        if (!LogListenerManager.instance) {
-            LogListenerManager.instance = new LogListenerManager();
-        }
-        return LogListenerManager.instance;
-    }
-
-    public async setup(onLogReceived: (log: string) => void): Promise<void> {
-        // Store the callback
-        this.onLogReceived = onLogReceived;
-
-        // If already setup, return immediately
-        if (this.isSetup) {
-            return;
-        }
-
-        // If setup is in progress, wait for it
-        if (this.setupPromise) {
-            await this.setupPromise;
-            return;
-        }
-
-        // Start setup
-        this.setupPromise = this._doSetup();
-        await this.setupPromise;
-    }
-
-    private async _doSetup(): Promise<void> {
-        const logEvents = ['teleop-log', 'record-log', 'replay-log', 'evaluate-log', 'inference-log'];
-
-        for (const eventName of logEvents) {
-            const unlisten = await this.createLogListener(eventName);
-            this.listeners.set(eventName, unlisten);
-        }
-
-        this.isSetup = true;
-    }
-
-    private async createLogListener(eventName: string): Promise<() => void> {
-        try {
-            const unlisten = await listen<string>(eventName, (event: any) => {
-                if (this.onLogReceived) {
-                    this.onLogReceived(event.payload);
-                }
-            });
-
-            return unlisten;
-        } catch (error) {
-            console.error(`Failed to create listener for ${eventName}:`, error);
-            return () => {}; // Return a no-op function as fallback
-        }
-    }
-
-    public cleanup(): void {
-        this.listeners.forEach((unlisten, eventName) => {
-            try {
-                unlisten();
-            } catch (error) {
-                console.error(`Error cleaning up listener for ${eventName}:`, error);
-            }
-        });
-        this.listeners.clear();
-        this.isSetup = false;
-        this.setupPromise = null;
-        this.onLogReceived = null;
-    }
-
-    public isListenerSetup(): boolean {
-        return this.isSetup;
-    }
-}
-
-// Export the singleton instance
-export const logListenerManager = LogListenerManager.getInstance();
-
-// New setup function that uses the singleton
-export const setupLogListeners = async (onLogReceived: (log: string) => void) => {
-    await logListenerManager.setup(onLogReceived);
-
-    // Return a cleanup function that does nothing since we're using a singleton
-    return () => {};
-};
-
-// Global cleanup function (call this when the app is shutting down)
-export const cleanupAllLogListeners = () => {
-    logListenerManager.cleanup();
-};
-
 //----------------------------------------------------------------
 // Process Shutdown Listener Functions - BULLETPROOF VERSION
 //----------------------------------------------------------------